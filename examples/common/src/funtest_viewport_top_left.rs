@@ -0,0 +1,123 @@
+//! This functional test renders a quad covering a small viewport which origin is at the top-left corner of an
+//! offscreen framebuffer, and reads back the texels to check that the quad lands in the top-left corner.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::{PipelineState, Viewport},
+  pixel::NormRGBA8UI,
+  render_state::RenderState,
+  shader::Program,
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+const VS: &str = "
+const vec2[4] POSITIONS = vec2[](
+  vec2(-1., -1.),
+  vec2( 1., -1.),
+  vec2( 1.,  1.),
+  vec2(-1.,  1.)
+);
+
+void main() {
+  gl_Position = vec4(POSITIONS[gl_VertexID], 0., 1.);
+}";
+
+const FS: &str = "
+out vec4 frag;
+
+void main() {
+  frag = vec4(1., 1., 1., 1.);
+}";
+
+const SIZE: u32 = 64;
+const RECT_SIZE: u32 = 16;
+
+pub struct LocalExample {
+  program: Program<(), (), ()>,
+  tess: Tess<()>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ()>()
+      .from_strings(VS, None, None, FS)
+      .unwrap()
+      .ignore_warnings();
+
+    let tess = context
+      .new_tess()
+      .set_mode(Mode::TriangleFan)
+      .set_render_vertex_nb(4)
+      .build()
+      .unwrap();
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([SIZE, SIZE], 0, Sampler::default())
+      .unwrap();
+
+    LocalExample {
+      program,
+      tess,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let program = &mut self.program;
+    let tess = &self.tess;
+    let pipeline_state = PipelineState::default()
+      .set_clear_color([0., 0., 0., 1.])
+      .set_viewport(Viewport::SpecificTopLeft {
+        x: 0,
+        y: 0,
+        width: RECT_SIZE,
+        height: RECT_SIZE,
+      });
+
+    context
+      .new_pipeline_gate()
+      .pipeline(&self.framebuffer, &pipeline_state, |_, mut shd_gate| {
+        shd_gate.shade(program, |_, _, mut rdr_gate| {
+          rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+            tess_gate.render(tess)
+          })
+        })
+      })
+      .assume()
+      .into_result()
+      .expect("offscreen render");
+
+    // texels are stored bottom row first
+    let texels = self.framebuffer.color_slot().get_raw_texels().unwrap();
+    let texel = |x: u32, y_from_top: u32| {
+      let i = (((SIZE - 1 - y_from_top) * SIZE + x) * 4) as usize;
+      &texels[i..i + 4]
+    };
+
+    let inside = RECT_SIZE / 2;
+    let outside = SIZE - RECT_SIZE / 2;
+
+    assert_eq!(texel(inside, inside), [255, 255, 255, 255], "top-left");
+    assert_eq!(texel(inside, outside), [0, 0, 0, 255], "bottom-left");
+    assert_eq!(texel(outside, inside), [0, 0, 0, 255], "top-right");
+
+    log::info!("top-left viewport rendered in the top-left corner");
+
+    LoopFeedback::Exit
+  }
+}
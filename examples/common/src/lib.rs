@@ -44,12 +44,14 @@ pub mod render_state;
 pub mod shader_data;
 pub mod shader_uniform_adapt;
 pub mod shader_uniforms;
+pub mod shadow_map;
 pub mod shared;
 pub mod skybox;
 pub mod sliced_tess;
 pub mod stencil;
 pub mod texture;
 pub mod vertex_instancing;
+pub mod wireframe;
 
 // functional tests
 #[cfg(feature = "funtest")]
@@ -66,6 +68,8 @@ pub mod funtest_pixel_array_encoding;
 pub mod funtest_scissor_test;
 #[cfg(feature = "funtest")]
 pub mod funtest_tess_no_data;
+#[cfg(feature = "funtest")]
+pub mod funtest_viewport_top_left;
 
 /// Example interface.
 pub trait Example<B = Backend>: Sized
@@ -149,4 +153,10 @@ pub trait PlatformServices {
 
   /// Fetch the next texture, if available.
   fn fetch_texture(&mut self) -> Result<image::RgbImage, Self::FetchError>;
+
+  /// Load the texture asset named `name`.
+  ///
+  /// How `name` is resolved to the actual image is platform-specific; for instance, the desktop platform resolves it
+  /// as a path relative to the executable.
+  fn load_texture(&mut self, name: &str) -> Result<image::RgbImage, Self::FetchError>;
 }
@@ -27,6 +27,8 @@ impl Example for LocalExample {
       "Maximum number of elements in a texture array: {:?}",
       q.max_texture_array_elements()
     );
+    log::info!("Backend limits: {:#?}", q.limits());
+    log::info!("sRGB-capable back buffer: {}", q.back_buffer_is_srgb());
 
     LocalExample
   }
@@ -0,0 +1,334 @@
+//! This program shows how to implement shadow mapping with a depth texture sampled with depth comparison.
+//!
+//! The scene — a cube floating over a flattened cube acting as the ground — is first rendered from the point of view
+//! of a directional light into a depth-only framebuffer: the shadow map. The scene is then rendered from the camera,
+//! and the depth of every fragment, as seen by the light, is compared with the depth stored in the shadow map to know
+//! whether the fragment is lit.
+//!
+//! The shadow map is created with depth comparison enabled in its sampler, and is sampled with a shadow sampler
+//! (`sampler2DShadow`) in the shader. Because the sampler uses a linear filter, the hardware interpolates the results
+//! of the comparisons of neighboring texels: that’s percentage-closer filtering (PCF). A few more samples are taken
+//! around each fragment to smooth the edges of the shadows even more.
+//!
+//! Press the main action to pause or resume the rotation of the camera.
+//!
+//! <https://docs.rs/luminance>
+
+use crate::{
+  shared::{cube, CubeVertex, VertexIndex},
+  Example, InputAction, LoopFeedback, PlatformServices,
+};
+use cgmath::{ortho, perspective, Deg, Matrix4, Point3, Rad, Vector3};
+use luminance::UniformInterface;
+use luminance_front::{
+  context::GraphicsContext,
+  depth_stencil::Comparison,
+  framebuffer::Framebuffer,
+  pipeline::{PipelineState, TextureBinding},
+  pixel::{Depth32F, Shadow},
+  render_state::RenderState,
+  shader::{
+    types::{Mat44, Vec3},
+    Program, Uniform,
+  },
+  tess::{Mode, Tess},
+  texture::{Dim2, MagFilter, MinFilter, Sampler},
+  Backend,
+};
+
+// depth pass: render the scene from the light
+const DEPTH_VS: &str = r#"
+in vec3 co3;
+
+uniform mat4 light_view_projection;
+uniform mat4 model;
+
+void main() {
+  gl_Position = light_view_projection * model * vec4(co3, 1.);
+}
+"#;
+
+// only the depth is needed
+const DEPTH_FS: &str = r#"
+void main() {
+}
+"#;
+
+// shading pass: render the scene from the camera, fetching the shadow map
+const SHADING_VS: &str = r#"
+in vec3 co3;
+in vec3 nor;
+
+out vec3 v_nor;
+out vec4 v_light_co;
+
+uniform mat4 projection;
+uniform mat4 view;
+uniform mat4 model;
+uniform mat4 light_view_projection;
+
+void main() {
+  vec4 world_co = model * vec4(co3, 1.);
+
+  v_nor = mat3(transpose(inverse(model))) * nor;
+  v_light_co = light_view_projection * world_co;
+  gl_Position = projection * view * world_co;
+}
+"#;
+
+const SHADING_FS: &str = r#"
+in vec3 v_nor;
+in vec4 v_light_co;
+
+out vec4 frag;
+
+uniform vec3 light_dir;
+uniform vec3 color;
+uniform sampler2DShadow shadow_map;
+
+void main() {
+  float diffuse = max(dot(normalize(v_nor), normalize(light_dir)), 0.);
+
+  // position of the fragment in the shadow map, with its depth as seen by the light
+  vec3 co = v_light_co.xyz / v_light_co.w * .5 + .5;
+
+  // offset the depth to compare with to prevent surfaces from shadowing themselves (shadow acne)
+  float bias = max(.005 * (1. - diffuse), .0005);
+
+  // each lookup compares the depth with the shadow map and returns the filtered result of the comparisons (PCF); we
+  // average a few of them around the fragment
+  vec2 texel_size = 1. / vec2(textureSize(shadow_map, 0));
+  float lit = 0.;
+  for (int y = -1; y <= 1; ++y) {
+    for (int x = -1; x <= 1; ++x) {
+      lit += texture(shadow_map, vec3(co.xy + vec2(x, y) * texel_size, co.z - bias));
+    }
+  }
+  lit /= 9.;
+
+  frag = vec4(color * (.2 + .8 * diffuse * lit), 1.);
+  frag = pow(frag, vec4(1./2.2));
+}
+"#;
+
+// size of the shadow map, in texels
+const SHADOW_MAP_SIZE: u32 = 1024;
+
+// direction pointing to the light
+const LIGHT_DIR: [f32; 3] = [-0.4, 0.8, 0.45];
+
+const FOVY: Deg<f32> = Deg(60.);
+const Z_NEAR: f32 = 0.1;
+const Z_FAR: f32 = 100.;
+
+#[derive(UniformInterface)]
+struct DepthShaderInterface {
+  light_view_projection: Uniform<Mat44<f32>>,
+  model: Uniform<Mat44<f32>>,
+}
+
+#[derive(UniformInterface)]
+struct ShadingShaderInterface {
+  projection: Uniform<Mat44<f32>>,
+  view: Uniform<Mat44<f32>>,
+  model: Uniform<Mat44<f32>>,
+  light_view_projection: Uniform<Mat44<f32>>,
+  light_dir: Uniform<Vec3<f32>>,
+  color: Uniform<Vec3<f32>>,
+  // the shadow map is sampled with depth comparison, so it needs a shadow sampler
+  shadow_map: Uniform<TextureBinding<Dim2, Shadow>>,
+}
+
+// an object of the scene
+struct Object {
+  model: Matrix4<f32>,
+  color: [f32; 3],
+}
+
+pub struct LocalExample {
+  depth_program: Program<(), (), DepthShaderInterface>,
+  shading_program: Program<(), (), ShadingShaderInterface>,
+  cube: Tess<CubeVertex, VertexIndex>,
+  shadow_map: Framebuffer<Dim2, (), Depth32F>,
+  light_view_projection: Matrix4<f32>,
+  aspect_ratio: f32,
+  rotating: bool,
+  cam_angle: f32,
+  last_time: f32,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let depth_program = context
+      .new_shader_program::<(), (), DepthShaderInterface>()
+      .from_strings(DEPTH_VS, None, None, DEPTH_FS)
+      .expect("depth program creation")
+      .ignore_warnings();
+
+    let shading_program = context
+      .new_shader_program::<(), (), ShadingShaderInterface>()
+      .from_strings(SHADING_VS, None, None, SHADING_FS)
+      .expect("shading program creation")
+      .ignore_warnings();
+
+    let (cube_vertices, cube_indices) = cube(1.);
+    let cube = context
+      .new_tess()
+      .set_vertices(&cube_vertices[..])
+      .set_indices(&cube_indices[..])
+      .set_mode(Mode::TriangleStrip)
+      .enable_primitive_restart_fixed()
+      .build()
+      .expect("cube tess creation");
+
+    // the shadow map only has a depth texture; depth comparison is enabled in its sampler, and linear filtering
+    // interpolates the results of the comparisons
+    let sampler = Sampler {
+      min_filter: MinFilter::Linear,
+      mag_filter: MagFilter::Linear,
+      depth_comparison: Some(Comparison::LessOrEqual),
+      ..Sampler::default()
+    };
+    let shadow_map = context
+      .new_framebuffer::<Dim2, (), Depth32F>([SHADOW_MAP_SIZE, SHADOW_MAP_SIZE], 0, sampler)
+      .expect("shadow map creation");
+
+    // the light is directional, so it uses an orthographic projection covering the whole scene
+    let light_dir = Vector3::from(LIGHT_DIR);
+    let light_view = Matrix4::look_at_rh(
+      Point3::new(0., 0., 0.) + light_dir * 5.,
+      Point3::new(0., 0., 0.),
+      Vector3::unit_y(),
+    );
+    let light_view_projection = ortho(-5., 5., -5., 5., 0.1, 10.) * light_view;
+
+    Self {
+      depth_program,
+      shading_program,
+      cube,
+      shadow_map,
+      light_view_projection,
+      aspect_ratio: 1.,
+      rotating: true,
+      cam_angle: 0.,
+      last_time: 0.,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    time: f32,
+    back_buffer: Framebuffer<Dim2, (), ()>,
+    actions: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    for action in actions {
+      match action {
+        InputAction::Quit => return LoopFeedback::Exit,
+        InputAction::MainToggle => self.rotating = !self.rotating,
+        InputAction::Resized { width, height } => {
+          self.aspect_ratio = width as f32 / height as f32;
+        }
+        _ => (),
+      }
+    }
+
+    if self.rotating {
+      self.cam_angle += (time - self.last_time) * 0.3;
+    }
+    self.last_time = time;
+
+    let objects = [
+      // the ground
+      Object {
+        model: Matrix4::from_translation(Vector3::new(0., -0.1, 0.))
+          * Matrix4::from_nonuniform_scale(8., 0.2, 8.),
+        color: [0.8, 0.8, 0.8],
+      },
+      // the floating cube
+      Object {
+        model: Matrix4::from_translation(Vector3::new(0., 1.2, 0.))
+          * Matrix4::from_angle_y(Rad(time))
+          * Matrix4::from_angle_x(Rad(time * 0.7)),
+        color: [0.9, 0.3, 0.2],
+      },
+    ];
+
+    let projection = perspective(FOVY, self.aspect_ratio, Z_NEAR, Z_FAR);
+    let view = Matrix4::look_at_rh(
+      Point3::new(5. * self.cam_angle.cos(), 3.5, 5. * self.cam_angle.sin()),
+      Point3::new(0., 0.5, 0.),
+      Vector3::unit_y(),
+    );
+    let light_view_projection = Mat44::new(self.light_view_projection);
+
+    let mut builder = context.new_pipeline_gate();
+    let depth_program = &mut self.depth_program;
+    let shading_program = &mut self.shading_program;
+    let cube = &self.cube;
+    let shadow_map = &mut self.shadow_map;
+
+    // render the depth of the scene, as seen by the light, into the shadow map
+    let render = builder
+      .pipeline(shadow_map, &PipelineState::default(), |_, mut shd_gate| {
+        shd_gate.shade(depth_program, |mut iface, unis, mut rdr_gate| {
+          iface.set(&unis.light_view_projection, light_view_projection);
+
+          for object in &objects {
+            iface.set(&unis.model, Mat44::new(object.model));
+
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(cube)
+            })?;
+          }
+
+          Ok(())
+        })
+      })
+      .assume();
+
+    if render.is_err() {
+      return LoopFeedback::Exit;
+    }
+
+    // render the scene from the camera, using the shadow map to know which fragments are lit
+    let render = builder
+      .pipeline(
+        &back_buffer,
+        &PipelineState::default().set_clear_color([0.1, 0.1, 0.15, 1.]),
+        |pipeline, mut shd_gate| {
+          let shadow_map = pipeline.bind_texture(shadow_map.depth_stencil_slot())?;
+
+          shd_gate.shade(shading_program, |mut iface, unis, mut rdr_gate| {
+            iface.set(&unis.projection, Mat44::new(projection));
+            iface.set(&unis.view, Mat44::new(view));
+            iface.set(&unis.light_view_projection, light_view_projection);
+            iface.set(&unis.light_dir, LIGHT_DIR.into());
+            // the shadow map is bound with a shadow binding, matching the sampler2DShadow uniform
+            iface.set(&unis.shadow_map, shadow_map.shadow_binding());
+
+            for object in &objects {
+              iface.set(&unis.model, Mat44::new(object.model));
+              iface.set(&unis.color, object.color.into());
+
+              rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+                tess_gate.render(cube)
+              })?;
+            }
+
+            Ok(())
+          })
+        },
+      )
+      .assume();
+
+    if render.is_ok() {
+      LoopFeedback::Continue(self)
+    } else {
+      LoopFeedback::Exit
+    }
+  }
+}
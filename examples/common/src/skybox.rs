@@ -192,7 +192,7 @@ impl Example for LocalExample {
       .set_vertices(&cube_vertices[..])
       .set_indices(&cube_indices[..])
       .set_mode(Mode::TriangleStrip)
-      .set_primitive_restart_index(VertexIndex::max_value())
+      .enable_primitive_restart_fixed()
       .build()
       .expect("cube tess creation");
 
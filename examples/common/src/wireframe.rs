@@ -0,0 +1,232 @@
+//! This program shows how to change the polygon mode of the render state to render the edges of the triangles of a
+//! mesh — i.e. its wireframe — which is a handy way to debug the topology of meshes.
+//!
+//! A rotating cube is rendered with one of the following views:
+//!
+//! - Filled: the regular, filled cube.
+//! - Overlay: the filled cube, with its wireframe rendered atop of it. This is a typical debug overlay.
+//! - Wireframe: only the wireframe of the cube.
+//!
+//! The overlay is rendered in a second pass, with the same geometry: the depth test must then accept fragments with
+//! the same depth as the ones already rendered.
+//!
+//! Polygon modes are not supported by all backends (e.g. WebGL2), which always fill polygons.
+//!
+//! Press the <main action> to cycle through the views.
+//!
+//! <https://docs.rs/luminance>
+
+use crate::{
+  shared::{cube, CubeVertex, VertexIndex},
+  Example, InputAction, LoopFeedback, PlatformServices,
+};
+use cgmath::{perspective, Deg, Matrix4, Point3, Rad, Vector3};
+use luminance::UniformInterface;
+use luminance_front::{
+  context::GraphicsContext,
+  depth_stencil::Comparison,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  render_state::{PolygonMode, RenderState},
+  shader::{
+    types::{Mat44, Vec3},
+    Program, Uniform,
+  },
+  tess::{Mode, Tess},
+  texture::Dim2,
+  Backend,
+};
+
+const VS: &str = r#"
+in vec3 co3;
+in vec3 nor;
+
+out vec3 v_nor;
+
+uniform mat4 projection;
+uniform mat4 view;
+uniform mat4 model;
+
+void main() {
+  v_nor = mat3(model) * nor;
+  gl_Position = projection * view * model * vec4(co3, 1.);
+}
+"#;
+
+const FS: &str = r#"
+in vec3 v_nor;
+
+out vec4 frag;
+
+uniform vec3 color;
+uniform float lighting;
+
+void main() {
+  // the wireframe is not lit (lighting = 0), so that it remains visible whatever the orientation of the faces
+  float diffuse = max(dot(normalize(v_nor), normalize(vec3(1., 1., 1.))), 0.) * .8 + .2;
+  frag = vec4(color * mix(1., diffuse, lighting), 1.);
+}
+"#;
+
+const FILL_COLOR: [f32; 3] = [0.3, 0.5, 0.8];
+const WIREFRAME_COLOR: [f32; 3] = [1., 0.8, 0.2];
+
+const FOVY: Deg<f32> = Deg(60.);
+const Z_NEAR: f32 = 0.1;
+const Z_FAR: f32 = 10.;
+
+#[derive(UniformInterface)]
+struct ShaderInterface {
+  projection: Uniform<Mat44<f32>>,
+  view: Uniform<Mat44<f32>>,
+  model: Uniform<Mat44<f32>>,
+  color: Uniform<Vec3<f32>>,
+  lighting: Uniform<f32>,
+}
+
+// the different ways to render the cube
+#[derive(Clone, Copy, Debug)]
+enum View {
+  Filled,
+  Overlay,
+  Wireframe,
+}
+
+impl View {
+  fn cycle(self) -> Self {
+    match self {
+      View::Filled => View::Overlay,
+      View::Overlay => View::Wireframe,
+      View::Wireframe => View::Filled,
+    }
+  }
+
+  // whether the filled cube is rendered
+  fn filled(self) -> bool {
+    matches!(self, View::Filled | View::Overlay)
+  }
+
+  // whether the wireframe is rendered
+  fn wireframe(self) -> bool {
+    matches!(self, View::Overlay | View::Wireframe)
+  }
+}
+
+pub struct LocalExample {
+  program: Program<(), (), ShaderInterface>,
+  cube: Tess<CubeVertex, VertexIndex>,
+  aspect_ratio: f32,
+  view: View,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ShaderInterface>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let (cube_vertices, cube_indices) = cube(0.5);
+    let cube = context
+      .new_tess()
+      .set_vertices(&cube_vertices[..])
+      .set_indices(&cube_indices[..])
+      .set_mode(Mode::TriangleStrip)
+      .enable_primitive_restart_fixed()
+      .build()
+      .expect("cube tess creation");
+
+    Self {
+      program,
+      cube,
+      aspect_ratio: 1.,
+      view: View::Overlay,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    time: f32,
+    back_buffer: Framebuffer<Dim2, (), ()>,
+    actions: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    for action in actions {
+      match action {
+        InputAction::Quit => return LoopFeedback::Exit,
+
+        InputAction::MainToggle => {
+          self.view = self.view.cycle();
+          log::info!("now rendering {:?}", self.view);
+        }
+
+        InputAction::Resized { width, height } => {
+          self.aspect_ratio = width as f32 / height as f32;
+        }
+
+        _ => (),
+      }
+    }
+
+    let projection = perspective(FOVY, self.aspect_ratio, Z_NEAR, Z_FAR);
+    let view = Matrix4::look_at_rh(
+      Point3::new(0., 1., 2.),
+      Point3::new(0., 0., 0.),
+      Vector3::unit_y(),
+    );
+    let model = Matrix4::from_angle_y(Rad(time)) * Matrix4::from_angle_x(Rad(time * 0.7));
+
+    let program = &mut self.program;
+    let cube = &self.cube;
+    let cube_view = self.view;
+
+    // the wireframe is rendered with the same geometry as the filled cube, so its fragments have the same depth as
+    // the ones already rendered
+    let wireframe_state = RenderState::default()
+      .set_polygon_mode(PolygonMode::Line)
+      .set_depth_test(Comparison::LessOrEqual);
+
+    let render = context
+      .new_pipeline_gate()
+      .pipeline(
+        &back_buffer,
+        &PipelineState::default().set_clear_color([0.1, 0.1, 0.1, 1.]),
+        |_, mut shd_gate| {
+          shd_gate.shade(program, |mut iface, unis, mut rdr_gate| {
+            iface.set(&unis.projection, Mat44::new(projection));
+            iface.set(&unis.view, Mat44::new(view));
+            iface.set(&unis.model, Mat44::new(model));
+
+            if cube_view.filled() {
+              iface.set(&unis.color, FILL_COLOR.into());
+              iface.set(&unis.lighting, 1.);
+
+              rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+                tess_gate.render(cube)
+              })?;
+            }
+
+            if cube_view.wireframe() {
+              iface.set(&unis.color, WIREFRAME_COLOR.into());
+              iface.set(&unis.lighting, 0.);
+
+              rdr_gate.render(&wireframe_state, |mut tess_gate| tess_gate.render(cube))?;
+            }
+
+            Ok(())
+          })
+        },
+      )
+      .assume();
+
+    if render.is_ok() {
+      LoopFeedback::Continue(self)
+    } else {
+      LoopFeedback::Exit
+    }
+  }
+}
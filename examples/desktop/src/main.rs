@@ -118,7 +118,7 @@ where
 
   // render a dummy frame to pass a single action containing the initial framebuffer size; some examples will use a
   // default size that is not correct, and this will allow them to bootstrap correctly
-  let (fb_w, fb_h) = context.window.get_framebuffer_size();
+  let [fb_w, fb_h] = context.framebuffer_size();
   let feedback = example.render_frame(
     0.,
     context.back_buffer().unwrap(),
@@ -135,7 +135,7 @@ where
 
   'app: loop {
     // handle events
-    context.window.glfw.poll_events();
+    context.window_mut().glfw.poll_events();
     let actions = glfw::flush_messages(&events).flat_map(|(_, event)| adapt_events(event));
 
     let elapsed = start_t.elapsed();
@@ -149,7 +149,7 @@ where
 
     if let LoopFeedback::Continue(stepped) = feedback {
       example = stepped;
-      context.window.swap_buffers();
+      context.window_mut().swap_buffers();
     } else {
       break 'app;
     }
@@ -229,6 +229,8 @@ examples! {
   "skybox", skybox,
   "shader-data", shader_data,
   "stencil", stencil,
+  "shadow-map", shadow_map,
+  "wireframe", wireframe,
 
   // examples that do not use luminance-front but luminance polymorphic interface directly
   polymorphic examples:
@@ -243,6 +245,7 @@ examples! {
   "funtest-flatten-slice", funtest_flatten_slice,
   "funtest-pixel-array-encoding", funtest_pixel_array_encoding,
   "funtest-483-indices-mut-corruption", funtest_483_indices_mut_corruption,
+  "funtest-viewport-top-left", funtest_viewport_top_left,
 }
 
 fn main() {
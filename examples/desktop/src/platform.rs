@@ -3,7 +3,7 @@
 use crate::CLIOpts;
 use image::ImageError;
 use luminance_examples::PlatformServices;
-use std::{error::Error, fmt};
+use std::{env, error::Error, fmt, io, path::PathBuf};
 
 /// Desktop implementation of the [`PlatformServices`] API.
 #[derive(Debug)]
@@ -37,6 +37,7 @@ impl DesktopPlatformServices {
 #[derive(Debug)]
 pub enum DesktopFetchError {
   NoMoreTexture,
+  IOError(io::Error),
   ImageError(ImageError),
 }
 
@@ -44,6 +45,7 @@ impl fmt::Display for DesktopFetchError {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
     match self {
       DesktopFetchError::NoMoreTexture => f.write_str("no more texture, sorry"),
+      DesktopFetchError::IOError(ref e) => write!(f, "cannot locate asset: {}", e),
       DesktopFetchError::ImageError(ref e) => write!(f, "cannot fetch texture: {}", e),
     }
   }
@@ -51,6 +53,12 @@ impl fmt::Display for DesktopFetchError {
 
 impl Error for DesktopFetchError {}
 
+impl From<io::Error> for DesktopFetchError {
+  fn from(source: io::Error) -> Self {
+    Self::IOError(source)
+  }
+}
+
 impl From<ImageError> for DesktopFetchError {
   fn from(source: ImageError) -> Self {
     Self::ImageError(source)
@@ -67,4 +75,25 @@ impl PlatformServices for DesktopPlatformServices {
       Ok(self.textures.remove(0)) // bit of a cost but for small textures who cares?
     }
   }
+
+  fn load_texture(&mut self, name: &str) -> Result<image::RgbImage, Self::FetchError> {
+    let path = asset_path(name)?;
+    let img = image::open(path)?;
+    Ok(img.flipv().to_rgb8())
+  }
+}
+
+/// Resolve an asset path relative to the directory containing the executable.
+///
+/// Absolute paths are returned unchanged.
+fn asset_path(name: &str) -> Result<PathBuf, io::Error> {
+  let exe = env::current_exe()?;
+  let dir = exe.parent().ok_or_else(|| {
+    io::Error::new(
+      io::ErrorKind::NotFound,
+      "executable has no parent directory",
+    )
+  })?;
+
+  Ok(dir.join(name))
 }
@@ -139,6 +139,10 @@ macro_rules! examples {
         self.platform.add_texture(blob);
       }
 
+      pub fn add_named_texture(&mut self, name: String, blob: Vec<u8>) {
+        self.platform.add_named_texture(name, blob);
+      }
+
       pub fn render_example(&mut self, name: &str, time: f32) -> bool {
         // first, check whether the example exists
         match name {
@@ -247,6 +251,8 @@ examples! {
   "skybox", skybox,
   "shader-data", shader_data,
   "stencil", stencil,
+  "shadow-map", shadow_map,
+  "wireframe", wireframe,
 
   funtests:
   "funtest-tess-no-data", funtest_tess_no_data,
@@ -255,6 +261,7 @@ examples! {
   "funtest-flatten-slice", funtest_flatten_slice,
   "funtest-pixel-array-encoding", funtest_pixel_array_encoding,
   "funtest-483-indices-mut-corruption", funtest_483_indices_mut_corruption,
+  "funtest-viewport-top-left", funtest_viewport_top_left,
 }
 
 #[wasm_bindgen]
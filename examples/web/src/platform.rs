@@ -2,18 +2,23 @@
 
 use image::ImageError;
 use luminance_examples::PlatformServices;
-use std::{error::Error, fmt};
+use std::{collections::HashMap, error::Error, fmt};
 
 /// Web implementation of the [`PlatformService`] API.
 #[derive(Debug)]
 pub struct WebPlatformServices {
   textures: Vec<image::RgbImage>,
+  named_textures: HashMap<String, image::RgbImage>,
 }
 
 impl WebPlatformServices {
   pub fn new() -> Self {
     let textures = Vec::new();
-    Self { textures }
+    let named_textures = HashMap::new();
+    Self {
+      textures,
+      named_textures,
+    }
   }
 
   pub fn add_texture(&mut self, blob: Vec<u8>) {
@@ -25,11 +30,25 @@ impl WebPlatformServices {
       }
     }
   }
+
+  /// Add a texture that can be loaded by name with [`PlatformServices::load_texture`].
+  ///
+  /// The web platform cannot read files on its own, so the page must fetch them and register them by name first.
+  pub fn add_named_texture(&mut self, name: String, blob: Vec<u8>) {
+    match image::load_from_memory(&blob) {
+      Err(err) => log::error!("cannot read texture {}: {}", name, err),
+      Ok(img) => {
+        log::info!("added a new texture: {}", name);
+        self.named_textures.insert(name, img.flipv().into_rgb8());
+      }
+    }
+  }
 }
 
 #[derive(Debug)]
 pub enum WebFetchError {
   NoMoreTexture,
+  UnknownTexture(String),
   ImageError(ImageError),
 }
 
@@ -37,6 +56,7 @@ impl fmt::Display for WebFetchError {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
     match self {
       WebFetchError::NoMoreTexture => f.write_str("no more texture, sorry"),
+      WebFetchError::UnknownTexture(ref name) => write!(f, "unknown texture: {}", name),
       WebFetchError::ImageError(ref e) => write!(f, "cannot fetch texture: {}", e),
     }
   }
@@ -60,4 +80,12 @@ impl PlatformServices for WebPlatformServices {
       Ok(self.textures.remove(0)) // bit of a cost but for small textures who cares?
     }
   }
+
+  fn load_texture(&mut self, name: &str) -> Result<image::RgbImage, Self::FetchError> {
+    self
+      .named_textures
+      .get(name)
+      .cloned()
+      .ok_or_else(|| WebFetchError::UnknownTexture(name.to_owned()))
+  }
 }
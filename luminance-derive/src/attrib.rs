@@ -1,3 +1,4 @@
+use quote::ToTokens;
 use std::error;
 use std::fmt;
 use syn::parse::Parse;
@@ -72,6 +73,8 @@ impl error::Error for AttrError {}
 /// syntax:
 ///
 ///   #[key(sub_key = "lit")]
+///
+/// Non-string literals, such as `#[key(sub_key = 3)]`, are parsed as is.
 pub(crate) fn get_field_attr_once<'a, A, T>(
   field_ident: &Ident,
   attrs: A,
@@ -97,11 +100,14 @@ where
                 return Err(AttrError::several(field_ident.clone(), key, sub_key));
               }
 
-              if let Lit::Str(ref strlit) = mnv.lit {
-                lit = Some(strlit.parse().map_err(|_| {
-                  AttrError::cannot_parse_attribute(field_ident.clone(), key, sub_key)
-                })?);
-              }
+              let parsed = match mnv.lit {
+                Lit::Str(ref strlit) => strlit.parse(),
+                ref other => syn::parse2(other.to_token_stream()),
+              };
+
+              lit = Some(parsed.map_err(|_| {
+                AttrError::cannot_parse_attribute(field_ident.clone(), key, sub_key)
+              })?);
             } else {
               let ident_str = mnv
                 .path
@@ -21,18 +21,29 @@
 //!
 //! [See the full documentation here](https://docs.rs/luminance/latest/luminance/#uniform-interface)
 //!
+//! # `Std140`
+//!
+//! This macro allows to derive the [`Std140`] trait for a custom `struct` type, so that it can be passed to shader
+//! programs in uniform blocks. The crate deriving it must depend on [luminance-std140].
+//!
+//! [See the full documentation here](https://docs.rs/luminance/latest/luminance/#std140)
+//!
 //! [luminance]: https://crates.io/crates/luminance
+//! [luminance-std140]: https://crates.io/crates/luminance-std140
 //! [`Vertex`]: https://docs.rs/luminance/latest/luminance/vertex/trait.Vertex.html
 //! [`Semantics`]: https://docs.rs/luminance/latest/luminance/vertex/trait.Semantics.html
+//! [`Std140`]: https://docs.rs/luminance-std140/latest/luminance_std140/trait.Std140.html
 
 extern crate proc_macro;
 
 mod attrib;
 mod semantics;
+mod std140;
 mod uniform_interface;
 mod vertex;
 
 use crate::semantics::generate_enum_semantics_impl;
+use crate::std140::generate_std140_impl;
 use crate::uniform_interface::generate_uniform_interface_impl;
 use crate::vertex::generate_vertex_impl;
 use proc_macro::TokenStream;
@@ -44,7 +55,8 @@ pub fn derive_vertex(input: TokenStream) -> TokenStream {
 
   match di.data {
     // for now, we only handle structs
-    Data::Struct(struct_) => match generate_vertex_impl(di.ident, di.attrs.iter(), struct_) {
+    Data::Struct(struct_) => match generate_vertex_impl(di.ident, di.vis, di.attrs.iter(), struct_)
+    {
       Ok(impl_) => impl_,
       Err(e) => panic!("{}", e),
     },
@@ -82,3 +94,18 @@ pub fn derive_uniform_interface(input: TokenStream) -> TokenStream {
     _ => panic!("only structs are currently supported for deriving UniformInterface"),
   }
 }
+
+#[proc_macro_derive(Std140)]
+pub fn derive_std140(input: TokenStream) -> TokenStream {
+  let di: DeriveInput = parse_macro_input!(input);
+
+  match di.data {
+    // for now, we only handle structs
+    Data::Struct(struct_) => match generate_std140_impl(di.ident, di.vis, di.generics, struct_) {
+      Ok(impl_) => impl_,
+      Err(e) => panic!("{}", e),
+    },
+
+    _ => panic!("only structs are currently supported for deriving Std140"),
+  }
+}
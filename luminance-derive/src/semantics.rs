@@ -79,12 +79,17 @@ pub(crate) fn generate_enum_semantics_impl(
 
   let mut parse_branches = Vec::new();
   let mut name_branches = Vec::new();
+  let mut index_branches = Vec::new();
   let mut field_based_gen = Vec::new();
   let mut semantics_set = Vec::new();
 
   let mut errors = Vec::new();
 
-  for (index, field) in fields.enumerate() {
+  // matrix attributes occupy several locations, so the index of a semantics is the number of locations occupied by the
+  // previous ones
+  let mut index = quote! { 0 };
+
+  for field in fields {
     match field {
       Ok(field) => {
         // parse branches
@@ -103,6 +108,11 @@ pub(crate) fn generate_enum_semantics_impl(
           #ident::#sem_var => #sem_name
         });
 
+        // index of a semantics
+        index_branches.push(quote! {
+          #ident::#sem_var => #index
+        });
+
         semantics_set.push(quote! {
           luminance::vertex::SemanticsDesc {
             index: #index,
@@ -110,6 +120,10 @@ pub(crate) fn generate_enum_semantics_impl(
           }
         });
 
+        index = quote! {
+          #index + <#repr_ty_name as luminance::vertex::VertexAttrib>::VERTEX_ATTRIB_DESC.locations()
+        };
+
         // field-based code generation
         let field_gen = quote! {
           /// Vertex attribute type (representing #repr_ty_name).
@@ -156,6 +170,9 @@ pub(crate) fn generate_enum_semantics_impl(
             type Sem = #ident;
 
             const SEMANTICS: Self::Sem = #ident::#sem_var;
+
+            const REPR_VERTEX_ATTRIB_DESC: Option<luminance::vertex::VertexAttribDesc> =
+              Some(<#repr_ty_name as luminance::vertex::VertexAttrib>::VERTEX_ATTRIB_DESC);
           }
 
           // make the vertex attrib impl VertexAttrib by forwarding implementation to the repr type
@@ -184,7 +201,9 @@ pub(crate) fn generate_enum_semantics_impl(
   let output_gen = quote! {
     impl luminance::vertex::Semantics for #ident {
       fn index(&self) -> usize {
-        *self as usize
+        match *self {
+          #(#index_branches,)*
+        }
       }
 
       fn name(&self) -> &'static str {
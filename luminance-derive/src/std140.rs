@@ -0,0 +1,129 @@
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use std::error;
+use std::fmt;
+use syn::{DataStruct, Fields, Generics, Ident, Type, Visibility};
+
+// scalar types std140 packs in the padding of a preceding 3D vector
+const PACKED_SCALARS: &[&str] = &["f32", "f64", "i32", "u32", "bool"];
+
+#[non_exhaustive]
+#[derive(Debug)]
+pub(crate) enum DeriveStd140Error {
+  UnsupportedUnnamed,
+  UnsupportedUnit,
+  UnsupportedGenerics,
+  ScalarAfterVec3(Ident),
+}
+
+impl DeriveStd140Error {
+  pub(crate) fn unsupported_unnamed() -> Self {
+    DeriveStd140Error::UnsupportedUnnamed
+  }
+
+  pub(crate) fn unsupported_unit() -> Self {
+    DeriveStd140Error::UnsupportedUnit
+  }
+
+  pub(crate) fn unsupported_generics() -> Self {
+    DeriveStd140Error::UnsupportedGenerics
+  }
+
+  pub(crate) fn scalar_after_vec3(field: Ident) -> Self {
+    DeriveStd140Error::ScalarAfterVec3(field)
+  }
+}
+
+impl fmt::Display for DeriveStd140Error {
+  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    match *self {
+      DeriveStd140Error::UnsupportedUnnamed => f.write_str("unsupported unnamed fields"),
+      DeriveStd140Error::UnsupportedUnit => f.write_str("unsupported unit struct"),
+      DeriveStd140Error::UnsupportedGenerics => f.write_str("unsupported generic struct"),
+      DeriveStd140Error::ScalarAfterVec3(ref field) => write!(
+        f,
+        "scalar field {} follows a Vec3, which std140 packs in the padding of the vector; reorder the fields",
+        field
+      ),
+    }
+  }
+}
+
+impl error::Error for DeriveStd140Error {}
+
+pub(crate) fn generate_std140_impl(
+  ident: Ident,
+  vis: Visibility,
+  generics: Generics,
+  struct_: DataStruct,
+) -> Result<TokenStream, DeriveStd140Error> {
+  if !generics.params.is_empty() {
+    return Err(DeriveStd140Error::unsupported_generics());
+  }
+
+  match struct_.fields {
+    Fields::Named(named_fields) => {
+      let encoded_ident = format_ident!("{}Std140", ident);
+      let mut field_idents = Vec::new();
+      let mut field_tys = Vec::new();
+      // whether the previous field is a 3D vector
+      let mut after_vec3 = false;
+
+      for field in named_fields.named {
+        let field_ident = field.ident.unwrap();
+
+        if after_vec3 && is_type_named(&field.ty, PACKED_SCALARS) {
+          return Err(DeriveStd140Error::scalar_after_vec3(field_ident));
+        }
+
+        after_vec3 = is_type_named(&field.ty, &["Vec3"]);
+        field_idents.push(field_ident);
+        field_tys.push(field.ty);
+      }
+
+      let output = quote! {
+        /// std140 representation of the type of the same name, without the suffix.
+        #[doc(hidden)]
+        #[repr(C)]
+        #[derive(Clone, Copy)]
+        #vis struct #encoded_ident {
+          #(#field_idents: <#field_tys as luminance_std140::Std140>::Encoded,)*
+        }
+
+        impl luminance_std140::Std140 for #ident {
+          type Encoded = luminance_std140::Aligned16<#encoded_ident>;
+
+          fn std140_encode(self) -> Self::Encoded {
+            luminance_std140::Aligned16(#encoded_ident {
+              #(#field_idents: luminance_std140::Std140::std140_encode(self.#field_idents),)*
+            })
+          }
+
+          fn std140_decode(encoded: Self::Encoded) -> Self {
+            let encoded = encoded.0;
+
+            #ident {
+              #(#field_idents: <#field_tys as luminance_std140::Std140>::std140_decode(encoded.#field_idents),)*
+            }
+          }
+        }
+      };
+
+      Ok(output.into())
+    }
+
+    Fields::Unnamed(_) => Err(DeriveStd140Error::unsupported_unnamed()),
+    Fields::Unit => Err(DeriveStd140Error::unsupported_unit()),
+  }
+}
+
+// check whether the last segment of a type path is one of the given names
+fn is_type_named(ty: &Type, names: &[&str]) -> bool {
+  if let Type::Path(ref path) = ty {
+    path.path.segments.last().map_or(false, |segment| {
+      names.iter().any(|name| segment.ident == name)
+    })
+  } else {
+    false
+  }
+}
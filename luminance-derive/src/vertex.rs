@@ -1,12 +1,21 @@
-use crate::attrib::{get_field_attr_once, AttrError};
+use crate::attrib::{get_field_attr_once, get_field_flag_once, AttrError};
 use proc_macro::TokenStream;
-use quote::{format_ident, quote};
+use quote::{format_ident, quote, quote_spanned};
 use std::error;
 use std::fmt;
-use syn::{Attribute, DataStruct, Field, Fields, Ident, Index, LitBool, Type};
+use syn::spanned::Spanned;
+use syn::{Attribute, DataStruct, Field, Fields, Ident, Index, LitBool, LitInt, Type, Visibility};
 
 // accepted sub keys for the "vertex" key
-const KNOWN_SUBKEYS: &[&str] = &["sem", "instanced", "normalized"];
+const KNOWN_SUBKEYS: &[&str] = &[
+  "sem",
+  "instanced",
+  "normalized",
+  "buffer",
+  "location",
+  "flatten",
+  "slice_set",
+];
 
 #[derive(Debug)]
 pub(crate) enum StructImplError {
@@ -14,6 +23,7 @@ pub(crate) enum StructImplError {
   FieldError(AttrError),
   UnsupportedUnit,
   SameTypes(String, String),
+  FlattenedSubKey(String, String),
 }
 
 impl StructImplError {
@@ -32,6 +42,10 @@ impl StructImplError {
   pub(crate) fn same_types(ident: String, dup: String) -> Self {
     StructImplError::SameTypes(ident, dup)
   }
+
+  pub(crate) fn flattened_sub_key(ident: String, sub_key: impl Into<String>) -> Self {
+    StructImplError::FlattenedSubKey(ident, sub_key.into())
+  }
 }
 
 impl fmt::Display for StructImplError {
@@ -43,6 +57,13 @@ impl fmt::Display for StructImplError {
       StructImplError::SameTypes(field, dup) => {
         write!(f, "field {} has the same type as field {}. Each field of this struct must have a different type", field, dup)
       }
+      StructImplError::FlattenedSubKey(field, sub_key) => {
+        write!(
+          f,
+          "field {} is flattened and cannot have the {} sub key",
+          field, sub_key
+        )
+      }
     }
   }
 }
@@ -60,6 +81,7 @@ impl error::Error for StructImplError {
 /// Generate the Vertex impl for a struct.
 pub(crate) fn generate_vertex_impl<'a, A>(
   ident: Ident,
+  vis: Visibility,
   attrs: A,
   struct_: DataStruct,
 ) -> Result<TokenStream, StructImplError>
@@ -72,13 +94,21 @@ where
 
   let instancing = get_instancing(&ident, attrs.clone())?;
 
+  // search for the slice_set argument; if there, a <Name>SliceSet struct is generated along with DeinterleaveAll
+  let slice_set = get_field_flag_once(&ident, attrs.clone(), "vertex", "slice_set", KNOWN_SUBKEYS)
+    .map_err(StructImplError::field_error)?;
+
   match struct_.fields {
     Fields::Unnamed(unnamed_fields) => {
       let mut indexed_vertex_attrib_descs = Vec::new();
       let mut fields_types = Vec::new();
+      let mut flattened = Vec::new();
+      let mut locations = Vec::new();
+      let mut fields_vis = Vec::new();
 
       for (i, field) in unnamed_fields.unnamed.into_iter().enumerate() {
         let field_ident = format_ident!("field_{}", i);
+        fields_vis.push(field.vis.clone());
 
         process_field(
           &field,
@@ -87,21 +117,40 @@ where
           &instancing,
           &mut indexed_vertex_attrib_descs,
           &mut fields_types,
+          &mut flattened,
+          &mut locations,
           None,
         )?;
       }
 
-      let output = process_struct(ident, indexed_vertex_attrib_descs, Vec::new(), fields_types);
-      Ok(output.into())
+      let output = process_struct(
+        ident,
+        vis,
+        &sem_type,
+        indexed_vertex_attrib_descs,
+        Vec::new(),
+        fields_types,
+        flattened,
+        fields_vis,
+        !locations.is_empty(),
+        slice_set,
+      );
+      let location_checks = generate_location_checks(&locations);
+
+      Ok(quote! { #location_checks #output }.into())
     }
 
     Fields::Named(named_fields) => {
       let mut indexed_vertex_attrib_descs = Vec::new();
       let mut fields_types = Vec::new();
+      let mut flattened = Vec::new();
+      let mut locations = Vec::new();
       let mut fields_names = Vec::new();
+      let mut fields_vis = Vec::new();
 
       for field in named_fields.named {
         let field_ident = field.ident.clone().unwrap();
+        fields_vis.push(field.vis.clone());
 
         process_field(
           &field,
@@ -110,23 +159,34 @@ where
           &instancing,
           &mut indexed_vertex_attrib_descs,
           &mut fields_types,
+          &mut flattened,
+          &mut locations,
           &mut fields_names,
         )?;
       }
 
       let output = process_struct(
         ident,
+        vis,
+        &sem_type,
         indexed_vertex_attrib_descs,
         fields_names,
         fields_types,
+        flattened,
+        fields_vis,
+        !locations.is_empty(),
+        slice_set,
       );
-      Ok(output.into())
+      let location_checks = generate_location_checks(&locations);
+
+      Ok(quote! { #location_checks #output }.into())
     }
 
     Fields::Unit => Err(StructImplError::unsupported_unit()),
   }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn process_field<'a, FN>(
   field: &Field,
   ident: Ident,
@@ -134,6 +194,8 @@ fn process_field<'a, FN>(
   instancing: &proc_macro2::TokenStream,
   indexed_vertex_attrib_descs: &mut Vec<proc_macro2::TokenStream>,
   fields_types: &mut Vec<Type>,
+  flattened: &mut Vec<bool>,
+  locations: &mut Vec<(LitInt, Type)>,
   fields_names: FN,
 ) -> Result<(), StructImplError>
 where
@@ -148,6 +210,42 @@ where
     })
     .map_err(StructImplError::field_error)?;
 
+  // search for the buffer argument; if not there, the attribute goes in the default buffer group
+  let buffer = get_field_attr_once(&ident, &field.attrs, "vertex", "buffer", KNOWN_SUBKEYS)
+    .map(|b: LitInt| Some(b))
+    .or_else(|e| match e {
+      AttrError::CannotFindAttribute(..) => Ok(None),
+      _ => Err(e),
+    })
+    .map_err(StructImplError::field_error)?;
+
+  // search for the location argument; if not there, the attribute is located at the index of its semantics
+  let location = get_field_attr_once(&ident, &field.attrs, "vertex", "location", KNOWN_SUBKEYS)
+    .map(|l: LitInt| Some(l))
+    .or_else(|e| match e {
+      AttrError::CannotFindAttribute(..) => Ok(None),
+      _ => Err(e),
+    })
+    .map_err(StructImplError::field_error)?;
+
+  // search for the flatten argument; if there, the attributes of the field are spliced into the struct
+  let flatten = get_field_flag_once(&ident, &field.attrs, "vertex", "flatten", KNOWN_SUBKEYS)
+    .map_err(StructImplError::field_error)?;
+
+  if flatten && normalized {
+    return Err(StructImplError::flattened_sub_key(
+      ident.to_string(),
+      "normalized",
+    ));
+  }
+
+  if flatten && location.is_some() {
+    return Err(StructImplError::flattened_sub_key(
+      ident.to_string(),
+      "location",
+    ));
+  }
+
   let field_ty = &field.ty;
   let names = fields_names.into();
 
@@ -170,6 +268,27 @@ where
     }
   }
 
+  if flatten {
+    // the attributes of the flattened field take the instancing of the struct, and its buffer group if any
+    let with_buffer = buffer.map(|buffer| quote! { .with_buffer(#buffer) });
+
+    indexed_vertex_attrib_descs.push(quote! {
+      desc.extend(
+        <#field_ty as luminance::vertex::Vertex>::vertex_desc()
+          .into_iter()
+          .map(|attrib| luminance::vertex::VertexBufferDesc { instancing: #instancing, ..attrib }#with_buffer),
+      );
+    });
+    fields_types.push(field_ty.clone());
+    flattened.push(true);
+
+    if let Some(fields_names) = names {
+      fields_names.push(ident);
+    }
+
+    return Ok(());
+  }
+
   let vertex_attrib_desc = if normalized {
     quote! { (<#field_ty as luminance::vertex::VertexAttrib>::VERTEX_ATTRIB_DESC).normalize() }
   } else {
@@ -184,8 +303,24 @@ where
     )
   };
 
-  indexed_vertex_attrib_descs.push(indexed_vertex_attrib_desc_q);
+  let indexed_vertex_attrib_desc_q = match buffer {
+    Some(buffer) => quote! { #indexed_vertex_attrib_desc_q.with_buffer(#buffer) },
+    None => indexed_vertex_attrib_desc_q,
+  };
+
+  let indexed_vertex_attrib_desc_q = match location {
+    Some(location) => {
+      let q = quote! { #indexed_vertex_attrib_desc_q.with_location(#location) };
+      locations.push((location, field_ty.clone()));
+      q
+    }
+
+    None => indexed_vertex_attrib_desc_q,
+  };
+
+  indexed_vertex_attrib_descs.push(quote! { desc.push(#indexed_vertex_attrib_desc_q); });
   fields_types.push(field_ty.clone());
+  flattened.push(false);
 
   if let Some(fields_names) = names {
     fields_names.push(ident);
@@ -197,11 +332,18 @@ where
 /// Process the output struct.
 ///
 /// If fields_names is empty, it is assumed to be a struct-tuple.
+#[allow(clippy::too_many_arguments)]
 fn process_struct(
   struct_name: Ident,
+  struct_vis: Visibility,
+  sem_type: &Type,
   indexed_vertex_attrib_descs: Vec<proc_macro2::TokenStream>,
   fields_names: Vec<Ident>,
   fields_types: Vec<Type>,
+  flattened: Vec<bool>,
+  fields_vis: Vec<Visibility>,
+  explicit_locations: bool,
+  slice_set: bool,
 ) -> proc_macro2::TokenStream {
   let fn_new = if fields_names.is_empty() {
     // struct tuple
@@ -228,30 +370,412 @@ fn process_struct(
     }
   };
 
-  let fields_ranks = (0..fields_types.len()).into_iter().map(Index::from);
+  // rank of the first attribute of each field; flattened fields shift the ranks of the fields following them by their
+  // number of attributes
+  let fields_ranks: Vec<_> = (0..fields_types.len())
+    .map(|i| {
+      let leaf_nb = Index::from(
+        flattened[..i]
+          .iter()
+          .filter(|flattened| !**flattened)
+          .count(),
+      );
+      let flattened_types = fields_types[..i]
+        .iter()
+        .zip(&flattened)
+        .filter(|(_, flattened)| **flattened)
+        .map(|(ty, _)| ty);
+
+      quote! { #leaf_nb #(+ <#flattened_types as luminance::vertex::FlattenVertex>::ATTRIB_NB)* }
+    })
+    .collect();
+
+  let leaf_types: Vec<_> = fields_types
+    .iter()
+    .zip(&flattened)
+    .filter(|(_, flattened)| !**flattened)
+    .map(|(ty, _)| ty)
+    .collect();
+  let leaf_ranks = fields_ranks
+    .iter()
+    .zip(&flattened)
+    .filter(|(_, flattened)| !**flattened)
+    .map(|(rank, _)| rank);
   let deinterleave_impls = quote! {
     #(
-      impl luminance::vertex::Deinterleave<#fields_types> for #struct_name {
-        const RANK: usize = #fields_ranks;
+      impl luminance::vertex::Deinterleave<#leaf_types> for #struct_name {
+        const RANK: usize = #leaf_ranks;
       }
     )*
   };
 
+  // structs without flattened fields can be flattened in turn; the others can’t, so that flattening is not recursive
+  let flatten_impl = if flattened.iter().any(|flattened| *flattened) {
+    quote! {}
+  } else {
+    let attrib_nb = Index::from(fields_types.len());
+    let first_attrib_align = match fields_types.first() {
+      Some(ty) => quote! { std::mem::align_of::<#ty>() },
+      None => quote! { 1 },
+    };
+
+    quote! {
+      unsafe impl luminance::vertex::FlattenVertex for #struct_name {
+        type Sem = #sem_type;
+
+        const ATTRIB_NB: usize = #attrib_nb;
+
+        const SEMANTICS: &'static [Self::Sem] = &[
+          #(<#fields_types as luminance::vertex::HasSemantics>::SEMANTICS),*
+        ];
+
+        const ATTRIBS_SIZE: usize = {
+          let size = 0;
+          #(
+            let align = std::mem::align_of::<#fields_types>();
+            let size = (size + align - 1) / align * align + std::mem::size_of::<#fields_types>();
+          )*
+          size
+        };
+
+        const FIRST_ATTRIB_ALIGN: usize = #first_attrib_align;
+      }
+    }
+  };
+
+  let slice_set_impl = if slice_set {
+    generate_slice_set(
+      &struct_name,
+      &struct_vis,
+      &fields_names,
+      &fields_types,
+      &flattened,
+      &fields_ranks,
+      &fields_vis,
+    )
+  } else {
+    quote! {}
+  };
+
+  // ensure the size of each field matches the size of the vertex attribute it represents; otherwise, the interleaved
+  // layout would be silently broken
+  let size_checks = quote! {
+    #(
+      const _: () = assert!(
+        std::mem::size_of::<#leaf_types>()
+          == <#leaf_types as luminance::vertex::VertexAttrib>::VERTEX_ATTRIB_DESC.size(),
+        concat!(
+          "the size of ",
+          stringify!(#leaf_types),
+          " doesn’t match the size of its vertex attribute representation"
+        )
+      );
+    )*
+  };
+
+  // ensure each field is a vertex attribute of the semantics of the struct; checks are spanned on the fields’ types so
+  // that errors point at the faulty fields
+  let semantics_checks = leaf_types.iter().map(|ty| {
+    quote_spanned! { ty.span() =>
+      const _: fn() = || {
+        fn has_semantics<T>() where T: luminance::vertex::HasSemantics<Sem = #sem_type> {}
+        has_semantics::<#ty>();
+      };
+    }
+  });
+
+  // ensure each field is represented with the type declared for its semantics, if any; otherwise, shaders would
+  // silently read attributes with the wrong format
+  let repr_checks = leaf_types.iter().map(|ty| {
+    quote_spanned! { ty.span() =>
+      const _: () = assert!(
+        match <#ty as luminance::vertex::HasSemantics>::REPR_VERTEX_ATTRIB_DESC {
+          Some(repr) => <#ty as luminance::vertex::VertexAttrib>::VERTEX_ATTRIB_DESC.same_format(&repr),
+          None => true,
+        },
+        concat!(
+          "the vertex attribute representation of ",
+          stringify!(#ty),
+          " doesn’t match the repr type declared by its semantics"
+        )
+      );
+    }
+  });
+
+  // ensure each flattened field is a vertex type of the semantics of the struct, which has no flattened fields itself,
+  // and which attributes are laid out in the struct as if they were its own fields
+  let flatten_checks = fields_types
+    .iter()
+    .zip(&flattened)
+    .filter(|(_, flattened)| **flattened)
+    .map(|(ty, _)| {
+      quote_spanned! { ty.span() =>
+        const _: fn() = || {
+          fn flattenable<T>() where T: luminance::vertex::FlattenVertex<Sem = #sem_type> {}
+          flattenable::<#ty>();
+        };
+
+        const _: () = assert!(
+          std::mem::size_of::<#ty>() == <#ty as luminance::vertex::FlattenVertex>::ATTRIBS_SIZE
+            && std::mem::align_of::<#ty>() == <#ty as luminance::vertex::FlattenVertex>::FIRST_ATTRIB_ALIGN,
+          concat!(
+            "the layout of ",
+            stringify!(#ty),
+            " has trailing padding or is more aligned than its first attribute, so it cannot be flattened"
+          )
+        );
+      }
+    });
+
+  // ensure flattened fields don’t repeat the semantics of other fields; the semantics of leaf fields are distinct
+  // already, as their types are, and so are the ones of a flattened type; semantics are compared by discriminant, as
+  // the derived ones are fieldless enums
+  let semantics_uniqueness_checks = fields_types
+    .iter()
+    .zip(&flattened)
+    .enumerate()
+    .filter(|(_, (_, flattened))| **flattened)
+    .map(|(i, (ty, _))| {
+      let other_flattened_types = fields_types
+        .iter()
+        .zip(&flattened)
+        .enumerate()
+        .filter(|(j, (_, flattened))| **flattened && *j != i)
+        .map(|(_, (ty, _))| ty);
+
+      quote_spanned! { ty.span() =>
+        const _: () = assert!(
+          {
+            let semantics = <#ty as luminance::vertex::FlattenVertex>::SEMANTICS;
+            let leaf_semantics: &[usize] = &[
+              #(<#leaf_types as luminance::vertex::HasSemantics>::SEMANTICS as usize),*
+            ];
+            let mut unique = true;
+            let mut i = 0;
+
+            while i < semantics.len() {
+              let sem = semantics[i] as usize;
+
+              let mut j = 0;
+              while j < leaf_semantics.len() {
+                unique &= leaf_semantics[j] != sem;
+                j += 1;
+              }
+
+              #(
+                let other_semantics = <#other_flattened_types as luminance::vertex::FlattenVertex>::SEMANTICS;
+                let mut j = 0;
+                while j < other_semantics.len() {
+                  unique &= other_semantics[j] as usize != sem;
+                  j += 1;
+                }
+              )*
+
+              i += 1;
+            }
+
+            unique
+          },
+          concat!(
+            "the semantics of ",
+            stringify!(#ty),
+            " are used by other attributes of ",
+            stringify!(#struct_name)
+          )
+        );
+      }
+    });
+
+  // ensure explicit locations don’t overlap the locations of the other attributes, which are at the indices of their
+  // semantics; overlaps between explicit locations are rejected at compile-time already
+  let location_overlap_check = if explicit_locations {
+    quote! {
+      for (i, attrib) in desc.iter().enumerate() {
+        let end = attrib.index + attrib.attrib_desc.locations();
+
+        if let Some(prev) = desc[..i]
+          .iter()
+          .find(|prev| attrib.index < prev.index + prev.attrib_desc.locations() && prev.index < end)
+        {
+          panic!(
+            "the locations of the {} attribute overlap the locations of the {} attribute of {}",
+            attrib.name,
+            prev.name,
+            stringify!(#struct_name)
+          );
+        }
+      }
+    }
+  } else {
+    quote! {}
+  };
+
   quote! {
+    #size_checks
+
+    #(#semantics_checks)*
+
+    #(#repr_checks)*
+
+    #(#flatten_checks)*
+
+    #(#semantics_uniqueness_checks)*
+
     // Vertex impl
     unsafe impl luminance::vertex::Vertex for #struct_name {
       fn vertex_desc() -> luminance::vertex::VertexDesc {
-        vec![#(#indexed_vertex_attrib_descs),*]
+        let mut desc = luminance::vertex::VertexDesc::new();
+        #(#indexed_vertex_attrib_descs)*
+        #location_overlap_check
+        desc
       }
     }
 
     #deinterleave_impls
 
+    #flatten_impl
+
+    #slice_set_impl
+
     // helper function for the generate type
     #fn_new
   }
 }
 
+/// Generate the checks ensuring that explicit locations don’t overlap.
+///
+/// Matrix attributes occupy one location per column, so the ranges of locations of attributes are checked, not only
+/// their first location. Checks are spanned on the locations so that errors point at the faulty fields. The semantics
+/// indices of the other attributes are only known at runtime, so overlaps with them are checked in
+/// `Vertex::vertex_desc` instead.
+fn generate_location_checks(locations: &[(LitInt, Type)]) -> proc_macro2::TokenStream {
+  let checks = locations.iter().enumerate().flat_map(|(i, (location, ty))| {
+    locations[..i].iter().map(move |(prev_location, prev_ty)| {
+      quote_spanned! { location.span() =>
+        const _: () = assert!(
+          #location + <#ty as luminance::vertex::VertexAttrib>::VERTEX_ATTRIB_DESC.locations() <= #prev_location
+            || #prev_location + <#prev_ty as luminance::vertex::VertexAttrib>::VERTEX_ATTRIB_DESC.locations()
+              <= #location,
+          concat!(
+            "the locations of ",
+            stringify!(#ty),
+            " overlap the locations of ",
+            stringify!(#prev_ty)
+          )
+        );
+      }
+    })
+  });
+
+  quote! { #(#checks)* }
+}
+
+/// Generate the `<Name>SliceSet` struct, holding one slice per field, and the `DeinterleaveAll` impl.
+///
+/// This is only done for structs with the `#[vertex(slice_set)]` attribute, so that the generated struct doesn’t
+/// collide with user types. Flattened fields hold the slice set of their own type instead of a slice. If fields_names is
+/// empty, it is assumed to be a struct-tuple.
+fn generate_slice_set(
+  struct_name: &Ident,
+  struct_vis: &Visibility,
+  fields_names: &[Ident],
+  fields_types: &[Type],
+  flattened: &[bool],
+  fields_ranks: &[proc_macro2::TokenStream],
+  fields_vis: &[Visibility],
+) -> proc_macro2::TokenStream {
+  let slice_set_name = format_ident!("{}SliceSet", struct_name);
+  let slice_set_doc = format!(
+    "Slices over all the attributes of deinterleaved [`{}`] vertices.",
+    struct_name
+  );
+
+  let slices_types: Vec<_> = fields_types
+    .iter()
+    .zip(flattened)
+    .map(|(ty, flattened)| {
+      if *flattened {
+        quote! { <#ty as luminance::vertex::DeinterleaveAll<'a>>::SliceSet }
+      } else {
+        quote! { &'a [#ty] }
+      }
+    })
+    .collect();
+
+  let slices = fields_types.iter().zip(flattened).zip(fields_ranks).map(
+    |((ty, flattened), rank)| {
+      if *flattened {
+        quote! {
+          <#ty as luminance::vertex::DeinterleaveAll<'a>>::slice_set(|rank| attribute(#rank + rank))
+        }
+      } else {
+        quote! {
+          {
+            let bytes = attribute(#rank);
+
+            assert_eq!(
+              bytes.as_ptr() as usize % std::mem::align_of::<#ty>(),
+              0,
+              concat!("misaligned bytes for ", stringify!(#ty))
+            );
+
+            std::slice::from_raw_parts(
+              bytes.as_ptr() as *const #ty,
+              bytes.len() / std::mem::size_of::<#ty>(),
+            )
+          }
+        }
+      }
+    },
+  );
+
+  let (def, ctor) = if fields_names.is_empty() {
+    // struct tuple
+    let def = quote! {
+      #struct_vis struct #slice_set_name<'a>(#(#fields_vis #slices_types),*);
+    };
+    let ctor = quote! { #slice_set_name(#(#slices),*) };
+
+    (def, ctor)
+  } else {
+    let fields_docs = fields_names.iter().zip(flattened).map(|(name, flattened)| {
+      if *flattened {
+        format!(
+          "Slices of the attributes of the flattened `{}` field.",
+          name
+        )
+      } else {
+        format!("Slice of the `{}` attribute.", name)
+      }
+    });
+    let def = quote! {
+      #struct_vis struct #slice_set_name<'a> {
+        #(
+          #[doc = #fields_docs]
+          #fields_vis #fields_names: #slices_types
+        ),*
+      }
+    };
+    let ctor = quote! { #slice_set_name { #(#fields_names: #slices),* } };
+
+    (def, ctor)
+  };
+
+  quote! {
+    #[doc = #slice_set_doc]
+    #[derive(Clone, Copy)]
+    #def
+
+    unsafe impl<'a> luminance::vertex::DeinterleaveAll<'a> for #struct_name {
+      type SliceSet = #slice_set_name<'a>;
+
+      unsafe fn slice_set(mut attribute: impl FnMut(usize) -> &'a [u8]) -> Self::SliceSet {
+        #ctor
+      }
+    }
+  }
+}
+
 fn get_instancing<'a, A>(
   ident: &Ident,
   attrs: A,
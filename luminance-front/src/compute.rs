@@ -0,0 +1,6 @@
+use crate::Backend;
+
+pub use luminance::compute::ComputeError;
+
+pub type ComputeProgram<Uni> = luminance::compute::ComputeProgram<Backend, Uni>;
+pub type ComputeGate<'a> = luminance::compute::ComputeGate<'a, Backend>;
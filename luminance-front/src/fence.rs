@@ -0,0 +1,4 @@
+use crate::Backend;
+
+pub type Fence = luminance::fence::Fence<Backend>;
+pub use luminance::fence::FenceError;
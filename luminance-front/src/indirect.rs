@@ -0,0 +1,7 @@
+use crate::Backend;
+
+pub use luminance::indirect::{
+  DrawArraysCommand, DrawElementsCommand, IndirectCommand, IndirectError,
+};
+
+pub type IndirectBuffer<C> = luminance::indirect::IndirectBuffer<Backend, C>;
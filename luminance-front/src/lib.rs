@@ -54,13 +54,17 @@
 //! - _Default_: `["gl33", "webgl2"]`.
 //! - **OpenGL**:
 //!   - `"gl33"`: OpenGL 3.3 implementation.
+//!   - `"gl33-gl-debug"`: debug groups and object labels (see [`debug`]), requiring OpenGL 4.3 or `GL_KHR_debug`.
 //! - **WebGL 2**:
 //!   - `"webgl2"`: WebGL 2 implementation.
 //!
 //! [luminance]: https://crates.io/crates/luminance
 
+pub mod compute;
 pub mod context;
+pub mod fence;
 pub mod framebuffer;
+pub mod indirect;
 pub mod pipeline;
 pub mod query;
 pub mod render_gate;
@@ -69,9 +73,11 @@ pub mod shading_gate;
 pub mod tess;
 pub mod tess_gate;
 pub mod texture;
+pub mod timer;
 
 // re-export
 pub use luminance::blending;
+pub use luminance::debug;
 pub use luminance::depth_stencil;
 pub use luminance::face_culling;
 pub use luminance::pixel;
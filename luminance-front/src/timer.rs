@@ -0,0 +1,4 @@
+use crate::Backend;
+
+pub type TimerQuery = luminance::timer::TimerQuery<Backend>;
+pub use luminance::timer::TimerQueryError;
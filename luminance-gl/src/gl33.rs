@@ -3,8 +3,12 @@
 //! This module implements an OpenGL 3.3 backend for luminance. The backend type is [`GL33`].
 
 mod buffer;
+mod compute;
+mod debug;
 mod depth_stencil;
+mod fence;
 mod framebuffer;
+mod indirect;
 mod pipeline;
 mod pixel;
 mod query;
@@ -12,6 +16,7 @@ pub mod shader;
 pub mod state;
 mod tess;
 pub mod texture;
+mod timer;
 mod vertex_restart;
 
 pub use self::state::GLState;
@@ -22,6 +27,22 @@ use std::rc::Rc;
 /// An OpenGL 3.3 backend.
 ///
 /// This type is to be used as a luminance backend type. It implements the whole public API.
+///
+/// # OpenGL function pointers
+///
+/// [`GL33`] calls OpenGL through the [gl](https://crates.io/crates/gl) crate, which stores the function pointers in
+/// **process-wide** globals, loaded with `gl::load_with` (the platform crates do that before calling [`GL33::new`]).
+/// There is no per-[`GL33`] function table: all the [`GL33`] values of a process share the same function pointers,
+/// the ones loaded last.
+///
+/// This is fine with a single OpenGL context, but **breaks applications using several contexts which function
+/// pointers differ**, such as contexts created on different GPUs or drivers, or on platforms where function pointers
+/// are only valid for the context they were loaded for (e.g. WGL on Windows). Loading the function pointers of a
+/// second context then silently changes the functions called by the first one. Such applications must make sure that
+/// all their contexts can use the same function pointers, or must only use luminance with one of them. The same
+/// applies to other OpenGL renderers of the process relying on the gl crate.
+///
+/// Note that a single [`GL33`] can be created per thread (see [`StateQueryError::UnavailableGLState`]).
 #[derive(Debug)]
 pub struct GL33 {
   pub state: Rc<RefCell<GLState>>,
@@ -29,6 +50,9 @@ pub struct GL33 {
 
 impl GL33 {
   /// Create a new OpenGL 3.3 backend.
+  ///
+  /// The OpenGL function pointers must have been loaded with `gl::load_with` for the context current on the calling
+  /// thread. They are process-wide: see the [OpenGL function pointers](GL33#opengl-function-pointers) section.
   pub fn new() -> Result<Self, StateQueryError> {
     GLState::new().map(|state| GL33 {
       state: Rc::new(RefCell::new(state)),
@@ -1,7 +1,7 @@
 //! OpenGL buffer implementation.
 
 use crate::gl33::{
-  state::{Bind, GLState},
+  state::{drain_gl_errors, Bind, GLState},
   GL33,
 };
 use gl;
@@ -19,12 +19,22 @@ use std::{
 pub enum SliceBufferError {
   /// Buffer mapping failed.
   MapFailed,
+  /// Buffer mapping failed because OpenGL ran out of memory.
+  AllocationFailed {
+    /// Number of bytes of the buffer.
+    requested_bytes: usize,
+  },
 }
 
 impl fmt::Display for SliceBufferError {
   fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
     match self {
       SliceBufferError::MapFailed => f.write_str("buffer mapping failed"),
+      SliceBufferError::AllocationFailed { requested_bytes } => write!(
+        f,
+        "buffer mapping failed: cannot allocate {} bytes",
+        requested_bytes
+      ),
     }
   }
 }
@@ -32,8 +42,13 @@ impl fmt::Display for SliceBufferError {
 impl error::Error for SliceBufferError {}
 
 impl From<SliceBufferError> for TessMapError {
-  fn from(_: SliceBufferError) -> Self {
-    TessMapError::CannotMap
+  fn from(e: SliceBufferError) -> Self {
+    match e {
+      SliceBufferError::MapFailed => TessMapError::cannot_map(),
+      SliceBufferError::AllocationFailed { requested_bytes } => {
+        TessMapError::buffer_allocation_failed(requested_bytes)
+      }
+    }
   }
 }
 
@@ -106,7 +121,8 @@ impl<T> Buffer<T> {
         .bind_array_buffer(self.handle(), Bind::Cached);
     }
 
-    mapping_buffer(gl::ARRAY_BUFFER, gl::READ_ONLY, |ptr| {
+    let bytes = mem::size_of::<T>() * self.buf.len();
+    mapping_buffer(gl::ARRAY_BUFFER, gl::READ_ONLY, bytes, |ptr| {
       let handle = self.handle();
       let state = &self.gl_buf.state;
       let raw = BufferSliceWrapper { handle, state };
@@ -125,7 +141,8 @@ impl<T> Buffer<T> {
         .bind_array_buffer(self.handle(), Bind::Cached);
     }
 
-    mapping_buffer(gl::ARRAY_BUFFER, gl::READ_WRITE, move |ptr| {
+    let bytes = mem::size_of::<T>() * self.buf.len();
+    mapping_buffer(gl::ARRAY_BUFFER, gl::READ_WRITE, bytes, move |ptr| {
       let handle = self.handle();
       let state = &self.gl_buf.state;
       let raw = BufferSliceWrapper { handle, state };
@@ -134,6 +151,29 @@ impl<T> Buffer<T> {
       BufferSliceMut { raw, len, ptr }
     })
   }
+
+  /// Replace the elements starting at `start` with `data`, uploading only that range.
+  pub(crate) unsafe fn update_range(&mut self, start: usize, data: &[T])
+  where
+    T: Copy,
+  {
+    self
+      .gl_buf
+      .state
+      .borrow_mut()
+      .bind_array_buffer(self.handle(), Bind::Cached);
+
+    let offset = mem::size_of::<T>() * start;
+    let bytes = mem::size_of_val(data);
+    gl::BufferSubData(
+      gl::ARRAY_BUFFER,
+      offset as isize,
+      bytes as isize,
+      data.as_ptr() as _,
+    );
+
+    self.buf[start..start + data.len()].copy_from_slice(data);
+  }
 }
 
 /// Wrapper to drop buffer slices.
@@ -224,16 +264,29 @@ impl<'a> BufferSliceMut<'a, u8> {
   }
 }
 
-/// Map a buffer and execute an action if correctly mapped; otherwise, return an error.
+/// Map a buffer of `bytes` bytes and execute an action if correctly mapped; otherwise, return an error.
+///
+/// If the mapping failed because OpenGL ran out of memory, [`SliceBufferError::AllocationFailed`] is returned, so that
+/// callers can tell it apart from other mapping failures.
 fn mapping_buffer<A, T>(
   target: GLenum,
   access: GLenum,
+  bytes: usize,
   f: impl FnOnce(*mut T) -> A,
 ) -> Result<A, SliceBufferError> {
+  // clear errors raised earlier so that they are not mistaken for a failure to map the buffer
+  drain_gl_errors();
+
   let ptr = unsafe { gl::MapBuffer(target, access) } as *mut T;
 
   if ptr.is_null() {
-    Err(SliceBufferError::MapFailed)
+    if unsafe { gl::GetError() } == gl::OUT_OF_MEMORY {
+      Err(SliceBufferError::AllocationFailed {
+        requested_bytes: bytes,
+      })
+    } else {
+      Err(SliceBufferError::MapFailed)
+    }
   } else {
     Ok(f(ptr))
   }
@@ -0,0 +1,65 @@
+use crate::gl33::{buffer::Buffer, shader::Program, GL33};
+use gl;
+use luminance::{
+  backend::{
+    compute::{Compute, ComputeStorage},
+    shader::ShaderData,
+  },
+  compute::ComputeError,
+  shader::ProgramError,
+};
+use luminance_std140::{ArrElem, Std140};
+
+unsafe impl Compute for GL33 {
+  unsafe fn new_compute_program(
+    &mut self,
+    stage: &Self::StageRepr,
+  ) -> Result<Self::ProgramRepr, ProgramError> {
+    let program = Program {
+      handle: gl::CreateProgram(),
+    };
+
+    gl::AttachShader(program.handle, stage.handle);
+    program.link().map(move |_| program)
+  }
+
+  unsafe fn apply_compute_program(&mut self, program: &Self::ProgramRepr) {
+    self.state.borrow_mut().use_program(program.handle);
+  }
+
+  unsafe fn dispatch_compute(&mut self, groups: [u32; 3]) -> Result<(), ComputeError> {
+    let (max, _) = self.state.borrow_mut().compute_limits();
+
+    if groups.iter().zip(&max).any(|(groups, max)| groups > max) {
+      return Err(ComputeError::too_many_work_groups(groups, max));
+    }
+
+    gl::DispatchCompute(groups[0], groups[1], groups[2]);
+    Ok(())
+  }
+
+  unsafe fn memory_barrier(&mut self) {
+    gl::MemoryBarrier(gl::ALL_BARRIER_BITS);
+  }
+}
+
+unsafe impl<T> ComputeStorage<T> for GL33
+where
+  Self: ShaderData<T, ShaderDataRepr = Buffer<<ArrElem<T> as Std140>::Encoded>>,
+  T: Std140,
+{
+  unsafe fn bind_storage(
+    &mut self,
+    shader_data: &Self::ShaderDataRepr,
+    binding: u32,
+  ) -> Result<(), ComputeError> {
+    let (_, max) = self.state.borrow_mut().compute_limits();
+
+    if binding >= max {
+      return Err(ComputeError::invalid_storage_binding(binding, max));
+    }
+
+    gl::BindBufferBase(gl::SHADER_STORAGE_BUFFER, binding, shader_data.handle());
+    Ok(())
+  }
+}
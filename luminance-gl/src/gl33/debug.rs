@@ -0,0 +1,108 @@
+//! Debug groups and object labels.
+//!
+//! They require OpenGL 4.3 or the `GL_KHR_debug` extension, and the `gl-debug` feature. Without them, annotations are
+//! ignored.
+
+use crate::gl33::{
+  framebuffer::Framebuffer,
+  shader::Program,
+  tess::{DeinterleavedTess, InterleavedTess},
+  GL33,
+};
+use gl::types::*;
+use luminance::{
+  backend::debug::{DebugGroup, DebugLabel},
+  tess::TessIndex,
+  texture::Dimensionable,
+  vertex::Vertex,
+};
+
+unsafe impl DebugGroup for GL33 {
+  unsafe fn push_debug_group(&mut self, name: &str) {
+    push_debug_group(name);
+  }
+
+  unsafe fn pop_debug_group(&mut self) {
+    pop_debug_group();
+  }
+}
+
+unsafe impl<V, I, W> DebugLabel<InterleavedTess<V, I, W>> for GL33
+where
+  V: Vertex,
+  I: TessIndex,
+  W: Vertex,
+{
+  unsafe fn set_label(&mut self, tess: &InterleavedTess<V, I, W>, label: &str) {
+    object_label(gl::VERTEX_ARRAY, tess.raw.vao, label);
+  }
+}
+
+unsafe impl<V, I, W> DebugLabel<DeinterleavedTess<V, I, W>> for GL33
+where
+  V: Vertex,
+  I: TessIndex,
+  W: Vertex,
+{
+  unsafe fn set_label(&mut self, tess: &DeinterleavedTess<V, I, W>, label: &str) {
+    object_label(gl::VERTEX_ARRAY, tess.raw.vao, label);
+  }
+}
+
+unsafe impl<D> DebugLabel<Framebuffer<D>> for GL33
+where
+  D: Dimensionable,
+{
+  unsafe fn set_label(&mut self, framebuffer: &Framebuffer<D>, label: &str) {
+    // the back buffer is not an object that can be labeled
+    if framebuffer.handle != 0 {
+      object_label(gl::FRAMEBUFFER, framebuffer.handle, label);
+    }
+  }
+}
+
+unsafe impl DebugLabel<Program> for GL33 {
+  unsafe fn set_label(&mut self, program: &Program, label: &str) {
+    object_label(gl::PROGRAM, program.handle, label);
+  }
+}
+
+#[cfg(feature = "gl-debug")]
+unsafe fn push_debug_group(name: &str) {
+  if gl::PushDebugGroup::is_loaded() {
+    gl::PushDebugGroup(
+      gl::DEBUG_SOURCE_APPLICATION,
+      0,
+      name.len() as GLsizei,
+      name.as_ptr() as *const GLchar,
+    );
+  }
+}
+
+#[cfg(not(feature = "gl-debug"))]
+unsafe fn push_debug_group(_: &str) {}
+
+#[cfg(feature = "gl-debug")]
+unsafe fn pop_debug_group() {
+  if gl::PopDebugGroup::is_loaded() {
+    gl::PopDebugGroup();
+  }
+}
+
+#[cfg(not(feature = "gl-debug"))]
+unsafe fn pop_debug_group() {}
+
+#[cfg(feature = "gl-debug")]
+unsafe fn object_label(identifier: GLenum, handle: GLuint, label: &str) {
+  if gl::ObjectLabel::is_loaded() {
+    gl::ObjectLabel(
+      identifier,
+      handle,
+      label.len() as GLsizei,
+      label.as_ptr() as *const GLchar,
+    );
+  }
+}
+
+#[cfg(not(feature = "gl-debug"))]
+unsafe fn object_label(_: GLenum, _: GLuint, _: &str) {}
@@ -0,0 +1,37 @@
+use crate::gl33::GL33;
+use gl::{self, types::*};
+use luminance::{backend::fence::Fence as FenceBackend, fence::FenceError};
+use std::time::Duration;
+
+pub struct Fence {
+  handle: GLsync,
+}
+
+impl Drop for Fence {
+  fn drop(&mut self) {
+    unsafe { gl::DeleteSync(self.handle) };
+  }
+}
+
+unsafe impl FenceBackend for GL33 {
+  type FenceRepr = Fence;
+
+  unsafe fn new_fence(&mut self) -> Result<Self::FenceRepr, FenceError> {
+    let handle = gl::FenceSync(gl::SYNC_GPU_COMMANDS_COMPLETE, 0);
+
+    if handle.is_null() {
+      Err(FenceError::cannot_create())
+    } else {
+      Ok(Fence { handle })
+    }
+  }
+
+  unsafe fn wait_fence(fence: &Self::FenceRepr, timeout: Duration) -> bool {
+    let timeout = timeout.as_nanos().min(GLuint64::MAX as u128) as GLuint64;
+
+    matches!(
+      gl::ClientWaitSync(fence.handle, gl::SYNC_FLUSH_COMMANDS_BIT, timeout),
+      gl::ALREADY_SIGNALED | gl::CONDITION_SATISFIED
+    )
+  }
+}
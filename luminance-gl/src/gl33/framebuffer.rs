@@ -1,5 +1,7 @@
 use crate::gl33::{
-  state::{Bind, GLState},
+  pixel::opengl_pixel_format,
+  state::{drain_gl_errors, Bind, GLState, ScissorState},
+  texture::set_pack_alignment,
   GL33,
 };
 use gl::{self, types::*};
@@ -7,12 +9,18 @@ use luminance::{
   backend::{
     color_slot::ColorSlot,
     depth_stencil_slot::DepthStencilSlot,
-    framebuffer::{Framebuffer as FramebufferBackend, FramebufferBackBuffer},
+    framebuffer::{
+      ClearBackBuffer, Framebuffer as FramebufferBackend, FramebufferBackBuffer, FramebufferBlit,
+      FramebufferReadback,
+    },
   },
-  framebuffer::{FramebufferError, IncompleteReason},
+  depth_stencil::Write,
+  framebuffer::{BlitFilter, BlitMask, FramebufferError, IncompleteReason},
+  pixel::{ColorPixel, PixelFormat, RenderablePixel},
+  scissor::ScissorRegion,
   texture::{Dim2, Dimensionable, Sampler},
 };
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, os::raw::c_void, rc::Rc};
 
 pub struct Framebuffer<D>
 where
@@ -21,6 +29,8 @@ where
   pub(crate) handle: GLuint,
   renderbuffer: Option<GLuint>,
   pub(crate) size: D::Size,
+  // pixel formats of the color attachments, in draw buffer order
+  pub(crate) color_formats: Vec<PixelFormat>,
   state: Rc<RefCell<GLState>>,
 }
 
@@ -116,6 +126,7 @@ where
       handle,
       renderbuffer: depth_renderbuffer,
       size,
+      color_formats,
       state: self.state.clone(),
     };
 
@@ -188,7 +199,213 @@ unsafe impl FramebufferBackBuffer for GL33 {
       handle: 0,
       renderbuffer: None,
       size,
+      color_formats: Vec::new(),
       state: self.state.clone(),
     })
   }
 }
+
+unsafe impl ClearBackBuffer for GL33 {
+  unsafe fn clear_back_buffer(
+    &mut self,
+    color: Option<[f32; 4]>,
+    depth: Option<f32>,
+    stencil: Option<i32>,
+  ) {
+    let mut state = self.state.borrow_mut();
+    let mut clear_buffer_bits = 0;
+
+    if let Some(color) = color {
+      state.set_clear_color(color);
+      state.set_color_mask([true; 4]);
+      clear_buffer_bits |= gl::COLOR_BUFFER_BIT;
+    }
+
+    if let Some(depth) = depth {
+      state.set_clear_depth(depth);
+      state.set_depth_write(Write::On);
+      clear_buffer_bits |= gl::DEPTH_BUFFER_BIT;
+    }
+
+    if let Some(stencil) = stencil {
+      state.set_clear_stencil(stencil);
+      state.set_stencil_write_mask(0xFF);
+      clear_buffer_bits |= gl::STENCIL_BUFFER_BIT;
+    }
+
+    if clear_buffer_bits != 0 {
+      state.bind_draw_framebuffer(0);
+      state.set_scissor_state(ScissorState::Off);
+      gl::Clear(clear_buffer_bits);
+    }
+  }
+}
+
+unsafe impl FramebufferBlit for GL33 {
+  unsafe fn blit_framebuffer(
+    &mut self,
+    src: &Self::FramebufferRepr,
+    dst: &Self::FramebufferRepr,
+    src_region: &ScissorRegion,
+    dst_region: &ScissorRegion,
+    mask: BlitMask,
+    filter: BlitFilter,
+  ) -> Result<(), FramebufferError> {
+    let mut state = self.state.borrow_mut();
+
+    let mut buffer_bits = 0;
+
+    if mask.color {
+      buffer_bits |= gl::COLOR_BUFFER_BIT;
+    }
+
+    if mask.depth {
+      buffer_bits |= gl::DEPTH_BUFFER_BIT;
+    }
+
+    if mask.stencil {
+      buffer_bits |= gl::STENCIL_BUFFER_BIT;
+    }
+
+    let filter = match filter {
+      BlitFilter::Nearest => gl::NEAREST,
+      BlitFilter::Linear => gl::LINEAR,
+    };
+
+    gl::BindFramebuffer(gl::READ_FRAMEBUFFER, src.handle);
+    state.bind_draw_framebuffer(dst.handle);
+
+    // colors are blitted from the first color attachment, whatever read buffer the source framebuffer was left with
+    if mask.color {
+      let read_buffer = if src.handle == 0 {
+        gl::BACK
+      } else {
+        gl::COLOR_ATTACHMENT0
+      };
+
+      gl::ReadBuffer(read_buffer);
+    }
+
+    // blitting is subject to the scissor test
+    state.set_scissor_state(ScissorState::Off);
+
+    gl::BlitFramebuffer(
+      src_region.x as GLint,
+      src_region.y as GLint,
+      (src_region.x + src_region.width) as GLint,
+      (src_region.y + src_region.height) as GLint,
+      dst_region.x as GLint,
+      dst_region.y as GLint,
+      (dst_region.x + dst_region.width) as GLint,
+      (dst_region.y + dst_region.height) as GLint,
+      buffer_bits,
+      filter,
+    );
+
+    Ok(())
+  }
+}
+
+unsafe impl FramebufferReadback for GL33 {
+  unsafe fn read_color_pixels<P>(
+    framebuffer: &Self::FramebufferRepr,
+    attachment_index: usize,
+    region: &ScissorRegion,
+  ) -> Result<Vec<P::RawEncoding>, FramebufferError>
+  where
+    P: ColorPixel + RenderablePixel,
+    P::RawEncoding: Copy + Default,
+  {
+    let pf = P::pixel_format();
+    let (format, _, ty) = opengl_pixel_format(pf).ok_or_else(|| {
+      FramebufferError::cannot_read_pixels(format!("unsupported pixel format: {:?}", pf))
+    })?;
+
+    let mut texels =
+      vec![Default::default(); (region.width * region.height) as usize * pf.channels_len()];
+
+    read_pixels(
+      framebuffer,
+      gl::COLOR_ATTACHMENT0 + attachment_index as GLenum,
+      region,
+      pf.format.bytes_len(),
+      format,
+      ty,
+      texels.as_mut_ptr() as *mut c_void,
+    );
+
+    Ok(texels)
+  }
+
+  unsafe fn read_depth_pixels(
+    framebuffer: &Self::FramebufferRepr,
+    region: &ScissorRegion,
+  ) -> Result<Vec<f32>, FramebufferError> {
+    let mut depths = vec![0.; (region.width * region.height) as usize];
+
+    // clear errors raised earlier so that they are not mistaken for a failure to read the depth values
+    drain_gl_errors();
+
+    read_pixels(
+      framebuffer,
+      gl::NONE,
+      region,
+      4,
+      gl::DEPTH_COMPONENT,
+      gl::FLOAT,
+      depths.as_mut_ptr() as *mut c_void,
+    );
+
+    // reading depth values from a framebuffer without a depth buffer fails with GL_INVALID_OPERATION, leaving the
+    // values untouched
+    match gl::GetError() {
+      gl::NO_ERROR => Ok(depths),
+      error => Err(FramebufferError::cannot_read_pixels(format!(
+        "cannot read depth values (OpenGL error {:#x})",
+        error
+      ))),
+    }
+  }
+}
+
+// Read the pixels of a region of a framebuffer into `data`.
+//
+// `read_buffer` selects the color attachment to read from; it is ignored when reading depth values or when reading from
+// the back buffer. `pixel_bytes` is the size of a single pixel, used to tightly pack the rows.
+unsafe fn read_pixels(
+  framebuffer: &Framebuffer<Dim2>,
+  read_buffer: GLenum,
+  region: &ScissorRegion,
+  pixel_bytes: usize,
+  format: GLenum,
+  ty: GLenum,
+  data: *mut c_void,
+) {
+  gl::BindFramebuffer(gl::READ_FRAMEBUFFER, framebuffer.handle);
+
+  if framebuffer.handle == 0 {
+    gl::ReadBuffer(gl::BACK);
+  } else if read_buffer != gl::NONE {
+    gl::ReadBuffer(read_buffer);
+  }
+
+  // set the packing alignment based on the number of bytes to skip
+  let skip_bytes = (pixel_bytes * region.width as usize) % 8;
+  set_pack_alignment(skip_bytes);
+
+  gl::ReadPixels(
+    region.x as GLint,
+    region.y as GLint,
+    region.width as GLsizei,
+    region.height as GLsizei,
+    format,
+    ty,
+    data,
+  );
+
+  // the read buffer is part of the state of the framebuffer; restore it so that blitting from it reads the first color
+  // attachment again
+  if framebuffer.handle != 0 && read_buffer != gl::NONE && read_buffer != gl::COLOR_ATTACHMENT0 {
+    gl::ReadBuffer(gl::COLOR_ATTACHMENT0);
+  }
+}
@@ -0,0 +1,58 @@
+use crate::gl33::{buffer::Buffer, GL33};
+use luminance::{
+  backend::indirect::IndirectBuffer as IndirectBufferBackend,
+  indirect::{IndirectCommand, IndirectError},
+};
+use std::mem;
+
+unsafe impl<C> IndirectBufferBackend<C> for GL33
+where
+  C: IndirectCommand,
+{
+  type IndirectBufferRepr = Buffer<C>;
+
+  unsafe fn new_indirect_buffer(
+    &mut self,
+    commands: impl Iterator<Item = C>,
+  ) -> Result<Self::IndirectBufferRepr, IndirectError> {
+    // indirect rendering requires OpenGL 4.0 or GL_ARB_draw_indirect; loaders might return function pointers for
+    // unsupported functions, so the context is asked instead
+    if !self.state.borrow_mut().supports_draw_indirect() {
+      return Err(IndirectError::unsupported());
+    }
+
+    Ok(Buffer::from_vec(self, commands.collect()))
+  }
+
+  unsafe fn indirect_buffer_len(buffer: &Self::IndirectBufferRepr) -> usize {
+    buffer.len()
+  }
+
+  unsafe fn indirect_command_at(
+    buffer: &Self::IndirectBufferRepr,
+    i: usize,
+  ) -> Result<C, IndirectError> {
+    buffer
+      .buf
+      .get(i)
+      .copied()
+      .ok_or_else(|| IndirectError::out_of_bounds(i))
+  }
+
+  unsafe fn set_indirect_command_at(
+    buffer: &mut Self::IndirectBufferRepr,
+    i: usize,
+    command: C,
+  ) -> Result<C, IndirectError> {
+    if i >= buffer.len() {
+      return Err(IndirectError::out_of_bounds(i));
+    }
+
+    buffer
+      .slice_buffer_mut()
+      .map_err(|_| IndirectError::cannot_set_data(i))?[i] = command;
+
+    // keep the cached version in sync so that reads don’t have to map the buffer
+    Ok(mem::replace(&mut buffer.buf[i], command))
+  }
+}
@@ -11,17 +11,20 @@ use luminance::{
     shader::ShaderData,
     shading_gate::ShadingGate,
     tess::Tess,
-    tess_gate::TessGate,
+    tess_gate::{TessGate, TessGateIndirect},
   },
   blending::BlendingMode,
-  pipeline::{PipelineError, PipelineState, Viewport},
-  pixel::Pixel,
+  indirect::IndirectCommand,
+  pipeline::{PipelineError, PipelineState},
+  pixel::{Pixel, PixelFormat, Type},
   render_state::RenderState,
-  tess::{Deinterleaved, DeinterleavedData, Interleaved, TessIndex, TessVertexData},
+  tess::{
+    Deinterleaved, DeinterleavedData, Interleaved, Mode, TessError, TessIndex, TessVertexData,
+  },
   texture::Dimensionable,
 };
 use luminance_std140::{ArrElem, Std140};
-use std::{cell::RefCell, marker::PhantomData, rc::Rc};
+use std::{cell::RefCell, marker::PhantomData, mem, rc::Rc};
 
 pub struct Pipeline {
   state: Rc<RefCell<GLState>>,
@@ -94,31 +97,40 @@ where
 
     let size = framebuffer.size;
 
-    match pipeline_state.viewport {
-      Viewport::Whole => {
-        state.set_viewport([0, 0, D::width(size) as GLint, D::height(size) as GLint]);
-      }
+    let viewport = pipeline_state
+      .viewport
+      .bottom_left_rect(D::width(size), D::height(size));
+    state.set_viewport(viewport.map(|v| v as GLint));
 
-      Viewport::Specific {
-        x,
-        y,
-        width,
-        height,
-      } => {
-        state.set_viewport([x as GLint, y as GLint, width as GLint, height as GLint]);
-      }
-    }
+    let (near, far) = pipeline_state.depth_range;
+    state.set_depth_range([near, far]);
+
+    // sRGB conversion applies to clears as well, so it must be set up before clearing, for clear colors to be
+    // converted the same way as shader outputs
+    state.enable_srgb_framebuffer(pipeline_state.srgb_enabled);
 
     let mut clear_buffer_bits = 0;
-    if let Some(clear_color) = pipeline_state.clear_color {
-      state.set_clear_color([
-        clear_color[0] as _,
-        clear_color[1] as _,
-        clear_color[2] as _,
-        clear_color[3] as _,
-      ]);
-
-      clear_buffer_bits |= gl::COLOR_BUFFER_BIT;
+
+    // integer attachments have undefined values when cleared with a floating-point color, and per-attachment clear
+    // colors cannot be honored with a single clear color, so in both cases attachments are cleared one by one with the
+    // clear function matching their encoding
+    let clear_attachments = !framebuffer.color_formats.is_empty()
+      && (pipeline_state.clear_colors.is_some()
+        || framebuffer.color_formats.iter().any(|f| f.is_integral()));
+
+    if !clear_attachments {
+      // the back buffer doesn’t have color formats and is cleared as a single color attachment
+      if let Some(clear_color) = pipeline_state.attachment_clear_color(0) {
+        state.set_clear_color([
+          clear_color[0] as _,
+          clear_color[1] as _,
+          clear_color[2] as _,
+          clear_color[3] as _,
+        ]);
+        state.set_color_mask([true; 4]);
+
+        clear_buffer_bits |= gl::COLOR_BUFFER_BIT;
+      }
     }
 
     if let Some(clear_depth) = pipeline_state.clear_depth {
@@ -129,10 +141,11 @@ where
 
     if let Some(clear_stencil) = pipeline_state.clear_stencil {
       state.set_clear_stencil(clear_stencil);
+      state.set_stencil_write_mask(0xFF);
       clear_buffer_bits |= gl::STENCIL_BUFFER_BIT;
     }
 
-    match pipeline_state.scissor().as_ref() {
+    match pipeline_state.clear_region() {
       Some(region) => {
         state.set_scissor_state(ScissorState::On);
         state.set_scissor_region(region);
@@ -145,7 +158,54 @@ where
       gl::Clear(clear_buffer_bits);
     }
 
-    state.enable_srgb_framebuffer(pipeline_state.srgb_enabled);
+    if clear_attachments {
+      state.set_color_mask([true; 4]);
+      clear_color_attachments(&framebuffer.color_formats, pipeline_state);
+    }
+
+    // the clear region must not leak into operations following the clear (draws set their own scissor state)
+    state.set_scissor_state(ScissorState::Off);
+  }
+}
+
+/// Clear each color attachment with its clear color and the clear function matching its encoding.
+///
+/// Integral attachments are cleared with the integer clear color matching their encoding, if any, or with the clear
+/// color converted to integers otherwise.
+unsafe fn clear_color_attachments(color_formats: &[PixelFormat], pipeline_state: &PipelineState) {
+  for (attachment, format) in color_formats.iter().enumerate() {
+    let clear_color = pipeline_state.attachment_clear_color(attachment);
+    let draw_buffer = attachment as GLint;
+
+    match format.encoding {
+      Type::Integral => {
+        let color = pipeline_state
+          .clear_color_i()
+          .copied()
+          .or_else(|| clear_color.map(|color| color.map(|c| c as GLint)));
+
+        if let Some(color) = color {
+          gl::ClearBufferiv(gl::COLOR, draw_buffer, color.as_ptr());
+        }
+      }
+
+      Type::Unsigned => {
+        let color = pipeline_state
+          .clear_color_u()
+          .copied()
+          .or_else(|| clear_color.map(|color| color.map(|c| c as GLuint)));
+
+        if let Some(color) = color {
+          gl::ClearBufferuiv(gl::COLOR, draw_buffer, color.as_ptr());
+        }
+      }
+
+      _ => {
+        if let Some(color) = clear_color {
+          gl::ClearBufferfv(gl::COLOR, draw_buffer, color.as_ptr());
+        }
+      }
+    }
   }
 }
 
@@ -234,9 +294,20 @@ where
     tess: &Self::TessRepr,
     start_index: usize,
     vert_nb: usize,
+    base_vertex: i32,
     inst_nb: usize,
-  ) {
-    let _ = <Self as Tess<V, I, W, Interleaved>>::render(tess, start_index, vert_nb, inst_nb);
+    inst_start_index: usize,
+    mode: Option<Mode>,
+  ) -> Result<(), TessError> {
+    <Self as Tess<V, I, W, Interleaved>>::render(
+      tess,
+      start_index,
+      vert_nb,
+      base_vertex,
+      inst_nb,
+      inst_start_index,
+      mode,
+    )
   }
 }
 
@@ -251,9 +322,62 @@ where
     tess: &Self::TessRepr,
     start_index: usize,
     vert_nb: usize,
+    base_vertex: i32,
     inst_nb: usize,
-  ) {
-    let _ = <Self as Tess<V, I, W, Deinterleaved>>::render(tess, start_index, vert_nb, inst_nb);
+    inst_start_index: usize,
+    mode: Option<Mode>,
+  ) -> Result<(), TessError> {
+    <Self as Tess<V, I, W, Deinterleaved>>::render(
+      tess,
+      start_index,
+      vert_nb,
+      base_vertex,
+      inst_nb,
+      inst_start_index,
+      mode,
+    )
+  }
+}
+
+unsafe impl<V, I, W, C> TessGateIndirect<V, I, W, Interleaved, C> for GL33
+where
+  V: TessVertexData<Interleaved, Data = Vec<V>>,
+  I: TessIndex,
+  W: TessVertexData<Interleaved, Data = Vec<W>>,
+  C: IndirectCommand,
+{
+  unsafe fn render_indirect(
+    &mut self,
+    tess: &Self::TessRepr,
+    indirect_buffer: &Self::IndirectBufferRepr,
+    index: usize,
+  ) -> Result<(), TessError> {
+    tess.raw.render_indirect(
+      indirect_buffer.handle(),
+      index * mem::size_of::<C>(),
+      C::INDEXED,
+    )
+  }
+}
+
+unsafe impl<V, I, W, C> TessGateIndirect<V, I, W, Deinterleaved, C> for GL33
+where
+  V: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+  I: TessIndex,
+  W: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+  C: IndirectCommand,
+{
+  unsafe fn render_indirect(
+    &mut self,
+    tess: &Self::TessRepr,
+    indirect_buffer: &Self::IndirectBufferRepr,
+    index: usize,
+  ) -> Result<(), TessError> {
+    tess.raw.render_indirect(
+      indirect_buffer.handle(),
+      index * mem::size_of::<C>(),
+      C::INDEXED,
+    )
   }
 }
 
@@ -261,6 +385,9 @@ unsafe impl RenderGate for GL33 {
   unsafe fn enter_render_state(&mut self, rdr_st: &RenderState) {
     let mut gfx_state = self.state.borrow_mut();
 
+    // draw buffers not overridden anymore must follow the blending state of all draw buffers again
+    gfx_state.reset_draw_buffers_blending(&rdr_st.draw_buffer_blending);
+
     // blending state
     match rdr_st.blending() {
       Some(blending) => {
@@ -281,6 +408,11 @@ unsafe impl RenderGate for GL33 {
       }
     }
 
+    // per-draw-buffer blending state, applied over the blending state of all draw buffers
+    for (draw_buffer, blending) in rdr_st.draw_buffer_blending_overrides() {
+      gfx_state.set_draw_buffer_blending(draw_buffer, blending);
+    }
+
     // depth-related state
     if let Some(depth_comparison) = rdr_st.depth_test() {
       gfx_state.set_depth_test(DepthTest::On);
@@ -290,17 +422,25 @@ unsafe impl RenderGate for GL33 {
     }
 
     gfx_state.set_depth_write(rdr_st.depth_write());
+    gfx_state.set_color_mask(rdr_st.color_mask());
 
     // stencil-related state
     if let Some(stencil_test) = rdr_st.stencil_test() {
+      let back_stencil_test = rdr_st.back_stencil_test().unwrap_or(stencil_test);
+
       gfx_state.enable_stencil_test(true);
-      gfx_state.set_stencil_test(*stencil_test);
+      gfx_state.set_stencil_test(*stencil_test, *back_stencil_test);
     } else {
       gfx_state.enable_stencil_test(false);
     }
 
     // stencil operations are always active
-    gfx_state.set_stencil_operations(*rdr_st.stencil_operations());
+    let stencil_operations = rdr_st.stencil_operations();
+    let back_stencil_operations = rdr_st
+      .back_stencil_operations()
+      .unwrap_or(stencil_operations);
+    gfx_state.set_stencil_operations(*stencil_operations, *back_stencil_operations);
+    gfx_state.set_stencil_write_mask(rdr_st.stencil_write_mask());
 
     // face-culling state
     match rdr_st.face_culling() {
@@ -325,6 +465,25 @@ unsafe impl RenderGate for GL33 {
         gfx_state.set_scissor_state(ScissorState::Off);
       }
     }
+
+    // sample shading
+    gfx_state.set_sample_shading(rdr_st.sample_shading().map(|ss| ss.rate()));
+
+    // alpha-to-coverage
+    gfx_state.enable_alpha_to_coverage(rdr_st.alpha_to_coverage());
+
+    // line width and point size
+    gfx_state.set_line_width(rdr_st.line_width().width());
+    gfx_state.set_point_size(rdr_st.point_size().map(|ps| ps.size()));
+
+    // polygon mode
+    gfx_state.set_polygon_mode(rdr_st.polygon_mode());
+
+    // depth clamp
+    gfx_state.enable_depth_clamp(rdr_st.depth_clamp());
+
+    // conservative rasterization
+    gfx_state.enable_conservative_raster(rdr_st.conservative_raster());
   }
 }
 
@@ -1,7 +1,15 @@
 //! Query API implementation for OpenGL 3.3.
 
 use crate::GL33;
-use luminance::backend::query::{Query as QueryBackend, QueryError};
+use gl::types::*;
+use luminance::backend::query::{BackendLimits, GpuMemoryInfo, Query as QueryBackend, QueryError};
+
+// GL_NVX_gpu_memory_info
+const GPU_MEMORY_INFO_TOTAL_AVAILABLE_MEMORY_NVX: GLenum = 0x9048;
+const GPU_MEMORY_INFO_CURRENT_AVAILABLE_VIDMEM_NVX: GLenum = 0x9049;
+
+// GL_ATI_meminfo
+const TEXTURE_FREE_MEMORY_ATI: GLenum = 0x87FC;
 
 unsafe impl QueryBackend for GL33 {
   fn backend_author(&self) -> Result<String, QueryError> {
@@ -28,4 +36,44 @@ unsafe impl QueryBackend for GL33 {
     let max = self.state.borrow_mut().get_max_texture_array_elements();
     Ok(max)
   }
+
+  fn limits(&self) -> Result<BackendLimits, QueryError> {
+    let limits = self.state.borrow_mut().get_limits();
+    Ok(limits)
+  }
+
+  fn back_buffer_is_srgb(&self) -> bool {
+    self.state.borrow_mut().is_back_buffer_srgb()
+  }
+
+  fn gpu_memory_info(&self) -> Option<GpuMemoryInfo> {
+    let mut state = self.state.borrow_mut();
+
+    if state.has_extension("GL_NVX_gpu_memory_info") {
+      let mut total = 0;
+      let mut available = 0;
+
+      unsafe {
+        gl::GetIntegerv(GPU_MEMORY_INFO_TOTAL_AVAILABLE_MEMORY_NVX, &mut total);
+        gl::GetIntegerv(GPU_MEMORY_INFO_CURRENT_AVAILABLE_VIDMEM_NVX, &mut available);
+      }
+
+      Some(GpuMemoryInfo {
+        total: Some(total.max(0) as usize),
+        available: available.max(0) as usize,
+      })
+    } else if state.has_extension("GL_ATI_meminfo") {
+      // the first value is the total free memory in the pool; the total amount of memory is not exposed
+      let mut info: [GLint; 4] = [0; 4];
+
+      unsafe { gl::GetIntegerv(TEXTURE_FREE_MEMORY_ATI, info.as_mut_ptr()) };
+
+      Some(GpuMemoryInfo {
+        total: None,
+        available: info[0].max(0) as usize,
+      })
+    } else {
+      None
+    }
+  }
 }
@@ -1,8 +1,8 @@
 use super::buffer::Buffer;
-use crate::gl33::GL33;
+use crate::gl33::{state::drain_gl_errors, GL33};
 use gl::{self, types::*};
 use luminance::{
-  backend::shader::{Shader, ShaderData, Uniformable},
+  backend::shader::{Shader, ShaderBinary, ShaderData, Uniformable, UniformableTransposed},
   pipeline::{ShaderDataBinding, TextureBinding},
   pixel::{SamplerType, Type as PixelType},
   shader::{
@@ -15,14 +15,15 @@ use luminance::{
 };
 use luminance_std140::{ArrElem, Std140};
 use std::{
-  ffi::CString,
+  ffi::{CStr, CString},
   mem,
+  os::raw::{c_char, c_void},
   ptr::{null, null_mut},
 };
 
 #[derive(Debug)]
 pub struct Stage {
-  handle: GLuint,
+  pub(crate) handle: GLuint,
 }
 
 impl Drop for Stage {
@@ -47,7 +48,7 @@ impl Drop for Program {
 }
 
 impl Program {
-  fn link(&self) -> Result<(), ProgramError> {
+  pub(crate) fn link(&self) -> Result<(), ProgramError> {
     let handle = self.handle;
 
     //todo https://github.com/servo/webrender/blob/9447930806f5ccc057826021a8d8f97f6b3fc803/webrender/src/device/gl.rs#L2461
@@ -136,6 +137,10 @@ unsafe impl Shader for GL33 {
   type UniformBuilderRepr = UniformBuilder;
 
   unsafe fn new_stage(&mut self, ty: StageType, src: &str) -> Result<Self::StageRepr, StageError> {
+    if ty == StageType::ComputeShader && !self.state.borrow_mut().supports_compute() {
+      return Err(StageError::unsupported_type(ty));
+    }
+
     let handle = gl::CreateShader(opengl_shader_type(ty));
 
     if handle == 0 {
@@ -145,7 +150,7 @@ unsafe impl Shader for GL33 {
       ));
     }
 
-    let c_src = CString::new(glsl_pragma_src(src).as_bytes()).unwrap();
+    let c_src = CString::new(glsl_pragma_src(ty, src).as_bytes()).unwrap();
     gl::ShaderSource(handle, 1, [c_src.as_ptr()].as_ptr(), null());
     gl::CompileShader(handle);
 
@@ -155,20 +160,20 @@ unsafe impl Shader for GL33 {
     if compiled == gl::TRUE.into() {
       Ok(Stage { handle })
     } else {
-      let mut log_len: GLint = 0;
-      gl::GetShaderiv(handle, gl::INFO_LOG_LENGTH, &mut log_len);
-
-      let mut log: Vec<u8> = Vec::with_capacity(log_len as usize);
-      gl::GetShaderInfoLog(handle, log_len, null_mut(), log.as_mut_ptr() as *mut GLchar);
-
+      let log = shader_info_log(handle);
       gl::DeleteShader(handle);
 
-      log.set_len(log_len as usize);
+      Err(StageError::compilation_failed(ty, log))
+    }
+  }
 
-      Err(StageError::compilation_failed(
-        ty,
-        String::from_utf8(log).unwrap(),
-      ))
+  unsafe fn stage_info_log(stage: &Self::StageRepr) -> Option<String> {
+    let log = shader_info_log(stage.handle);
+
+    if log.trim().is_empty() {
+      None
+    } else {
+      Some(log)
     }
   }
 
@@ -244,6 +249,144 @@ unsafe impl Shader for GL33 {
   }
 }
 
+unsafe impl ShaderBinary for GL33 {
+  unsafe fn program_binary(program: &Self::ProgramRepr) -> Result<(u32, Vec<u8>), ProgramError> {
+    if !supports_program_binary() {
+      return Err(ProgramError::binary_unsupported());
+    }
+
+    let mut len: GLint = 0;
+    gl::GetProgramiv(program.handle, gl::PROGRAM_BINARY_LENGTH, &mut len);
+
+    // drivers are allowed not to support any binary format
+    if len <= 0 {
+      return Err(ProgramError::binary_unsupported());
+    }
+
+    let mut binary: Vec<u8> = vec![0; len as usize];
+    let mut written: GLsizei = 0;
+    let mut format: GLenum = 0;
+    gl::GetProgramBinary(
+      program.handle,
+      len,
+      &mut written,
+      &mut format,
+      binary.as_mut_ptr() as *mut c_void,
+    );
+    binary.truncate(written.max(0) as usize);
+
+    Ok((format, binary))
+  }
+
+  unsafe fn new_program_from_binary(
+    &mut self,
+    format: u32,
+    binary: &[u8],
+  ) -> Result<Self::ProgramRepr, ProgramError> {
+    if !supports_program_binary() {
+      return Err(ProgramError::binary_unsupported());
+    }
+
+    let program = Program {
+      handle: gl::CreateProgram(),
+    };
+
+    // clear errors raised earlier so that they are not mistaken for a rejected binary
+    drain_gl_errors();
+
+    gl::ProgramBinary(
+      program.handle,
+      format,
+      binary.as_ptr() as *const c_void,
+      binary.len() as GLsizei,
+    );
+
+    // an unknown format generates an error, while an outdated binary only fails the link
+    let error = gl::GetError();
+    let mut linked: GLint = gl::FALSE.into();
+    gl::GetProgramiv(program.handle, gl::LINK_STATUS, &mut linked);
+
+    if error == gl::NO_ERROR && linked == gl::TRUE.into() {
+      Ok(program)
+    } else if error == gl::INVALID_ENUM {
+      Err(ProgramError::incompatible_binary(format!(
+        "unknown binary format {:#x}",
+        format
+      )))
+    } else {
+      Err(ProgramError::incompatible_binary(program_info_log(
+        program.handle,
+      )))
+    }
+  }
+}
+
+/// Check whether program binaries are supported, either by OpenGL 4.1 or by the `GL_ARB_get_program_binary` extension.
+///
+/// [`ShaderBinary::program_binary`] has no access to the [`GLState`](crate::gl33::state::GLState), so the version and
+/// extensions are queried directly.
+unsafe fn supports_program_binary() -> bool {
+  let (mut major, mut minor) = (0, 0);
+  gl::GetIntegerv(gl::MAJOR_VERSION, &mut major);
+  gl::GetIntegerv(gl::MINOR_VERSION, &mut minor);
+
+  if (major, minor) >= (4, 1) {
+    return true;
+  }
+
+  let mut nb = 0;
+  gl::GetIntegerv(gl::NUM_EXTENSIONS, &mut nb);
+
+  (0..nb.max(0) as GLuint).any(|i| {
+    let name = CStr::from_ptr(gl::GetStringi(gl::EXTENSIONS, i) as *const c_char);
+    name.to_bytes() == b"GL_ARB_get_program_binary"
+  })
+}
+
+/// Get the info log of a shader, without its trailing null byte.
+unsafe fn shader_info_log(handle: GLuint) -> String {
+  let mut log_len: GLint = 0;
+  gl::GetShaderiv(handle, gl::INFO_LOG_LENGTH, &mut log_len);
+
+  if log_len <= 0 {
+    return String::new();
+  }
+
+  let mut log: Vec<u8> = vec![0; log_len as usize];
+  let mut written: GLsizei = 0;
+  gl::GetShaderInfoLog(
+    handle,
+    log_len,
+    &mut written,
+    log.as_mut_ptr() as *mut GLchar,
+  );
+  log.truncate(written.max(0) as usize);
+
+  String::from_utf8_lossy(&log).into_owned()
+}
+
+/// Get the info log of a program, without its trailing null byte.
+unsafe fn program_info_log(handle: GLuint) -> String {
+  let mut log_len: GLint = 0;
+  gl::GetProgramiv(handle, gl::INFO_LOG_LENGTH, &mut log_len);
+
+  if log_len <= 0 {
+    return String::new();
+  }
+
+  let mut log: Vec<u8> = vec![0; log_len as usize];
+  let mut written: GLsizei = 0;
+  gl::GetProgramInfoLog(
+    handle,
+    log_len,
+    &mut written,
+    log.as_mut_ptr() as *mut GLchar,
+  );
+  log.truncate(written.max(0) as usize);
+
+  String::from_utf8_lossy(&log).into_owned()
+}
+
 fn opengl_shader_type(t: StageType) -> GLenum {
   match t {
     StageType::TessellationControlShader => gl::TESS_CONTROL_SHADER,
@@ -251,6 +394,7 @@ fn opengl_shader_type(t: StageType) -> GLenum {
     StageType::VertexShader => gl::VERTEX_SHADER,
     StageType::GeometryShader => gl::GEOMETRY_SHADER,
     StageType::FragmentShader => gl::FRAGMENT_SHADER,
+    StageType::ComputeShader => gl::COMPUTE_SHADER,
   }
 }
 
@@ -264,8 +408,16 @@ const GLSL_PRAGMA: &str = "#version 330 core\n\
                            #extension GL_ARB_separate_shader_objects : require\n\
                            layout(std140) uniform;\n";
 
-fn glsl_pragma_src(src: &str) -> String {
-  let mut pragma = String::from(GLSL_PRAGMA);
+// compute shaders require OpenGL 4.3, and hence GLSL 430
+const GLSL_COMPUTE_PRAGMA: &str = "#version 430 core\n\
+                                   layout(std140) uniform;\n";
+
+fn glsl_pragma_src(ty: StageType, src: &str) -> String {
+  let mut pragma = String::from(if ty == StageType::ComputeShader {
+    GLSL_COMPUTE_PRAGMA
+  } else {
+    GLSL_PRAGMA
+  });
   pragma.push_str(src);
   pragma
 }
@@ -391,6 +543,11 @@ fn check_uniform_type_match(
     (ICubemap, INT_SAMPLER_CUBE),
     (UICubemap, UNSIGNED_INT_SAMPLER_CUBE),
     (Cubemap, SAMPLER_CUBE),
+    (Sampler1DShadow, SAMPLER_1D_SHADOW),
+    (Sampler2DShadow, SAMPLER_2D_SHADOW),
+    (Sampler1DArrayShadow, SAMPLER_1D_ARRAY_SHADOW),
+    (Sampler2DArrayShadow, SAMPLER_2D_ARRAY_SHADOW),
+    (CubemapShadow, SAMPLER_CUBE_SHADOW),
   )
 }
 
@@ -503,6 +660,16 @@ macro_rules! impl_Uniformable {
         );
       }
     }
+
+    unsafe impl<'a, const N: usize> UniformableTransposed<'a, Arr<$t, N>> for GL33 {
+      unsafe fn update_transposed(
+        _: &mut Program,
+        uniform: &'a Uniform<Arr<$t, N>>,
+        value: Self::Target,
+      ) {
+        gl::$f(uniform.index(), N as GLsizei, gl::TRUE, value.as_ptr() as _);
+      }
+    }
   };
 
   (mat $t:ty, $uty:tt, $f:tt) => {
@@ -519,6 +686,12 @@ macro_rules! impl_Uniformable {
         gl::$f(uniform.index(), 1, gl::FALSE, value.as_ptr() as _);
       }
     }
+
+    unsafe impl<'a> UniformableTransposed<'a, $t> for GL33 {
+      unsafe fn update_transposed(_: &mut Program, uniform: &'a Uniform<$t>, value: Self::Target) {
+        gl::$f(uniform.index(), 1, gl::TRUE, value.as_ptr() as _);
+      }
+    }
   };
 }
 
@@ -769,6 +942,18 @@ where
   const SIZE: usize = 0;
 
   unsafe fn ty() -> UniformType {
+    if S::is_shadow() {
+      return match D::dim() {
+        Dim::Dim1 => UniformType::Sampler1DShadow,
+        Dim::Dim2 => UniformType::Sampler2DShadow,
+        // there is no 3D shadow sampler
+        Dim::Dim3 => UniformType::Sampler3D,
+        Dim::Cubemap => UniformType::CubemapShadow,
+        Dim::Dim1Array => UniformType::Sampler1DArrayShadow,
+        Dim::Dim2Array => UniformType::Sampler2DArrayShadow,
+      };
+    }
+
     match (S::sample_type(), D::dim()) {
       (PixelType::NormIntegral, Dim::Dim1) => UniformType::Sampler1D,
       (PixelType::NormUnsigned, Dim::Dim1) => UniformType::Sampler1D,
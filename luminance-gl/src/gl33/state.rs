@@ -8,13 +8,20 @@ use crate::gl33::{
 };
 use gl::types::*;
 use luminance::{
-  blending::{Equation, Factor},
+  backend::query::BackendLimits,
+  blending::{BlendingMode, Equation, Factor},
   depth_stencil::{Comparison, StencilOperations, StencilTest, Write},
   face_culling::{FaceCullingMode, FaceCullingOrder},
+  render_state::{DrawBufferBlending, PolygonMode, MAX_DRAW_BUFFER_BLENDING},
   scissor::ScissorRegion,
 };
 use std::{cell::RefCell, error, ffi::CStr, fmt, marker::PhantomData, os::raw::c_char};
 
+// GL_NV_conservative_raster
+const CONSERVATIVE_RASTERIZATION_NV: GLenum = 0x9346;
+// GL_INTEL_conservative_rasterization
+const CONSERVATIVE_RASTERIZATION_INTEL: GLenum = 0x83FE;
+
 // TLS synchronization barrier for `GLState`.
 //
 // Note: disable on no_std.
@@ -107,6 +114,9 @@ pub struct GLState {
   // viewport
   viewport: Cached<[GLint; 4]>,
 
+  // depth range
+  depth_range: Cached<[GLfloat; 2]>,
+
   // clear buffers
   clear_color: Cached<[GLfloat; 4]>,
   clear_depth: Cached<GLfloat>,
@@ -124,10 +134,14 @@ pub struct GLState {
   // depth write
   depth_write: Cached<Write>,
 
-  // stencil test
+  // color mask
+  color_mask: Cached<[bool; 4]>,
+
+  // stencil test; front-facing and back-facing primitives
   stencil_test_enabled: Cached<bool>,
-  stencil_test: Cached<StencilTest>,
-  stencil_operations: Cached<StencilOperations>,
+  stencil_test: Cached<[StencilTest; 2]>,
+  stencil_operations: Cached<[StencilOperations; 2]>,
+  stencil_write_mask: Cached<u8>,
 
   // face culling
   face_culling_state: Cached<FaceCullingState>,
@@ -165,6 +179,9 @@ pub struct GLState {
   // element buffer
   bound_element_array_buffer: GLuint,
 
+  // draw indirect buffer
+  bound_draw_indirect_buffer: GLuint,
+
   // framebuffer
   bound_draw_framebuffer: Cached<GLuint>,
 
@@ -174,9 +191,66 @@ pub struct GLState {
   // shader program
   current_program: GLuint,
 
+  // whether a timer query is active; only one can be at a time
+  timer_query_active: bool,
+
   // framebuffer sRGB
   srgb_framebuffer_enabled: Cached<bool>,
 
+  // sample shading minimum rate, if enabled
+  sample_shading: Cached<Option<f32>>,
+
+  // whether sample shading is supported; cached when asked the first time and then re-used
+  sample_shading_supported: Option<bool>,
+
+  // whether per-draw-buffer blending is supported; cached when asked the first time and then re-used
+  draw_buffer_blending_supported: Option<bool>,
+
+  // maximum number of draw buffers; cached when asked the first time and then re-used
+  max_draw_buffers: Option<usize>,
+
+  // per-draw-buffer blending
+  draw_buffers_blending: [DrawBufferBlendingState; MAX_DRAW_BUFFER_BLENDING],
+
+  // whether compute shaders are supported; cached when asked the first time and then re-used
+  compute_supported: Option<bool>,
+
+  // whether rendering from a base instance is supported; cached when asked the first time and then re-used
+  base_instance_supported: Option<bool>,
+
+  // whether indirect rendering is supported; cached when asked the first time and then re-used
+  draw_indirect_supported: Option<bool>,
+
+  // compute limits (maximum work group count and number of storage bindings); cached when asked the first time and
+  // then re-used
+  compute_limits: Option<([u32; 3], u32)>,
+
+  // alpha-to-coverage
+  alpha_to_coverage_enabled: Cached<bool>,
+
+  // line width
+  line_width: Cached<f32>,
+
+  // range of supported line widths; cached when asked the first time and then re-used
+  line_width_range: Option<[f32; 2]>,
+
+  // fixed point size, or None if the size is set by programs
+  point_size: Cached<Option<f32>>,
+
+  // range of supported point sizes; cached when asked the first time and then re-used
+  point_size_range: Option<[f32; 2]>,
+
+  // polygon mode
+  polygon_mode: Cached<PolygonMode>,
+
+  // depth clamp
+  depth_clamp_enabled: Cached<bool>,
+
+  // conservative rasterization; the capability to enable, if any extension provides it, is cached when asked the first
+  // time and then re-used
+  conservative_raster_enabled: Cached<bool>,
+  conservative_raster_cap: Option<Option<GLenum>>,
+
   // vendor name; cached when asked the first time and then re-used
   vendor_name: Option<String>,
 
@@ -191,6 +265,18 @@ pub struct GLState {
 
   /// Maximum number of elements a texture array can hold.
   max_texture_array_elements: Option<usize>,
+
+  /// Maximum number of vertices a patch can hold.
+  max_patch_vertex_nb: Option<usize>,
+
+  /// Limits of the context.
+  limits: Option<BackendLimits>,
+
+  /// Whether the back buffer is sRGB-capable.
+  back_buffer_srgb: Option<bool>,
+
+  // supported extensions; cached when asked the first time and then re-used
+  extensions: Option<Vec<String>>,
 }
 
 impl GLState {
@@ -219,6 +305,7 @@ impl GLState {
     unsafe {
       let binding_stack = BindingStack::new();
       let viewport = Cached::new(get_ctx_viewport()?);
+      let depth_range = Cached::new(get_ctx_depth_range()?);
       let clear_color = Cached::new(get_ctx_clear_color()?);
       let clear_depth = Cached::new(get_ctx_clear_depth()?);
       let clear_stencil = Cached::new(get_ctx_clear_stencil()?);
@@ -228,9 +315,11 @@ impl GLState {
       let depth_test = Cached::new(get_ctx_depth_test()?);
       let depth_test_comparison = Cached::new(Comparison::Less);
       let depth_write = Cached::new(get_ctx_depth_write()?);
+      let color_mask = Cached::new(get_ctx_color_mask()?);
       let stencil_test_enabled = Cached::new(get_ctx_stencil_test_enabled()?);
       let stencil_test = Cached::new(get_ctx_stencil_test()?);
       let stencil_operations = Cached::new(get_ctx_stencil_operations()?);
+      let stencil_write_mask = get_ctx_stencil_write_mask();
       let face_culling_state = Cached::new(get_ctx_face_culling_state()?);
       let face_culling_order = Cached::new(get_ctx_face_culling_order()?);
       let face_culling_mode = Cached::new(get_ctx_face_culling_mode()?);
@@ -242,10 +331,29 @@ impl GLState {
       let bound_uniform_buffers = vec![0; 36]; // 36 is the platform minimal requirement
       let bound_array_buffer = 0;
       let bound_element_array_buffer = 0;
+      let bound_draw_indirect_buffer = 0;
       let bound_draw_framebuffer = Cached::new(get_ctx_bound_draw_framebuffer()?);
       let bound_vertex_array = get_ctx_bound_vertex_array()?;
       let current_program = get_ctx_current_program()?;
       let srgb_framebuffer_enabled = Cached::new(get_ctx_srgb_framebuffer_enabled()?);
+      let sample_shading = Cached::new(None);
+      let sample_shading_supported = None;
+      let draw_buffer_blending_supported = None;
+      let max_draw_buffers = None;
+      let draw_buffers_blending = [DrawBufferBlendingState::Global; MAX_DRAW_BUFFER_BLENDING];
+      let compute_supported = None;
+      let base_instance_supported = None;
+      let draw_indirect_supported = None;
+      let compute_limits = None;
+      let alpha_to_coverage_enabled = Cached::new(get_ctx_alpha_to_coverage_enabled()?);
+      let line_width = Cached::new(get_ctx_line_width()?);
+      let line_width_range = None;
+      let point_size = Cached::new(get_ctx_point_size()?);
+      let point_size_range = None;
+      let polygon_mode = Cached::new(get_ctx_polygon_mode()?);
+      let depth_clamp_enabled = Cached::new(get_ctx_depth_clamp_enabled()?);
+      let conservative_raster_enabled = Cached::new(false);
+      let conservative_raster_cap = None;
       let scissor_state = Cached::new(get_ctx_scissor_state()?);
       let scissor_region = Cached::new(get_ctx_scissor_region()?);
       let vendor_name = None;
@@ -253,11 +361,17 @@ impl GLState {
       let gl_version = None;
       let glsl_version = None;
       let max_texture_array_elements = None;
+      let max_patch_vertex_nb = None;
+      let limits = None;
+      let back_buffer_srgb = None;
+      let extensions = None;
+      let timer_query_active = false;
 
       Ok(GLState {
         _a: PhantomData,
         binding_stack,
         viewport,
+        depth_range,
         clear_color,
         clear_depth,
         clear_stencil,
@@ -267,9 +381,11 @@ impl GLState {
         depth_test,
         depth_test_comparison,
         depth_write,
+        color_mask,
         stencil_test_enabled,
         stencil_test,
         stencil_operations,
+        stencil_write_mask,
         face_culling_state,
         face_culling_order,
         face_culling_mode,
@@ -281,10 +397,30 @@ impl GLState {
         bound_uniform_buffers,
         bound_array_buffer,
         bound_element_array_buffer,
+        bound_draw_indirect_buffer,
         bound_draw_framebuffer,
         bound_vertex_array,
         current_program,
+        timer_query_active,
         srgb_framebuffer_enabled,
+        sample_shading,
+        sample_shading_supported,
+        draw_buffer_blending_supported,
+        max_draw_buffers,
+        draw_buffers_blending,
+        compute_supported,
+        base_instance_supported,
+        draw_indirect_supported,
+        compute_limits,
+        alpha_to_coverage_enabled,
+        line_width,
+        line_width_range,
+        point_size,
+        point_size_range,
+        polygon_mode,
+        depth_clamp_enabled,
+        conservative_raster_enabled,
+        conservative_raster_cap,
         scissor_state,
         scissor_region,
         vendor_name,
@@ -292,6 +428,10 @@ impl GLState {
         gl_version,
         glsl_version,
         max_texture_array_elements,
+        max_patch_vertex_nb,
+        limits,
+        back_buffer_srgb,
+        extensions,
       })
     }
   }
@@ -321,6 +461,11 @@ impl GLState {
     self.bound_element_array_buffer = 0;
   }
 
+  /// Invalidate the currently in-use draw indirect buffer.
+  pub fn invalidate_draw_indirect_buffer(&mut self) {
+    self.bound_draw_indirect_buffer = 0;
+  }
+
   /// Invalidate the currently in-use texture unit.
   pub fn invalidate_texture_unit(&mut self) {
     self.current_texture_unit.invalidate();
@@ -345,6 +490,11 @@ impl GLState {
     self.viewport.invalidate()
   }
 
+  /// Invalidate the currently in-use depth range.
+  pub fn invalidate_depth_range(&mut self) {
+    self.depth_range.invalidate()
+  }
+
   /// Invalidate the currently in-use clear color.
   pub fn invalidate_clear_color(&mut self) {
     self.clear_color.invalidate()
@@ -380,6 +530,11 @@ impl GLState {
     self.depth_write.invalidate()
   }
 
+  /// Invalidate the currently in-use color mask.
+  pub fn invalidate_color_mask(&mut self) {
+    self.color_mask.invalidate()
+  }
+
   /// Invalidate the currently in-use face culling state.
   pub fn invalidate_face_culling_state(&mut self) {
     self.face_culling_state.invalidate()
@@ -410,6 +565,41 @@ impl GLState {
     self.srgb_framebuffer_enabled.invalidate()
   }
 
+  /// Invalidate the currently in-use sample shading state.
+  pub fn invalidate_sample_shading(&mut self) {
+    self.sample_shading.invalidate()
+  }
+
+  /// Invalidate the currently in-use alpha-to-coverage state.
+  pub fn invalidate_alpha_to_coverage_enabled(&mut self) {
+    self.alpha_to_coverage_enabled.invalidate()
+  }
+
+  /// Invalidate the currently in-use line width.
+  pub fn invalidate_line_width(&mut self) {
+    self.line_width.invalidate()
+  }
+
+  /// Invalidate the currently in-use point size state.
+  pub fn invalidate_point_size(&mut self) {
+    self.point_size.invalidate()
+  }
+
+  /// Invalidate the currently in-use polygon mode.
+  pub fn invalidate_polygon_mode(&mut self) {
+    self.polygon_mode.invalidate()
+  }
+
+  /// Invalidate the currently in-use depth clamp state.
+  pub fn invalidate_depth_clamp_enabled(&mut self) {
+    self.depth_clamp_enabled.invalidate()
+  }
+
+  /// Invalidate the currently in-use conservative rasterization state.
+  pub fn invalidate_conservative_raster_enabled(&mut self) {
+    self.conservative_raster_enabled.invalidate()
+  }
+
   /// Marshal a string represented as `*const c_uchar`, represented by the input argument, into a `&str`.
   ///
   /// The string is returned in a lossy way, which means that non-unicode characters go wheeeeeeeeeeee.
@@ -478,6 +668,117 @@ impl GLState {
     })
   }
 
+  /// Get the maximum number of vertices a patch can hold.
+  ///
+  /// Cache the number on the first call and then re-use it for later calls. If tessellation shaders are not supported
+  /// by the context (they require OpenGL 4.0 or the `GL_ARB_tessellation_shader` extension), the number is `0` and
+  /// `GL_MAX_PATCH_VERTICES` is not queried, as it’s an invalid enum for such contexts.
+  pub fn get_max_patch_vertex_nb(&mut self) -> usize {
+    if let Some(max) = self.max_patch_vertex_nb {
+      return max;
+    }
+
+    let mut major = 0;
+    unsafe { gl::GetIntegerv(gl::MAJOR_VERSION, &mut major) };
+
+    let max = if major >= 4 || self.has_extension("GL_ARB_tessellation_shader") {
+      let mut max = 0;
+      unsafe { gl::GetIntegerv(gl::MAX_PATCH_VERTICES, &mut max) };
+      max.max(0) as usize
+    } else {
+      0
+    };
+    self.max_patch_vertex_nb = Some(max);
+
+    max
+  }
+
+  /// Get the limits of the context.
+  ///
+  /// Cache the limits on the first call and then re-use them for later calls.
+  pub fn get_limits(&mut self) -> BackendLimits {
+    if let Some(limits) = self.limits {
+      return limits;
+    }
+
+    let get = |param| {
+      let mut value = 0;
+      unsafe { gl::GetIntegerv(param, &mut value) };
+      value.max(0) as usize
+    };
+
+    let mut max_uniform_block_size = 0;
+    unsafe { gl::GetInteger64v(gl::MAX_UNIFORM_BLOCK_SIZE, &mut max_uniform_block_size) };
+
+    let conservative_raster = self.conservative_raster_cap().is_some();
+
+    let limits = BackendLimits::new(
+      get(gl::MAX_TEXTURE_SIZE),
+      self.get_max_texture_array_elements(),
+      get(gl::MAX_COLOR_ATTACHMENTS),
+      get(gl::MAX_VERTEX_ATTRIBS),
+      max_uniform_block_size.max(0) as usize,
+      get(gl::MAX_SAMPLES),
+    )
+    .set_conservative_raster(conservative_raster);
+
+    self.limits = Some(limits);
+    limits
+  }
+
+  /// Check whether the back buffer is sRGB-capable, i.e. whether its color encoding is sRGB.
+  ///
+  /// Cache the result on the first call and then re-use it for later calls.
+  pub fn is_back_buffer_srgb(&mut self) -> bool {
+    if let Some(srgb) = self.back_buffer_srgb {
+      return srgb;
+    }
+
+    let mut encoding = 0;
+
+    unsafe {
+      self.bind_draw_framebuffer(0);
+      gl::GetFramebufferAttachmentParameteriv(
+        gl::DRAW_FRAMEBUFFER,
+        gl::BACK_LEFT,
+        gl::FRAMEBUFFER_ATTACHMENT_COLOR_ENCODING,
+        &mut encoding,
+      );
+    }
+
+    let srgb = encoding as GLenum == gl::SRGB;
+    self.back_buffer_srgb = Some(srgb);
+
+    srgb
+  }
+
+  /// Check whether an extension is supported by the context.
+  ///
+  /// Cache the list of extensions on the first call and then re-use it for later calls.
+  pub fn has_extension(&mut self, name: &str) -> bool {
+    let extensions = self.extensions.get_or_insert_with(|| unsafe {
+      let mut nb = 0;
+      gl::GetIntegerv(gl::NUM_EXTENSIONS, &mut nb);
+
+      (0..nb.max(0) as GLuint)
+        .map(|i| {
+          let name = CStr::from_ptr(gl::GetStringi(gl::EXTENSIONS, i) as *const c_char);
+          name.to_string_lossy().into_owned()
+        })
+        .collect()
+    });
+
+    extensions.iter().any(|ext| ext == name)
+  }
+
+  pub(crate) fn is_timer_query_active(&self) -> bool {
+    self.timer_query_active
+  }
+
+  pub(crate) fn set_timer_query_active(&mut self, active: bool) {
+    self.timer_query_active = active;
+  }
+
   pub(crate) fn binding_stack_mut(&mut self) -> &mut BindingStack {
     &mut self.binding_stack
   }
@@ -513,6 +814,13 @@ impl GLState {
     }
   }
 
+  pub(crate) unsafe fn set_depth_range(&mut self, depth_range: [GLfloat; 2]) {
+    if self.depth_range.is_invalid(&depth_range) {
+      gl::DepthRange(depth_range[0] as _, depth_range[1] as _);
+      self.depth_range.set(depth_range);
+    }
+  }
+
   pub(crate) unsafe fn set_clear_color(&mut self, clear_color: [GLfloat; 4]) {
     if self.clear_color.is_invalid(&clear_color) {
       gl::ClearColor(
@@ -547,7 +855,184 @@ impl GLState {
       }
 
       self.blending_state.set(state);
+      self.overwrite_draw_buffers_blending();
+    }
+  }
+
+  /// Check whether per-draw-buffer blending is supported, which requires OpenGL 4.0.
+  fn supports_draw_buffer_blending(&mut self) -> bool {
+    if let Some(supported) = self.draw_buffer_blending_supported {
+      return supported;
+    }
+
+    let mut major = 0;
+    unsafe { gl::GetIntegerv(gl::MAJOR_VERSION, &mut major) };
+    let supported = major >= 4;
+    self.draw_buffer_blending_supported = Some(supported);
+
+    supported
+  }
+
+  /// Maximum number of draw buffers.
+  fn max_draw_buffers(&mut self) -> usize {
+    *self.max_draw_buffers.get_or_insert_with(|| unsafe {
+      let mut max = 0;
+      gl::GetIntegerv(gl::MAX_DRAW_BUFFERS, &mut max);
+      max.max(0) as usize
+    })
+  }
+
+  /// Mark the draw buffers with their own blending configuration as overwritten by a global blending call.
+  fn overwrite_draw_buffers_blending(&mut self) {
+    for state in &mut self.draw_buffers_blending {
+      if let DrawBufferBlendingState::Set(_) = state {
+        *state = DrawBufferBlendingState::Overwritten;
+      }
+    }
+  }
+
+  /// Make the draw buffers not overridden by `overrides` anymore follow the global blending state again.
+  ///
+  /// The global blending states are invalidated if any draw buffer is reset, so that setting them applies to all the
+  /// draw buffers again. This must then be called before setting the global blending states.
+  pub(crate) fn reset_draw_buffers_blending(
+    &mut self,
+    overrides: &[DrawBufferBlending; MAX_DRAW_BUFFER_BLENDING],
+  ) {
+    let mut reset = false;
+
+    for (state, blending) in self.draw_buffers_blending.iter_mut().zip(overrides) {
+      if *blending == DrawBufferBlending::Inherit && *state != DrawBufferBlendingState::Global {
+        *state = DrawBufferBlendingState::Global;
+        reset = true;
+      }
+    }
+
+    if reset {
+      self.blending_state.invalidate();
+      self.blending_equations.invalidate();
+      self.blending_funcs.invalidate();
+    }
+  }
+
+  /// Check whether compute shaders are supported, which requires OpenGL 4.3.
+  pub(crate) fn supports_compute(&mut self) -> bool {
+    if let Some(supported) = self.compute_supported {
+      return supported;
+    }
+
+    let (mut major, mut minor) = (0, 0);
+    unsafe {
+      gl::GetIntegerv(gl::MAJOR_VERSION, &mut major);
+      gl::GetIntegerv(gl::MINOR_VERSION, &mut minor);
+    }
+    let supported = (major, minor) >= (4, 3);
+    self.compute_supported = Some(supported);
+
+    supported
+  }
+
+  /// Check whether rendering from a base instance is supported, either by OpenGL 4.2 or by the `GL_ARB_base_instance`
+  /// extension.
+  pub(crate) fn supports_base_instance(&mut self) -> bool {
+    if let Some(supported) = self.base_instance_supported {
+      return supported;
+    }
+
+    let (mut major, mut minor) = (0, 0);
+    unsafe {
+      gl::GetIntegerv(gl::MAJOR_VERSION, &mut major);
+      gl::GetIntegerv(gl::MINOR_VERSION, &mut minor);
+    }
+    let supported = (major, minor) >= (4, 2) || self.has_extension("GL_ARB_base_instance");
+    self.base_instance_supported = Some(supported);
+
+    supported
+  }
+
+  /// Check whether indirect rendering is supported, either by OpenGL 4.0 or by the `GL_ARB_draw_indirect` extension.
+  pub(crate) fn supports_draw_indirect(&mut self) -> bool {
+    if let Some(supported) = self.draw_indirect_supported {
+      return supported;
+    }
+
+    let mut major = 0;
+    unsafe { gl::GetIntegerv(gl::MAJOR_VERSION, &mut major) };
+    let supported = major >= 4 || self.has_extension("GL_ARB_draw_indirect");
+    self.draw_indirect_supported = Some(supported);
+
+    supported
+  }
+
+  /// Maximum number of compute work groups along each dimension and number of shader storage binding points.
+  pub(crate) fn compute_limits(&mut self) -> ([u32; 3], u32) {
+    *self.compute_limits.get_or_insert_with(|| unsafe {
+      let mut groups = [0; 3];
+
+      for (i, max) in groups.iter_mut().enumerate() {
+        let mut value = 0;
+        gl::GetIntegeri_v(gl::MAX_COMPUTE_WORK_GROUP_COUNT, i as GLuint, &mut value);
+        *max = value.max(0) as u32;
+      }
+
+      let mut bindings = 0;
+      gl::GetIntegerv(gl::MAX_SHADER_STORAGE_BUFFER_BINDINGS, &mut bindings);
+
+      (groups, bindings.max(0) as u32)
+    })
+  }
+
+  /// Set the blending configuration of a single draw buffer, or disable blending for it with `None`.
+  ///
+  /// This must be called after setting the global blending states, which overwrite the configuration of all the draw
+  /// buffers. This is a no-op if per-draw-buffer blending is not supported or if `draw_buffer` is not lesser than
+  /// `GL_MAX_DRAW_BUFFERS`.
+  pub(crate) unsafe fn set_draw_buffer_blending(
+    &mut self,
+    draw_buffer: usize,
+    blending: Option<BlendingMode>,
+  ) {
+    if !self.supports_draw_buffer_blending() || draw_buffer >= self.max_draw_buffers() {
+      return;
     }
+
+    let state = DrawBufferBlendingState::Set(blending);
+    if self.draw_buffers_blending[draw_buffer] == state {
+      return;
+    }
+
+    let index = draw_buffer as GLuint;
+    match blending {
+      Some(BlendingMode::Combined(b)) => {
+        gl::Enablei(gl::BLEND, index);
+        gl::BlendEquationi(index, from_blending_equation(b.equation));
+        gl::BlendFunci(
+          index,
+          from_blending_factor(b.src),
+          from_blending_factor(b.dst),
+        );
+      }
+
+      Some(BlendingMode::Separate { rgb, alpha }) => {
+        gl::Enablei(gl::BLEND, index);
+        gl::BlendEquationSeparatei(
+          index,
+          from_blending_equation(rgb.equation),
+          from_blending_equation(alpha.equation),
+        );
+        gl::BlendFuncSeparatei(
+          index,
+          from_blending_factor(rgb.src),
+          from_blending_factor(rgb.dst),
+          from_blending_factor(alpha.src),
+          from_blending_factor(alpha.dst),
+        );
+      }
+
+      None => gl::Disablei(gl::BLEND, index),
+    }
+
+    self.draw_buffers_blending[draw_buffer] = state;
   }
 
   pub(crate) unsafe fn set_scissor_state(&mut self, state: ScissorState) {
@@ -570,7 +1055,9 @@ impl GLState {
         height,
       } = *region;
 
-      gl::Scissor(x as GLint, y as GLint, width as GLint, height as GLint);
+      // regions larger than what GL can represent are saturated instead of wrapping to negative values
+      let clamp = |v: u32| v.min(GLint::MAX as u32) as GLint;
+      gl::Scissor(clamp(x), clamp(y), clamp(width), clamp(height));
 
       self.scissor_region.set(*region);
     }
@@ -585,6 +1072,7 @@ impl GLState {
     if self.blending_equations.is_invalid(&equations) {
       gl::BlendEquation(from_blending_equation(equation));
       self.blending_equations.set(equations);
+      self.overwrite_draw_buffers_blending();
     }
   }
 
@@ -605,6 +1093,7 @@ impl GLState {
       );
 
       self.blending_equations.set(equations);
+      self.overwrite_draw_buffers_blending();
     }
   }
 
@@ -619,6 +1108,7 @@ impl GLState {
     if self.blending_funcs.is_invalid(&funcs) {
       gl::BlendFunc(from_blending_factor(src), from_blending_factor(dst));
       self.blending_funcs.set(funcs);
+      self.overwrite_draw_buffers_blending();
     }
   }
 
@@ -645,6 +1135,7 @@ impl GLState {
       );
 
       self.blending_funcs.set(funcs);
+      self.overwrite_draw_buffers_blending();
     }
   }
 
@@ -682,6 +1173,15 @@ impl GLState {
     }
   }
 
+  pub(crate) unsafe fn set_color_mask(&mut self, color_mask: [bool; 4]) {
+    if self.color_mask.is_invalid(&color_mask) {
+      let [r, g, b, a] = color_mask.map(|enabled| if enabled { gl::TRUE } else { gl::FALSE });
+      gl::ColorMask(r, g, b, a);
+
+      self.color_mask.set(color_mask);
+    }
+  }
+
   pub(crate) unsafe fn enable_stencil_test(&mut self, enable: bool) {
     if self.stencil_test_enabled.is_invalid(&enable) {
       if enable {
@@ -694,31 +1194,69 @@ impl GLState {
     }
   }
 
-  pub(crate) unsafe fn set_stencil_test(&mut self, stencil_test: StencilTest) {
+  /// Set the stencil test of front-facing and back-facing primitives.
+  pub(crate) unsafe fn set_stencil_test(&mut self, front: StencilTest, back: StencilTest) {
+    let stencil_test = [front, back];
+
     if self.stencil_test.is_invalid(&stencil_test) {
-      let comparison = comparison_to_glenum(stencil_test.comparison);
-      gl::StencilFunc(
-        comparison,
-        stencil_test.reference as _,
-        stencil_test.mask as _,
-      );
+      if front == back {
+        gl::StencilFunc(
+          comparison_to_glenum(front.comparison),
+          front.reference as _,
+          front.mask as _,
+        );
+      } else {
+        for (face, test) in [(gl::FRONT, front), (gl::BACK, back)] {
+          gl::StencilFuncSeparate(
+            face,
+            comparison_to_glenum(test.comparison),
+            test.reference as _,
+            test.mask as _,
+          );
+        }
+      }
 
       self.stencil_test.set(stencil_test);
     }
   }
 
-  pub(crate) unsafe fn set_stencil_operations(&mut self, stencil_ops: StencilOperations) {
+  /// Set the stencil operations of front-facing and back-facing primitives.
+  pub(crate) unsafe fn set_stencil_operations(
+    &mut self,
+    front: StencilOperations,
+    back: StencilOperations,
+  ) {
+    let stencil_ops = [front, back];
+
     if self.stencil_operations.is_invalid(&stencil_ops) {
-      gl::StencilOp(
-        stencil_op_to_glenum(stencil_ops.depth_passes_stencil_fails),
-        stencil_op_to_glenum(stencil_ops.depth_fails_stencil_passes),
-        stencil_op_to_glenum(stencil_ops.depth_stencil_pass),
-      );
+      if front == back {
+        gl::StencilOp(
+          stencil_op_to_glenum(front.depth_passes_stencil_fails),
+          stencil_op_to_glenum(front.depth_fails_stencil_passes),
+          stencil_op_to_glenum(front.depth_stencil_pass),
+        );
+      } else {
+        for (face, ops) in [(gl::FRONT, front), (gl::BACK, back)] {
+          gl::StencilOpSeparate(
+            face,
+            stencil_op_to_glenum(ops.depth_passes_stencil_fails),
+            stencil_op_to_glenum(ops.depth_fails_stencil_passes),
+            stencil_op_to_glenum(ops.depth_stencil_pass),
+          );
+        }
+      }
 
       self.stencil_operations.set(stencil_ops);
     }
   }
 
+  pub(crate) unsafe fn set_stencil_write_mask(&mut self, mask: u8) {
+    if self.stencil_write_mask.is_invalid(&mask) {
+      gl::StencilMask(mask as _);
+      self.stencil_write_mask.set(mask);
+    }
+  }
+
   pub(crate) unsafe fn set_face_culling_state(&mut self, state: FaceCullingState) {
     if self.face_culling_state.is_invalid(&state) {
       match state {
@@ -827,6 +1365,13 @@ impl GLState {
     }
   }
 
+  pub(crate) unsafe fn bind_draw_indirect_buffer(&mut self, handle: GLuint, bind: Bind) {
+    if bind == Bind::Forced || self.bound_draw_indirect_buffer != handle {
+      gl::BindBuffer(gl::DRAW_INDIRECT_BUFFER, handle);
+      self.bound_draw_indirect_buffer = handle;
+    }
+  }
+
   pub(crate) unsafe fn bind_uniform_buffer(&mut self, handle: GLuint, binding: u32) {
     let binding_ = binding as usize;
 
@@ -853,6 +1398,8 @@ impl GLState {
       self.bind_array_buffer(0, Bind::Cached);
     } else if self.bound_element_array_buffer == handle {
       self.bind_element_array_buffer(0, Bind::Cached);
+    } else if self.bound_draw_indirect_buffer == handle {
+      self.bind_draw_indirect_buffer(0, Bind::Cached);
     } else if let Some(handle_) = self
       .bound_uniform_buffers
       .iter_mut()
@@ -901,6 +1448,191 @@ impl GLState {
       self.srgb_framebuffer_enabled.set(srgb_framebuffer_enabled);
     }
   }
+
+  pub(crate) unsafe fn enable_alpha_to_coverage(&mut self, alpha_to_coverage_enabled: bool) {
+    if self
+      .alpha_to_coverage_enabled
+      .is_invalid(&alpha_to_coverage_enabled)
+    {
+      if alpha_to_coverage_enabled {
+        gl::Enable(gl::SAMPLE_ALPHA_TO_COVERAGE);
+      } else {
+        gl::Disable(gl::SAMPLE_ALPHA_TO_COVERAGE);
+      }
+
+      self
+        .alpha_to_coverage_enabled
+        .set(alpha_to_coverage_enabled);
+    }
+  }
+
+  /// Check whether sample shading is supported, either by OpenGL 4.0 or by the `GL_ARB_sample_shading` extension.
+  fn supports_sample_shading(&mut self) -> bool {
+    if let Some(supported) = self.sample_shading_supported {
+      return supported;
+    }
+
+    let mut major = 0;
+    unsafe { gl::GetIntegerv(gl::MAJOR_VERSION, &mut major) };
+    let supported = major >= 4 || self.has_extension("GL_ARB_sample_shading");
+    self.sample_shading_supported = Some(supported);
+
+    supported
+  }
+
+  /// Set the sample shading minimum rate, or disable sample shading with `None`.
+  ///
+  /// This is a no-op if sample shading is not supported.
+  pub(crate) unsafe fn set_sample_shading(&mut self, sample_shading: Option<f32>) {
+    if self.sample_shading.is_invalid(&sample_shading) && self.supports_sample_shading() {
+      match sample_shading {
+        Some(rate) => {
+          gl::Enable(gl::SAMPLE_SHADING);
+          gl::MinSampleShading(rate);
+        }
+
+        None => gl::Disable(gl::SAMPLE_SHADING),
+      }
+
+      self.sample_shading.set(sample_shading);
+    }
+  }
+
+  /// Set the line width, clamped to the range of widths supported by the implementation.
+  ///
+  /// Forward-compatible core contexts might report a range wider than what they actually support; in that case, the
+  /// width is rejected by OpenGL (`GL_INVALID_VALUE`) and left unchanged. The range is then reduced to `1.`, so that
+  /// the cached width always matches the one OpenGL uses and the rejected width is not set again.
+  pub(crate) unsafe fn set_line_width(&mut self, width: f32) {
+    let [min, max] = self.line_width_range();
+    let width = width.clamp(min, max);
+
+    if self.line_width.is_invalid(&width) {
+      drain_gl_errors();
+      gl::LineWidth(width);
+
+      if gl::GetError() == gl::INVALID_VALUE {
+        self.line_width_range = Some([1., 1.]);
+        self.line_width.invalidate();
+        self.set_line_width(1.);
+      } else {
+        self.line_width.set(width);
+      }
+    }
+  }
+
+  fn line_width_range(&mut self) -> [f32; 2] {
+    *self.line_width_range.get_or_insert_with(|| {
+      let mut range = [1., 1.];
+      unsafe { gl::GetFloatv(gl::ALIASED_LINE_WIDTH_RANGE, range.as_mut_ptr()) };
+      range
+    })
+  }
+
+  /// Set a fixed point size, clamped to the range of sizes supported by the implementation, or let programs set it
+  /// with `None`.
+  pub(crate) unsafe fn set_point_size(&mut self, size: Option<f32>) {
+    let [min, max] = self.point_size_range();
+    let size = size.map(|size| size.clamp(min, max));
+
+    if self.point_size.is_invalid(&size) {
+      match size {
+        Some(size) => {
+          gl::Disable(gl::PROGRAM_POINT_SIZE);
+          gl::PointSize(size);
+        }
+
+        None => gl::Enable(gl::PROGRAM_POINT_SIZE),
+      }
+
+      self.point_size.set(size);
+    }
+  }
+
+  fn point_size_range(&mut self) -> [f32; 2] {
+    *self.point_size_range.get_or_insert_with(|| {
+      let mut range = [1., 1.];
+      unsafe { gl::GetFloatv(gl::POINT_SIZE_RANGE, range.as_mut_ptr()) };
+      range
+    })
+  }
+
+  /// Set the polygon mode of both front-facing and back-facing polygons, which is the only option in core profile.
+  pub(crate) unsafe fn set_polygon_mode(&mut self, polygon_mode: PolygonMode) {
+    if self.polygon_mode.is_invalid(&polygon_mode) {
+      gl::PolygonMode(gl::FRONT_AND_BACK, polygon_mode_to_glenum(polygon_mode));
+      self.polygon_mode.set(polygon_mode);
+    }
+  }
+
+  pub(crate) unsafe fn enable_depth_clamp(&mut self, depth_clamp_enabled: bool) {
+    if self.depth_clamp_enabled.is_invalid(&depth_clamp_enabled) {
+      if depth_clamp_enabled {
+        gl::Enable(gl::DEPTH_CLAMP);
+      } else {
+        gl::Disable(gl::DEPTH_CLAMP);
+      }
+
+      self.depth_clamp_enabled.set(depth_clamp_enabled);
+    }
+  }
+
+  /// Capability to enable conservative rasterization with, provided by either the `GL_NV_conservative_raster` or the
+  /// `GL_INTEL_conservative_rasterization` extension.
+  fn conservative_raster_cap(&mut self) -> Option<GLenum> {
+    if let Some(cap) = self.conservative_raster_cap {
+      return cap;
+    }
+
+    let cap = if self.has_extension("GL_NV_conservative_raster") {
+      Some(CONSERVATIVE_RASTERIZATION_NV)
+    } else if self.has_extension("GL_INTEL_conservative_rasterization") {
+      Some(CONSERVATIVE_RASTERIZATION_INTEL)
+    } else {
+      None
+    };
+    self.conservative_raster_cap = Some(cap);
+
+    cap
+  }
+
+  /// Enable or disable conservative rasterization.
+  ///
+  /// This is a no-op if conservative rasterization is not supported.
+  pub(crate) unsafe fn enable_conservative_raster(&mut self, conservative_raster_enabled: bool) {
+    if self
+      .conservative_raster_enabled
+      .is_invalid(&conservative_raster_enabled)
+    {
+      if let Some(cap) = self.conservative_raster_cap() {
+        if conservative_raster_enabled {
+          gl::Enable(cap);
+        } else {
+          gl::Disable(cap);
+        }
+
+        self
+          .conservative_raster_enabled
+          .set(conservative_raster_enabled);
+      }
+    }
+  }
+}
+
+/// Clear the OpenGL error queue.
+///
+/// This must be called before a call whose failure is detected with `glGetError`, so that errors raised earlier —
+/// possibly by code outside of luminance — are not mistaken for errors of that call.
+///
+/// The number of errors read is capped, as some drivers keep reporting errors, e.g. once the context is lost.
+pub(crate) fn drain_gl_errors() {
+  const MAX_DRAINED_ERRORS: usize = 32;
+
+  for _ in 0..MAX_DRAINED_ERRORS {
+    if unsafe { gl::GetError() } == gl::NO_ERROR {
+      break;
+    }
+  }
 }
 
 /// Should the binding be cached or forced to the provided value?
@@ -940,7 +1672,7 @@ fn from_blending_factor(factor: Factor) -> GLenum {
 
 /// An error that might happen when the context is queried.
 #[non_exhaustive]
-#[derive(Debug)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum StateQueryError {
   /// The [`GLState`] object is unavailable.
   ///
@@ -977,6 +1709,14 @@ pub enum StateQueryError {
   UnknownSRGBFramebufferState(GLboolean),
   /// Corrupted scissor state.
   UnknownScissorState(GLboolean),
+  /// Corrupted alpha-to-coverage state.
+  UnknownAlphaToCoverageState(GLboolean),
+  /// Corrupted program point size state.
+  UnknownProgramPointSizeState(GLboolean),
+  /// Corrupted polygon mode.
+  UnknownPolygonMode(GLenum),
+  /// Corrupted depth clamp state.
+  UnknownDepthClampState(GLboolean),
 }
 
 impl fmt::Display for StateQueryError {
@@ -1022,6 +1762,16 @@ impl fmt::Display for StateQueryError {
         write!(f, "unknown sRGB framebuffer state: {}", s)
       }
       StateQueryError::UnknownScissorState(ref s) => write!(f, "unknown scissor state: {}", s),
+      StateQueryError::UnknownAlphaToCoverageState(ref s) => {
+        write!(f, "unknown alpha-to-coverage state: {}", s)
+      }
+      StateQueryError::UnknownProgramPointSizeState(ref s) => {
+        write!(f, "unknown program point size state: {}", s)
+      }
+      StateQueryError::UnknownPolygonMode(ref m) => write!(f, "unknown polygon mode: {}", m),
+      StateQueryError::UnknownDepthClampState(ref s) => {
+        write!(f, "unknown depth clamp state: {}", s)
+      }
     }
   }
 }
@@ -1034,6 +1784,12 @@ unsafe fn get_ctx_viewport() -> Result<[GLint; 4], StateQueryError> {
   Ok(data)
 }
 
+unsafe fn get_ctx_depth_range() -> Result<[GLfloat; 2], StateQueryError> {
+  let mut data = [0.; 2];
+  gl::GetFloatv(gl::DEPTH_RANGE, data.as_mut_ptr());
+  Ok(data)
+}
+
 unsafe fn get_ctx_clear_color() -> Result<[GLfloat; 4], StateQueryError> {
   let mut data = [0.; 4];
   gl::GetFloatv(gl::COLOR_CLEAR_VALUE, data.as_mut_ptr());
@@ -1177,6 +1933,23 @@ unsafe fn get_ctx_depth_write() -> Result<Write, StateQueryError> {
   }
 }
 
+unsafe fn get_ctx_color_mask() -> Result<[bool; 4], StateQueryError> {
+  let mut state = [gl::FALSE; 4];
+
+  gl::GetBooleanv(gl::COLOR_WRITEMASK, state.as_mut_ptr());
+
+  let mut color_mask = [false; 4];
+  for (enabled, state) in color_mask.iter_mut().zip(state) {
+    *enabled = match state {
+      gl::TRUE => true,
+      gl::FALSE => false,
+      _ => return Err(StateQueryError::UnknownWriteState(state)),
+    };
+  }
+
+  Ok(color_mask)
+}
+
 unsafe fn get_ctx_stencil_test_enabled() -> Result<bool, StateQueryError> {
   let state = gl::IsEnabled(gl::STENCIL_TEST);
 
@@ -1187,18 +1960,33 @@ unsafe fn get_ctx_stencil_test_enabled() -> Result<bool, StateQueryError> {
   }
 }
 
-unsafe fn get_ctx_stencil_test() -> Result<StencilTest, StateQueryError> {
+unsafe fn get_ctx_stencil_test() -> Result<[StencilTest; 2], StateQueryError> {
+  let front = get_ctx_face_stencil_test(gl::STENCIL_FUNC, gl::STENCIL_REF, gl::STENCIL_VALUE_MASK)?;
+  let back = get_ctx_face_stencil_test(
+    gl::STENCIL_BACK_FUNC,
+    gl::STENCIL_BACK_REF,
+    gl::STENCIL_BACK_VALUE_MASK,
+  )?;
+
+  Ok([front, back])
+}
+
+unsafe fn get_ctx_face_stencil_test(
+  func: GLenum,
+  reference: GLenum,
+  mask: GLenum,
+) -> Result<StencilTest, StateQueryError> {
   // we need the comparison function, the reference value and the mask
   let mut data = gl::ALWAYS as GLint;
 
-  gl::GetIntegerv(gl::STENCIL_FUNC, &mut data);
+  gl::GetIntegerv(func, &mut data);
   let comparison = glenum_to_comparison(data as GLenum)
     .ok_or_else(|| StateQueryError::UnknownStencilTestComparison(data))?;
 
-  gl::GetIntegerv(gl::STENCIL_REF, &mut data);
+  gl::GetIntegerv(reference, &mut data);
   let reference = data as u8;
 
-  gl::GetIntegerv(gl::STENCIL_VALUE_MASK, &mut data);
+  gl::GetIntegerv(mask, &mut data);
   let mask = data as u8;
 
   Ok(StencilTest {
@@ -1208,16 +1996,35 @@ unsafe fn get_ctx_stencil_test() -> Result<StencilTest, StateQueryError> {
   })
 }
 
-unsafe fn get_ctx_stencil_operations() -> Result<StencilOperations, StateQueryError> {
+unsafe fn get_ctx_stencil_operations() -> Result<[StencilOperations; 2], StateQueryError> {
+  let front = get_ctx_face_stencil_operations(
+    gl::STENCIL_FAIL,
+    gl::STENCIL_PASS_DEPTH_FAIL,
+    gl::STENCIL_PASS_DEPTH_PASS,
+  )?;
+  let back = get_ctx_face_stencil_operations(
+    gl::STENCIL_BACK_FAIL,
+    gl::STENCIL_BACK_PASS_DEPTH_FAIL,
+    gl::STENCIL_BACK_PASS_DEPTH_PASS,
+  )?;
+
+  Ok([front, back])
+}
+
+unsafe fn get_ctx_face_stencil_operations(
+  fail: GLenum,
+  pass_depth_fail: GLenum,
+  pass_depth_pass: GLenum,
+) -> Result<StencilOperations, StateQueryError> {
   let mut data = 0 as GLint;
 
-  gl::GetIntegerv(gl::STENCIL_FAIL, &mut data);
+  gl::GetIntegerv(fail, &mut data);
   let depth_passes_stencil_fails =
     glenum_to_stencil_op(data as _).ok_or_else(|| StateQueryError::UnknownStencilOp(data))?;
-  gl::GetIntegerv(gl::STENCIL_PASS_DEPTH_FAIL, &mut data);
+  gl::GetIntegerv(pass_depth_fail, &mut data);
   let depth_fails_stencil_passes =
     glenum_to_stencil_op(data as _).ok_or_else(|| StateQueryError::UnknownStencilOp(data))?;
-  gl::GetIntegerv(gl::STENCIL_PASS_DEPTH_PASS, &mut data);
+  gl::GetIntegerv(pass_depth_pass, &mut data);
   let depth_stencil_pass =
     glenum_to_stencil_op(data as _).ok_or_else(|| StateQueryError::UnknownStencilOp(data))?;
 
@@ -1228,6 +2035,22 @@ unsafe fn get_ctx_stencil_operations() -> Result<StencilOperations, StateQueryEr
   })
 }
 
+unsafe fn get_ctx_stencil_write_mask() -> Cached<u8> {
+  let mut front = 0xFF;
+  let mut back = 0xFF;
+
+  gl::GetIntegerv(gl::STENCIL_WRITEMASK, &mut front);
+  gl::GetIntegerv(gl::STENCIL_BACK_WRITEMASK, &mut back);
+
+  // the mask is always set for both faces at once, so different masks are treated as unknown
+  let mut mask = Cached::new(front as u8);
+  if front as u8 != back as u8 {
+    mask.invalidate();
+  }
+
+  mask
+}
+
 unsafe fn get_ctx_face_culling_state() -> Result<FaceCullingState, StateQueryError> {
   let state = gl::IsEnabled(gl::CULL_FACE);
 
@@ -1307,6 +2130,67 @@ unsafe fn get_ctx_srgb_framebuffer_enabled() -> Result<bool, StateQueryError> {
   }
 }
 
+unsafe fn get_ctx_alpha_to_coverage_enabled() -> Result<bool, StateQueryError> {
+  let state = gl::IsEnabled(gl::SAMPLE_ALPHA_TO_COVERAGE);
+
+  match state {
+    gl::TRUE => Ok(true),
+    gl::FALSE => Ok(false),
+    _ => Err(StateQueryError::UnknownAlphaToCoverageState(state)),
+  }
+}
+
+unsafe fn get_ctx_line_width() -> Result<GLfloat, StateQueryError> {
+  let mut data = 0.;
+  gl::GetFloatv(gl::LINE_WIDTH, &mut data);
+  Ok(data)
+}
+
+unsafe fn get_ctx_point_size() -> Result<Option<GLfloat>, StateQueryError> {
+  let state = gl::IsEnabled(gl::PROGRAM_POINT_SIZE);
+
+  match state {
+    gl::TRUE => Ok(None),
+    gl::FALSE => {
+      let mut data = 0.;
+      gl::GetFloatv(gl::POINT_SIZE, &mut data);
+      Ok(Some(data))
+    }
+    _ => Err(StateQueryError::UnknownProgramPointSizeState(state)),
+  }
+}
+
+unsafe fn get_ctx_polygon_mode() -> Result<PolygonMode, StateQueryError> {
+  // compatibility profiles return both the front and back modes; core profile has a single mode for both
+  let mut data = [gl::FILL as GLint; 2];
+  gl::GetIntegerv(gl::POLYGON_MODE, data.as_mut_ptr());
+
+  match data[0] as GLenum {
+    gl::FILL => Ok(PolygonMode::Fill),
+    gl::LINE => Ok(PolygonMode::Line),
+    gl::POINT => Ok(PolygonMode::Point),
+    mode => Err(StateQueryError::UnknownPolygonMode(mode)),
+  }
+}
+
+unsafe fn get_ctx_depth_clamp_enabled() -> Result<bool, StateQueryError> {
+  let state = gl::IsEnabled(gl::DEPTH_CLAMP);
+
+  match state {
+    gl::TRUE => Ok(true),
+    gl::FALSE => Ok(false),
+    _ => Err(StateQueryError::UnknownDepthClampState(state)),
+  }
+}
+
+fn polygon_mode_to_glenum(polygon_mode: PolygonMode) -> GLenum {
+  match polygon_mode {
+    PolygonMode::Fill => gl::FILL,
+    PolygonMode::Line => gl::LINE,
+    PolygonMode::Point => gl::POINT,
+  }
+}
+
 /// Whether or not enable blending.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub(crate) enum BlendingState {
@@ -1316,6 +2200,17 @@ pub(crate) enum BlendingState {
   Off,
 }
 
+/// Blending configuration of a single draw buffer.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum DrawBufferBlendingState {
+  /// The draw buffer follows the global blending states.
+  Global,
+  /// The draw buffer has its own blending configuration.
+  Set(Option<BlendingMode>),
+  /// The draw buffer had its own blending configuration, partially overwritten by global blending calls since.
+  Overwritten,
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub(crate) struct BlendingFactors {
   src_rgb: Factor,
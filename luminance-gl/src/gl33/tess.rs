@@ -6,16 +6,17 @@ use crate::gl33::{
 };
 use gl::{self, types::*};
 use luminance::backend::tess::{
-  IndexSlice as IndexSliceBackend, InstanceSlice as InstanceSliceBackend, Tess as TessBackend,
-  VertexSlice as VertexSliceBackend,
+  IndexSlice as IndexSliceBackend, InstanceSlice as InstanceSliceBackend,
+  RawTess as RawTessBackend, Tess as TessBackend, VertexSlice as VertexSliceBackend,
+  VertexSliceSet as VertexSliceSetBackend, VertexUpdate as VertexUpdateBackend,
 };
 use luminance::tess::{
   Deinterleaved, DeinterleavedData, Interleaved, Mode, TessError, TessIndex, TessIndexType,
   TessMapError, TessVertexData,
 };
 use luminance::vertex::{
-  Deinterleave, Normalized, Vertex, VertexAttribDesc, VertexAttribDim, VertexAttribType,
-  VertexBufferDesc, VertexInstancing,
+  interleaved_layout, split_buffer_groups, Deinterleave, Normalized, Vertex, VertexAttribDesc,
+  VertexAttribDim, VertexAttribType, VertexBufferDesc, VertexInstancing,
 };
 use std::{cell::RefCell, marker::PhantomData, os::raw::c_void, ptr, rc::Rc};
 
@@ -30,11 +31,11 @@ where
 }
 
 #[derive(Debug)]
-struct TessRaw<I>
+pub(crate) struct TessRaw<I>
 where
   I: TessIndex,
 {
-  vao: GLenum,
+  pub(crate) vao: GLenum,
   mode: GLenum,
   patch_vert_nb: usize,
   index_state: Option<IndexedDrawState<I>>,
@@ -49,16 +50,32 @@ where
     &self,
     start_index: usize,
     vert_nb: usize,
+    base_vertex: i32,
     inst_nb: usize,
+    inst_start_index: usize,
+    mode: Option<Mode>,
   ) -> Result<(), TessError> {
     let vert_nb = vert_nb as GLsizei;
     let inst_nb = inst_nb as GLsizei;
+    let base_inst = inst_start_index as GLuint;
+
+    // rendering from a base instance requires OpenGL 4.2 or GL_ARB_base_instance
+    if base_inst != 0 && !self.state.borrow_mut().supports_base_instance() {
+      return Err(TessError::unsupported_base_instance());
+    }
+
+    // an overridden mode takes precedence over the mode the tessellation was built with
+    let (mode, patch_vert_nb) = match mode {
+      Some(Mode::Patch(nb)) => (gl::PATCHES, nb),
+      Some(mode) => (opengl_mode(mode), 0),
+      None => (self.mode, self.patch_vert_nb),
+    };
 
     let mut gfx_st = self.state.borrow_mut();
     gfx_st.bind_vertex_array(self.vao, Bind::Cached);
 
-    if self.mode == gl::PATCHES {
-      gfx_st.set_patch_vertex_nb(self.patch_vert_nb);
+    if mode == gl::PATCHES {
+      gfx_st.set_patch_vertex_nb(patch_vert_nb);
     }
 
     match (I::INDEX_TYPE, self.index_state.as_ref()) {
@@ -73,11 +90,42 @@ where
           gfx_st.set_vertex_restart(VertexRestart::Off);
         }
 
-        if inst_nb <= 1 {
-          gl::DrawElements(self.mode, vert_nb, index_type_to_glenum(index_ty), first);
+        if base_inst != 0 {
+          if base_vertex != 0 {
+            gl::DrawElementsInstancedBaseVertexBaseInstance(
+              mode,
+              vert_nb,
+              index_type_to_glenum(index_ty),
+              first,
+              inst_nb.max(1),
+              base_vertex,
+              base_inst,
+            );
+          } else {
+            gl::DrawElementsInstancedBaseInstance(
+              mode,
+              vert_nb,
+              index_type_to_glenum(index_ty),
+              first,
+              inst_nb.max(1),
+              base_inst,
+            );
+          }
+        } else if base_vertex != 0 {
+          // base vertex rendering is core since OpenGL 3.2
+          gl::DrawElementsInstancedBaseVertex(
+            mode,
+            vert_nb,
+            index_type_to_glenum(index_ty),
+            first,
+            inst_nb.max(1),
+            base_vertex,
+          );
+        } else if inst_nb <= 1 {
+          gl::DrawElements(mode, vert_nb, index_type_to_glenum(index_ty), first);
         } else {
           gl::DrawElementsInstanced(
-            self.mode,
+            mode,
             vert_nb,
             index_type_to_glenum(index_ty),
             first,
@@ -90,12 +138,53 @@ where
         // direct render
         let first = start_index as GLint;
 
-        if inst_nb <= 1 {
-          gl::DrawArrays(self.mode, first, vert_nb);
+        if base_inst != 0 {
+          gl::DrawArraysInstancedBaseInstance(mode, first, vert_nb, inst_nb.max(1), base_inst);
+        } else if inst_nb <= 1 {
+          gl::DrawArrays(mode, first, vert_nb);
+        } else {
+          gl::DrawArraysInstanced(mode, first, vert_nb, inst_nb);
+        }
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Render with the command at byte `offset` in the indirect buffer `indirect_handle`.
+  pub(crate) unsafe fn render_indirect(
+    &self,
+    indirect_handle: GLuint,
+    offset: usize,
+    indexed: bool,
+  ) -> Result<(), TessError> {
+    let mut gfx_st = self.state.borrow_mut();
+    gfx_st.bind_vertex_array(self.vao, Bind::Cached);
+    gfx_st.bind_draw_indirect_buffer(indirect_handle, Bind::Cached);
+
+    if self.mode == gl::PATCHES {
+      gfx_st.set_patch_vertex_nb(self.patch_vert_nb);
+    }
+
+    let offset = offset as *const c_void;
+
+    match (I::INDEX_TYPE, self.index_state.as_ref(), indexed) {
+      (Some(index_ty), Some(index_state), true) => {
+        if let Some(restart_index) = index_state.restart_index {
+          gfx_st.set_vertex_restart(VertexRestart::On);
+          gl::PrimitiveRestartIndex(restart_index.try_into_u32().unwrap_or(0));
         } else {
-          gl::DrawArraysInstanced(self.mode, first, vert_nb, inst_nb);
+          gfx_st.set_vertex_restart(VertexRestart::Off);
         }
+
+        gl::DrawElementsIndirect(self.mode, index_type_to_glenum(index_ty), offset);
       }
+
+      (_, None, false) => {
+        gl::DrawArraysIndirect(self.mode, offset);
+      }
+
+      _ => return Err(TessError::indirect_command_mismatch()),
     }
 
     Ok(())
@@ -114,6 +203,30 @@ where
   }
 }
 
+/// Interleaved vertex storage.
+///
+/// Vertices are stored in a single buffer, unless their attributes are split into several buffer groups, in which case
+/// each group gets its own buffer.
+#[derive(Debug)]
+enum VertexBuffer<V> {
+  Single(Buffer<V>),
+  Grouped {
+    // only held to keep the buffers alive while the VAO references them
+    #[allow(dead_code)]
+    buffers: Vec<Buffer<u8>>,
+    len: usize,
+  },
+}
+
+impl<V> VertexBuffer<V> {
+  fn len(&self) -> usize {
+    match self {
+      VertexBuffer::Single(vb) => vb.len(),
+      VertexBuffer::Grouped { len, .. } => *len,
+    }
+  }
+}
+
 #[derive(Debug)]
 pub struct InterleavedTess<V, I, W>
 where
@@ -121,9 +234,9 @@ where
   I: TessIndex,
   W: Vertex,
 {
-  raw: TessRaw<I>,
-  vertex_buffer: Option<Buffer<V>>,
-  instance_buffer: Option<Buffer<W>>,
+  pub(crate) raw: TessRaw<I>,
+  vertex_buffer: Option<VertexBuffer<V>>,
+  instance_buffer: Option<VertexBuffer<W>>,
 }
 
 unsafe impl<V, I, W> TessBackend<V, I, W, Interleaved> for GL33
@@ -180,11 +293,15 @@ where
     })
   }
 
+  fn max_patch_vertex_nb(&self) -> usize {
+    self.state.borrow_mut().get_max_patch_vertex_nb()
+  }
+
   unsafe fn tess_vertices_nb(tess: &Self::TessRepr) -> usize {
     tess
       .vertex_buffer
       .as_ref()
-      .map(|vb| vb.buf.len())
+      .map(VertexBuffer::len)
       .unwrap_or(0)
   }
 
@@ -201,7 +318,7 @@ where
     tess
       .instance_buffer
       .as_ref()
-      .map(|ib| ib.buf.len())
+      .map(VertexBuffer::len)
       .unwrap_or(0)
   }
 
@@ -209,9 +326,19 @@ where
     tess: &Self::TessRepr,
     start_index: usize,
     vert_nb: usize,
+    base_vertex: i32,
     inst_nb: usize,
+    inst_start_index: usize,
+    mode: Option<Mode>,
   ) -> Result<(), TessError> {
-    tess.raw.render(start_index, vert_nb, inst_nb)
+    tess.raw.render(
+      start_index,
+      vert_nb,
+      base_vertex,
+      inst_nb,
+      inst_start_index,
+      mode,
+    )
   }
 }
 
@@ -226,7 +353,8 @@ where
 
   unsafe fn vertices(tess: &'a mut Self::TessRepr) -> Result<Self::VertexSliceRepr, TessMapError> {
     match tess.vertex_buffer {
-      Some(ref vb) => Ok(vb.slice_buffer()?),
+      Some(VertexBuffer::Single(ref vb)) => Ok(vb.slice_buffer()?),
+      Some(VertexBuffer::Grouped { .. }) => Err(TessMapError::forbidden_deinterleaved_mapping()),
       None => Err(TessMapError::forbidden_attributeless_mapping()),
     }
   }
@@ -235,7 +363,30 @@ where
     tess: &'a mut Self::TessRepr,
   ) -> Result<Self::VertexSliceMutRepr, TessMapError> {
     match tess.vertex_buffer {
-      Some(ref mut vb) => Ok(vb.slice_buffer_mut()?),
+      Some(VertexBuffer::Single(ref mut vb)) => Ok(vb.slice_buffer_mut()?),
+      Some(VertexBuffer::Grouped { .. }) => Err(TessMapError::forbidden_deinterleaved_mapping()),
+      None => Err(TessMapError::forbidden_attributeless_mapping()),
+    }
+  }
+}
+
+unsafe impl<V, I, W> VertexUpdateBackend<V, I, W> for GL33
+where
+  V: TessVertexData<Interleaved, Data = Vec<V>>,
+  I: TessIndex,
+  W: TessVertexData<Interleaved, Data = Vec<W>>,
+{
+  unsafe fn update_vertices(
+    tess: &mut Self::TessRepr,
+    start: usize,
+    data: &[V],
+  ) -> Result<(), TessMapError> {
+    match tess.vertex_buffer {
+      Some(VertexBuffer::Single(ref mut vb)) => {
+        vb.update_range(start, data);
+        Ok(())
+      }
+      Some(VertexBuffer::Grouped { .. }) => Err(TessMapError::forbidden_deinterleaved_mapping()),
       None => Err(TessMapError::forbidden_attributeless_mapping()),
     }
   }
@@ -280,7 +431,8 @@ where
     tess: &'a mut Self::TessRepr,
   ) -> Result<Self::InstanceSliceRepr, TessMapError> {
     match tess.instance_buffer {
-      Some(ref vb) => Ok(vb.slice_buffer()?),
+      Some(VertexBuffer::Single(ref vb)) => Ok(vb.slice_buffer()?),
+      Some(VertexBuffer::Grouped { .. }) => Err(TessMapError::forbidden_deinterleaved_mapping()),
       None => Err(TessMapError::forbidden_attributeless_mapping()),
     }
   }
@@ -289,7 +441,8 @@ where
     tess: &'a mut Self::TessRepr,
   ) -> Result<Self::InstanceSliceMutRepr, TessMapError> {
     match tess.instance_buffer {
-      Some(ref mut vb) => Ok(vb.slice_buffer_mut()?),
+      Some(VertexBuffer::Single(ref mut vb)) => Ok(vb.slice_buffer_mut()?),
+      Some(VertexBuffer::Grouped { .. }) => Err(TessMapError::forbidden_deinterleaved_mapping()),
       None => Err(TessMapError::forbidden_attributeless_mapping()),
     }
   }
@@ -302,7 +455,7 @@ where
   I: TessIndex,
   W: Vertex,
 {
-  raw: TessRaw<I>,
+  pub(crate) raw: TessRaw<I>,
   vertex_buffers: Vec<Buffer<u8>>,
   instance_buffers: Vec<Buffer<u8>>,
   _phantom: PhantomData<*const (V, W)>,
@@ -363,6 +516,10 @@ where
     })
   }
 
+  fn max_patch_vertex_nb(&self) -> usize {
+    self.state.borrow_mut().get_max_patch_vertex_nb()
+  }
+
   unsafe fn tess_vertices_nb(tess: &Self::TessRepr) -> usize {
     tess
       .vertex_buffers
@@ -392,9 +549,19 @@ where
     tess: &Self::TessRepr,
     start_index: usize,
     vert_nb: usize,
+    base_vertex: i32,
     inst_nb: usize,
+    inst_start_index: usize,
+    mode: Option<Mode>,
   ) -> Result<(), TessError> {
-    tess.raw.render(start_index, vert_nb, inst_nb)
+    tess.raw.render(
+      start_index,
+      vert_nb,
+      base_vertex,
+      inst_nb,
+      inst_start_index,
+      mode,
+    )
   }
 }
 
@@ -431,6 +598,35 @@ where
   }
 }
 
+unsafe impl<'a, V, I, W> VertexSliceSetBackend<'a, V, I, W> for GL33
+where
+  V: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+  I: TessIndex,
+  W: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+{
+  type VertexSliceSetRepr = Vec<BufferSlice<'a, u8>>;
+
+  unsafe fn vertices_all(
+    tess: &'a mut Self::TessRepr,
+  ) -> Result<Self::VertexSliceSetRepr, TessMapError> {
+    if tess.vertex_buffers.is_empty() {
+      Err(TessMapError::forbidden_attributeless_mapping())
+    } else {
+      // each attribute lives in its own buffer; all of them are mapped at the same time
+      let slices = tess
+        .vertex_buffers
+        .iter()
+        .map(Buffer::slice_buffer)
+        .collect::<Result<_, _>>()?;
+      Ok(slices)
+    }
+  }
+
+  unsafe fn attribute_bytes(slices: &Self::VertexSliceSetRepr, rank: usize) -> &[u8] {
+    &slices[rank]
+  }
+}
+
 unsafe impl<'a, V, I, W> IndexSliceBackend<'a, V, I, W, Deinterleaved> for GL33
 where
   V: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
@@ -492,10 +688,162 @@ where
   }
 }
 
+/// Tessellation built from raw bytes.
+#[derive(Debug)]
+pub struct RawTess {
+  vao: GLuint,
+  mode: GLenum,
+  patch_vert_nb: usize,
+  // only held to keep the buffer alive while the VAO references it
+  #[allow(dead_code)]
+  vertex_buffer: Buffer<u8>,
+  vert_nb: usize,
+  index_buffer: Option<(Buffer<u8>, TessIndexType)>,
+  state: Rc<RefCell<GLState>>,
+}
+
+impl Drop for RawTess {
+  fn drop(&mut self) {
+    unsafe {
+      self.state.borrow_mut().unbind_vertex_array();
+      gl::DeleteVertexArrays(1, &self.vao);
+    }
+  }
+}
+
+unsafe impl RawTessBackend for GL33 {
+  type RawTessRepr = RawTess;
+
+  unsafe fn new_raw_tess(
+    &mut self,
+    vertices: &[u8],
+    desc: &[VertexBufferDesc],
+    indices: Option<(&[u8], TessIndexType)>,
+    mode: Mode,
+  ) -> Result<Self::RawTessRepr, TessError> {
+    let stride = interleaved_layout(desc).1;
+
+    if vertices.len().checked_rem(stride) != Some(0) {
+      return Err(TessError::length_incoherency(vertices.len()));
+    }
+
+    let mut vao: GLuint = 0;
+
+    let patch_vert_nb = match mode {
+      Mode::Patch(nb) => nb,
+      _ => 0,
+    };
+
+    gl::GenVertexArrays(1, &mut vao);
+
+    // force binding the vertex array so that previously bound vertex arrays (possibly the same
+    // handle) don’t prevent us from binding here
+    self.state.borrow_mut().bind_vertex_array(vao, Bind::Forced);
+
+    let vertex_buffer = Buffer::from_vec(self, vertices.to_vec());
+
+    // force binding as it’s meaningful when a vao is bound
+    self
+      .state
+      .borrow_mut()
+      .bind_array_buffer(vertex_buffer.handle(), Bind::Forced);
+    set_vertex_pointers(desc);
+
+    // in case of indexed render, create an index buffer
+    let index_buffer = match indices {
+      Some((bytes, index_ty)) if !bytes.is_empty() => {
+        let ib = Buffer::from_vec(self, bytes.to_vec());
+
+        // force binding as it’s meaningful when a vao is bound
+        self
+          .state
+          .borrow_mut()
+          .bind_element_array_buffer(ib.handle(), Bind::Forced);
+
+        Some((ib, index_ty))
+      }
+
+      _ => None,
+    };
+
+    Ok(RawTess {
+      vao,
+      mode: opengl_mode(mode),
+      patch_vert_nb,
+      vertex_buffer,
+      vert_nb: vertices.len() / stride,
+      index_buffer,
+      state: self.state.clone(),
+    })
+  }
+
+  unsafe fn raw_tess_vertices_nb(tess: &Self::RawTessRepr) -> usize {
+    tess.vert_nb
+  }
+
+  unsafe fn raw_tess_indices_nb(tess: &Self::RawTessRepr) -> usize {
+    tess
+      .index_buffer
+      .as_ref()
+      .map(|(ib, index_ty)| ib.len() / index_ty.bytes())
+      .unwrap_or(0)
+  }
+
+  unsafe fn render_raw_tess(
+    tess: &Self::RawTessRepr,
+    start_index: usize,
+    vert_nb: usize,
+    inst_nb: usize,
+  ) -> Result<(), TessError> {
+    let vert_nb = vert_nb as GLsizei;
+    let inst_nb = inst_nb as GLsizei;
+
+    let mut gfx_st = tess.state.borrow_mut();
+    gfx_st.bind_vertex_array(tess.vao, Bind::Cached);
+
+    if tess.mode == gl::PATCHES {
+      gfx_st.set_patch_vertex_nb(tess.patch_vert_nb);
+    }
+
+    match tess.index_buffer {
+      Some((_, index_ty)) => {
+        // indexed render
+        let first = (index_ty.bytes() * start_index) as *const c_void;
+        gfx_st.set_vertex_restart(VertexRestart::Off);
+
+        if inst_nb <= 1 {
+          gl::DrawElements(tess.mode, vert_nb, index_type_to_glenum(index_ty), first);
+        } else {
+          gl::DrawElementsInstanced(
+            tess.mode,
+            vert_nb,
+            index_type_to_glenum(index_ty),
+            first,
+            inst_nb,
+          );
+        }
+      }
+
+      None => {
+        // direct render
+        let first = start_index as GLint;
+
+        if inst_nb <= 1 {
+          gl::DrawArrays(tess.mode, first, vert_nb);
+        } else {
+          gl::DrawArraysInstanced(tess.mode, first, vert_nb, inst_nb);
+        }
+      }
+    }
+
+    Ok(())
+  }
+}
+
 fn build_interleaved_vertex_buffer<V>(
   gl33: &mut GL33,
   vertices: Option<Vec<V>>,
-) -> Result<Option<Buffer<V>>, TessError>
+) -> Result<Option<VertexBuffer<V>>, TessError>
 where
   V: Vertex,
 {
@@ -505,7 +853,7 @@ where
 
       let vb = if vertices.is_empty() {
         None
-      } else {
+      } else if fmt.iter().all(|desc| desc.buffer == fmt[0].buffer) {
         let vb = unsafe { Buffer::from_vec(gl33, vertices) };
 
         // force binding as it’s meaningful when a vao is bound
@@ -517,7 +865,28 @@ where
         };
         set_vertex_pointers(&fmt);
 
-        Some(vb)
+        Some(VertexBuffer::Single(vb))
+      } else {
+        let len = vertices.len();
+        let buffers = split_buffer_groups(&vertices, &fmt)
+          .into_iter()
+          .map(|(descs, raw)| {
+            let vb = unsafe { Buffer::from_vec(gl33, raw) };
+
+            // force binding as it’s meaningful when a vao is bound
+            unsafe {
+              gl33
+                .state
+                .borrow_mut()
+                .bind_array_buffer(vb.handle(), Bind::Forced)
+            };
+            set_vertex_pointers(&descs);
+
+            vb
+          })
+          .collect();
+
+        Some(VertexBuffer::Grouped { buffers, len })
       };
 
       Ok(vb)
@@ -600,117 +969,83 @@ fn set_vertex_pointers(descriptors: &[VertexBufferDesc]) {
   //   - The stride: this is easily computed, since it’s the size (bytes) of a single vertex.
   //   - The offsets: each attribute has a given offset in the buffer. This is computed by
   //     accumulating the size of all previously set attributes.
-  let offsets = aligned_offsets(descriptors);
-  let vertex_weight = offset_based_vertex_weight(descriptors, &offsets) as GLsizei;
+  let (offsets, stride) = interleaved_layout(descriptors);
+  let vertex_weight = stride as GLsizei;
 
   for (desc, off) in descriptors.iter().zip(offsets) {
     set_component_format(vertex_weight, off, desc);
   }
 }
 
-/// Compute offsets for all the vertex components according to the alignments provided.
-fn aligned_offsets(descriptor: &[VertexBufferDesc]) -> Vec<usize> {
-  let mut offsets = Vec::with_capacity(descriptor.len());
-  let mut off = 0;
-
-  // compute offsets
-  for desc in descriptor {
-    let desc = &desc.attrib_desc;
-    off = off_align(off, desc.align); // keep the current component descriptor aligned
-    offsets.push(off);
-    off += component_weight(desc); // increment the offset by the pratical size of the component
-  }
-
-  offsets
-}
-
-/// Align an offset.
-#[inline]
-fn off_align(off: usize, align: usize) -> usize {
-  let a = align - 1;
-  (off + a) & !a
-}
-
-/// Weight in bytes of a vertex component.
-fn component_weight(f: &VertexAttribDesc) -> usize {
-  dim_as_size(f.dim) as usize * f.unit_size
-}
-
+/// Number of components of a single attribute location.
 fn dim_as_size(d: VertexAttribDim) -> GLint {
   match d {
     VertexAttribDim::Dim1 => 1,
-    VertexAttribDim::Dim2 => 2,
-    VertexAttribDim::Dim3 => 3,
-    VertexAttribDim::Dim4 => 4,
+    VertexAttribDim::Dim2 | VertexAttribDim::Mat2 => 2,
+    VertexAttribDim::Dim3 | VertexAttribDim::Mat3 => 3,
+    VertexAttribDim::Dim4 | VertexAttribDim::Mat4 => 4,
   }
 }
 
-/// Weight in bytes of a single vertex, taking into account padding so that the vertex stay correctly
-/// aligned.
-fn offset_based_vertex_weight(descriptors: &[VertexBufferDesc], offsets: &[usize]) -> usize {
-  if descriptors.is_empty() || offsets.is_empty() {
-    return 0;
-  }
-
-  off_align(
-    offsets[offsets.len() - 1] + component_weight(&descriptors[descriptors.len() - 1].attrib_desc),
-    descriptors[0].attrib_desc.align,
-  )
-}
-
 /// Set the vertex component OpenGL pointers regarding the index of the component and the vertex
 /// stride.
 fn set_component_format(stride: GLsizei, off: usize, desc: &VertexBufferDesc) {
   let attrib_desc = &desc.attrib_desc;
-  let index = desc.index as GLuint;
-
-  unsafe {
-    match attrib_desc.ty {
-      VertexAttribType::Floating => {
-        gl::VertexAttribPointer(
-          index,
-          dim_as_size(attrib_desc.dim),
-          opengl_sized_type(&attrib_desc),
-          gl::FALSE,
-          stride,
-          ptr::null::<c_void>().add(off),
-        );
-      }
+  // matrices occupy one location per column
+  let column_weight = dim_as_size(attrib_desc.dim) as usize * attrib_desc.unit_size;
 
-      VertexAttribType::Integral(Normalized::No)
-      | VertexAttribType::Unsigned(Normalized::No)
-      | VertexAttribType::Boolean => {
-        // non-normalized integrals / booleans
-        gl::VertexAttribIPointer(
-          index,
-          dim_as_size(attrib_desc.dim),
-          opengl_sized_type(&attrib_desc),
-          stride,
-          ptr::null::<c_void>().add(off),
-        );
-      }
+  for column in 0..attrib_desc.locations() {
+    let index = (desc.index + column) as GLuint;
+    let off = off + column * column_weight;
 
-      _ => {
-        // normalized integrals
-        gl::VertexAttribPointer(
-          index,
-          dim_as_size(attrib_desc.dim),
-          opengl_sized_type(&attrib_desc),
-          gl::TRUE,
-          stride,
-          ptr::null::<c_void>().add(off),
-        );
+    unsafe {
+      match attrib_desc.ty {
+        VertexAttribType::Floating => {
+          gl::VertexAttribPointer(
+            index,
+            dim_as_size(attrib_desc.dim),
+            opengl_sized_type(&attrib_desc),
+            gl::FALSE,
+            stride,
+            ptr::null::<c_void>().add(off),
+          );
+        }
+
+        VertexAttribType::Integral(Normalized::No)
+        | VertexAttribType::Unsigned(Normalized::No)
+        | VertexAttribType::Boolean => {
+          // non-normalized integrals / booleans
+          gl::VertexAttribIPointer(
+            index,
+            dim_as_size(attrib_desc.dim),
+            opengl_sized_type(&attrib_desc),
+            stride,
+            ptr::null::<c_void>().add(off),
+          );
+        }
+
+        _ => {
+          // normalized integrals
+          gl::VertexAttribPointer(
+            index,
+            dim_as_size(attrib_desc.dim),
+            opengl_sized_type(&attrib_desc),
+            gl::TRUE,
+            stride,
+            ptr::null::<c_void>().add(off),
+          );
+        }
       }
-    }
 
-    // set vertex attribute divisor based on the vertex instancing configuration
-    let divisor = match desc.instancing {
-      VertexInstancing::On => 1,
-      VertexInstancing::Off => 0,
-    };
-    gl::VertexAttribDivisor(index, divisor);
+      // set vertex attribute divisor based on the vertex instancing configuration
+      let divisor = match desc.instancing {
+        VertexInstancing::On => 1,
+        VertexInstancing::Off => 0,
+      };
+      gl::VertexAttribDivisor(index, divisor);
 
-    gl::EnableVertexAttribArray(index);
+      gl::EnableVertexAttribArray(index);
+    }
   }
 }
 
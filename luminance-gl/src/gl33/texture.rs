@@ -55,6 +55,18 @@ where
     texture.mipmaps
   }
 
+  unsafe fn generate_mipmaps(texture: &mut Self::TextureRepr) -> Result<(), TextureError> {
+    if texture.mipmaps > 0 {
+      let mut gfx_state = texture.state.borrow_mut();
+
+      gfx_state.bind_texture(texture.target, texture.handle);
+      gl::GenerateMipmap(texture.target);
+      gfx_state.bind_texture(texture.target, 0);
+    }
+
+    Ok(())
+  }
+
   unsafe fn upload_part(
     texture: &mut Self::TextureRepr,
     offset: D::Offset,
@@ -530,7 +542,7 @@ fn set_unpack_alignment(skip_bytes: usize) {
 }
 
 // set the pack alignment for downloading aligned texels
-fn set_pack_alignment(skip_bytes: usize) {
+pub(crate) fn set_pack_alignment(skip_bytes: usize) {
   let pack_alignment = match skip_bytes {
     0 => 8,
     2 => 2,
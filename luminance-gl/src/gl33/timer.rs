@@ -0,0 +1,74 @@
+use crate::gl33::GL33;
+use gl::{self, types::*};
+use luminance::{backend::timer::TimerQuery as TimerQueryBackend, timer::TimerQueryError};
+use std::time::Duration;
+
+pub struct TimerQuery {
+  handle: GLuint,
+  // whether a measurement was performed; a query object doesn’t exist until it is first started
+  measured: bool,
+}
+
+impl Drop for TimerQuery {
+  fn drop(&mut self) {
+    unsafe { gl::DeleteQueries(1, &self.handle) };
+  }
+}
+
+unsafe impl TimerQueryBackend for GL33 {
+  type TimerQueryRepr = TimerQuery;
+
+  unsafe fn new_timer_query(&mut self) -> Result<Self::TimerQueryRepr, TimerQueryError> {
+    let mut handle: GLuint = 0;
+    gl::GenQueries(1, &mut handle);
+
+    if handle == 0 {
+      Err(TimerQueryError::cannot_create())
+    } else {
+      Ok(TimerQuery {
+        handle,
+        measured: false,
+      })
+    }
+  }
+
+  unsafe fn begin_timer_query(
+    &mut self,
+    query: &mut Self::TimerQueryRepr,
+  ) -> Result<(), TimerQueryError> {
+    let mut state = self.state.borrow_mut();
+
+    if state.is_timer_query_active() {
+      return Err(TimerQueryError::already_active());
+    }
+
+    gl::BeginQuery(gl::TIME_ELAPSED, query.handle);
+    state.set_timer_query_active(true);
+    query.measured = true;
+
+    Ok(())
+  }
+
+  unsafe fn end_timer_query(&mut self, _: &mut Self::TimerQueryRepr) {
+    gl::EndQuery(gl::TIME_ELAPSED);
+    self.state.borrow_mut().set_timer_query_active(false);
+  }
+
+  unsafe fn timer_query_elapsed(query: &Self::TimerQueryRepr) -> Option<Duration> {
+    if !query.measured {
+      return None;
+    }
+
+    let mut available: GLint = gl::FALSE as GLint;
+    gl::GetQueryObjectiv(query.handle, gl::QUERY_RESULT_AVAILABLE, &mut available);
+
+    if available == gl::FALSE as GLint {
+      return None;
+    }
+
+    let mut elapsed: GLuint64 = 0;
+    gl::GetQueryObjectui64v(query.handle, gl::QUERY_RESULT, &mut elapsed);
+
+    Some(Duration::from_nanos(elapsed))
+  }
+}
@@ -3,15 +3,16 @@
 #![deny(missing_docs)]
 
 use gl;
-use glfw::{self, Glfw, InitError, Window, WindowEvent};
+use glfw::{self, Context as _, CursorMode, Glfw, InitError, Window, WindowEvent};
 use luminance::{
   context::GraphicsContext,
   framebuffer::{Framebuffer, FramebufferError},
+  surface::{InputState, Key, MouseButton, Surface, SurfaceEvent},
   texture::Dim2,
 };
 pub use luminance_gl::gl33::StateQueryError;
 use luminance_gl::GL33;
-use std::{error, fmt, os::raw::c_void, sync::mpsc::Receiver};
+use std::{convert::Infallible, error, fmt, os::raw::c_void, sync::mpsc::Receiver};
 
 /// Error that can be risen while creating a surface.
 #[non_exhaustive]
@@ -66,6 +67,72 @@ where
   }
 }
 
+/// Window hints applied by [`GlfwSurface::new_with_hints`].
+///
+/// The default hints match the defaults of GLFW: a resizable and decorated window, with an opaque framebuffer, not
+/// floating above other windows and not maximized.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct WindowHints {
+  /// Whether the window can be resized by the user.
+  ///
+  /// The window can still be resized programmatically with [`Window::set_size`].
+  pub resizable: bool,
+
+  /// Whether the window has decorations, such as a border and a close widget.
+  pub decorated: bool,
+
+  /// Whether the framebuffer of the window is transparent, if supported by the system.
+  pub transparent_framebuffer: bool,
+
+  /// Whether the window floats above other windows (also called _always-on-top_).
+  pub floating: bool,
+
+  /// Whether the window is maximized once created.
+  pub maximized: bool,
+}
+
+impl Default for WindowHints {
+  fn default() -> Self {
+    WindowHints {
+      resizable: true,
+      decorated: true,
+      transparent_framebuffer: false,
+      floating: false,
+      maximized: false,
+    }
+  }
+}
+
+impl WindowHints {
+  /// Set whether the window can be resized by the user.
+  pub fn set_resizable(self, resizable: bool) -> Self {
+    WindowHints { resizable, ..self }
+  }
+
+  /// Set whether the window has decorations.
+  pub fn set_decorated(self, decorated: bool) -> Self {
+    WindowHints { decorated, ..self }
+  }
+
+  /// Set whether the framebuffer of the window is transparent.
+  pub fn set_transparent_framebuffer(self, transparent_framebuffer: bool) -> Self {
+    WindowHints {
+      transparent_framebuffer,
+      ..self
+    }
+  }
+
+  /// Set whether the window floats above other windows.
+  pub fn set_floating(self, floating: bool) -> Self {
+    WindowHints { floating, ..self }
+  }
+
+  /// Set whether the window is maximized once created.
+  pub fn set_maximized(self, maximized: bool) -> Self {
+    WindowHints { maximized, ..self }
+  }
+}
+
 /// GLFW surface.
 ///
 /// This type is a helper that exposes two important concepts: the GLFW event receiver that you can use it with to
@@ -77,15 +144,43 @@ pub struct GlfwSurface {
 
   /// Wrapped luminance context.
   pub context: GL33Context,
+
+  /// Last known cursor position, used to compute [`GlfwSurface::mouse_delta`].
+  last_cursor_pos: Option<(f64, f64)>,
+
+  /// Cursor motion accumulated since the last call to [`GlfwSurface::mouse_delta`].
+  mouse_delta: [f64; 2],
 }
 
 impl GlfwSurface {
   /// Initialize GLFW to provide a luminance environment.
+  ///
+  /// The OpenGL hints are set before `create_window` is called, which is responsible for creating the window. Window
+  /// hints set in `create_window` with [`Glfw::window_hint`] are honored, as long as they are set before creating the
+  /// window. See [`GlfwSurface::new_with_hints`] to set the most common window hints without having to deal with GLFW.
+  ///
+  /// The OpenGL function pointers are loaded from the window in the process-wide function pointers of the gl crate,
+  /// so all the surfaces of the process share the pointers loaded last. See the [`GL33`] documentation for further
+  /// details.
   pub fn new<E>(
     create_window: impl FnOnce(
       &mut Glfw,
     )
       -> Result<(Window, Receiver<(f64, WindowEvent)>), GlfwSurfaceError<E>>,
+  ) -> Result<Self, GlfwSurfaceError<E>> {
+    Self::new_with_hints(WindowHints::default(), create_window)
+  }
+
+  /// Initialize GLFW to provide a luminance environment, with window hints.
+  ///
+  /// `hints` are applied along with the OpenGL hints, before `create_window` is called. Hints set in `create_window`
+  /// take precedence over them. [`WindowHints::maximized`] is applied once the window is created.
+  pub fn new_with_hints<E>(
+    hints: WindowHints,
+    create_window: impl FnOnce(
+      &mut Glfw,
+    )
+      -> Result<(Window, Receiver<(f64, WindowEvent)>), GlfwSurfaceError<E>>,
   ) -> Result<Self, GlfwSurfaceError<E>> {
     let mut test = std::time::Instant::now();
     
@@ -108,10 +203,22 @@ impl GlfwSurface {
     glfw.window_hint(glfw::WindowHint::ContextVersionMajor(3));
     glfw.window_hint(glfw::WindowHint::ContextVersionMinor(3));
     
+    // window hints
+    glfw.window_hint(glfw::WindowHint::Resizable(hints.resizable));
+    glfw.window_hint(glfw::WindowHint::Decorated(hints.decorated));
+    glfw.window_hint(glfw::WindowHint::TransparentFramebuffer(
+      hints.transparent_framebuffer,
+    ));
+    glfw.window_hint(glfw::WindowHint::Floating(hints.floating));
+
     dbg!(test.elapsed());
 
     let (mut window, events_rx) = create_window(&mut glfw)?;
     
+    if hints.maximized {
+      window.maximize();
+    }
+
     dbg!(test.elapsed());
 
     // init OpenGL
@@ -124,29 +231,142 @@ impl GlfwSurface {
     dbg!(test.elapsed());
     
     let context = GL33Context { window, gl };
-    let surface = GlfwSurface { events_rx, context };
+    let surface = GlfwSurface {
+      events_rx,
+      context,
+      last_cursor_pos: None,
+      mouse_delta: [0., 0.],
+    };
 
     Ok(surface)
   }
+
+  /// Flush the pending events of the events queue.
+  ///
+  /// This is akin to calling [`glfw::flush_messages`] on [`GlfwSurface::events_rx`], but it also keeps track of
+  /// [`WindowEvent::CursorPos`] events to accumulate the motion of the cursor, which you can later get with
+  /// [`GlfwSurface::mouse_delta`]. Events are still forwarded unchanged, so you can handle them as usual.
+  ///
+  /// Cursor position polling must be enabled on the window (see [`Window::set_cursor_pos_polling`]) for cursor
+  /// motion to be tracked, and focus polling (see [`Window::set_focus_polling`]) for focus loss to be handled.
+  pub fn events(&mut self) -> impl Iterator<Item = (f64, WindowEvent)> + '_ {
+    let last_cursor_pos = &mut self.last_cursor_pos;
+    let mouse_delta = &mut self.mouse_delta;
+
+    glfw::flush_messages(&self.events_rx).inspect(move |(_, event)| match *event {
+      WindowEvent::CursorPos(x, y) => {
+        if let Some((last_x, last_y)) = last_cursor_pos.replace((x, y)) {
+          mouse_delta[0] += x - last_x;
+          mouse_delta[1] += y - last_y;
+        }
+      }
+
+      // the cursor is released while the window is not focused, and its position might jump when the focus comes
+      // back; forget about the last position so that the jump is not seen as a motion
+      WindowEvent::Focus(false) => *last_cursor_pos = None,
+
+      _ => (),
+    })
+  }
+
+  /// Get the cursor motion accumulated since the last call to this function, as `[dx, dy]` in screen coordinates.
+  ///
+  /// The motion is accumulated from the [`WindowEvent::CursorPos`] events seen by [`GlfwSurface::events`], so you
+  /// typically want to call this function once per frame, after having flushed the events. Combined with
+  /// [`GL33Context::set_cursor_grabbed`], this provides relative mouse motion, as needed by a first-person camera.
+  ///
+  /// # Focus loss
+  ///
+  /// When the window loses focus ([`WindowEvent::Focus(false)`](WindowEvent::Focus)), the last known cursor position
+  /// is forgotten, so that the cursor jumping around while the window is not focused — or when it gets focused
+  /// again — doesn't show up as a huge motion. The first [`WindowEvent::CursorPos`] event received afterwards only
+  /// sets the new reference position. The motion accumulated before the focus loss is kept until you call this
+  /// function.
+  pub fn mouse_delta(&mut self) -> [f64; 2] {
+    std::mem::take(&mut self.mouse_delta)
+  }
 }
 
 /// Luminance OpenGL 3.3 context.
 ///
-/// This type also re-exports the GLFW window, if you need access to it.
+/// This type also wraps the GLFW window, which you can borrow with [`GL33Context::window`] and
+/// [`GL33Context::window_mut`] if you need access to it.
 #[derive(Debug)]
 pub struct GL33Context {
   /// Wrapped GLFW window.
-  pub window: Window,
+  window: Window,
 
   /// OpenGL 3.3 state.
   gl: GL33,
 }
 
 impl GL33Context {
+  /// Borrow the underlying GLFW window of this context.
+  pub fn window(&self) -> &Window {
+    &self.window
+  }
+
+  /// Mutably borrow the underlying GLFW window of this context.
+  ///
+  /// The window is owned by the context, which renders to it: it can be borrowed but not moved out of the context.
+  pub fn window_mut(&mut self) -> &mut Window {
+    &mut self.window
+  }
+
+  /// Get the size of the framebuffer of the window, in pixels.
+  ///
+  /// This is the size to use for viewports and framebuffers. It is **not** the same as the size of the window
+  /// ([`Window::get_size`]), which is expressed in screen coordinates: on HiDPI displays, a screen coordinate spans
+  /// several pixels, so the framebuffer is bigger than the window.
+  pub fn framebuffer_size(&self) -> [u32; 2] {
+    let (w, h) = self.window.get_framebuffer_size();
+    [w.max(0) as u32, h.max(0) as u32]
+  }
+
   /// Get the back buffer.
+  ///
+  /// The back buffer has the size of the framebuffer of the window, as returned by [`GL33Context::framebuffer_size`].
   pub fn back_buffer(&mut self) -> Result<Framebuffer<GL33, Dim2, (), ()>, FramebufferError> {
-    let (w, h) = self.window.get_framebuffer_size();
-    Framebuffer::back_buffer(self, [w as u32, h as u32])
+    let size = self.framebuffer_size();
+    Framebuffer::back_buffer(self, size)
+  }
+
+  /// Grab or release the cursor.
+  ///
+  /// A grabbed cursor is hidden and locked to the window ([`CursorMode::Disabled`]), which provides unlimited cursor
+  /// motion, as needed by a first-person camera; use [`GlfwSurface::mouse_delta`] to get the motion. Releasing the
+  /// cursor puts it back in [`CursorMode::Normal`].
+  ///
+  /// Grabbing or releasing the cursor moves it, so you might want to discard the next mouse delta.
+  ///
+  /// GLFW automatically releases the cursor when the window loses focus and grabs it again when the focus comes back.
+  pub fn set_cursor_grabbed(&mut self, grabbed: bool) {
+    let mode = if grabbed {
+      CursorMode::Disabled
+    } else {
+      CursorMode::Normal
+    };
+
+    self.window.set_cursor_mode(mode);
+  }
+}
+
+impl Surface for GlfwSurface {
+  type Context = GL33Context;
+
+  type SwapError = Infallible;
+
+  fn context(&mut self) -> &mut Self::Context {
+    &mut self.context
+  }
+
+  fn size(&self) -> [u32; 2] {
+    self.context.framebuffer_size()
+  }
+
+  fn swap_buffers(&mut self) -> Result<(), Self::SwapError> {
+    self.context.window.swap_buffers();
+    Ok(())
   }
 }
 
@@ -157,3 +377,121 @@ unsafe impl GraphicsContext for GL33Context {
     &mut self.gl
   }
 }
+
+/// Convert a GLFW [`WindowEvent`] into a [`SurfaceEvent`].
+///
+/// This allows writing event handling code against [`SurfaceEvent`] and hence not depending on GLFW. Events without
+/// [`SurfaceEvent`] counterpart are converted to [`SurfaceEvent::Other`]; the [`WindowEvent`] must then be used
+/// directly. Remember that GLFW only emits the events you enabled polling for on the [`Window`].
+pub fn surface_event(event: &WindowEvent) -> SurfaceEvent {
+  match *event {
+    WindowEvent::Key(key, _, action, _) => SurfaceEvent::Key(surface_key(key), input_state(action)),
+
+    WindowEvent::MouseButton(button, action, _) => {
+      SurfaceEvent::MouseButton(surface_mouse_button(button), input_state(action))
+    }
+
+    WindowEvent::CursorPos(x, y) => SurfaceEvent::CursorMoved { x, y },
+
+    WindowEvent::FramebufferSize(width, height) => SurfaceEvent::Resized {
+      width: width.max(0) as u32,
+      height: height.max(0) as u32,
+    },
+
+    WindowEvent::Scroll(x, y) => SurfaceEvent::Scroll { x, y },
+    WindowEvent::Close => SurfaceEvent::Close,
+    _ => SurfaceEvent::Other,
+  }
+}
+
+fn input_state(action: glfw::Action) -> InputState {
+  match action {
+    glfw::Action::Press => InputState::Pressed,
+    glfw::Action::Release => InputState::Released,
+    glfw::Action::Repeat => InputState::Repeated,
+  }
+}
+
+fn surface_mouse_button(button: glfw::MouseButton) -> MouseButton {
+  match button {
+    glfw::MouseButtonLeft => MouseButton::Left,
+    glfw::MouseButtonRight => MouseButton::Right,
+    glfw::MouseButtonMiddle => MouseButton::Middle,
+    _ => MouseButton::Other(button as u16),
+  }
+}
+
+fn surface_key(key: glfw::Key) -> Key {
+  match key {
+    glfw::Key::A => Key::A,
+    glfw::Key::B => Key::B,
+    glfw::Key::C => Key::C,
+    glfw::Key::D => Key::D,
+    glfw::Key::E => Key::E,
+    glfw::Key::F => Key::F,
+    glfw::Key::G => Key::G,
+    glfw::Key::H => Key::H,
+    glfw::Key::I => Key::I,
+    glfw::Key::J => Key::J,
+    glfw::Key::K => Key::K,
+    glfw::Key::L => Key::L,
+    glfw::Key::M => Key::M,
+    glfw::Key::N => Key::N,
+    glfw::Key::O => Key::O,
+    glfw::Key::P => Key::P,
+    glfw::Key::Q => Key::Q,
+    glfw::Key::R => Key::R,
+    glfw::Key::S => Key::S,
+    glfw::Key::T => Key::T,
+    glfw::Key::U => Key::U,
+    glfw::Key::V => Key::V,
+    glfw::Key::W => Key::W,
+    glfw::Key::X => Key::X,
+    glfw::Key::Y => Key::Y,
+    glfw::Key::Z => Key::Z,
+    glfw::Key::Num0 => Key::Num0,
+    glfw::Key::Num1 => Key::Num1,
+    glfw::Key::Num2 => Key::Num2,
+    glfw::Key::Num3 => Key::Num3,
+    glfw::Key::Num4 => Key::Num4,
+    glfw::Key::Num5 => Key::Num5,
+    glfw::Key::Num6 => Key::Num6,
+    glfw::Key::Num7 => Key::Num7,
+    glfw::Key::Num8 => Key::Num8,
+    glfw::Key::Num9 => Key::Num9,
+    glfw::Key::F1 => Key::F1,
+    glfw::Key::F2 => Key::F2,
+    glfw::Key::F3 => Key::F3,
+    glfw::Key::F4 => Key::F4,
+    glfw::Key::F5 => Key::F5,
+    glfw::Key::F6 => Key::F6,
+    glfw::Key::F7 => Key::F7,
+    glfw::Key::F8 => Key::F8,
+    glfw::Key::F9 => Key::F9,
+    glfw::Key::F10 => Key::F10,
+    glfw::Key::F11 => Key::F11,
+    glfw::Key::F12 => Key::F12,
+    glfw::Key::Space => Key::Space,
+    glfw::Key::Escape => Key::Escape,
+    glfw::Key::Enter => Key::Enter,
+    glfw::Key::Tab => Key::Tab,
+    glfw::Key::Backspace => Key::Backspace,
+    glfw::Key::Insert => Key::Insert,
+    glfw::Key::Delete => Key::Delete,
+    glfw::Key::Home => Key::Home,
+    glfw::Key::End => Key::End,
+    glfw::Key::PageUp => Key::PageUp,
+    glfw::Key::PageDown => Key::PageDown,
+    glfw::Key::Left => Key::Left,
+    glfw::Key::Right => Key::Right,
+    glfw::Key::Up => Key::Up,
+    glfw::Key::Down => Key::Down,
+    glfw::Key::LeftShift => Key::LeftShift,
+    glfw::Key::RightShift => Key::RightShift,
+    glfw::Key::LeftControl => Key::LeftControl,
+    glfw::Key::RightControl => Key::RightControl,
+    glfw::Key::LeftAlt => Key::LeftAlt,
+    glfw::Key::RightAlt => Key::RightAlt,
+    _ => Key::Other,
+  }
+}
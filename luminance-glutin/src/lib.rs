@@ -3,25 +3,34 @@
 #![deny(missing_docs)]
 
 use glutin::{
+  config::{GetGlConfig, GlConfig},
   context::PossiblyCurrentContext,
-  surface::{SurfaceTypeTrait, GlSurface},
+  display::{GetGlDisplay, GlDisplay},
+  surface::{GlSurface, ResizeableSurface, Surface, SurfaceTypeTrait},
 };
 use luminance::context::GraphicsContext;
 use luminance::framebuffer::{Framebuffer, FramebufferError};
+use luminance::surface::Surface as LuminanceSurface;
 use luminance::texture::Dim2;
 pub use luminance_gl::gl33::StateQueryError;
 use luminance_gl::GL33;
+use std::{ffi::CString, num::NonZeroU32};
 
 /// The Glutin surface.
 ///
-/// You want to create such an object in order to use any [luminance] construct.
+/// You want to create such an object in order to use any [luminance] construct. See [`GlutinSurface::new`].
+///
+/// A [`GlutinSurface`] only owns the OpenGL context and the surface, not the window they render to: glutin doesn’t
+/// create windows. Everything about the window itself — its title, icon, cursor visibility and shape, fullscreen mode,
+/// etc. — is then handled by the application with the windowing library it created the window with (typically winit,
+/// e.g. `Window::set_window_icon`, `Window::set_cursor_visible` and `Window::set_cursor_icon`).
 ///
 /// [luminance]: https://crates.io/crates/luminance
 pub struct GlutinSurface<T: SurfaceTypeTrait> {
   /// The context.
   pub ctx: PossiblyCurrentContext,
   /// The surface.
-  pub surface: glutin::surface::Surface<T>,
+  pub surface: Surface<T>,
   /// Underlying size (in physical pixels) of the surface.
   pub size: [u32; 2],
   /// OpenGL 3.3 state.
@@ -37,6 +46,38 @@ unsafe impl<T: SurfaceTypeTrait> GraphicsContext for GlutinSurface<T> {
 }
 
 impl<T: SurfaceTypeTrait> GlutinSurface<T> {
+  /// Create a [`GlutinSurface`] from an OpenGL context and a surface built by the application.
+  ///
+  /// The window, the event loop and the display are not owned by the [`GlutinSurface`]: the application creates them
+  /// (e.g. on an event loop it already runs for other windows or for platform integration), builds the OpenGL context
+  /// and the surface out of them, and keeps driving its event loop as usual. `size` is the size of the surface, in
+  /// physical pixels.
+  ///
+  /// `ctx` must be current on `surface`. The OpenGL symbols are loaded from the display of `ctx`, in the process-wide
+  /// function pointers of the gl crate: creating several [`GlutinSurface`]s with different function pointers (e.g. on
+  /// different GPUs) makes them all use the pointers loaded last. See the [`GL33`] documentation for further details.
+  pub fn new(
+    ctx: PossiblyCurrentContext,
+    surface: Surface<T>,
+    size: [u32; 2],
+  ) -> Result<Self, StateQueryError> {
+    let display = ctx.display();
+    gl::load_with(|symbol| {
+      CString::new(symbol)
+        .map(|symbol| display.get_proc_address(&symbol))
+        .unwrap_or(std::ptr::null())
+    });
+
+    let gl = GL33::new()?;
+
+    Ok(GlutinSurface {
+      ctx,
+      surface,
+      size,
+      gl,
+    })
+  }
+
   /// Get the underlying size (in physical pixels) of the surface.
   ///
   /// This is equivalent to getting the inner size of the windowed context and converting it to
@@ -45,13 +86,73 @@ impl<T: SurfaceTypeTrait> GlutinSurface<T> {
     self.size
   }
 
+  /// Get the number of samples per pixel of the back buffer, `0` meaning it is not multisampled.
+  ///
+  /// This is the sample count of the config the context was actually created with. Requesting a number of samples
+  /// (e.g. with `ConfigTemplateBuilder::with_multisampling`) doesn’t guarantee to get that number: configs are picked
+  /// among the ones the driver supports, and it might only offer fewer samples. Use this function to know how many
+  /// samples were granted, for instance to create off-screen framebuffers matching the back buffer.
+  pub fn sample_count(&self) -> u8 {
+    self.ctx.config().num_samples()
+  }
+
   /// Get access to the back buffer.
   pub fn back_buffer(&mut self) -> Result<Framebuffer<GL33, Dim2, (), ()>, FramebufferError> {
     Framebuffer::back_buffer(self, self.size())
   }
 
   /// Swap the back and front buffers.
+  ///
+  /// Errors reported by the driver are returned as is and should not be ignored. In particular, an error of kind
+  /// [`ErrorKind::ContextLost`] means the context was lost (e.g. after a GPU reset or a driver crash): nothing can be
+  /// rendered with it anymore, and the surface must be recreated, along with all the GPU resources.
+  ///
+  /// [`ErrorKind::ContextLost`]: glutin::error::ErrorKind::ContextLost
   pub fn swap_buffers(&self) -> glutin::error::Result<()> {
     self.surface.swap_buffers(&self.ctx)
   }
 }
+
+impl<T: SurfaceTypeTrait> LuminanceSurface for GlutinSurface<T> {
+  type Context = Self;
+
+  type SwapError = glutin::error::Error;
+
+  fn context(&mut self) -> &mut Self::Context {
+    self
+  }
+
+  fn size(&self) -> [u32; 2] {
+    self.size
+  }
+
+  fn swap_buffers(&mut self) -> Result<(), Self::SwapError> {
+    self.surface.swap_buffers(&self.ctx)
+  }
+}
+
+impl<T: SurfaceTypeTrait + ResizeableSurface> GlutinSurface<T> {
+  /// Resize the surface and get access to the resized back buffer.
+  ///
+  /// `new_size` is the new size of the surface, in physical pixels. Call this function whenever the window gets
+  /// resized (e.g. when receiving a winit `WindowEvent::Resized`, which size is already physical) or when its scale
+  /// factor changes, and render to the returned back buffer from then on; a back buffer obtained before the resize
+  /// still has the old size.
+  ///
+  /// If one of the dimensions is `0` (e.g. the window is minimized), the underlying surface is not resized, but the
+  /// size of the back buffer is still updated.
+  pub fn on_resize(
+    &mut self,
+    new_size: [u32; 2],
+  ) -> Result<Framebuffer<GL33, Dim2, (), ()>, FramebufferError> {
+    self.size = new_size;
+
+    if let (Some(width), Some(height)) =
+      (NonZeroU32::new(new_size[0]), NonZeroU32::new(new_size[1]))
+    {
+      self.surface.resize(&self.ctx, width, height);
+    }
+
+    self.back_buffer()
+  }
+}
@@ -0,0 +1,397 @@
+//! Mesh loading helpers for [luminance](https://crates.io/crates/luminance).
+//!
+//! This crate loads meshes from files into plain CPU data, shaped to be passed straight to a [`TessBuilder`]. It
+//! doesn’t create any [`Tess`] by itself, so it works with any backend.
+//!
+//! Meshes are made of [`MeshVertex`] vertices, which use the [`MeshSemantics`] vertex semantics, and `u32` indices
+//! forming triangles ([`Mode::Triangle`]). Your shaders must then declare the `position`, `normal` and `uv` vertex
+//! inputs.
+//!
+//! # Wavefront OBJ
+//!
+//! [Wavefront OBJ] files are loaded with [`load_obj`], or [`read_obj`] if you already have the content at hand. Each
+//! object (`o` record) of the file is returned as its own [`MeshData`]:
+//!
+//! ```ignore
+//! for mesh in luminance_mesh::load_obj("scene.obj")? {
+//!   let tess = ctx
+//!     .new_tess()
+//!     .set_mode(Mode::Triangle)
+//!     .set_vertices(mesh.vertices)
+//!     .set_indices(mesh.indices)
+//!     .build()?;
+//! }
+//! ```
+//!
+//! Faces with more than three vertices are triangulated as fans, which is correct for convex faces. Faces without
+//! normals get flat normals, computed from their triangles. Vertices without texture coordinates get `[0., 0.]`.
+//! Materials, smoothing groups, lines and points are ignored.
+//!
+//! [`TessBuilder`]: luminance::tess::TessBuilder
+//! [`Tess`]: luminance::tess::Tess
+//! [`Mode::Triangle`]: luminance::tess::Mode::Triangle
+//! [Wavefront OBJ]: https://en.wikipedia.org/wiki/Wavefront_.obj_file
+
+use luminance::{Semantics, Vertex};
+use std::{
+  collections::HashMap,
+  error, fmt,
+  fs::File,
+  io::{self, BufRead, BufReader},
+  path::Path,
+};
+
+/// Vertex semantics of [`MeshVertex`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Semantics)]
+pub enum MeshSemantics {
+  /// Position of the vertex.
+  #[sem(name = "position", repr = "[f32; 3]", wrapper = "MeshPosition")]
+  Position,
+  /// Normal of the vertex.
+  #[sem(name = "normal", repr = "[f32; 3]", wrapper = "MeshNormal")]
+  Normal,
+  /// Texture coordinates of the vertex.
+  #[sem(name = "uv", repr = "[f32; 2]", wrapper = "MeshUV")]
+  UV,
+}
+
+/// Vertex of a [`MeshData`].
+#[derive(Clone, Copy, Debug, PartialEq, Vertex)]
+#[vertex(sem = "MeshSemantics")]
+pub struct MeshVertex {
+  /// Position of the vertex.
+  pub position: MeshPosition,
+  /// Normal of the vertex.
+  pub normal: MeshNormal,
+  /// Texture coordinates of the vertex.
+  pub uv: MeshUV,
+}
+
+/// CPU data of a mesh.
+///
+/// `vertices` and `indices` can be passed to [`TessBuilder::set_vertices`] and [`TessBuilder::set_indices`], and
+/// must be rendered with [`Mode::Triangle`].
+///
+/// [`TessBuilder::set_vertices`]: luminance::tess::TessBuilder::set_vertices
+/// [`TessBuilder::set_indices`]: luminance::tess::TessBuilder::set_indices
+/// [`Mode::Triangle`]: luminance::tess::Mode::Triangle
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MeshData {
+  /// Name of the mesh, if any.
+  pub name: Option<String>,
+  /// Vertices of the mesh.
+  pub vertices: Vec<MeshVertex>,
+  /// Indices of the mesh, three per triangle.
+  pub indices: Vec<u32>,
+}
+
+/// Possible errors that might occur while loading a Wavefront OBJ file.
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum ObjError {
+  /// The OBJ data could not be read.
+  IoError(io::Error),
+
+  /// A record could not be parsed.
+  ParseError {
+    /// Line (starting at `1`) of the record.
+    line: usize,
+    /// Reason of the failure.
+    reason: String,
+  },
+}
+
+impl ObjError {
+  /// The OBJ data could not be read.
+  pub fn io_error(e: io::Error) -> Self {
+    ObjError::IoError(e)
+  }
+
+  /// A record could not be parsed.
+  pub fn parse_error(line: usize, reason: impl Into<String>) -> Self {
+    ObjError::ParseError {
+      line,
+      reason: reason.into(),
+    }
+  }
+}
+
+impl fmt::Display for ObjError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    match *self {
+      ObjError::IoError(ref e) => write!(f, "cannot read OBJ data: {}", e),
+      ObjError::ParseError { line, ref reason } => {
+        write!(f, "cannot parse OBJ data at line {}: {}", line, reason)
+      }
+    }
+  }
+}
+
+impl error::Error for ObjError {
+  fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+    match self {
+      ObjError::IoError(e) => Some(e),
+      ObjError::ParseError { .. } => None,
+    }
+  }
+}
+
+impl From<io::Error> for ObjError {
+  fn from(e: io::Error) -> Self {
+    ObjError::io_error(e)
+  }
+}
+
+/// Load the meshes of a Wavefront OBJ file.
+///
+/// See [`read_obj`].
+pub fn load_obj(path: impl AsRef<Path>) -> Result<Vec<MeshData>, ObjError> {
+  read_obj(BufReader::new(File::open(path)?))
+}
+
+/// Read the meshes of Wavefront OBJ data.
+///
+/// Every object (`o` record) is returned as its own [`MeshData`], named after the object. Faces appearing before the
+/// first object form an unnamed mesh. Objects without faces are skipped.
+pub fn read_obj(reader: impl BufRead) -> Result<Vec<MeshData>, ObjError> {
+  let mut parser = ObjParser::default();
+
+  for (i, line) in reader.lines().enumerate() {
+    parser
+      .parse_line(&line?)
+      .map_err(|reason| ObjError::parse_error(i + 1, reason))?;
+  }
+
+  parser.finish_mesh();
+  Ok(parser.meshes)
+}
+
+/// Key identifying a vertex of a mesh being built.
+///
+/// Vertices with flat normals are keyed by the triangle they belong to, so that they are not shared between
+/// triangles.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum VertexKey {
+  Smooth {
+    position: usize,
+    uv: Option<usize>,
+    normal: usize,
+  },
+  Flat {
+    position: usize,
+    uv: Option<usize>,
+    triangle: usize,
+  },
+}
+
+/// Corner of a face, as indices into the attributes read so far.
+#[derive(Clone, Copy, Debug)]
+struct Corner {
+  position: usize,
+  uv: Option<usize>,
+  normal: Option<usize>,
+}
+
+#[derive(Debug, Default)]
+struct ObjParser {
+  positions: Vec<[f32; 3]>,
+  uvs: Vec<[f32; 2]>,
+  normals: Vec<[f32; 3]>,
+  meshes: Vec<MeshData>,
+  // mesh being built, along with its vertex cache
+  mesh: MeshData,
+  vertex_cache: HashMap<VertexKey, u32>,
+  triangle_nb: usize,
+}
+
+impl ObjParser {
+  fn parse_line(&mut self, line: &str) -> Result<(), String> {
+    let line = line.split('#').next().unwrap_or_default();
+    let mut words = line.split_whitespace();
+
+    match words.next() {
+      Some("v") => {
+        let [x, y, z] = parse_floats(words, 3, 3)?;
+        self.positions.push([x, y, z]);
+      }
+
+      Some("vt") => {
+        let [u, v, _] = parse_floats(words, 1, 2)?;
+        self.uvs.push([u, v]);
+      }
+
+      Some("vn") => {
+        let [x, y, z] = parse_floats(words, 3, 3)?;
+        self.normals.push([x, y, z]);
+      }
+
+      Some("f") => {
+        let corners = words
+          .map(|word| self.parse_corner(word))
+          .collect::<Result<Vec<_>, _>>()?;
+
+        if corners.len() < 3 {
+          return Err(format!(
+            "face with {} vertices; at least 3 are required",
+            corners.len()
+          ));
+        }
+
+        // triangulate the face as a fan
+        for k in 1..corners.len() - 1 {
+          self.push_triangle([corners[0], corners[k], corners[k + 1]]);
+        }
+      }
+
+      Some("o") => {
+        self.finish_mesh();
+        self.mesh.name = Some(words.collect::<Vec<_>>().join(" "));
+      }
+
+      _ => (),
+    }
+
+    Ok(())
+  }
+
+  /// Parse a face corner, such as `1`, `1/2`, `1//3` or `1/2/3`.
+  fn parse_corner(&self, word: &str) -> Result<Corner, String> {
+    let mut indices = word.split('/');
+    let position = indices.next().unwrap_or_default();
+    let uv = indices.next().filter(|uv| !uv.is_empty());
+    let normal = indices.next().filter(|normal| !normal.is_empty());
+
+    Ok(Corner {
+      position: resolve_index(position, self.positions.len())?,
+      uv: uv.map(|uv| resolve_index(uv, self.uvs.len())).transpose()?,
+      normal: normal
+        .map(|normal| resolve_index(normal, self.normals.len()))
+        .transpose()?,
+    })
+  }
+
+  fn push_triangle(&mut self, corners: [Corner; 3]) {
+    let triangle = self.triangle_nb;
+    self.triangle_nb += 1;
+
+    // use flat normals unless all the corners have normals
+    let flat_normal = if corners.iter().all(|corner| corner.normal.is_some()) {
+      None
+    } else {
+      let [a, b, c] = corners.map(|corner| self.positions[corner.position]);
+      Some(normalize(cross(sub(b, a), sub(c, a))))
+    };
+
+    for corner in corners {
+      let key = match (corner.normal, flat_normal) {
+        (Some(normal), None) => VertexKey::Smooth {
+          position: corner.position,
+          uv: corner.uv,
+          normal,
+        },
+
+        _ => VertexKey::Flat {
+          position: corner.position,
+          uv: corner.uv,
+          triangle,
+        },
+      };
+
+      let vertices = &mut self.mesh.vertices;
+      let index = *self.vertex_cache.entry(key).or_insert_with(|| {
+        let normal = flat_normal
+          .or_else(|| corner.normal.map(|normal| self.normals[normal]))
+          .unwrap_or_default();
+        let uv = corner.uv.map(|uv| self.uvs[uv]).unwrap_or_default();
+
+        vertices.push(MeshVertex::new(
+          MeshPosition::new(self.positions[corner.position]),
+          MeshNormal::new(normal),
+          MeshUV::new(uv),
+        ));
+
+        vertices.len() as u32 - 1
+      });
+
+      self.mesh.indices.push(index);
+    }
+  }
+
+  /// Finish the mesh being built, if it has any face, and start a new, unnamed one.
+  fn finish_mesh(&mut self) {
+    let mesh = std::mem::take(&mut self.mesh);
+    self.vertex_cache.clear();
+    self.triangle_nb = 0;
+
+    if !mesh.indices.is_empty() {
+      self.meshes.push(mesh);
+    }
+  }
+}
+
+/// Parse between `min` and `max` (at most 3) floats; missing ones are set to `0`.
+fn parse_floats<'a>(
+  words: impl Iterator<Item = &'a str>,
+  min: usize,
+  max: usize,
+) -> Result<[f32; 3], String> {
+  let mut floats = [0.; 3];
+  let mut nb = 0;
+
+  // extra components (e.g. the w component of positions) are ignored
+  for word in words.take(max) {
+    floats[nb] = word
+      .parse()
+      .map_err(|_| format!("invalid number: {}", word))?;
+    nb += 1;
+  }
+
+  if nb < min {
+    return Err(format!("expected at least {} numbers, got {}", min, nb));
+  }
+
+  Ok(floats)
+}
+
+/// Resolve a 1-based (or negative, relative to the end) OBJ index into an index into `len` elements.
+fn resolve_index(word: &str, len: usize) -> Result<usize, String> {
+  let index: isize = word
+    .parse()
+    .map_err(|_| format!("invalid index: {}", word))?;
+
+  let resolved = if index > 0 {
+    index as usize - 1
+  } else if index < 0 && index.unsigned_abs() <= len {
+    len - index.unsigned_abs()
+  } else {
+    return Err(format!("invalid index: {}", word));
+  };
+
+  if resolved >= len {
+    return Err(format!("index {} out of range ({} elements)", word, len));
+  }
+
+  Ok(resolved)
+}
+
+fn sub(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+  [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+  [
+    a[1] * b[2] - a[2] * b[1],
+    a[2] * b[0] - a[0] * b[2],
+    a[0] * b[1] - a[1] * b[0],
+  ]
+}
+
+fn normalize(v: [f32; 3]) -> [f32; 3] {
+  let len = (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+
+  if len > 0. {
+    [v[0] / len, v[1] / len, v[2] / len]
+  } else {
+    v
+  }
+}
@@ -0,0 +1,104 @@
+use luminance_mesh::{read_obj, MeshNormal, MeshPosition, MeshUV, MeshVertex, ObjError};
+
+fn vertex(position: [f32; 3], normal: [f32; 3], uv: [f32; 2]) -> MeshVertex {
+  MeshVertex::new(
+    MeshPosition::new(position),
+    MeshNormal::new(normal),
+    MeshUV::new(uv),
+  )
+}
+
+#[test]
+fn smooth_quad() {
+  let obj = "
+# a quad with shared normals and texture coordinates
+v 0 0 0
+v 1 0 0
+v 1 1 0
+v 0 1 0
+vt 0 0
+vt 1 0
+vt 1 1
+vt 0 1
+vn 0 0 1
+f 1/1/1 2/2/1 3/3/1 4/4/1
+";
+
+  let meshes = read_obj(obj.as_bytes()).unwrap();
+  assert_eq!(meshes.len(), 1);
+
+  let mesh = &meshes[0];
+  assert_eq!(mesh.name, None);
+  assert_eq!(
+    mesh.vertices,
+    vec![
+      vertex([0., 0., 0.], [0., 0., 1.], [0., 0.]),
+      vertex([1., 0., 0.], [0., 0., 1.], [1., 0.]),
+      vertex([1., 1., 0.], [0., 0., 1.], [1., 1.]),
+      vertex([0., 1., 0.], [0., 0., 1.], [0., 1.]),
+    ]
+  );
+  assert_eq!(mesh.indices, vec![0, 1, 2, 0, 2, 3]);
+}
+
+#[test]
+fn flat_normals() {
+  let obj = "
+v 0 0 0
+v 1 0 0
+v 0 1 0
+v 0 0 1
+f 1 2 3
+f 1 4 2
+";
+
+  let meshes = read_obj(obj.as_bytes()).unwrap();
+  let mesh = &meshes[0];
+
+  // vertices are not shared between triangles with flat normals
+  assert_eq!(mesh.indices, vec![0, 1, 2, 3, 4, 5]);
+  assert_eq!(
+    mesh.vertices[0],
+    vertex([0., 0., 0.], [0., 0., 1.], [0., 0.])
+  );
+  assert_eq!(
+    mesh.vertices[3],
+    vertex([0., 0., 0.], [0., 1., 0.], [0., 0.])
+  );
+}
+
+#[test]
+fn multiple_objects() {
+  let obj = "
+o first
+v 0 0 0
+v 1 0 0
+v 0 1 0
+f 1 2 3
+o empty
+o second
+v 0 0 1
+f -4 -3 -1
+";
+
+  let meshes = read_obj(obj.as_bytes()).unwrap();
+  let names: Vec<_> = meshes.iter().map(|mesh| mesh.name.as_deref()).collect();
+  assert_eq!(names, vec![Some("first"), Some("second")]);
+
+  // negative indices are relative to the attributes read so far
+  assert_eq!(*meshes[1].vertices[2].position, [0., 0., 1.]);
+  assert_eq!(meshes[1].indices, vec![0, 1, 2]);
+}
+
+#[test]
+fn parse_errors() {
+  let err = |obj: &str| match read_obj(obj.as_bytes()) {
+    Err(ObjError::ParseError { line, .. }) => Some(line),
+    _ => None,
+  };
+
+  assert_eq!(err("v 0 0 0\nv 0 x 0\n"), Some(2));
+  assert_eq!(err("v 0 0 0\nv 1 0 0\nf 1 2\n"), Some(3));
+  assert_eq!(err("v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 4\n"), Some(4));
+  assert_eq!(err("v 0 0 0\nv 1 0 0\nv 0 1 0\nf 0 1 2\n"), Some(4));
+}
@@ -76,6 +76,9 @@ impl GL33Surface {
   /// This is your chance to change GL attributes before creating the window with your preferred
   /// settings.
   ///
+  /// The OpenGL function pointers are loaded in the process-wide function pointers of the gl crate, so all the
+  /// surfaces of the process share the pointers loaded last. See the [`GL33`] documentation for further details.
+  ///
   /// ```ignore
   /// use luminance_sdl2::GL33Surface;
   ///
@@ -1,5 +1,9 @@
 //! Types and traits implementing the [std140] OpenGL rule.
 //!
+//! [`Std140`] can be derived for structs with the `Std140` derive proc-macro of [luminance], so that they can be used
+//! in uniform blocks.
+//!
+//! [luminance]: https://crates.io/crates/luminance
 //! [std140]: https://www.khronos.org/registry/OpenGL/specs/gl/glspec45.core.pdf#page=159
 
 use luminance::shader::types::{Mat22, Mat33, Mat44, Vec2, Vec3, Vec4};
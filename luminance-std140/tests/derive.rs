@@ -0,0 +1,69 @@
+use luminance::{
+  shader::types::{Mat44, Vec2, Vec3, Vec4},
+  Std140,
+};
+use luminance_std140::Std140;
+use std::mem;
+
+#[derive(Clone, Copy, Debug, PartialEq, Std140)]
+struct Light {
+  intensity: f32,
+  position: Vec3<f32>,
+  color: Vec4<f32>,
+  uv: Vec2<f32>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Std140)]
+struct Scene {
+  view: Mat44<f32>,
+  light: Light,
+  exposure: f32,
+}
+
+// offset of a field in the std140 representation of a value
+fn offset_of<T, F>(encoded: &T, field: &F) -> usize {
+  field as *const F as usize - encoded as *const T as usize
+}
+
+#[test]
+fn derive_layout() {
+  // intensity: 0, position: 16, color: 32, uv: 48, rounded up to a multiple of 16
+  assert_eq!(mem::size_of::<<Light as Std140>::Encoded>(), 64);
+  assert_eq!(mem::align_of::<<Light as Std140>::Encoded>(), 16);
+
+  let light = Light {
+    intensity: 1.,
+    position: Vec3::new(1., 2., 3.),
+    color: Vec4::new(1., 1., 1., 1.),
+    uv: Vec2::new(0.5, 0.5),
+  };
+  let encoded = light.std140_encode();
+  assert_eq!(offset_of(&encoded, &encoded.0.intensity), 0);
+  assert_eq!(offset_of(&encoded, &encoded.0.position), 16);
+  assert_eq!(offset_of(&encoded, &encoded.0.color), 32);
+  assert_eq!(offset_of(&encoded, &encoded.0.uv), 48);
+
+  // view: 0, light: 64, exposure: 128, rounded up to a multiple of 16
+  assert_eq!(mem::size_of::<<Scene as Std140>::Encoded>(), 144);
+}
+
+#[test]
+fn derive_roundtrip() {
+  let scene = Scene {
+    view: Mat44::new([
+      [1., 0., 0., 0.],
+      [0., 1., 0., 0.],
+      [0., 0., 1., 0.],
+      [0., 0., 0., 1.],
+    ]),
+    light: Light {
+      intensity: 2.,
+      position: Vec3::new(1., 2., 3.),
+      color: Vec4::new(0.1, 0.2, 0.3, 1.),
+      uv: Vec2::new(0.25, 0.75),
+    },
+    exposure: 0.5,
+  };
+
+  assert_eq!(Scene::std140_decode(scene.std140_encode()), scene);
+}
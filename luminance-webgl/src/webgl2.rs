@@ -2,7 +2,10 @@
 
 mod array_buffer;
 pub mod buffer;
+mod debug;
+pub mod fence;
 pub mod framebuffer;
+mod indirect;
 pub mod pipeline;
 pub mod pixel;
 pub mod query;
@@ -10,6 +13,7 @@ pub mod shader;
 pub mod state;
 pub mod tess;
 pub mod texture;
+pub mod timer;
 
 pub use crate::webgl2::array_buffer::IntoArrayBuffer;
 pub use crate::webgl2::state::StateQueryError;
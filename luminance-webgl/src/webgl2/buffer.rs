@@ -120,6 +120,25 @@ where
       _phantom: PhantomData,
     }
   }
+
+  /// Replace the elements starting at `start` with `data`, uploading only that range.
+  pub(crate) fn update_range(&mut self, start: usize, data: &[T]) -> Result<(), BufferError>
+  where
+    T: Copy,
+  {
+    self.buf[start..start + data.len()].copy_from_slice(data);
+
+    let mut state = self.gl_buf.state.borrow_mut();
+    let offset = mem::size_of::<T>() * start;
+    let bytes = mem::size_of_val(data);
+    update_webgl_buffer::<TARGET>(
+      &mut state,
+      &self.gl_buf.handle,
+      data.as_ptr() as *const u8,
+      bytes,
+      offset,
+    )
+  }
 }
 
 pub struct BufferSlice<'a, T> {
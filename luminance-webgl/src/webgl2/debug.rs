@@ -0,0 +1,17 @@
+//! WebGL2 has no debug groups nor object labels: annotations are ignored.
+
+use crate::webgl2::WebGL2;
+use luminance::backend::debug::{DebugGroup, DebugLabel};
+
+unsafe impl DebugGroup for WebGL2 {
+  unsafe fn push_debug_group(&mut self, _: &str) {}
+
+  unsafe fn pop_debug_group(&mut self) {}
+}
+
+unsafe impl<R> DebugLabel<R> for WebGL2
+where
+  R: ?Sized,
+{
+  unsafe fn set_label(&mut self, _: &R, _: &str) {}
+}
@@ -0,0 +1,53 @@
+//! Fence support for WebGL2.
+
+use crate::webgl2::{state::WebGL2State, WebGL2};
+use luminance::{backend::fence::Fence as FenceBackend, fence::FenceError};
+use std::{cell::RefCell, rc::Rc, time::Duration};
+use web_sys::{WebGl2RenderingContext, WebGlSync};
+
+pub struct Fence {
+  handle: WebGlSync,
+  state: Rc<RefCell<WebGL2State>>,
+}
+
+impl Drop for Fence {
+  fn drop(&mut self) {
+    self.state.borrow().ctx.delete_sync(Some(&self.handle));
+  }
+}
+
+unsafe impl FenceBackend for WebGL2 {
+  type FenceRepr = Fence;
+
+  unsafe fn new_fence(&mut self) -> Result<Self::FenceRepr, FenceError> {
+    let handle = self
+      .state
+      .borrow()
+      .ctx
+      .fence_sync(WebGl2RenderingContext::SYNC_GPU_COMMANDS_COMPLETE, 0)
+      .ok_or_else(FenceError::cannot_create)?;
+
+    Ok(Fence {
+      handle,
+      state: self.state.clone(),
+    })
+  }
+
+  unsafe fn wait_fence(fence: &Self::FenceRepr, timeout: Duration) -> bool {
+    let mut state = fence.state.borrow_mut();
+
+    // WebGL2 fails to wait with a timeout greater than MAX_CLIENT_WAIT_TIMEOUT_WEBGL
+    let timeout = timeout
+      .as_nanos()
+      .min(state.get_max_client_wait_timeout() as u128);
+
+    matches!(
+      state.ctx.client_wait_sync_with_f64(
+        &fence.handle,
+        WebGl2RenderingContext::SYNC_FLUSH_COMMANDS_BIT,
+        timeout as f64
+      ),
+      WebGl2RenderingContext::ALREADY_SIGNALED | WebGl2RenderingContext::CONDITION_SATISFIED
+    )
+  }
+}
@@ -1,17 +1,28 @@
 //! Framebuffer support for WebGL2.
 
-use crate::webgl2::{state::WebGL2State, WebGL2};
+use crate::webgl2::{
+  pixel::webgl_pixel_format,
+  state::{ScissorState, WebGL2State},
+  texture::set_pack_alignment,
+  WebGL2,
+};
 use js_sys::Uint32Array;
 use luminance::{
   backend::{
     color_slot::ColorSlot,
     depth_stencil_slot::DepthStencilSlot,
-    framebuffer::{Framebuffer as FramebufferBackend, FramebufferBackBuffer},
+    framebuffer::{
+      ClearBackBuffer, Framebuffer as FramebufferBackend, FramebufferBackBuffer, FramebufferBlit,
+      FramebufferReadback,
+    },
   },
-  framebuffer::{FramebufferError, IncompleteReason},
+  depth_stencil::Write,
+  framebuffer::{BlitFilter, BlitMask, FramebufferError, IncompleteReason},
+  pixel::{ColorPixel, PixelFormat, RenderablePixel},
+  scissor::ScissorRegion,
   texture::{Dim2, Dimensionable, Sampler},
 };
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, mem, rc::Rc, slice};
 use web_sys::{WebGl2RenderingContext, WebGlFramebuffer, WebGlRenderbuffer};
 
 pub struct Framebuffer<D>
@@ -22,6 +33,8 @@ where
   pub(crate) handle: Option<WebGlFramebuffer>,
   renderbuffer: Option<WebGlRenderbuffer>,
   pub(crate) size: D::Size,
+  // pixel formats of the color attachments, in draw buffer order
+  pub(crate) color_formats: Vec<PixelFormat>,
   state: Rc<RefCell<WebGL2State>>,
 }
 
@@ -116,6 +129,7 @@ where
       handle: Some(handle),
       renderbuffer: depth_renderbuffer,
       size,
+      color_formats,
       state: self.state.clone(),
     };
 
@@ -212,7 +226,161 @@ unsafe impl FramebufferBackBuffer for WebGL2 {
       handle: None, // None is the default framebuffer in WebGL
       renderbuffer: None,
       size,
+      color_formats: Vec::new(),
       state: self.state.clone(),
     })
   }
 }
+
+unsafe impl ClearBackBuffer for WebGL2 {
+  unsafe fn clear_back_buffer(
+    &mut self,
+    color: Option<[f32; 4]>,
+    depth: Option<f32>,
+    stencil: Option<i32>,
+  ) {
+    let mut state = self.state.borrow_mut();
+    let mut clear_buffer_bits = 0;
+
+    if let Some(color) = color {
+      state.set_clear_color(color);
+      state.set_color_mask([true; 4]);
+      clear_buffer_bits |= WebGl2RenderingContext::COLOR_BUFFER_BIT;
+    }
+
+    if let Some(depth) = depth {
+      state.set_clear_depth(depth);
+      state.set_depth_write(Write::On);
+      clear_buffer_bits |= WebGl2RenderingContext::DEPTH_BUFFER_BIT;
+    }
+
+    if let Some(stencil) = stencil {
+      state.set_clear_stencil(stencil);
+      state.set_stencil_write_mask(0xFF);
+      clear_buffer_bits |= WebGl2RenderingContext::STENCIL_BUFFER_BIT;
+    }
+
+    if clear_buffer_bits != 0 {
+      state.bind_draw_framebuffer(None);
+      state.set_scissor_state(ScissorState::Off);
+      state.ctx.clear(clear_buffer_bits);
+    }
+  }
+}
+
+unsafe impl FramebufferBlit for WebGL2 {
+  unsafe fn blit_framebuffer(
+    &mut self,
+    src: &Self::FramebufferRepr,
+    dst: &Self::FramebufferRepr,
+    src_region: &ScissorRegion,
+    dst_region: &ScissorRegion,
+    mask: BlitMask,
+    filter: BlitFilter,
+  ) -> Result<(), FramebufferError> {
+    let mut state = self.state.borrow_mut();
+
+    let mut buffer_bits = 0;
+
+    if mask.color {
+      buffer_bits |= WebGl2RenderingContext::COLOR_BUFFER_BIT;
+    }
+
+    if mask.depth {
+      buffer_bits |= WebGl2RenderingContext::DEPTH_BUFFER_BIT;
+    }
+
+    if mask.stencil {
+      buffer_bits |= WebGl2RenderingContext::STENCIL_BUFFER_BIT;
+    }
+
+    let filter = match filter {
+      BlitFilter::Nearest => WebGl2RenderingContext::NEAREST,
+      BlitFilter::Linear => WebGl2RenderingContext::LINEAR,
+    };
+
+    state.bind_read_framebuffer(src.handle.as_ref());
+    state.bind_draw_framebuffer(dst.handle.as_ref());
+
+    // blitting is subject to the scissor test
+    state.set_scissor_state(ScissorState::Off);
+
+    state.ctx.blit_framebuffer(
+      src_region.x as i32,
+      src_region.y as i32,
+      (src_region.x + src_region.width) as i32,
+      (src_region.y + src_region.height) as i32,
+      dst_region.x as i32,
+      dst_region.y as i32,
+      (dst_region.x + dst_region.width) as i32,
+      (dst_region.y + dst_region.height) as i32,
+      buffer_bits,
+      filter,
+    );
+
+    Ok(())
+  }
+}
+
+unsafe impl FramebufferReadback for WebGL2 {
+  unsafe fn read_color_pixels<P>(
+    framebuffer: &Self::FramebufferRepr,
+    attachment_index: usize,
+    region: &ScissorRegion,
+  ) -> Result<Vec<P::RawEncoding>, FramebufferError>
+  where
+    P: ColorPixel + RenderablePixel,
+    P::RawEncoding: Copy + Default,
+  {
+    let pf = P::pixel_format();
+    let (format, _, ty) = webgl_pixel_format(pf).ok_or_else(|| {
+      FramebufferError::cannot_read_pixels(format!("unsupported pixel format: {:?}", pf))
+    })?;
+
+    let mut state = framebuffer.state.borrow_mut();
+
+    state.bind_read_framebuffer(framebuffer.handle.as_ref());
+
+    if framebuffer.handle.is_some() {
+      state
+        .ctx
+        .read_buffer(WebGl2RenderingContext::COLOR_ATTACHMENT0 + attachment_index as u32);
+    }
+
+    // set the packing alignment based on the number of bytes to skip
+    let skip_bytes = (pf.format.bytes_len() * region.width as usize) % 8;
+    set_pack_alignment(&mut state, skip_bytes);
+
+    let texels_nb = (region.width * region.height) as usize * pf.channels_len();
+    let mut texels = vec![Default::default(); texels_nb];
+
+    state
+      .ctx
+      .read_pixels_with_u8_array_and_dst_offset(
+        region.x as i32,
+        region.y as i32,
+        region.width as i32,
+        region.height as i32,
+        format,
+        ty,
+        slice::from_raw_parts_mut(
+          texels.as_mut_ptr() as *mut u8,
+          texels_nb * mem::size_of::<P::RawEncoding>(),
+        ),
+        0,
+      )
+      .map_err(|e| FramebufferError::cannot_read_pixels(format!("{:?}", e)))?;
+
+    Ok(texels)
+  }
+
+  unsafe fn read_depth_pixels(
+    _: &Self::FramebufferRepr,
+    _: &ScissorRegion,
+  ) -> Result<Vec<f32>, FramebufferError> {
+    // WebGL2 cannot read depth values with readPixels
+    Err(FramebufferError::cannot_read_pixels(
+      "depth values cannot be read back in WebGL2",
+    ))
+  }
+}
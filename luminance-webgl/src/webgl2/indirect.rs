@@ -0,0 +1,78 @@
+//! WebGL2 has no indirect rendering: indirect buffers cannot be created.
+
+use crate::webgl2::WebGL2;
+use luminance::{
+  backend::{
+    indirect::IndirectBuffer as IndirectBufferBackend, tess::Tess, tess_gate::TessGateIndirect,
+  },
+  indirect::{IndirectCommand, IndirectError},
+  tess::{Deinterleaved, DeinterleavedData, Interleaved, TessError, TessIndex, TessVertexData},
+};
+use std::convert::Infallible;
+
+unsafe impl<C> IndirectBufferBackend<C> for WebGL2
+where
+  C: IndirectCommand,
+{
+  type IndirectBufferRepr = Infallible;
+
+  unsafe fn new_indirect_buffer(
+    &mut self,
+    _: impl Iterator<Item = C>,
+  ) -> Result<Self::IndirectBufferRepr, IndirectError> {
+    Err(IndirectError::unsupported())
+  }
+
+  unsafe fn indirect_buffer_len(buffer: &Self::IndirectBufferRepr) -> usize {
+    match *buffer {}
+  }
+
+  unsafe fn indirect_command_at(
+    buffer: &Self::IndirectBufferRepr,
+    _: usize,
+  ) -> Result<C, IndirectError> {
+    match *buffer {}
+  }
+
+  unsafe fn set_indirect_command_at(
+    buffer: &mut Self::IndirectBufferRepr,
+    _: usize,
+    _: C,
+  ) -> Result<C, IndirectError> {
+    match *buffer {}
+  }
+}
+
+unsafe impl<V, I, W, C> TessGateIndirect<V, I, W, Interleaved, C> for WebGL2
+where
+  V: TessVertexData<Interleaved, Data = Vec<V>>,
+  I: TessIndex,
+  W: TessVertexData<Interleaved, Data = Vec<W>>,
+  C: IndirectCommand,
+{
+  unsafe fn render_indirect(
+    &mut self,
+    _: &<Self as Tess<V, I, W, Interleaved>>::TessRepr,
+    indirect_buffer: &Self::IndirectBufferRepr,
+    _: usize,
+  ) -> Result<(), TessError> {
+    match *indirect_buffer {}
+  }
+}
+
+unsafe impl<V, I, W, C> TessGateIndirect<V, I, W, Deinterleaved, C> for WebGL2
+where
+  V: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+  I: TessIndex,
+  W: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+  C: IndirectCommand,
+{
+  unsafe fn render_indirect(
+    &mut self,
+    _: &<Self as Tess<V, I, W, Deinterleaved>>::TessRepr,
+    indirect_buffer: &Self::IndirectBufferRepr,
+    _: usize,
+  ) -> Result<(), TessError> {
+    match *indirect_buffer {}
+  }
+}
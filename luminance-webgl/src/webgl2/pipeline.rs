@@ -10,10 +10,12 @@ use luminance::{
     tess_gate::TessGate,
   },
   blending::BlendingMode,
-  pipeline::{PipelineError, PipelineState, Viewport},
-  pixel::Pixel,
+  pipeline::{PipelineError, PipelineState},
+  pixel::{Pixel, PixelFormat, Type},
   render_state::RenderState,
-  tess::{Deinterleaved, DeinterleavedData, Interleaved, TessIndex, TessVertexData},
+  tess::{
+    Deinterleaved, DeinterleavedData, Interleaved, Mode, TessError, TessIndex, TessVertexData,
+  },
   texture::Dimensionable,
 };
 use luminance_std140::{ArrElem, Std140};
@@ -103,23 +105,30 @@ where
 
     let size = framebuffer.size;
 
-    let (x, y, w, h) = match pipeline_state.viewport {
-      Viewport::Whole => (0, 0, D::width(size), D::height(size)),
-      Viewport::Specific {
-        x,
-        y,
-        width,
-        height,
-      } => (x, y, width, height),
-    };
+    let viewport = pipeline_state
+      .viewport
+      .bottom_left_rect(D::width(size), D::height(size));
+    state.set_viewport(viewport.map(|v| v as _));
 
-    state.set_viewport([x as _, y as _, w as _, h as _]);
+    let (near, far) = pipeline_state.depth_range;
+    state.set_depth_range([near, far]);
 
     let mut clear_buffer_bits = 0;
 
-    if let Some(clear_color) = pipeline_state.clear_color {
-      state.set_clear_color(clear_color);
-      clear_buffer_bits |= WebGl2RenderingContext::COLOR_BUFFER_BIT;
+    // integer attachments have undefined values when cleared with a floating-point color, and per-attachment clear
+    // colors cannot be honored with a single clear color, so in both cases attachments are cleared one by one with the
+    // clear function matching their encoding
+    let clear_attachments = !framebuffer.color_formats.is_empty()
+      && (pipeline_state.clear_colors.is_some()
+        || framebuffer.color_formats.iter().any(|f| f.is_integral()));
+
+    if !clear_attachments {
+      // the back buffer doesn’t have color formats and is cleared as a single color attachment
+      if let Some(clear_color) = pipeline_state.attachment_clear_color(0) {
+        state.set_clear_color(clear_color);
+        state.set_color_mask([true; 4]);
+        clear_buffer_bits |= WebGl2RenderingContext::COLOR_BUFFER_BIT;
+      }
     }
 
     if let Some(clear_depth) = pipeline_state.clear_depth {
@@ -130,11 +139,12 @@ where
 
     if let Some(clear_stencil) = pipeline_state.clear_stencil {
       state.set_clear_stencil(clear_stencil);
+      state.set_stencil_write_mask(0xFF);
       clear_buffer_bits |= WebGl2RenderingContext::STENCIL_BUFFER_BIT;
     }
 
     // scissor test
-    match pipeline_state.scissor() {
+    match pipeline_state.clear_region() {
       Some(region) => {
         state.set_scissor_state(ScissorState::On);
         state.set_scissor_region(region);
@@ -148,6 +158,59 @@ where
     if clear_buffer_bits != 0 {
       state.ctx.clear(clear_buffer_bits);
     }
+
+    if clear_attachments {
+      state.set_color_mask([true; 4]);
+      clear_color_attachments(&state.ctx, &framebuffer.color_formats, pipeline_state);
+    }
+
+    // the clear region must not leak into operations following the clear (draws set their own scissor state)
+    state.set_scissor_state(ScissorState::Off);
+  }
+}
+
+/// Clear each color attachment with its clear color and the clear function matching its encoding.
+///
+/// Integral attachments are cleared with the integer clear color matching their encoding, if any, or with the clear
+/// color converted to integers otherwise.
+fn clear_color_attachments(
+  ctx: &WebGl2RenderingContext,
+  color_formats: &[PixelFormat],
+  pipeline_state: &PipelineState,
+) {
+  for (attachment, format) in color_formats.iter().enumerate() {
+    let clear_color = pipeline_state.attachment_clear_color(attachment);
+    let draw_buffer = attachment as i32;
+
+    match format.encoding {
+      Type::Integral => {
+        let color = pipeline_state
+          .clear_color_i()
+          .copied()
+          .or_else(|| clear_color.map(|color| color.map(|c| c as i32)));
+
+        if let Some(color) = color {
+          ctx.clear_bufferiv_with_i32_array(WebGl2RenderingContext::COLOR, draw_buffer, &color);
+        }
+      }
+
+      Type::Unsigned => {
+        let color = pipeline_state
+          .clear_color_u()
+          .copied()
+          .or_else(|| clear_color.map(|color| color.map(|c| c as u32)));
+
+        if let Some(color) = color {
+          ctx.clear_bufferuiv_with_u32_array(WebGl2RenderingContext::COLOR, draw_buffer, &color);
+        }
+      }
+
+      _ => {
+        if let Some(color) = clear_color {
+          ctx.clear_bufferfv_with_f32_array(WebGl2RenderingContext::COLOR, draw_buffer, &color);
+        }
+      }
+    }
   }
 }
 
@@ -245,9 +308,20 @@ where
     tess: &Self::TessRepr,
     start_index: usize,
     vert_nb: usize,
+    base_vertex: i32,
     inst_nb: usize,
-  ) {
-    let _ = <Self as Tess<V, I, W, Interleaved>>::render(tess, start_index, vert_nb, inst_nb);
+    inst_start_index: usize,
+    mode: Option<Mode>,
+  ) -> Result<(), TessError> {
+    <Self as Tess<V, I, W, Interleaved>>::render(
+      tess,
+      start_index,
+      vert_nb,
+      base_vertex,
+      inst_nb,
+      inst_start_index,
+      mode,
+    )
   }
 }
 
@@ -262,9 +336,20 @@ where
     tess: &Self::TessRepr,
     start_index: usize,
     vert_nb: usize,
+    base_vertex: i32,
     inst_nb: usize,
-  ) {
-    let _ = <Self as Tess<V, I, W, Deinterleaved>>::render(tess, start_index, vert_nb, inst_nb);
+    inst_start_index: usize,
+    mode: Option<Mode>,
+  ) -> Result<(), TessError> {
+    <Self as Tess<V, I, W, Deinterleaved>>::render(
+      tess,
+      start_index,
+      vert_nb,
+      base_vertex,
+      inst_nb,
+      inst_start_index,
+      mode,
+    )
   }
 }
 
@@ -293,6 +378,8 @@ unsafe impl RenderGate for WebGL2 {
       }
     }
 
+    // per-draw-buffer blending is not supported by WebGL2 and is ignored; all draw buffers use the blending state above
+
     // depth-related state
     if let Some(depth_comparison) = rdr_st.depth_test() {
       state.enable_depth_test(true);
@@ -302,17 +389,25 @@ unsafe impl RenderGate for WebGL2 {
     }
 
     state.set_depth_write(rdr_st.depth_write());
+    state.set_color_mask(rdr_st.color_mask());
 
     // stencil-related state
     if let Some(stencil_test) = rdr_st.stencil_test() {
+      let back_stencil_test = rdr_st.back_stencil_test().unwrap_or(stencil_test);
+
       state.enable_stencil_test(true);
-      state.set_stencil_test(*stencil_test);
+      state.set_stencil_test(*stencil_test, *back_stencil_test);
     } else {
       state.enable_stencil_test(false);
     }
 
     // stencil operations are always active
-    state.set_stencil_operations(*rdr_st.stencil_operations());
+    let stencil_operations = rdr_st.stencil_operations();
+    let back_stencil_operations = rdr_st
+      .back_stencil_operations()
+      .unwrap_or(stencil_operations);
+    state.set_stencil_operations(*stencil_operations, *back_stencil_operations);
+    state.set_stencil_write_mask(rdr_st.stencil_write_mask());
 
     // face culling state
     match rdr_st.face_culling() {
@@ -337,6 +432,18 @@ unsafe impl RenderGate for WebGL2 {
         state.set_scissor_state(ScissorState::Off);
       }
     }
+
+    // alpha-to-coverage
+    state.enable_alpha_to_coverage(rdr_st.alpha_to_coverage());
+
+    // depth clamp; ignored if not supported
+    state.enable_depth_clamp(rdr_st.depth_clamp());
+
+    // conservative rasterization is not available in WebGL2 and is ignored
+
+    // line width; WebGL2 has no fixed point size, so it is always set by programs, nor polygon mode, so polygons are
+    // always filled
+    state.set_line_width(rdr_st.line_width().width());
   }
 }
 
@@ -1,7 +1,7 @@
 //! Query API implementation.
 
 use crate::WebGL2;
-use luminance::backend::query::{Query as QueryBackend, QueryError};
+use luminance::backend::query::{BackendLimits, GpuMemoryInfo, Query as QueryBackend, QueryError};
 
 unsafe impl QueryBackend for WebGL2 {
   fn backend_author(&self) -> Result<String, QueryError> {
@@ -43,4 +43,22 @@ unsafe impl QueryBackend for WebGL2 {
       .get_max_texture_array_elements()
       .ok_or_else(|| QueryError::NoMaxTextureArrayElements)
   }
+
+  fn limits(&self) -> Result<BackendLimits, QueryError> {
+    self
+      .state
+      .borrow_mut()
+      .get_limits()
+      .ok_or(QueryError::NoBackendLimits)
+  }
+
+  fn gpu_memory_info(&self) -> Option<GpuMemoryInfo> {
+    // WebGL2 doesn’t expose any memory information
+    None
+  }
+
+  fn back_buffer_is_srgb(&self) -> bool {
+    // the default framebuffer of WebGL2 is never sRGB-encoded
+    false
+  }
 }
@@ -3,7 +3,7 @@
 use super::buffer::{Buffer, BufferError};
 use crate::webgl2::{state::WebGL2State, WebGL2};
 use luminance::{
-  backend::shader::{Shader, ShaderData, Uniformable},
+  backend::shader::{Shader, ShaderData, Uniformable, UniformableTransposed},
   pipeline::{ShaderDataBinding, TextureBinding},
   pixel::{SamplerType, Type as PixelType},
   shader::{
@@ -35,8 +35,7 @@ impl Stage {
   fn new(webgl2: &mut WebGL2, ty: StageType, src: &str) -> Result<Self, StageError> {
     let state = webgl2.state.borrow();
 
-    let shader_ty = webgl_shader_type(ty)
-      .ok_or_else(|| StageError::CompilationFailed(ty, "unsupported shader type".to_owned()))?;
+    let shader_ty = webgl_shader_type(ty).ok_or_else(|| StageError::unsupported_type(ty))?;
 
     let handle = state.ctx.create_shader(shader_ty).ok_or_else(|| {
       StageError::CompilationFailed(ty, "unable to create shader stage".to_owned())
@@ -74,6 +73,15 @@ impl Stage {
   fn handle(&self) -> &WebGlShader {
     &self.handle
   }
+
+  fn info_log(&self) -> Option<String> {
+    self
+      .state
+      .borrow()
+      .ctx
+      .get_shader_info_log(&self.handle)
+      .filter(|log| !log.trim().is_empty())
+  }
 }
 
 /// A type used to map [`i32`] (uniform locations) to [`WebGlUniformLocation`].
@@ -249,6 +257,10 @@ unsafe impl Shader for WebGL2 {
     Stage::new(self, ty, src)
   }
 
+  unsafe fn stage_info_log(stage: &Self::StageRepr) -> Option<String> {
+    stage.info_log()
+  }
+
   unsafe fn new_program(
     &mut self,
     vertex: &Self::StageRepr,
@@ -427,6 +439,9 @@ fn check_types_match(name: &str, ty: UniformType, glty: u32) -> Result<(), Unifo
     (ICubemap, INT_SAMPLER_CUBE),
     (UICubemap, UNSIGNED_INT_SAMPLER_CUBE),
     (Cubemap, SAMPLER_CUBE),
+    (Sampler2DShadow, SAMPLER_2D_SHADOW),
+    (Sampler2DArrayShadow, SAMPLER_2D_ARRAY_SHADOW),
+    (CubemapShadow, SAMPLER_CUBE_SHADOW),
   )
 }
 
@@ -592,6 +607,24 @@ macro_rules! impl_Uniformable {
         );
       }
     }
+
+    unsafe impl<'a, const N: usize> UniformableTransposed<'a, Arr<$q<$t>, N>> for WebGL2 {
+      unsafe fn update_transposed(
+        program: &mut Program,
+        uniform: &'a Uniform<Arr<$q<$t>, N>>,
+        value: Self::Target,
+      ) {
+        let data = flatten_slice!(value: $t, len = $size * N);
+
+        program.state.borrow().ctx.$f(
+          program.location_map.borrow().get(&uniform.index()),
+          true,
+          data,
+          0,
+          value.len() as u32,
+        );
+      }
+    }
   };
 
   (mat $q:ident $t:ty, $size:expr, $uty:tt, $f:tt) => {
@@ -614,6 +647,22 @@ macro_rules! impl_Uniformable {
         );
       }
     }
+
+    unsafe impl<'a> UniformableTransposed<'a, $q<$t>> for WebGL2 {
+      unsafe fn update_transposed(
+        program: &mut Program,
+        uniform: &'a Uniform<$q<$t>>,
+        value: Self::Target,
+      ) {
+        let data = flatten_slice!(value: $t, len = $size);
+
+        program.state.borrow().ctx.$f(
+          program.location_map.borrow().get(&uniform.index()),
+          true,
+          data,
+        );
+      }
+    }
   };
 }
 
@@ -926,6 +975,18 @@ where
   const SIZE: usize = 0;
 
   unsafe fn ty() -> UniformType {
+    if S::is_shadow() {
+      return match D::dim() {
+        Dim::Dim1 => UniformType::Sampler1DShadow,
+        Dim::Dim2 => UniformType::Sampler2DShadow,
+        // there is no 3D shadow sampler
+        Dim::Dim3 => UniformType::Sampler3D,
+        Dim::Cubemap => UniformType::CubemapShadow,
+        Dim::Dim1Array => UniformType::Sampler1DArrayShadow,
+        Dim::Dim2Array => UniformType::Sampler2DArrayShadow,
+      };
+    }
+
     match (S::sample_type(), D::dim()) {
       (PixelType::NormIntegral, Dim::Dim1) => UniformType::Sampler1D,
       (PixelType::NormUnsigned, Dim::Dim1) => UniformType::Sampler1D,
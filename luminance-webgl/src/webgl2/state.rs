@@ -1,7 +1,8 @@
 //! Graphics state.
 
-use js_sys::{Float32Array, Int32Array, Uint32Array};
+use js_sys::{Array, Float32Array, Int32Array, Uint32Array};
 use luminance::{
+  backend::query::BackendLimits,
   blending::{Equation, Factor},
   depth_stencil::{Comparison, StencilOp, StencilOperations, StencilTest, Write},
   face_culling::{FaceCullingMode, FaceCullingOrder},
@@ -13,6 +14,9 @@ use web_sys::{
   WebGlVertexArrayObject,
 };
 
+// GL_DEPTH_CLAMP_EXT, from the EXT_depth_clamp extension
+const DEPTH_CLAMP_EXT: u32 = 0x864F;
+
 #[derive(Debug)]
 pub(crate) struct BindingStack {
   pub(crate) next_texture_unit: u32,
@@ -52,6 +56,9 @@ pub struct WebGL2State {
   // viewport
   viewport: [i32; 4],
 
+  // depth range
+  depth_range: [f32; 2],
+
   // clear buffers
   clear_color: [f32; 4],
   clear_depth: f32,
@@ -66,14 +73,18 @@ pub struct WebGL2State {
   depth_test_enabled: bool,
   depth_test_comparison: Comparison,
 
-  // stencil test
+  // stencil test; front-facing and back-facing primitives
   stencil_test_enabled: bool,
-  stencil_test: StencilTest,
-  stencil_operations: StencilOperations,
+  stencil_test: [StencilTest; 2],
+  stencil_operations: [StencilOperations; 2],
+  stencil_write_mask: u8,
 
   // depth write
   depth_write: Write,
 
+  // color mask
+  color_mask: [bool; 4],
+
   // face culling
   face_culling_state: FaceCullingState,
   face_culling_order: FaceCullingOrder,
@@ -83,6 +94,19 @@ pub struct WebGL2State {
   scissor_state: ScissorState,
   scissor_region: ScissorRegion,
 
+  // alpha-to-coverage
+  alpha_to_coverage_enabled: bool,
+
+  // depth clamp; only available with the EXT_depth_clamp extension, which is checked when the state is first changed
+  depth_clamp_enabled: bool,
+  depth_clamp_supported: Option<bool>,
+
+  // line width
+  line_width: f32,
+
+  // range of supported line widths; cached when asked the first time and then re-used
+  line_width_range: Option<[f32; 2]>,
+
   // texture
   current_texture_unit: u32,
   bound_textures: Vec<(u32, Option<WebGlTexture>)>,
@@ -134,6 +158,18 @@ pub struct WebGL2State {
 
   /// Maximum number of elements a texture array can hold.
   max_texture_array_elements: Option<usize>,
+
+  /// Limits of the context.
+  limits: Option<BackendLimits>,
+
+  /// Maximum timeout, in nanoseconds, to wait for a fence.
+  max_client_wait_timeout: Option<u64>,
+
+  /// Whether timer queries are supported.
+  timer_query_supported: Option<bool>,
+
+  /// Whether a timer query is active; only one can be at a time.
+  timer_query_active: bool,
 }
 
 impl WebGL2State {
@@ -152,6 +188,7 @@ impl WebGL2State {
 
     let binding_stack = BindingStack::new();
     let viewport = get_ctx_viewport(&mut ctx)?;
+    let depth_range = get_ctx_depth_range(&mut ctx)?;
     let clear_color = get_ctx_clear_color(&mut ctx)?;
     let clear_depth = get_ctx_clear_depth(&mut ctx)?;
     let clear_stencil = get_ctx_clear_stencil(&mut ctx)?;
@@ -161,14 +198,21 @@ impl WebGL2State {
     let depth_test_enabled = get_ctx_depth_test_enabled(&mut ctx);
     let depth_test_comparison = Comparison::Less;
     let depth_write = get_ctx_depth_write(&mut ctx)?;
+    let color_mask = get_ctx_color_mask(&mut ctx)?;
     let stencil_test_enabled = get_ctx_stencil_test_enabled(&mut ctx);
     let stencil_test = get_ctx_stencil_test(&mut ctx)?;
     let stencil_operations = get_ctx_stencil_operations(&mut ctx)?;
+    let stencil_write_mask = get_ctx_stencil_write_mask(&mut ctx)?;
     let face_culling_state = get_ctx_face_culling_state(&mut ctx);
     let face_culling_order = get_ctx_face_culling_order(&mut ctx)?;
     let face_culling_mode = get_ctx_face_culling_mode(&mut ctx)?;
     let scissor_state = get_ctx_scissor_state(&mut ctx)?;
     let scissor_region = get_ctx_scissor_region(&mut ctx)?;
+    let alpha_to_coverage_enabled = get_ctx_alpha_to_coverage_enabled(&mut ctx);
+    let depth_clamp_enabled = false;
+    let depth_clamp_supported = None;
+    let line_width = get_ctx_line_width(&mut ctx);
+    let line_width_range = None;
 
     let current_texture_unit = 0;
     let bound_textures = vec![(WebGl2RenderingContext::TEXTURE0, None); 48]; // 48 is the platform minimal requirement
@@ -188,12 +232,17 @@ impl WebGL2State {
     let gl_version = None;
     let glsl_version = None;
     let max_texture_array_elements = None;
+    let limits = None;
+    let max_client_wait_timeout = None;
+    let timer_query_supported = None;
+    let timer_query_active = false;
 
     Ok(WebGL2State {
       _phantom: PhantomData,
       ctx,
       binding_stack,
       viewport,
+      depth_range,
       clear_color,
       clear_depth,
       clear_stencil,
@@ -203,14 +252,21 @@ impl WebGL2State {
       depth_test_enabled,
       depth_test_comparison,
       depth_write,
+      color_mask,
       stencil_test_enabled,
       stencil_test,
       stencil_operations,
+      stencil_write_mask,
       face_culling_state,
       face_culling_order,
       face_culling_mode,
       scissor_state,
       scissor_region,
+      alpha_to_coverage_enabled,
+      depth_clamp_enabled,
+      depth_clamp_supported,
+      line_width,
+      line_width_range,
       current_texture_unit,
       bound_textures,
       texture_swimming_pool,
@@ -228,6 +284,10 @@ impl WebGL2State {
       webgl_version: gl_version,
       glsl_version,
       max_texture_array_elements,
+      limits,
+      max_client_wait_timeout,
+      timer_query_supported,
+      timer_query_active,
     })
   }
 
@@ -427,6 +487,13 @@ impl WebGL2State {
     }
   }
 
+  pub(crate) fn set_depth_range(&mut self, depth_range: [f32; 2]) {
+    if self.depth_range != depth_range {
+      self.ctx.depth_range(depth_range[0], depth_range[1]);
+      self.depth_range = depth_range;
+    }
+  }
+
   pub(crate) fn set_clear_color(&mut self, clear_color: [f32; 4]) {
     if self.clear_color != clear_color {
       self.ctx.clear_color(
@@ -575,6 +642,15 @@ impl WebGL2State {
     }
   }
 
+  pub(crate) fn set_color_mask(&mut self, color_mask: [bool; 4]) {
+    if self.color_mask != color_mask {
+      let [r, g, b, a] = color_mask;
+      self.ctx.color_mask(r, g, b, a);
+
+      self.color_mask = color_mask;
+    }
+  }
+
   pub(crate) fn enable_stencil_test(&mut self, enabled: bool) {
     if self.stencil_test_enabled != enabled {
       if enabled {
@@ -587,27 +663,75 @@ impl WebGL2State {
     }
   }
 
-  pub(crate) fn set_stencil_test(&mut self, stencil_test: StencilTest) {
+  /// Set the stencil test of front-facing and back-facing primitives.
+  pub(crate) fn set_stencil_test(&mut self, front: StencilTest, back: StencilTest) {
+    let stencil_test = [front, back];
+
     if self.stencil_test != stencil_test {
-      self.ctx.stencil_func(
-        comparison_to_glenum(stencil_test.comparison),
-        stencil_test.reference as _,
-        stencil_test.mask as _,
-      );
+      if front == back {
+        self.ctx.stencil_func(
+          comparison_to_glenum(front.comparison),
+          front.reference as _,
+          front.mask as _,
+        );
+      } else {
+        for (face, test) in [
+          (WebGl2RenderingContext::FRONT, front),
+          (WebGl2RenderingContext::BACK, back),
+        ] {
+          self.ctx.stencil_func_separate(
+            face,
+            comparison_to_glenum(test.comparison),
+            test.reference as _,
+            test.mask as _,
+          );
+        }
+      }
+
+      self.stencil_test = stencil_test;
     }
   }
 
-  pub(crate) fn set_stencil_operations(&mut self, ops: StencilOperations) {
+  /// Set the stencil operations of front-facing and back-facing primitives.
+  pub(crate) fn set_stencil_operations(
+    &mut self,
+    front: StencilOperations,
+    back: StencilOperations,
+  ) {
+    let ops = [front, back];
+
     if self.stencil_operations != ops {
-      self.ctx.stencil_op(
-        stencil_op_to_glenum(ops.depth_passes_stencil_fails),
-        stencil_op_to_glenum(ops.depth_fails_stencil_passes),
-        stencil_op_to_glenum(ops.depth_stencil_pass),
-      );
+      if front == back {
+        self.ctx.stencil_op(
+          stencil_op_to_glenum(front.depth_passes_stencil_fails),
+          stencil_op_to_glenum(front.depth_fails_stencil_passes),
+          stencil_op_to_glenum(front.depth_stencil_pass),
+        );
+      } else {
+        for (face, ops) in [
+          (WebGl2RenderingContext::FRONT, front),
+          (WebGl2RenderingContext::BACK, back),
+        ] {
+          self.ctx.stencil_op_separate(
+            face,
+            stencil_op_to_glenum(ops.depth_passes_stencil_fails),
+            stencil_op_to_glenum(ops.depth_fails_stencil_passes),
+            stencil_op_to_glenum(ops.depth_stencil_pass),
+          );
+        }
+      }
+
       self.stencil_operations = ops;
     }
   }
 
+  pub(crate) fn set_stencil_write_mask(&mut self, mask: u8) {
+    if self.stencil_write_mask != mask {
+      self.ctx.stencil_mask(mask as _);
+      self.stencil_write_mask = mask;
+    }
+  }
+
   pub(crate) fn set_face_culling_state(&mut self, state: FaceCullingState) {
     if self.face_culling_state != state {
       match state {
@@ -662,13 +786,85 @@ impl WebGL2State {
         height,
       } = *region;
 
+      // regions larger than what WebGL can represent are saturated instead of wrapping to negative values
+      let clamp = |v: u32| v.min(i32::MAX as u32) as i32;
       self
         .ctx
-        .scissor(x as i32, y as i32, width as i32, height as i32);
+        .scissor(clamp(x), clamp(y), clamp(width), clamp(height));
       self.scissor_region = *region;
     }
   }
 
+  pub(crate) fn enable_alpha_to_coverage(&mut self, alpha_to_coverage_enabled: bool) {
+    if self.alpha_to_coverage_enabled != alpha_to_coverage_enabled {
+      if alpha_to_coverage_enabled {
+        self
+          .ctx
+          .enable(WebGl2RenderingContext::SAMPLE_ALPHA_TO_COVERAGE);
+      } else {
+        self
+          .ctx
+          .disable(WebGl2RenderingContext::SAMPLE_ALPHA_TO_COVERAGE);
+      }
+
+      self.alpha_to_coverage_enabled = alpha_to_coverage_enabled;
+    }
+  }
+
+  /// Enable or disable depth clamping; ignored if the `EXT_depth_clamp` extension is not available.
+  pub(crate) fn enable_depth_clamp(&mut self, depth_clamp_enabled: bool) {
+    if self.depth_clamp_enabled != depth_clamp_enabled && self.is_depth_clamp_supported() {
+      if depth_clamp_enabled {
+        self.ctx.enable(DEPTH_CLAMP_EXT);
+      } else {
+        self.ctx.disable(DEPTH_CLAMP_EXT);
+      }
+
+      self.depth_clamp_enabled = depth_clamp_enabled;
+    }
+  }
+
+  /// Check whether depth clamping is supported, which requires the `EXT_depth_clamp` extension.
+  ///
+  /// Cache the result on the first call and then re-use it for later calls.
+  fn is_depth_clamp_supported(&mut self) -> bool {
+    let ctx = &self.ctx;
+
+    *self.depth_clamp_supported.get_or_insert_with(|| {
+      ctx
+        .get_extension("EXT_depth_clamp")
+        .ok()
+        .flatten()
+        .is_some()
+    })
+  }
+
+  /// Set the line width, clamped to the range of widths supported by the implementation.
+  pub(crate) fn set_line_width(&mut self, width: f32) {
+    let [min, max] = self.line_width_range();
+    let width = width.clamp(min, max);
+
+    if self.line_width != width {
+      self.ctx.line_width(width);
+      self.line_width = width;
+    }
+  }
+
+  fn line_width_range(&mut self) -> [f32; 2] {
+    if let Some(range) = self.line_width_range {
+      return range;
+    }
+
+    let range = self
+      .ctx
+      .get_webgl_param(WebGl2RenderingContext::ALIASED_LINE_WIDTH_RANGE)
+      .map(|array: Float32Array| [array.get_index(0), array.get_index(1)])
+      .unwrap_or([1., 1.]);
+    self.line_width_range = Some(range);
+
+    range
+  }
+
   pub(crate) fn get_vendor_name(&mut self) -> Option<String> {
     self.vendor_name.as_ref().cloned().or_else(|| {
       let name = self.ctx.get_webgl_param(WebGl2RenderingContext::VENDOR)?;
@@ -715,6 +911,73 @@ impl WebGL2State {
       max
     })
   }
+
+  /// Get the maximum timeout, in nanoseconds, to wait for a fence.
+  ///
+  /// Cache the number on the first call and then re-use it for later calls.
+  pub(crate) fn get_max_client_wait_timeout(&mut self) -> u64 {
+    self.max_client_wait_timeout.unwrap_or_else(|| {
+      let max = self
+        .ctx
+        .get_parameter(WebGl2RenderingContext::MAX_CLIENT_WAIT_TIMEOUT_WEBGL)
+        .ok()
+        .and_then(|x| x.as_f64())
+        .map_or(0, |x| x as u64);
+      self.max_client_wait_timeout = Some(max);
+      max
+    })
+  }
+
+  /// Check whether timer queries are supported, which requires the `EXT_disjoint_timer_query_webgl2` extension.
+  ///
+  /// Cache the result on the first call and then re-use it for later calls.
+  pub(crate) fn is_timer_query_supported(&mut self) -> bool {
+    let ctx = &self.ctx;
+
+    *self.timer_query_supported.get_or_insert_with(|| {
+      ctx
+        .get_extension("EXT_disjoint_timer_query_webgl2")
+        .ok()
+        .flatten()
+        .is_some()
+    })
+  }
+
+  pub(crate) fn is_timer_query_active(&self) -> bool {
+    self.timer_query_active
+  }
+
+  pub(crate) fn set_timer_query_active(&mut self, active: bool) {
+    self.timer_query_active = active;
+  }
+
+  /// Get the limits of the context.
+  ///
+  /// Cache the limits on the first call and then re-use them for later calls.
+  pub fn get_limits(&mut self) -> Option<BackendLimits> {
+    if self.limits.is_none() {
+      self.limits = Some(BackendLimits::new(
+        self
+          .ctx
+          .get_webgl_param(WebGl2RenderingContext::MAX_TEXTURE_SIZE)?,
+        self.get_max_texture_array_elements()?,
+        self
+          .ctx
+          .get_webgl_param(WebGl2RenderingContext::MAX_COLOR_ATTACHMENTS)?,
+        self
+          .ctx
+          .get_webgl_param(WebGl2RenderingContext::MAX_VERTEX_ATTRIBS)?,
+        self
+          .ctx
+          .get_webgl_param(WebGl2RenderingContext::MAX_UNIFORM_BLOCK_SIZE)?,
+        self
+          .ctx
+          .get_webgl_param(WebGl2RenderingContext::MAX_SAMPLES)?,
+      ));
+    }
+
+    self.limits
+  }
 }
 
 impl Drop for WebGL2State {
@@ -741,6 +1004,8 @@ pub enum StateQueryError {
   UnknownArrayBufferInitialState,
   /// Unknown viewport initial state.
   UnknownViewportInitialState,
+  /// Unknown depth range initial state.
+  UnknownDepthRangeInitialState,
   /// Unknown clear color initial state.
   UnknownClearColorInitialState,
   /// Unknown clear depth initial state.
@@ -757,6 +1022,8 @@ pub enum StateQueryError {
   UnknownStencilOpState,
   /// Unknown depth write mask initial state.
   UnknownDepthWriteMaskState,
+  /// Unknown color write mask initial state.
+  UnknownColorWriteMaskState,
   /// Corrupted blending equation.
   UnknownBlendingEquation(u32),
   /// RGB blending equation couldn’t be retrieved when initializing the WebGL2 state.
@@ -800,6 +1067,10 @@ impl fmt::Display for StateQueryError {
 
       StateQueryError::UnknownViewportInitialState => write!(f, "unknown viewport initial state"),
 
+      StateQueryError::UnknownDepthRangeInitialState => {
+        write!(f, "unknown depth range initial state")
+      }
+
       StateQueryError::UnknownClearColorInitialState => {
         write!(f, "unknown clear color initial state")
       }
@@ -828,6 +1099,8 @@ impl fmt::Display for StateQueryError {
 
       StateQueryError::UnknownDepthWriteMaskState => f.write_str("unknown depth write mask state"),
 
+      StateQueryError::UnknownColorWriteMaskState => f.write_str("unknown color write mask state"),
+
       StateQueryError::UnknownBlendingEquation(ref e) => {
         write!(f, "unknown blending equation: {}", e)
       }
@@ -906,6 +1179,21 @@ fn get_ctx_viewport(ctx: &mut WebGl2RenderingContext) -> Result<[i32; 4], StateQ
   Ok(viewport)
 }
 
+fn get_ctx_depth_range(ctx: &mut WebGl2RenderingContext) -> Result<[f32; 2], StateQueryError> {
+  let array: Float32Array = ctx
+    .get_webgl_param(WebGl2RenderingContext::DEPTH_RANGE)
+    .ok_or(StateQueryError::UnknownDepthRangeInitialState)?;
+
+  if array.length() != 2 {
+    return Err(StateQueryError::UnknownDepthRangeInitialState);
+  }
+
+  let mut depth_range = [0.0; 2];
+  array.copy_to(&mut depth_range); // safe thanks to the test above on array.length() above
+
+  Ok(depth_range)
+}
+
 fn get_ctx_clear_color(ctx: &mut WebGl2RenderingContext) -> Result<[f32; 4], StateQueryError> {
   let array: Float32Array = ctx
     .get_webgl_param(WebGl2RenderingContext::COLOR_CLEAR_VALUE)
@@ -1033,16 +1321,40 @@ fn get_ctx_stencil_test_enabled(ctx: &mut WebGl2RenderingContext) -> bool {
   ctx.is_enabled(WebGl2RenderingContext::STENCIL_TEST)
 }
 
-fn get_ctx_stencil_test(ctx: &mut WebGl2RenderingContext) -> Result<StencilTest, StateQueryError> {
+fn get_ctx_stencil_test(
+  ctx: &mut WebGl2RenderingContext,
+) -> Result<[StencilTest; 2], StateQueryError> {
+  let front = get_ctx_face_stencil_test(
+    ctx,
+    WebGl2RenderingContext::STENCIL_FUNC,
+    WebGl2RenderingContext::STENCIL_REF,
+    WebGl2RenderingContext::STENCIL_VALUE_MASK,
+  )?;
+  let back = get_ctx_face_stencil_test(
+    ctx,
+    WebGl2RenderingContext::STENCIL_BACK_FUNC,
+    WebGl2RenderingContext::STENCIL_BACK_REF,
+    WebGl2RenderingContext::STENCIL_BACK_VALUE_MASK,
+  )?;
+
+  Ok([front, back])
+}
+
+fn get_ctx_face_stencil_test(
+  ctx: &mut WebGl2RenderingContext,
+  func: u32,
+  reference: u32,
+  mask: u32,
+) -> Result<StencilTest, StateQueryError> {
   let comparison = ctx
-    .get_webgl_param(WebGl2RenderingContext::STENCIL_FUNC)
+    .get_webgl_param(func)
     .and_then(glenum_to_comparison)
     .ok_or_else(|| StateQueryError::UnknownStencilComparisonInitialState)?;
   let reference = ctx
-    .get_webgl_param(WebGl2RenderingContext::STENCIL_REF)
+    .get_webgl_param(reference)
     .ok_or_else(|| StateQueryError::UnknownStencilReferenceState)?;
   let mask = ctx
-    .get_webgl_param(WebGl2RenderingContext::STENCIL_VALUE_MASK)
+    .get_webgl_param(mask)
     .ok_or_else(|| StateQueryError::UnknownStencilMaskState)?;
 
   Ok(StencilTest::new(comparison, reference, mask))
@@ -1050,17 +1362,39 @@ fn get_ctx_stencil_test(ctx: &mut WebGl2RenderingContext) -> Result<StencilTest,
 
 fn get_ctx_stencil_operations(
   ctx: &mut WebGl2RenderingContext,
+) -> Result<[StencilOperations; 2], StateQueryError> {
+  let front = get_ctx_face_stencil_operations(
+    ctx,
+    WebGl2RenderingContext::STENCIL_FAIL,
+    WebGl2RenderingContext::STENCIL_PASS_DEPTH_FAIL,
+    WebGl2RenderingContext::STENCIL_PASS_DEPTH_PASS,
+  )?;
+  let back = get_ctx_face_stencil_operations(
+    ctx,
+    WebGl2RenderingContext::STENCIL_BACK_FAIL,
+    WebGl2RenderingContext::STENCIL_BACK_PASS_DEPTH_FAIL,
+    WebGl2RenderingContext::STENCIL_BACK_PASS_DEPTH_PASS,
+  )?;
+
+  Ok([front, back])
+}
+
+fn get_ctx_face_stencil_operations(
+  ctx: &mut WebGl2RenderingContext,
+  fail: u32,
+  pass_depth_fail: u32,
+  pass_depth_pass: u32,
 ) -> Result<StencilOperations, StateQueryError> {
   let depth_passes_stencil_fails = ctx
-    .get_webgl_param(WebGl2RenderingContext::STENCIL_FAIL)
+    .get_webgl_param(fail)
     .and_then(glenum_to_stencil_op)
     .ok_or_else(|| StateQueryError::UnknownStencilOpState)?;
   let depth_fails_stencil_passes = ctx
-    .get_webgl_param(WebGl2RenderingContext::STENCIL_PASS_DEPTH_FAIL)
+    .get_webgl_param(pass_depth_fail)
     .and_then(glenum_to_stencil_op)
     .ok_or_else(|| StateQueryError::UnknownStencilOpState)?;
   let depth_stencil_pass = ctx
-    .get_webgl_param(WebGl2RenderingContext::STENCIL_PASS_DEPTH_PASS)
+    .get_webgl_param(pass_depth_pass)
     .and_then(glenum_to_stencil_op)
     .ok_or_else(|| StateQueryError::UnknownStencilOpState)?;
 
@@ -1071,6 +1405,12 @@ fn get_ctx_stencil_operations(
   })
 }
 
+fn get_ctx_stencil_write_mask(ctx: &mut WebGl2RenderingContext) -> Result<u8, StateQueryError> {
+  ctx
+    .get_webgl_param(WebGl2RenderingContext::STENCIL_WRITEMASK)
+    .ok_or(StateQueryError::UnknownStencilMaskState)
+}
+
 pub(crate) fn comparison_to_glenum(comparison: Comparison) -> u32 {
   match comparison {
     Comparison::Never => WebGl2RenderingContext::NEVER,
@@ -1137,6 +1477,26 @@ fn get_ctx_depth_write(ctx: &mut WebGl2RenderingContext) -> Result<Write, StateQ
   }
 }
 
+fn get_ctx_color_mask(ctx: &mut WebGl2RenderingContext) -> Result<[bool; 4], StateQueryError> {
+  let array: Array = ctx
+    .get_webgl_param(WebGl2RenderingContext::COLOR_WRITEMASK)
+    .ok_or(StateQueryError::UnknownColorWriteMaskState)?;
+
+  if array.length() != 4 {
+    return Err(StateQueryError::UnknownColorWriteMaskState);
+  }
+
+  let mut color_mask = [false; 4];
+  for (i, enabled) in color_mask.iter_mut().enumerate() {
+    *enabled = array
+      .get(i as u32)
+      .as_bool()
+      .ok_or(StateQueryError::UnknownColorWriteMaskState)?;
+  }
+
+  Ok(color_mask)
+}
+
 fn get_ctx_face_culling_state(ctx: &mut WebGl2RenderingContext) -> FaceCullingState {
   let enabled = ctx.is_enabled(WebGl2RenderingContext::CULL_FACE);
 
@@ -1188,6 +1548,16 @@ fn get_ctx_scissor_state(
   Ok(state)
 }
 
+fn get_ctx_alpha_to_coverage_enabled(ctx: &mut WebGl2RenderingContext) -> bool {
+  ctx.is_enabled(WebGl2RenderingContext::SAMPLE_ALPHA_TO_COVERAGE)
+}
+
+fn get_ctx_line_width(ctx: &mut WebGl2RenderingContext) -> f32 {
+  ctx
+    .get_webgl_param(WebGl2RenderingContext::LINE_WIDTH)
+    .unwrap_or(1.)
+}
+
 fn get_ctx_scissor_region(
   ctx: &mut WebGl2RenderingContext,
 ) -> Result<ScissorRegion, StateQueryError> {
@@ -1339,7 +1709,7 @@ macro_rules! impl_GetWebGLParam_array {
   }
 }
 
-impl_GetWebGLParam_array!(Int32Array, Uint32Array, Float32Array);
+impl_GetWebGLParam_array!(Array, Int32Array, Uint32Array, Float32Array);
 
 impl GetWebGLParam<bool> for WebGl2RenderingContext {
   fn get_webgl_param(&mut self, param: u32) -> Option<bool> {
@@ -1,16 +1,17 @@
 //! WebGL2 tessellation implementation.
 
 use luminance::backend::tess::{
-  IndexSlice as IndexSliceBackend, InstanceSlice as InstanceSliceBackend, Tess as TessBackend,
-  VertexSlice as VertexSliceBackend,
+  IndexSlice as IndexSliceBackend, InstanceSlice as InstanceSliceBackend,
+  RawTess as RawTessBackend, Tess as TessBackend, VertexSlice as VertexSliceBackend,
+  VertexSliceSet as VertexSliceSetBackend, VertexUpdate as VertexUpdateBackend,
 };
 use luminance::tess::{
   Deinterleaved, DeinterleavedData, Interleaved, Mode, TessError, TessIndex, TessIndexType,
   TessMapError, TessVertexData,
 };
 use luminance::vertex::{
-  Deinterleave, Normalized, Vertex, VertexAttribDesc, VertexAttribDim, VertexAttribType,
-  VertexBufferDesc, VertexInstancing,
+  interleaved_layout, split_buffer_groups, Deinterleave, Normalized, Vertex, VertexAttribDesc,
+  VertexAttribDim, VertexAttribType, VertexBufferDesc, VertexInstancing,
 };
 use std::cell::RefCell;
 use std::marker::PhantomData;
@@ -42,11 +43,32 @@ where
     &self,
     start_index: usize,
     vert_nb: usize,
+    base_vertex: i32,
     inst_nb: usize,
+    inst_start_index: usize,
+    mode: Option<Mode>,
   ) -> Result<(), TessError> {
+    // WebGL2 cannot render from a base instance
+    if inst_start_index != 0 {
+      return Err(TessError::unsupported_base_instance());
+    }
+
+    let indexed = I::INDEX_TYPE.is_some() && self.index_buffer.is_some();
+
+    // WebGL2 cannot render with a base vertex either
+    if base_vertex != 0 && indexed {
+      return Err(TessError::unsupported_base_vertex());
+    }
+
     let vert_nb = vert_nb as _;
     let inst_nb = inst_nb as _;
 
+    // an overridden mode takes precedence over the mode the tessellation was built with
+    let mode = match mode {
+      Some(mode) => webgl_mode(mode).ok_or(TessError::ForbiddenPrimitiveMode(mode))?,
+      None => self.mode,
+    };
+
     let mut gfx_st = self.state.borrow_mut();
     gfx_st.bind_vertex_array(Some(&self.vao), Bind::Cached);
 
@@ -56,15 +78,12 @@ where
         let first = (index_ty.bytes() * start_index) as _;
 
         if inst_nb <= 1 {
-          gfx_st.ctx.draw_elements_with_i32(
-            self.mode,
-            vert_nb,
-            index_type_to_glenum(index_ty),
-            first,
-          );
+          gfx_st
+            .ctx
+            .draw_elements_with_i32(mode, vert_nb, index_type_to_glenum(index_ty), first);
         } else {
           gfx_st.ctx.draw_elements_instanced_with_i32(
-            self.mode,
+            mode,
             vert_nb,
             index_type_to_glenum(index_ty),
             first,
@@ -78,11 +97,11 @@ where
         let first = start_index as _;
 
         if inst_nb <= 1 {
-          gfx_st.ctx.draw_arrays(self.mode, first, vert_nb);
+          gfx_st.ctx.draw_arrays(mode, first, vert_nb);
         } else {
           gfx_st
             .ctx
-            .draw_arrays_instanced(self.mode, first, vert_nb, inst_nb);
+            .draw_arrays_instanced(mode, first, vert_nb, inst_nb);
         }
       }
     }
@@ -102,6 +121,30 @@ where
   }
 }
 
+/// Interleaved vertex storage.
+///
+/// Vertices are stored in a single buffer, unless their attributes are split into several buffer groups, in which case
+/// each group gets its own buffer.
+#[derive(Debug)]
+enum VertexBuffer<V> {
+  Single(Buffer<V, { WebGl2RenderingContext::ARRAY_BUFFER }>),
+  Grouped {
+    // only held to keep the buffers alive while the VAO references them
+    #[allow(dead_code)]
+    buffers: Vec<Buffer<u8, { WebGl2RenderingContext::ARRAY_BUFFER }>>,
+    len: usize,
+  },
+}
+
+impl<V> VertexBuffer<V> {
+  fn len(&self) -> usize {
+    match self {
+      VertexBuffer::Single(vb) => vb.buf.len(),
+      VertexBuffer::Grouped { len, .. } => *len,
+    }
+  }
+}
+
 #[derive(Debug)]
 pub struct InterleavedTess<V, I, W>
 where
@@ -110,8 +153,8 @@ where
   W: Vertex,
 {
   raw: TessRaw<I>,
-  vertex_buffer: Option<Buffer<V, { WebGl2RenderingContext::ARRAY_BUFFER }>>,
-  instance_buffer: Option<Buffer<W, { WebGl2RenderingContext::ARRAY_BUFFER }>>,
+  vertex_buffer: Option<VertexBuffer<V>>,
+  instance_buffer: Option<VertexBuffer<W>>,
 }
 
 unsafe impl<V, I, W> TessBackend<V, I, W, Interleaved> for WebGL2
@@ -163,11 +206,16 @@ where
     })
   }
 
+  fn max_patch_vertex_nb(&self) -> usize {
+    // WebGL2 doesn’t support tessellation shaders
+    0
+  }
+
   unsafe fn tess_vertices_nb(tess: &Self::TessRepr) -> usize {
     tess
       .vertex_buffer
       .as_ref()
-      .map(|vb| vb.buf.len())
+      .map(VertexBuffer::len)
       .unwrap_or(0)
   }
 
@@ -184,7 +232,7 @@ where
     tess
       .instance_buffer
       .as_ref()
-      .map(|ib| ib.buf.len())
+      .map(VertexBuffer::len)
       .unwrap_or(0)
   }
 
@@ -192,9 +240,19 @@ where
     tess: &Self::TessRepr,
     start_index: usize,
     vert_nb: usize,
+    base_vertex: i32,
     inst_nb: usize,
+    inst_start_index: usize,
+    mode: Option<Mode>,
   ) -> Result<(), TessError> {
-    tess.raw.render(start_index, vert_nb, inst_nb)
+    tess.raw.render(
+      start_index,
+      vert_nb,
+      base_vertex,
+      inst_nb,
+      inst_start_index,
+      mode,
+    )
   }
 }
 
@@ -209,7 +267,8 @@ where
 
   unsafe fn vertices(tess: &'a mut Self::TessRepr) -> Result<Self::VertexSliceRepr, TessMapError> {
     match tess.vertex_buffer {
-      Some(ref vb) => Ok(vb.slice_buffer()),
+      Some(VertexBuffer::Single(ref vb)) => Ok(vb.slice_buffer()),
+      Some(VertexBuffer::Grouped { .. }) => Err(TessMapError::forbidden_deinterleaved_mapping()),
       None => Err(TessMapError::forbidden_attributeless_mapping()),
     }
   }
@@ -218,7 +277,29 @@ where
     tess: &'a mut Self::TessRepr,
   ) -> Result<Self::VertexSliceMutRepr, TessMapError> {
     match tess.vertex_buffer {
-      Some(ref mut vb) => Ok(vb.slice_buffer_mut()),
+      Some(VertexBuffer::Single(ref mut vb)) => Ok(vb.slice_buffer_mut()),
+      Some(VertexBuffer::Grouped { .. }) => Err(TessMapError::forbidden_deinterleaved_mapping()),
+      None => Err(TessMapError::forbidden_attributeless_mapping()),
+    }
+  }
+}
+
+unsafe impl<V, I, W> VertexUpdateBackend<V, I, W> for WebGL2
+where
+  V: TessVertexData<Interleaved, Data = Vec<V>>,
+  I: TessIndex,
+  W: TessVertexData<Interleaved, Data = Vec<W>>,
+{
+  unsafe fn update_vertices(
+    tess: &mut Self::TessRepr,
+    start: usize,
+    data: &[V],
+  ) -> Result<(), TessMapError> {
+    match tess.vertex_buffer {
+      Some(VertexBuffer::Single(ref mut vb)) => vb
+        .update_range(start, data)
+        .map_err(|_| TessMapError::cannot_map()),
+      Some(VertexBuffer::Grouped { .. }) => Err(TessMapError::forbidden_deinterleaved_mapping()),
       None => Err(TessMapError::forbidden_attributeless_mapping()),
     }
   }
@@ -263,7 +344,8 @@ where
     tess: &'a mut Self::TessRepr,
   ) -> Result<Self::InstanceSliceRepr, TessMapError> {
     match tess.instance_buffer {
-      Some(ref vb) => Ok(vb.slice_buffer()),
+      Some(VertexBuffer::Single(ref vb)) => Ok(vb.slice_buffer()),
+      Some(VertexBuffer::Grouped { .. }) => Err(TessMapError::forbidden_deinterleaved_mapping()),
       None => Err(TessMapError::forbidden_attributeless_mapping()),
     }
   }
@@ -272,7 +354,8 @@ where
     tess: &'a mut Self::TessRepr,
   ) -> Result<Self::InstanceSliceMutRepr, TessMapError> {
     match tess.instance_buffer {
-      Some(ref mut vb) => Ok(vb.slice_buffer_mut()),
+      Some(VertexBuffer::Single(ref mut vb)) => Ok(vb.slice_buffer_mut()),
+      Some(VertexBuffer::Grouped { .. }) => Err(TessMapError::forbidden_deinterleaved_mapping()),
       None => Err(TessMapError::forbidden_attributeless_mapping()),
     }
   }
@@ -341,6 +424,11 @@ where
     })
   }
 
+  fn max_patch_vertex_nb(&self) -> usize {
+    // WebGL2 doesn’t support tessellation shaders
+    0
+  }
+
   unsafe fn tess_vertices_nb(tess: &Self::TessRepr) -> usize {
     tess
       .vertex_buffers
@@ -370,9 +458,19 @@ where
     tess: &Self::TessRepr,
     start_index: usize,
     vert_nb: usize,
+    base_vertex: i32,
     inst_nb: usize,
+    inst_start_index: usize,
+    mode: Option<Mode>,
   ) -> Result<(), TessError> {
-    tess.raw.render(start_index, vert_nb, inst_nb)
+    tess.raw.render(
+      start_index,
+      vert_nb,
+      base_vertex,
+      inst_nb,
+      inst_start_index,
+      mode,
+    )
   }
 }
 
@@ -409,6 +507,34 @@ where
   }
 }
 
+unsafe impl<'a, V, I, W> VertexSliceSetBackend<'a, V, I, W> for WebGL2
+where
+  V: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+  I: TessIndex,
+  W: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+{
+  type VertexSliceSetRepr = Vec<BufferSlice<'a, u8>>;
+
+  unsafe fn vertices_all(
+    tess: &'a mut Self::TessRepr,
+  ) -> Result<Self::VertexSliceSetRepr, TessMapError> {
+    if tess.vertex_buffers.is_empty() {
+      Err(TessMapError::forbidden_attributeless_mapping())
+    } else {
+      let slices = tess
+        .vertex_buffers
+        .iter()
+        .map(Buffer::slice_buffer)
+        .collect();
+      Ok(slices)
+    }
+  }
+
+  unsafe fn attribute_bytes(slices: &Self::VertexSliceSetRepr, rank: usize) -> &[u8] {
+    &slices[rank]
+  }
+}
+
 unsafe impl<'a, V, I, W> IndexSliceBackend<'a, V, I, W, Deinterleaved> for WebGL2
 where
   V: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
@@ -470,10 +596,166 @@ where
   }
 }
 
+/// Tessellation built from raw bytes.
+#[derive(Debug)]
+pub struct RawTess {
+  vao: WebGlVertexArrayObject,
+  mode: u32,
+  // only held to keep the buffer alive while the VAO references it
+  #[allow(dead_code)]
+  vertex_buffer: Buffer<u8, { WebGl2RenderingContext::ARRAY_BUFFER }>,
+  vert_nb: usize,
+  index_buffer: Option<(
+    Buffer<u8, { WebGl2RenderingContext::ELEMENT_ARRAY_BUFFER }>,
+    TessIndexType,
+  )>,
+  state: Rc<RefCell<WebGL2State>>,
+}
+
+impl Drop for RawTess {
+  fn drop(&mut self) {
+    let mut state = self.state.borrow_mut();
+    state.bind_vertex_array(None, Bind::Cached);
+    state.ctx.delete_vertex_array(Some(&self.vao));
+  }
+}
+
+unsafe impl RawTessBackend for WebGL2 {
+  type RawTessRepr = RawTess;
+
+  unsafe fn new_raw_tess(
+    &mut self,
+    vertices: &[u8],
+    desc: &[VertexBufferDesc],
+    indices: Option<(&[u8], TessIndexType)>,
+    mode: Mode,
+  ) -> Result<Self::RawTessRepr, TessError> {
+    let stride = interleaved_layout(desc).1;
+
+    if vertices.len().checked_rem(stride) != Some(0) {
+      return Err(TessError::length_incoherency(vertices.len()));
+    }
+
+    let mode = webgl_mode(mode).ok_or(TessError::ForbiddenPrimitiveMode(mode))?;
+
+    let vao = self
+      .state
+      .borrow_mut()
+      .create_vertex_array()
+      .ok_or_else(|| TessError::cannot_create("the backend failed to create the VAO"))?;
+
+    // force binding the vertex array so that previously bound vertex arrays (possibly the same
+    // handle) don’t prevent us from binding here
+    self
+      .state
+      .borrow_mut()
+      .bind_vertex_array(Some(&vao), Bind::Forced);
+
+    let vertex_buffer = Buffer::from_vec(self, vertices.to_vec())?;
+
+    // force binding as it’s meaningful when a vao is bound
+    self
+      .state
+      .borrow_mut()
+      .bind_array_buffer(Some(vertex_buffer.handle()), Bind::Forced);
+    set_vertex_pointers(&mut self.state.borrow_mut().ctx, desc);
+
+    // in case of indexed render, create an index buffer
+    let index_buffer = match indices {
+      Some((bytes, index_ty)) if !bytes.is_empty() => {
+        let ib = Buffer::from_vec(self, bytes.to_vec())?;
+
+        // force binding as it’s meaningful when a vao is bound
+        self
+          .state
+          .borrow_mut()
+          .bind_element_array_buffer(Some(ib.handle()), Bind::Forced);
+
+        Some((ib, index_ty))
+      }
+
+      _ => None,
+    };
+
+    Ok(RawTess {
+      vao,
+      mode,
+      vertex_buffer,
+      vert_nb: vertices.len() / stride,
+      index_buffer,
+      state: self.state.clone(),
+    })
+  }
+
+  unsafe fn raw_tess_vertices_nb(tess: &Self::RawTessRepr) -> usize {
+    tess.vert_nb
+  }
+
+  unsafe fn raw_tess_indices_nb(tess: &Self::RawTessRepr) -> usize {
+    tess
+      .index_buffer
+      .as_ref()
+      .map(|(ib, index_ty)| ib.buf.len() / index_ty.bytes())
+      .unwrap_or(0)
+  }
+
+  unsafe fn render_raw_tess(
+    tess: &Self::RawTessRepr,
+    start_index: usize,
+    vert_nb: usize,
+    inst_nb: usize,
+  ) -> Result<(), TessError> {
+    let vert_nb = vert_nb as _;
+    let inst_nb = inst_nb as _;
+
+    let mut gfx_st = tess.state.borrow_mut();
+    gfx_st.bind_vertex_array(Some(&tess.vao), Bind::Cached);
+
+    match tess.index_buffer {
+      Some((_, index_ty)) => {
+        // indexed render
+        let first = (index_ty.bytes() * start_index) as _;
+
+        if inst_nb <= 1 {
+          gfx_st.ctx.draw_elements_with_i32(
+            tess.mode,
+            vert_nb,
+            index_type_to_glenum(index_ty),
+            first,
+          );
+        } else {
+          gfx_st.ctx.draw_elements_instanced_with_i32(
+            tess.mode,
+            vert_nb,
+            index_type_to_glenum(index_ty),
+            first,
+            inst_nb,
+          );
+        }
+      }
+
+      None => {
+        // direct render
+        let first = start_index as _;
+
+        if inst_nb <= 1 {
+          gfx_st.ctx.draw_arrays(tess.mode, first, vert_nb);
+        } else {
+          gfx_st
+            .ctx
+            .draw_arrays_instanced(tess.mode, first, vert_nb, inst_nb);
+        }
+      }
+    }
+
+    Ok(())
+  }
+}
+
 fn build_interleaved_vertex_buffer<V>(
   webgl2: &mut WebGL2,
   vertices: Option<Vec<V>>,
-) -> Result<Option<Buffer<V, { WebGl2RenderingContext::ARRAY_BUFFER }>>, TessError>
+) -> Result<Option<VertexBuffer<V>>, TessError>
 where
   V: Vertex,
 {
@@ -483,7 +765,7 @@ where
 
       let vb = if vertices.is_empty() {
         None
-      } else {
+      } else if fmt.iter().all(|desc| desc.buffer == fmt[0].buffer) {
         let vb = Buffer::from_vec(webgl2, vertices)?;
 
         // force binding as it’s meaningful when a vao is bound
@@ -493,7 +775,26 @@ where
           .bind_array_buffer(Some(vb.handle()), Bind::Forced);
         set_vertex_pointers(&mut webgl2.state.borrow_mut().ctx, &fmt);
 
-        Some(vb)
+        Some(VertexBuffer::Single(vb))
+      } else {
+        let len = vertices.len();
+        let buffers = split_buffer_groups(&vertices, &fmt)
+          .into_iter()
+          .map(|(descs, raw)| {
+            let vb = Buffer::from_vec(webgl2, raw)?;
+
+            // force binding as it’s meaningful when a vao is bound
+            webgl2
+              .state
+              .borrow_mut()
+              .bind_array_buffer(Some(vb.handle()), Bind::Forced);
+            set_vertex_pointers(&mut webgl2.state.borrow_mut().ctx, &descs);
+
+            Ok(vb)
+          })
+          .collect::<Result<Vec<_>, TessError>>()?;
+
+        Some(VertexBuffer::Grouped { buffers, len })
       };
 
       Ok(vb)
@@ -568,64 +869,23 @@ fn set_vertex_pointers(ctx: &mut WebGl2RenderingContext, descriptors: &[VertexBu
   //   - The stride: this is easily computed, since it’s the size (bytes) of a single vertex.
   //   - The offsets: each attribute has a given offset in the buffer. This is computed by
   //     accumulating the size of all previously set attributes.
-  let offsets = aligned_offsets(descriptors);
-  let vertex_weight = offset_based_vertex_weight(descriptors, &offsets);
+  let (offsets, vertex_weight) = interleaved_layout(descriptors);
 
   for (desc, off) in descriptors.iter().zip(offsets) {
     set_component_format(ctx, vertex_weight, off, desc);
   }
 }
 
-/// Compute offsets for all the vertex components according to the alignments provided.
-fn aligned_offsets(descriptor: &[VertexBufferDesc]) -> Vec<usize> {
-  let mut offsets = Vec::with_capacity(descriptor.len());
-  let mut off = 0;
-
-  // compute offsets
-  for desc in descriptor {
-    let desc = &desc.attrib_desc;
-    off = off_align(off, desc.align); // keep the current component descriptor aligned
-    offsets.push(off);
-    off += component_weight(desc); // increment the offset by the pratical size of the component
-  }
-
-  offsets
-}
-
-/// Align an offset.
-#[inline]
-fn off_align(off: usize, align: usize) -> usize {
-  let a = align - 1;
-  (off + a) & !a
-}
-
-/// Weight in bytes of a vertex component.
-fn component_weight(f: &VertexAttribDesc) -> usize {
-  dim_as_size(f.dim) as usize * f.unit_size
-}
-
+/// Number of components of a single attribute location.
 fn dim_as_size(d: VertexAttribDim) -> usize {
   match d {
     VertexAttribDim::Dim1 => 1,
-    VertexAttribDim::Dim2 => 2,
-    VertexAttribDim::Dim3 => 3,
-    VertexAttribDim::Dim4 => 4,
+    VertexAttribDim::Dim2 | VertexAttribDim::Mat2 => 2,
+    VertexAttribDim::Dim3 | VertexAttribDim::Mat3 => 3,
+    VertexAttribDim::Dim4 | VertexAttribDim::Mat4 => 4,
   }
 }
 
-/// Weight in bytes of a single vertex, taking into account padding so that the vertex stay correctly
-/// aligned.
-fn offset_based_vertex_weight(descriptors: &[VertexBufferDesc], offsets: &[usize]) -> usize {
-  if descriptors.is_empty() || offsets.is_empty() {
-    return 0;
-  }
-
-  off_align(
-    offsets[offsets.len() - 1] + component_weight(&descriptors[descriptors.len() - 1].attrib_desc),
-    descriptors[0].attrib_desc.align,
-  )
-}
-
 /// Set the vertex component OpenGL pointers regarding the index of the component and the vertex
 /// stride.
 fn set_component_format(
@@ -635,54 +895,60 @@ fn set_component_format(
   desc: &VertexBufferDesc,
 ) {
   let attrib_desc = &desc.attrib_desc;
-  let index = desc.index as u32;
-
-  match attrib_desc.ty {
-    VertexAttribType::Floating => {
-      ctx.vertex_attrib_pointer_with_i32(
-        index,
-        dim_as_size(attrib_desc.dim) as _,
-        webgl_sized_type(&attrib_desc),
-        false,
-        stride as _,
-        off as _,
-      );
-    }
+  // matrices occupy one location per column
+  let column_weight = dim_as_size(attrib_desc.dim) * attrib_desc.unit_size;
+
+  for column in 0..attrib_desc.locations() {
+    let index = (desc.index + column) as u32;
+    let off = off + column * column_weight;
+
+    match attrib_desc.ty {
+      VertexAttribType::Floating => {
+        ctx.vertex_attrib_pointer_with_i32(
+          index,
+          dim_as_size(attrib_desc.dim) as _,
+          webgl_sized_type(&attrib_desc),
+          false,
+          stride as _,
+          off as _,
+        );
+      }
 
-    VertexAttribType::Integral(Normalized::No)
-    | VertexAttribType::Unsigned(Normalized::No)
-    | VertexAttribType::Boolean => {
-      // non-normalized integrals / booleans
-      ctx.vertex_attrib_i_pointer_with_i32(
-        index,
-        dim_as_size(attrib_desc.dim) as _,
-        webgl_sized_type(&attrib_desc),
-        stride as _,
-        off as _,
-      );
-    }
+      VertexAttribType::Integral(Normalized::No)
+      | VertexAttribType::Unsigned(Normalized::No)
+      | VertexAttribType::Boolean => {
+        // non-normalized integrals / booleans
+        ctx.vertex_attrib_i_pointer_with_i32(
+          index,
+          dim_as_size(attrib_desc.dim) as _,
+          webgl_sized_type(&attrib_desc),
+          stride as _,
+          off as _,
+        );
+      }
 
-    _ => {
-      // normalized integrals
-      ctx.vertex_attrib_pointer_with_i32(
-        index,
-        dim_as_size(attrib_desc.dim) as _,
-        webgl_sized_type(&attrib_desc),
-        true,
-        stride as _,
-        off as _,
-      );
+      _ => {
+        // normalized integrals
+        ctx.vertex_attrib_pointer_with_i32(
+          index,
+          dim_as_size(attrib_desc.dim) as _,
+          webgl_sized_type(&attrib_desc),
+          true,
+          stride as _,
+          off as _,
+        );
+      }
     }
-  }
 
-  // set vertex attribute divisor based on the vertex instancing configuration
-  let divisor = match desc.instancing {
-    VertexInstancing::On => 1,
-    VertexInstancing::Off => 0,
-  };
-  ctx.vertex_attrib_divisor(index, divisor);
+    // set vertex attribute divisor based on the vertex instancing configuration
+    let divisor = match desc.instancing {
+      VertexInstancing::On => 1,
+      VertexInstancing::Off => 0,
+    };
+    ctx.vertex_attrib_divisor(index, divisor);
 
-  ctx.enable_vertex_attrib_array(index);
+    ctx.enable_vertex_attrib_array(index);
+  }
 }
 
 fn webgl_sized_type(f: &VertexAttribDesc) -> u32 {
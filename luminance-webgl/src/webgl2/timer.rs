@@ -0,0 +1,110 @@
+//! Timer query support for WebGL2.
+//!
+//! Timer queries require the `EXT_disjoint_timer_query_webgl2` extension.
+
+use crate::webgl2::{state::WebGL2State, WebGL2};
+use luminance::{backend::timer::TimerQuery as TimerQueryBackend, timer::TimerQueryError};
+use std::{cell::RefCell, rc::Rc, time::Duration};
+use web_sys::{WebGl2RenderingContext, WebGlQuery};
+
+// constants from EXT_disjoint_timer_query_webgl2, which are not exposed by web-sys
+const TIME_ELAPSED_EXT: u32 = 0x88BF;
+const GPU_DISJOINT_EXT: u32 = 0x8FBB;
+
+pub struct TimerQuery {
+  handle: WebGlQuery,
+  // whether a measurement was performed
+  measured: bool,
+  state: Rc<RefCell<WebGL2State>>,
+}
+
+impl Drop for TimerQuery {
+  fn drop(&mut self) {
+    self.state.borrow().ctx.delete_query(Some(&self.handle));
+  }
+}
+
+unsafe impl TimerQueryBackend for WebGL2 {
+  type TimerQueryRepr = TimerQuery;
+
+  unsafe fn new_timer_query(&mut self) -> Result<Self::TimerQueryRepr, TimerQueryError> {
+    let mut state = self.state.borrow_mut();
+
+    if !state.is_timer_query_supported() {
+      return Err(TimerQueryError::unsupported());
+    }
+
+    let handle = state
+      .ctx
+      .create_query()
+      .ok_or_else(TimerQueryError::cannot_create)?;
+
+    Ok(TimerQuery {
+      handle,
+      measured: false,
+      state: self.state.clone(),
+    })
+  }
+
+  unsafe fn begin_timer_query(
+    &mut self,
+    query: &mut Self::TimerQueryRepr,
+  ) -> Result<(), TimerQueryError> {
+    let mut state = self.state.borrow_mut();
+
+    if state.is_timer_query_active() {
+      return Err(TimerQueryError::already_active());
+    }
+
+    state.ctx.begin_query(TIME_ELAPSED_EXT, &query.handle);
+    state.set_timer_query_active(true);
+    query.measured = true;
+
+    Ok(())
+  }
+
+  unsafe fn end_timer_query(&mut self, _: &mut Self::TimerQueryRepr) {
+    let mut state = self.state.borrow_mut();
+
+    state.ctx.end_query(TIME_ELAPSED_EXT);
+    state.set_timer_query_active(false);
+  }
+
+  unsafe fn timer_query_elapsed(query: &Self::TimerQueryRepr) -> Option<Duration> {
+    if !query.measured {
+      return None;
+    }
+
+    let state = query.state.borrow();
+    let available = state
+      .ctx
+      .get_query_parameter(
+        &query.handle,
+        WebGl2RenderingContext::QUERY_RESULT_AVAILABLE,
+      )
+      .as_bool()
+      .unwrap_or(false);
+
+    if !available {
+      return None;
+    }
+
+    // the result is meaningless if the GPU clock got disjoint while measuring
+    let disjoint = state
+      .ctx
+      .get_parameter(GPU_DISJOINT_EXT)
+      .ok()
+      .and_then(|x| x.as_bool())
+      .unwrap_or(true);
+
+    if disjoint {
+      return None;
+    }
+
+    state
+      .ctx
+      .get_query_parameter(&query.handle, WebGl2RenderingContext::QUERY_RESULT)
+      .as_f64()
+      .map(|nanos| Duration::from_nanos(nanos as u64))
+  }
+}
@@ -121,8 +121,12 @@
 #![allow(missing_docs)]
 
 pub mod color_slot;
+pub mod compute;
+pub mod debug;
 pub mod depth_stencil_slot;
+pub mod fence;
 pub mod framebuffer;
+pub mod indirect;
 pub mod pipeline;
 pub mod query;
 pub mod render_gate;
@@ -131,3 +135,4 @@ pub mod shading_gate;
 pub mod tess;
 pub mod tess_gate;
 pub mod texture;
+pub mod timer;
@@ -65,6 +65,18 @@ where
   ) -> Result<Self::ColorTextures, FramebufferError>
   where
     C: GraphicsContext<Backend = B>;
+
+  /// Sizes of the textures of the color slot, in attachment order.
+  fn color_textures_sizes(textures: &Self::ColorTextures) -> Vec<D::Size>;
+
+  /// Attach already existing textures to the color slot.
+  ///
+  /// This function must attach all the textures, starting at `attachment_index`.
+  fn attach_color_textures(
+    textures: &Self::ColorTextures,
+    framebuffer: &mut B::FramebufferRepr,
+    attachment_index: usize,
+  ) -> Result<(), FramebufferError>;
 }
 
 impl<B, D> ColorSlot<B, D> for ()
@@ -92,6 +104,18 @@ where
   {
     Ok(())
   }
+
+  fn color_textures_sizes(_: &Self::ColorTextures) -> Vec<D::Size> {
+    Vec::new()
+  }
+
+  fn attach_color_textures(
+    _: &Self::ColorTextures,
+    _: &mut B::FramebufferRepr,
+    _: usize,
+  ) -> Result<(), FramebufferError> {
+    Ok(())
+  }
 }
 
 impl<B, D, P> ColorSlot<B, D> for P
@@ -124,6 +148,18 @@ where
 
     Ok(texture)
   }
+
+  fn color_textures_sizes(texture: &Self::ColorTextures) -> Vec<D::Size> {
+    vec![texture.size()]
+  }
+
+  fn attach_color_textures(
+    texture: &Self::ColorTextures,
+    framebuffer: &mut B::FramebufferRepr,
+    attachment_index: usize,
+  ) -> Result<(), FramebufferError> {
+    unsafe { B::attach_color_texture(framebuffer, &texture.repr, attachment_index) }
+  }
 }
 
 macro_rules! impl_color_slot_tuple {
@@ -145,6 +181,14 @@ macro_rules! impl_color_slot_tuple {
       }
 
       impl_reify_color_textures!{ $($pf),* }
+
+      #[allow(non_snake_case)]
+      fn color_textures_sizes(textures: &Self::ColorTextures) -> Vec<D::Size> {
+        let ($($pf),*) = textures;
+        vec![$($pf.size()),*]
+      }
+
+      impl_attach_color_textures!{ $($pf),* }
     }
   }
 }
@@ -197,6 +241,32 @@ macro_rules! impl_reify_color_textures {
   }
 }
 
+// A small helper macro to implement attach_color_textures in impl_color_slot_tuple!, for the same reasons as
+// impl_reify_color_textures!.
+macro_rules! impl_attach_color_textures {
+  ($pf:ident , $($pfr:ident),*) => {
+    #[allow(non_snake_case)]
+    fn attach_color_textures(
+      textures: &Self::ColorTextures,
+      framebuffer: &mut B::FramebufferRepr,
+      mut attachment_index: usize,
+    ) -> Result<(), FramebufferError> {
+      let ($pf, $($pfr),*) = textures;
+
+      // first element of the tuple
+      <$pf as ColorSlot<B, D>>::attach_color_textures($pf, framebuffer, attachment_index)?;
+
+      // rest of the tuple
+      $(
+        attachment_index += 1;
+        <$pfr as ColorSlot<B, D>>::attach_color_textures($pfr, framebuffer, attachment_index)?;
+      )*
+
+      Ok(())
+    }
+  }
+}
+
 macro_rules! impl_color_slot_tuples {
   ($first:ident , $second:ident) => {
     // stop at pairs
@@ -212,3 +282,67 @@ macro_rules! impl_color_slot_tuples {
 }
 
 impl_color_slot_tuples!(P0, P1, P2, P3, P4, P5, P6, P7, P8, P9, P10, P11);
+
+/// Color slot containing a color attachment at index `IDX`.
+///
+/// This trait allows to select, at compile-time, a single part of a color slot and its pixel type. It is implemented by
+/// single pixel types for `IDX = 0` and by tuples of pixel types for every index of the tuple.
+pub trait ColorSlotAt<const IDX: usize> {
+  /// Pixel type of the color attachment at index `IDX`.
+  type Pixel: ColorPixel + RenderablePixel;
+}
+
+impl<P> ColorSlotAt<0> for P
+where
+  P: ColorPixel + RenderablePixel,
+{
+  type Pixel = P;
+}
+
+// Implement ColorSlotAt for a tuple, for each (index, pixel type) pair.
+macro_rules! impl_color_slot_at {
+  (($($pf:ident),*) ; ) => {};
+
+  (($($pf:ident),*) ; $idx:literal => $sel:ident $(, $ridx:literal => $rsel:ident)*) => {
+    impl<$($pf),*> ColorSlotAt<$idx> for ($($pf),*)
+    where
+      $(
+        $pf: ColorPixel + RenderablePixel
+      ),*
+    {
+      type Pixel = $sel;
+    }
+
+    impl_color_slot_at!(($($pf),*) ; $($ridx => $rsel),*);
+  };
+}
+
+impl_color_slot_at!((P0, P1) ; 0 => P0, 1 => P1);
+impl_color_slot_at!((P0, P1, P2) ; 0 => P0, 1 => P1, 2 => P2);
+impl_color_slot_at!((P0, P1, P2, P3) ; 0 => P0, 1 => P1, 2 => P2, 3 => P3);
+impl_color_slot_at!((P0, P1, P2, P3, P4) ; 0 => P0, 1 => P1, 2 => P2, 3 => P3, 4 => P4);
+impl_color_slot_at!((P0, P1, P2, P3, P4, P5) ; 0 => P0, 1 => P1, 2 => P2, 3 => P3, 4 => P4, 5 => P5);
+impl_color_slot_at!(
+  (P0, P1, P2, P3, P4, P5, P6) ;
+  0 => P0, 1 => P1, 2 => P2, 3 => P3, 4 => P4, 5 => P5, 6 => P6
+);
+impl_color_slot_at!(
+  (P0, P1, P2, P3, P4, P5, P6, P7) ;
+  0 => P0, 1 => P1, 2 => P2, 3 => P3, 4 => P4, 5 => P5, 6 => P6, 7 => P7
+);
+impl_color_slot_at!(
+  (P0, P1, P2, P3, P4, P5, P6, P7, P8) ;
+  0 => P0, 1 => P1, 2 => P2, 3 => P3, 4 => P4, 5 => P5, 6 => P6, 7 => P7, 8 => P8
+);
+impl_color_slot_at!(
+  (P0, P1, P2, P3, P4, P5, P6, P7, P8, P9) ;
+  0 => P0, 1 => P1, 2 => P2, 3 => P3, 4 => P4, 5 => P5, 6 => P6, 7 => P7, 8 => P8, 9 => P9
+);
+impl_color_slot_at!(
+  (P0, P1, P2, P3, P4, P5, P6, P7, P8, P9, P10) ;
+  0 => P0, 1 => P1, 2 => P2, 3 => P3, 4 => P4, 5 => P5, 6 => P6, 7 => P7, 8 => P8, 9 => P9, 10 => P10
+);
+impl_color_slot_at!(
+  (P0, P1, P2, P3, P4, P5, P6, P7, P8, P9, P10, P11) ;
+  0 => P0, 1 => P1, 2 => P2, 3 => P3, 4 => P4, 5 => P5, 6 => P6, 7 => P7, 8 => P8, 9 => P9, 10 => P10, 11 => P11
+);
@@ -0,0 +1,74 @@
+//! Compute backend interface.
+//!
+//! This interface defines the low-level API compute shaders must implement to be usable.
+
+use crate::{
+  backend::shader::{Shader, ShaderData},
+  compute::ComputeError,
+  shader::ProgramError,
+};
+
+/// Compute backend.
+///
+/// Compute programs are shader programs made of a single compute stage, created with [`Shader::new_stage`] and
+/// [`StageType::ComputeShader`]. They use the same representation as regular shader programs, so that uniforms are
+/// handled the same way.
+///
+/// # Safety
+///
+/// Backends must only create compute stages and programs if they can dispatch them.
+///
+/// [`StageType::ComputeShader`]: crate::shader::StageType::ComputeShader
+pub unsafe trait Compute: Shader {
+  /// Create a new compute program from a compute stage.
+  ///
+  /// # Safety
+  ///
+  /// `stage` must have been created by the same backend with [`StageType::ComputeShader`].
+  ///
+  /// [`StageType::ComputeShader`]: crate::shader::StageType::ComputeShader
+  unsafe fn new_compute_program(
+    &mut self,
+    stage: &Self::StageRepr,
+  ) -> Result<Self::ProgramRepr, ProgramError>;
+
+  /// Make the compute program currently in-use, so that its uniforms can be set and it can be dispatched.
+  ///
+  /// # Safety
+  ///
+  /// `program` must have been created by the same backend with [`Compute::new_compute_program`].
+  unsafe fn apply_compute_program(&mut self, program: &Self::ProgramRepr);
+
+  /// Dispatch the in-use compute program with `groups` work groups along each dimension.
+  ///
+  /// # Safety
+  ///
+  /// A compute program must have been applied with [`Compute::apply_compute_program`].
+  unsafe fn dispatch_compute(&mut self, groups: [u32; 3]) -> Result<(), ComputeError>;
+
+  /// Make the memory written by the previously dispatched compute programs visible to subsequent commands.
+  ///
+  /// # Safety
+  ///
+  /// The backend must be in a state where it can issue commands (e.g. its context must be current).
+  unsafe fn memory_barrier(&mut self);
+}
+
+/// Storage of [`ShaderData`] for compute programs.
+///
+/// # Safety
+///
+/// The storage binding must refer to the same memory as the [`ShaderData`], so that the values written by compute
+/// programs are seen by subsequent commands using the [`ShaderData`].
+pub unsafe trait ComputeStorage<T>: Compute + ShaderData<T> {
+  /// Bind `shader_data` to the storage binding point `binding`.
+  ///
+  /// # Safety
+  ///
+  /// `shader_data` must have been created by the same backend, which must still be alive.
+  unsafe fn bind_storage(
+    &mut self,
+    shader_data: &Self::ShaderDataRepr,
+    binding: u32,
+  ) -> Result<(), ComputeError>;
+}
@@ -0,0 +1,47 @@
+//! Debug backend interface.
+//!
+//! This interface defines the low-level API backends must implement to annotate the command stream and resources
+//! for debugging tools, such as RenderDoc or Nsight.
+
+/// Debug groups.
+///
+/// Debug groups are named, nested regions of the command stream that debugging tools display to structure the
+/// commands.
+///
+/// # Safety
+///
+/// Backends not supporting debug groups must implement those methods as no-ops.
+pub unsafe trait DebugGroup {
+  /// Open a new debug group named `name`, nested in the current one, if any.
+  ///
+  /// # Safety
+  ///
+  /// The backend must be in a state where it can issue commands (e.g. its context must be current).
+  unsafe fn push_debug_group(&mut self, name: &str);
+
+  /// Close the current debug group.
+  ///
+  /// # Safety
+  ///
+  /// The backend must be in a state where it can issue commands (e.g. its context must be current).
+  unsafe fn pop_debug_group(&mut self);
+}
+
+/// Resource labels.
+///
+/// `R` is the backend representation of the resource to label.
+///
+/// # Safety
+///
+/// Backends not supporting labels must implement this trait as a no-op.
+pub unsafe trait DebugLabel<R>
+where
+  R: ?Sized,
+{
+  /// Label `resource` with `label`.
+  ///
+  /// # Safety
+  ///
+  /// `resource` must have been created by the same backend, which must still be alive.
+  unsafe fn set_label(&mut self, resource: &R, label: &str);
+}
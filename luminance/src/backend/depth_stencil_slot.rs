@@ -57,6 +57,15 @@ where
   ) -> Result<Self::DepthStencilTexture, FramebufferError>
   where
     C: GraphicsContext<Backend = B>;
+
+  /// Size of the texture of the depth/stencil slot, if any.
+  fn depth_texture_size(texture: &Self::DepthStencilTexture) -> Option<D::Size>;
+
+  /// Attach an already existing texture to the depth/stencil slot.
+  fn attach_depth_texture(
+    texture: &Self::DepthStencilTexture,
+    framebuffer: &mut B::FramebufferRepr,
+  ) -> Result<(), FramebufferError>;
 }
 
 impl<B, D> DepthStencilSlot<B, D> for ()
@@ -83,6 +92,17 @@ where
   {
     Ok(())
   }
+
+  fn depth_texture_size(_: &Self::DepthStencilTexture) -> Option<D::Size> {
+    None
+  }
+
+  fn attach_depth_texture(
+    _: &Self::DepthStencilTexture,
+    _: &mut B::FramebufferRepr,
+  ) -> Result<(), FramebufferError> {
+    Ok(())
+  }
 }
 
 impl<B, D> DepthStencilSlot<B, D> for Depth32F
@@ -112,6 +132,17 @@ where
 
     Ok(texture)
   }
+
+  fn depth_texture_size(texture: &Self::DepthStencilTexture) -> Option<D::Size> {
+    Some(texture.size())
+  }
+
+  fn attach_depth_texture(
+    texture: &Self::DepthStencilTexture,
+    framebuffer: &mut B::FramebufferRepr,
+  ) -> Result<(), FramebufferError> {
+    unsafe { B::attach_depth_texture(framebuffer, &texture.repr) }
+  }
 }
 
 impl<B, D> DepthStencilSlot<B, D> for Depth32FStencil8
@@ -141,4 +172,15 @@ where
 
     Ok(texture)
   }
+
+  fn depth_texture_size(texture: &Self::DepthStencilTexture) -> Option<D::Size> {
+    Some(texture.size())
+  }
+
+  fn attach_depth_texture(
+    texture: &Self::DepthStencilTexture,
+    framebuffer: &mut B::FramebufferRepr,
+  ) -> Result<(), FramebufferError> {
+    unsafe { B::attach_depth_texture(framebuffer, &texture.repr) }
+  }
 }
@@ -0,0 +1,37 @@
+//! Fence backend interface.
+//!
+//! This interface defines the low-level API fences must implement to be usable.
+
+use crate::fence::FenceError;
+use std::time::Duration;
+
+/// Fence backend.
+///
+/// A fence is inserted in the command stream of the backend and gets signaled once all the commands issued before it
+/// have completed on the GPU.
+///
+/// # Safety
+///
+/// Implementors must only report a fence as signaled once the GPU has actually completed the commands issued before
+/// it.
+pub unsafe trait Fence {
+  /// Backend representation of the fence.
+  type FenceRepr;
+
+  /// Insert a new fence in the command stream.
+  ///
+  /// # Safety
+  ///
+  /// The backend must be in a state where it can issue commands (e.g. its context must be current).
+  unsafe fn new_fence(&mut self) -> Result<Self::FenceRepr, FenceError>;
+
+  /// Block until the fence is signaled or `timeout` elapses.
+  ///
+  /// Commands issued before the fence must be flushed so that the fence eventually gets signaled. Return `true` if the
+  /// fence was signaled, `false` otherwise.
+  ///
+  /// # Safety
+  ///
+  /// `fence` must have been created by the same backend, which must still be alive.
+  unsafe fn wait_fence(fence: &Self::FenceRepr, timeout: Duration) -> bool;
+}
@@ -4,7 +4,9 @@
 
 use crate::{
   backend::{color_slot::ColorSlot, depth_stencil_slot::DepthStencilSlot, texture::TextureBase},
-  framebuffer::FramebufferError,
+  framebuffer::{BlitFilter, BlitMask, FramebufferError},
+  pixel::{ColorPixel, RenderablePixel},
+  scissor::ScissorRegion,
   texture::{Dim2, Dimensionable, Sampler},
 };
 
@@ -99,3 +101,97 @@ pub unsafe trait FramebufferBackBuffer: Framebuffer<Dim2> {
     size: <Dim2 as Dimensionable>::Size,
   ) -> Result<Self::FramebufferRepr, FramebufferError>;
 }
+
+/// Back buffer clearing.
+///
+/// This allows to clear the back buffer outside of any pipeline.
+///
+/// # Safety
+///
+/// Implementors must leave the back buffer bound for drawing, and must not leave any state that would alter the
+/// behavior of the next pipeline.
+pub unsafe trait ClearBackBuffer: FramebufferBackBuffer {
+  /// Clear the color, depth and stencil buffers of the back buffer with the values that are `Some`.
+  ///
+  /// The whole back buffer is cleared, whatever the scissor, color mask and depth write states.
+  ///
+  /// # Safety
+  ///
+  /// The backend must be in a state where it can issue commands (e.g. its context must be current).
+  unsafe fn clear_back_buffer(
+    &mut self,
+    color: Option<[f32; 4]>,
+    depth: Option<f32>,
+    stencil: Option<i32>,
+  );
+}
+
+/// Framebuffer readback.
+///
+/// Backends implementing this trait can copy the pixels of a 2D framebuffer back to the CPU.
+///
+/// # Safety
+///
+/// Implementors must return exactly as many pixels as the region contains, encoded with the memory layout of the
+/// requested pixel type.
+pub unsafe trait FramebufferReadback: Framebuffer<Dim2> {
+  /// Read the pixels of the color attachment at `attachment_index` lying in `region`.
+  ///
+  /// `region` is always fully contained in the framebuffer. Pixels must be returned row by row, starting with the
+  /// bottom row, without any padding between rows.
+  ///
+  /// # Safety
+  ///
+  /// `framebuffer` must have been created by the same backend, which must still be alive.
+  unsafe fn read_color_pixels<P>(
+    framebuffer: &Self::FramebufferRepr,
+    attachment_index: usize,
+    region: &ScissorRegion,
+  ) -> Result<Vec<P::RawEncoding>, FramebufferError>
+  where
+    P: ColorPixel + RenderablePixel,
+    P::RawEncoding: Copy + Default;
+
+  /// Read the depth values of the framebuffer lying in `region`.
+  ///
+  /// `region` is always fully contained in the framebuffer. Depth values must be returned row by row, starting with the
+  /// bottom row, without any padding between rows. If the depth values cannot be read — for instance because the
+  /// framebuffer has no depth buffer — [`FramebufferError::CannotReadPixels`] must be returned instead of made-up
+  /// values.
+  ///
+  /// # Safety
+  ///
+  /// `framebuffer` must have been created by the same backend, which must still be alive.
+  unsafe fn read_depth_pixels(
+    framebuffer: &Self::FramebufferRepr,
+    region: &ScissorRegion,
+  ) -> Result<Vec<f32>, FramebufferError>;
+}
+
+/// Framebuffer blitting.
+///
+/// Backends implementing this trait can copy regions of a 2D framebuffer to another one.
+///
+/// # Safety
+///
+/// Implementors must only write to the destination region of the destination framebuffer.
+pub unsafe trait FramebufferBlit: Framebuffer<Dim2> {
+  /// Copy `src_region` of `src` to `dst_region` of `dst`.
+  ///
+  /// Only the buffers selected by `mask` are copied, scaled with `filter` if the regions have different sizes. Regions
+  /// are always fully contained in their framebuffers, and depth and stencil values are always copied with
+  /// [`BlitFilter::Nearest`].
+  ///
+  /// # Safety
+  ///
+  /// `src` and `dst` must have been created by the same backend, which must still be alive.
+  unsafe fn blit_framebuffer(
+    &mut self,
+    src: &Self::FramebufferRepr,
+    dst: &Self::FramebufferRepr,
+    src_region: &ScissorRegion,
+    dst_region: &ScissorRegion,
+    mask: BlitMask,
+    filter: BlitFilter,
+  ) -> Result<(), FramebufferError>;
+}
@@ -0,0 +1,63 @@
+//! Indirect buffer backend interface.
+//!
+//! This interface defines the low-level API indirect buffers must implement to be usable.
+
+use crate::indirect::{IndirectCommand, IndirectError};
+
+/// Indirect buffer backend.
+///
+/// An indirect buffer holds draw commands, read by the GPU when rendering tessellations with
+/// [`TessGateIndirect::render_indirect`](crate::backend::tess_gate::TessGateIndirect::render_indirect).
+///
+/// # Safety
+///
+/// Implementors must store the commands with the memory layout of `C`, as the GPU reads them directly.
+pub unsafe trait IndirectBuffer<C>
+where
+  C: IndirectCommand,
+{
+  /// Backend representation of the indirect buffer.
+  type IndirectBufferRepr;
+
+  /// Create a new indirect buffer holding `commands`.
+  ///
+  /// Backends not supporting indirect rendering must return [`IndirectError::Unsupported`].
+  ///
+  /// # Safety
+  ///
+  /// The backend must be in a state where it can issue commands (e.g. its context must be current).
+  unsafe fn new_indirect_buffer(
+    &mut self,
+    commands: impl Iterator<Item = C>,
+  ) -> Result<Self::IndirectBufferRepr, IndirectError>;
+
+  /// Number of commands held by the indirect buffer.
+  ///
+  /// # Safety
+  ///
+  /// `buffer` must have been created by the same backend, which must still be alive.
+  unsafe fn indirect_buffer_len(buffer: &Self::IndirectBufferRepr) -> usize;
+
+  /// Access the command at index `i`.
+  ///
+  /// # Safety
+  ///
+  /// `buffer` must have been created by the same backend, which must still be alive.
+  unsafe fn indirect_command_at(
+    buffer: &Self::IndirectBufferRepr,
+    i: usize,
+  ) -> Result<C, IndirectError>;
+
+  /// Set the command at index `i`.
+  ///
+  /// Return the previous command.
+  ///
+  /// # Safety
+  ///
+  /// `buffer` must have been created by the same backend, which must still be alive.
+  unsafe fn set_indirect_command_at(
+    buffer: &mut Self::IndirectBufferRepr,
+    i: usize,
+    command: C,
+  ) -> Result<C, IndirectError>;
+}
@@ -61,6 +61,9 @@ where
   /// This method should perform the required backend action to take into account the framebuffer and the state to start
   /// the pipeline.
   ///
+  /// Buffers whose clear value is `None` in the [`PipelineState`] (e.g. [`PipelineState::clear_depth`]) must not be
+  /// cleared, so that their contents are preserved from a pipeline to another.
+  ///
   /// [`Framebuffer`]: crate::backend::framebuffer::Framebuffer
   unsafe fn start_pipeline(
     &mut self,
@@ -22,6 +22,9 @@ pub enum QueryError {
 
   /// No maximum number of elements for texture arrays information available.
   NoMaxTextureArrayElements,
+
+  /// No backend limits information available.
+  NoBackendLimits,
 }
 
 impl fmt::Display for QueryError {
@@ -36,6 +39,86 @@ impl fmt::Display for QueryError {
       QueryError::NoMaxTextureArrayElements => {
         f.write_str("no maximum number of elements for texture arrays available")
       }
+      QueryError::NoBackendLimits => f.write_str("no backend limits available"),
+    }
+  }
+}
+
+/// Memory information about the GPU.
+///
+/// All the sizes are expressed in kilobytes.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct GpuMemoryInfo {
+  /// Total amount of memory, if the backend knows about it.
+  pub total: Option<usize>,
+
+  /// Currently available amount of memory.
+  pub available: usize,
+}
+
+/// Limits of the backend.
+///
+/// Those limits are the maximum values the backend supports when creating resources; going over them makes resource
+/// creation fail.
+///
+/// Backends create values with [`BackendLimits::new`], as more limits might be added in the future.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub struct BackendLimits {
+  /// Maximum width and height, in texels, of a 2D texture.
+  pub max_texture_2d_size: usize,
+
+  /// Maximum number of layers a texture array can hold.
+  pub max_texture_array_layers: usize,
+
+  /// Maximum number of color attachments of a framebuffer.
+  pub max_color_attachments: usize,
+
+  /// Maximum number of vertex attributes a vertex can have.
+  pub max_vertex_attributes: usize,
+
+  /// Maximum size, in bytes, of a uniform block.
+  pub max_uniform_block_size: usize,
+
+  /// Maximum number of samples for multisampling.
+  pub max_samples: usize,
+
+  /// Whether conservative rasterization is supported.
+  ///
+  /// If not, [`RenderState::set_conservative_raster`] is ignored.
+  ///
+  /// [`RenderState::set_conservative_raster`]: crate::render_state::RenderState::set_conservative_raster
+  pub conservative_raster: bool,
+}
+
+impl BackendLimits {
+  /// Create limits from the maximum values supported by the backend.
+  ///
+  /// Optional features, such as [`BackendLimits::conservative_raster`], are unsupported by default.
+  pub fn new(
+    max_texture_2d_size: usize,
+    max_texture_array_layers: usize,
+    max_color_attachments: usize,
+    max_vertex_attributes: usize,
+    max_uniform_block_size: usize,
+    max_samples: usize,
+  ) -> Self {
+    BackendLimits {
+      max_texture_2d_size,
+      max_texture_array_layers,
+      max_color_attachments,
+      max_vertex_attributes,
+      max_uniform_block_size,
+      max_samples,
+      conservative_raster: false,
+    }
+  }
+
+  /// Set whether conservative rasterization is supported.
+  pub fn set_conservative_raster(self, conservative_raster: bool) -> Self {
+    BackendLimits {
+      conservative_raster,
+      ..self
     }
   }
 }
@@ -60,4 +143,19 @@ pub unsafe trait Query {
 
   /// The maximum number of elements a texture array can hold.
   fn max_texture_array_elements(&self) -> Result<usize, QueryError>;
+
+  /// The limits of the backend.
+  fn limits(&self) -> Result<BackendLimits, QueryError>;
+
+  /// Memory information about the GPU.
+  ///
+  /// This is best-effort, vendor-specific information: backends that cannot get it must return `None`.
+  fn gpu_memory_info(&self) -> Option<GpuMemoryInfo>;
+
+  /// Whether the back buffer is sRGB-capable.
+  ///
+  /// Backends that cannot tell must return `false`, which is what the default implementation does.
+  fn back_buffer_is_srgb(&self) -> bool {
+    false
+  }
 }
@@ -64,6 +64,28 @@ pub unsafe trait Uniformable<'a, T>: Shader {
   unsafe fn update(program: &mut Self::ProgramRepr, uniform: &'a Uniform<T>, value: Self::Target);
 }
 
+/// Backend support for transposed matrix uniforms.
+///
+/// This trait is only implemented for matrix uniform types (and arrays of matrices). It allows to upload matrices stored
+/// in row-major order without having to transpose them on the CPU first.
+///
+/// # Safety
+///
+/// [`UniformableTransposed::update_transposed`] must update the [`Uniform`] with the transpose of the value, so that
+/// row-major matrices end up being read as column-major ones by shaders.
+pub unsafe trait UniformableTransposed<'a, T>: Uniformable<'a, T> {
+  /// Update the associated value of the [`Uniform`] in the given shader program, transposing it.
+  ///
+  /// # Safety
+  ///
+  /// `program` must be the shader program `uniform` was retrieved from.
+  unsafe fn update_transposed(
+    program: &mut Self::ProgramRepr,
+    uniform: &'a Uniform<T>,
+    value: Self::Target,
+  );
+}
+
 /// Shader support.
 ///
 /// This trait provides several concepts as once, as they all depend on each other:
@@ -88,6 +110,16 @@ pub unsafe trait Shader {
   /// Create a new shader stage of type [`StageType`].
   unsafe fn new_stage(&mut self, ty: StageType, src: &str) -> Result<Self::StageRepr, StageError>;
 
+  /// Get the log of the compiler for a successfully compiled shader stage, if any.
+  ///
+  /// This log typically contains warnings (deprecated features, implicit conversions, etc.). Backends must return
+  /// `None` if the log is empty or if they cannot get it.
+  ///
+  /// # Safety
+  ///
+  /// `stage` must have been created by the same backend, which must still be alive.
+  unsafe fn stage_info_log(stage: &Self::StageRepr) -> Option<String>;
+
   /// Create a new shader program by combining several shader stages.
   ///
   /// The vertex and fragment stages are mandatory. The other ones are optional and then must be inspected to check
@@ -136,6 +168,38 @@ pub unsafe trait Shader {
     Self: for<'u> Uniformable<'u, T>;
 }
 
+/// Shader program binaries.
+///
+/// This trait is optional: backends implement it if they can get the binary of a linked shader program and create a
+/// shader program back from such a binary, which allows caching shader programs instead of compiling them again.
+///
+/// # Safety
+///
+/// Shader programs created from binaries must be usable like the ones created with [`Shader::new_program`].
+pub unsafe trait ShaderBinary: Shader {
+  /// Get the binary of a linked shader program, along with its backend-specific format.
+  ///
+  /// # Safety
+  ///
+  /// `program` must have been created by the same backend, which must still be alive.
+  unsafe fn program_binary(program: &Self::ProgramRepr) -> Result<(u32, Vec<u8>), ProgramError>;
+
+  /// Create a shader program from a binary previously returned by [`ShaderBinary::program_binary`].
+  ///
+  /// The binary must fail to load with [`ProgramError::IncompatibleBinary`] if the backend doesn’t accept it anymore —
+  /// typically after a driver update — instead of creating an unusable shader program. The vertex attribute locations
+  /// are part of the binary, so [`Shader::apply_semantics`] is not called on the created shader program.
+  ///
+  /// # Safety
+  ///
+  /// `format` and `binary` can be anything: they must be validated by the backend.
+  unsafe fn new_program_from_binary(
+    &mut self,
+    format: u32,
+    binary: &[u8],
+  ) -> Result<Self::ProgramRepr, ProgramError>;
+}
+
 /// Shader data backend.
 pub unsafe trait ShaderData<T> {
   /// Representation of the data by the backend.
@@ -13,5 +13,8 @@ use crate::backend::shader::Shader as ShaderBackend;
 /// [`Shader`]: crate::backend::shader::Shader
 pub unsafe trait ShadingGate: ShaderBackend {
   /// Apply the shader program and make it currently in-use for subsequent pipeline nodes.
+  ///
+  /// This method is called every time a shading gate is entered, even if the shader program is already in-use, so
+  /// implementations should track the in-use shader program and skip applying it again.
   unsafe fn apply_shader_program(&mut self, shader_program: &Self::ProgramRepr);
 }
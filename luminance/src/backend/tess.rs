@@ -18,7 +18,11 @@
 
 use std::ops::{Deref, DerefMut};
 
-use crate::tess::{Mode, TessError, TessIndex, TessMapError, TessVertexData};
+use crate::tess::{
+  Deinterleaved, Interleaved, Mode, TessError, TessIndex, TessIndexType, TessMapError,
+  TessVertexData,
+};
+use crate::vertex::VertexBufferDesc;
 
 /// Tessellation support on the backend.
 ///
@@ -75,6 +79,11 @@ where
     restart_index: Option<I>,
   ) -> Result<Self::TessRepr, TessError>;
 
+  /// Maximum number of vertices a [`Mode::Patch`] can hold.
+  ///
+  /// Backends that don’t support tessellation shaders must return `0`.
+  fn max_patch_vertex_nb(&self) -> usize;
+
   /// Number of vertices available in the [`Tess`].
   unsafe fn tess_vertices_nb(tess: &Self::TessRepr) -> usize;
 
@@ -87,11 +96,25 @@ where
   /// Render the tessellation, starting at `start_index`, rendering `vert_nb` vertices, instantiating `inst_nb` times.
   ///
   /// If `inst_nb` is `0`, you should perform a render as if you were asking for `1`.
+  ///
+  /// For indexed tessellations, `base_vertex` must be added to every index before fetching vertices. If the backend
+  /// doesn’t support rendering with a base vertex and `base_vertex` is not `0`, it must not render anything and return
+  /// [`TessError::UnsupportedBaseVertex`]. `base_vertex` is ignored for tessellations without indices.
+  ///
+  /// Instance data must be fetched starting at instance `inst_start_index`. If the backend doesn’t support rendering
+  /// from a base instance and `inst_start_index` is not `0`, it must not render anything and return
+  /// [`TessError::UnsupportedBaseInstance`].
+  ///
+  /// If `mode` is `Some(mode)`, the tessellation must be rendered with `mode` instead of the [`Mode`] it was built
+  /// with.
   unsafe fn render(
     tess: &Self::TessRepr,
     start_index: usize,
     vert_nb: usize,
+    base_vertex: i32,
     inst_nb: usize,
+    inst_start_index: usize,
+    mode: Option<Mode>,
   ) -> Result<(), TessError>;
 }
 
@@ -129,6 +152,68 @@ where
   ) -> Result<Self::VertexSliceMutRepr, TessMapError>;
 }
 
+/// Slice all the attributes of deinterleaved vertex data on CPU at once.
+///
+/// This trait is similar to [`VertexSlice`], but instead of slicing a single attribute, all the attributes are mapped
+/// at once and retained until the [`VertexSliceSet::VertexSliceSetRepr`] object is dropped. The raw bytes of each
+/// attribute are then accessed with [`VertexSliceSet::attribute_bytes`].
+///
+/// # Safety
+///
+/// The bytes returned by [`VertexSliceSet::attribute_bytes`] must be correctly aligned for the type of the attribute.
+pub unsafe trait VertexSliceSet<'a, V, I, W>: Tess<V, I, W, Deinterleaved>
+where
+  V: TessVertexData<Deinterleaved>,
+  I: TessIndex,
+  W: TessVertexData<Deinterleaved>,
+{
+  /// Backend representation of the mapped attributes.
+  type VertexSliceSetRepr: 'a;
+
+  /// Map all the vertex attributes.
+  ///
+  /// # Safety
+  ///
+  /// `tess` must have been created by the same backend, which must still be alive.
+  unsafe fn vertices_all(
+    tess: &'a mut Self::TessRepr,
+  ) -> Result<Self::VertexSliceSetRepr, TessMapError>;
+
+  /// Raw bytes of the attribute at rank `rank`.
+  ///
+  /// # Safety
+  ///
+  /// `rank` must be smaller than the number of attributes of `V`.
+  unsafe fn attribute_bytes(slices: &Self::VertexSliceSetRepr, rank: usize) -> &[u8];
+}
+
+/// Update a contiguous range of interleaved vertex data.
+///
+/// Contrary to [`VertexSlice::vertices_mut`], which maps the whole vertex storage, this trait uploads only the updated
+/// range, which is much cheaper when a few vertices of a large tessellation change.
+///
+/// # Safety
+///
+/// The updated vertices must be visible to subsequent renders and mappings of the tessellation.
+pub unsafe trait VertexUpdate<V, I, W>: Tess<V, I, W, Interleaved>
+where
+  V: TessVertexData<Interleaved>,
+  I: TessIndex,
+  W: TessVertexData<Interleaved>,
+{
+  /// Replace the vertices starting at `start` with `data`.
+  ///
+  /// # Safety
+  ///
+  /// `tess` must have been created by the same backend, which must still be alive. `start + data.len()` must not exceed
+  /// the number of vertices of `tess`.
+  unsafe fn update_vertices(
+    tess: &mut Self::TessRepr,
+    start: usize,
+    data: &[V],
+  ) -> Result<(), TessMapError>;
+}
+
 /// Slice index data on CPU.
 ///
 /// This trait must be implemented by the backend so that it’s possible to _slice_ the index data. The idea is that the
@@ -198,3 +283,62 @@ where
     tess: &'a mut Self::TessRepr,
   ) -> Result<Self::InstanceSliceMutRepr, TessMapError>;
 }
+
+/// Raw tessellation support on the backend.
+///
+/// Raw tessellations are built from raw bytes and a vertex layout only known at runtime, instead of typed vertices and
+/// indices. Vertices are interleaved and laid out the same way [`Tess`] lays out the vertices of a given vertex type.
+///
+/// # Safety
+///
+/// Implementors must only read `vertices` with the layout described by the vertex descriptors, and indices with the
+/// provided index type.
+pub unsafe trait RawTess {
+  /// Backend representation of the raw tessellation.
+  type RawTessRepr;
+
+  /// Build a raw tessellation from interleaved vertex bytes, their layout, optional index bytes and a mode.
+  ///
+  /// If the length of `vertices` is not a multiple of the size of a vertex, the backend must return
+  /// [`TessError::LengthIncoherency`].
+  ///
+  /// # Safety
+  ///
+  /// The backend must be in a state where it can issue commands (e.g. its context must be current).
+  unsafe fn new_raw_tess(
+    &mut self,
+    vertices: &[u8],
+    desc: &[VertexBufferDesc],
+    indices: Option<(&[u8], TessIndexType)>,
+    mode: Mode,
+  ) -> Result<Self::RawTessRepr, TessError>;
+
+  /// Number of vertices available in the raw tessellation.
+  ///
+  /// # Safety
+  ///
+  /// `tess` must have been created by the same backend, which must still be alive.
+  unsafe fn raw_tess_vertices_nb(tess: &Self::RawTessRepr) -> usize;
+
+  /// Number of indices available in the raw tessellation.
+  ///
+  /// # Safety
+  ///
+  /// `tess` must have been created by the same backend, which must still be alive.
+  unsafe fn raw_tess_indices_nb(tess: &Self::RawTessRepr) -> usize;
+
+  /// Render the raw tessellation, starting at `start_index`, rendering `vert_nb` vertices, instantiating `inst_nb`
+  /// times.
+  ///
+  /// If `inst_nb` is `0`, you should perform a render as if you were asking for `1`.
+  ///
+  /// # Safety
+  ///
+  /// `tess` must have been created by the same backend, which must still be alive.
+  unsafe fn render_raw_tess(
+    tess: &Self::RawTessRepr,
+    start_index: usize,
+    vert_nb: usize,
+    inst_nb: usize,
+  ) -> Result<(), TessError>;
+}
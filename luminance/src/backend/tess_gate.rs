@@ -4,8 +4,10 @@
 //!
 //! A tessellation gate allows to render [`Tess`] objects.
 
+use crate::backend::indirect::IndirectBuffer;
 use crate::backend::tess::Tess;
-use crate::tess::{TessIndex, TessVertexData};
+use crate::indirect::IndirectCommand;
+use crate::tess::{Mode, TessError, TessIndex, TessVertexData};
 
 /// Trait to implement to be able to render [`Tess`] objects.
 ///
@@ -18,12 +20,52 @@ where
   W: TessVertexData<S>,
   S: ?Sized,
 {
-  /// Render the [`Tess`] starting at `start_index`, for `vert_nb` vertices with `inst_nb` instances.
+  /// Render the [`Tess`] starting at `start_index`, for `vert_nb` vertices offset by `base_vertex`, with `inst_nb`
+  /// instances, starting at instance `inst_start_index`.
+  ///
+  /// If `mode` is `Some(mode)`, the [`Tess`] must be rendered with `mode` instead of the [`Mode`] it was built with.
+  ///
+  /// Errors returned by [`Tess::render`] must be forwarded, so that nothing is rendered silently.
+  #[allow(clippy::too_many_arguments)]
   unsafe fn render(
     &mut self,
     tess: &Self::TessRepr,
     start_index: usize,
     vert_nb: usize,
+    base_vertex: i32,
     inst_nb: usize,
-  );
+    inst_start_index: usize,
+    mode: Option<Mode>,
+  ) -> Result<(), TessError>;
+}
+
+/// Trait to implement to be able to render [`Tess`] objects with commands read from an [`IndirectBuffer`].
+///
+/// # Safety
+///
+/// Implementors must only issue the draw command if `C` matches the kind of [`Tess`] being rendered (indexed or not).
+pub unsafe trait TessGateIndirect<V, I, W, S, C>:
+  TessGate<V, I, W, S> + IndirectBuffer<C>
+where
+  V: TessVertexData<S>,
+  I: TessIndex,
+  W: TessVertexData<S>,
+  S: ?Sized,
+  C: IndirectCommand,
+{
+  /// Render the [`Tess`] with the command stored at index `index` in `indirect_buffer`.
+  ///
+  /// If the [`Tess`] has indices but `C` is not an indexed command (or the other way around), the backend must return
+  /// [`TessError::IndirectCommandMismatch`].
+  ///
+  /// # Safety
+  ///
+  /// `tess` and `indirect_buffer` must have been created by the same backend, which must still be alive. `index` must
+  /// be smaller than the number of commands held by `indirect_buffer`.
+  unsafe fn render_indirect(
+    &mut self,
+    tess: &Self::TessRepr,
+    indirect_buffer: &Self::IndirectBufferRepr,
+    index: usize,
+  ) -> Result<(), TessError>;
 }
@@ -53,6 +53,16 @@ where
   /// Get the number of mimaps associated with the texture.
   unsafe fn mipmaps(texture: &Self::TextureRepr) -> usize;
 
+  /// Generate the mipmaps of a texture from its base level.
+  ///
+  /// Only the mipmap levels allocated when creating the texture must be generated. The pixel format of the texture is
+  /// guaranteed to be filterable.
+  ///
+  /// # Safety
+  ///
+  /// `texture` must have been created by the same backend, which must still be alive.
+  unsafe fn generate_mipmaps(texture: &mut Self::TextureRepr) -> Result<(), TextureError>;
+
   /// Upload texels to a part of a texture.
   ///
   /// This method will use the input texels and will copy them everywhere in the part formed with `offset` and `size`. For
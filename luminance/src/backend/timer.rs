@@ -0,0 +1,59 @@
+//! Timer query backend interface.
+//!
+//! This interface defines the low-level API timer queries must implement to be usable.
+
+use crate::timer::TimerQueryError;
+use std::time::Duration;
+
+/// Timer query backend.
+///
+/// A timer query measures the time the GPU spends executing the commands issued between the beginning and the end of
+/// the query.
+///
+/// # Safety
+///
+/// Implementors must only report a duration once the GPU has actually completed the commands issued while the query
+/// was active.
+pub unsafe trait TimerQuery {
+  /// Backend representation of the timer query.
+  type TimerQueryRepr;
+
+  /// Create a new timer query.
+  ///
+  /// Backends not supporting timer queries must return [`TimerQueryError::Unsupported`].
+  ///
+  /// # Safety
+  ///
+  /// The backend must be in a state where it can issue commands (e.g. its context must be current).
+  unsafe fn new_timer_query(&mut self) -> Result<Self::TimerQueryRepr, TimerQueryError>;
+
+  /// Start measuring the time spent by the GPU to execute the next commands.
+  ///
+  /// Only one timer query can be active at a time; backends must return [`TimerQueryError::AlreadyActive`] if another
+  /// timer query is active. Starting a new measurement discards the result of the previous one, if any.
+  ///
+  /// # Safety
+  ///
+  /// `query` must have been created by the same backend.
+  unsafe fn begin_timer_query(
+    &mut self,
+    query: &mut Self::TimerQueryRepr,
+  ) -> Result<(), TimerQueryError>;
+
+  /// Stop measuring the time spent by the GPU.
+  ///
+  /// # Safety
+  ///
+  /// `query` must have been created by the same backend, and be the active timer query.
+  unsafe fn end_timer_query(&mut self, query: &mut Self::TimerQueryRepr);
+
+  /// Get the duration of the last measurement, if available.
+  ///
+  /// This must not block: `None` is returned if the GPU hasn’t completed the measured commands yet, if no measurement
+  /// was performed, or if the measurement is invalid.
+  ///
+  /// # Safety
+  ///
+  /// `query` must have been created by the same backend, which must still be alive.
+  unsafe fn timer_query_elapsed(query: &Self::TimerQueryRepr) -> Option<Duration>;
+}
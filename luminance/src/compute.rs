@@ -0,0 +1,223 @@
+//! Compute shaders.
+//!
+//! Compute shaders run arbitrary computations on the GPU, outside of the graphics pipeline — for instance, to simulate
+//! particles or to cull objects before rendering them. A [`ComputeProgram`] is made of a single compute stage and is
+//! dispatched over a grid of _work groups_ with [`GraphicsContext::dispatch_compute`]; the size of a work group is
+//! declared in the shader, with `layout(local_size_x = …) in;`.
+//!
+//! Compute programs read and write [`ShaderData`] bound as _storage_ with [`ComputeGate::bind_storage`]. The storage
+//! blocks must be declared with the `std140` layout, which is the layout of [`ShaderData`]:
+//!
+//! ```glsl
+//! layout(local_size_x = 64) in;
+//!
+//! layout(std140, binding = 0) buffer Particles {
+//!   vec4 positions[];
+//! };
+//! ```
+//!
+//! Commands issued after a dispatch don’t necessarily see what the compute program wrote: call
+//! [`GraphicsContext::memory_barrier`] before using the written [`ShaderData`] — in a pipeline, for instance.
+//!
+//! # Backend support
+//!
+//! Compute shaders require backend support (e.g. OpenGL 4.3). If the backend doesn’t support them, creating a
+//! [`ComputeProgram`] fails with [`StageError::UnsupportedType`].
+//!
+//! [`GraphicsContext::dispatch_compute`]: crate::context::GraphicsContext::dispatch_compute
+//! [`GraphicsContext::memory_barrier`]: crate::context::GraphicsContext::memory_barrier
+//! [`StageError::UnsupportedType`]: crate::shader::StageError::UnsupportedType
+
+use crate::{
+  backend::compute::{Compute, ComputeStorage},
+  context::GraphicsContext,
+  shader::{
+    ProgramError, ProgramInterface, ProgramWarning, ShaderData, Stage, StageType, UniformBuilder,
+    UniformInterface,
+  },
+};
+use std::{error, fmt, marker::PhantomData};
+
+/// Compute error.
+#[non_exhaustive]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ComputeError {
+  /// The number of work groups exceeds what the backend supports along at least one dimension.
+  TooManyWorkGroups {
+    /// Number of work groups that was asked.
+    groups: [u32; 3],
+    /// Maximum number of work groups along each dimension.
+    max: [u32; 3],
+  },
+
+  /// The storage binding point is not supported by the backend.
+  InvalidStorageBinding {
+    /// Binding point that was asked.
+    binding: u32,
+    /// Number of storage binding points.
+    max: u32,
+  },
+}
+
+impl ComputeError {
+  /// The number of work groups exceeds what the backend supports along at least one dimension.
+  pub fn too_many_work_groups(groups: [u32; 3], max: [u32; 3]) -> Self {
+    ComputeError::TooManyWorkGroups { groups, max }
+  }
+
+  /// The storage binding point is not supported by the backend.
+  pub fn invalid_storage_binding(binding: u32, max: u32) -> Self {
+    ComputeError::InvalidStorageBinding { binding, max }
+  }
+}
+
+impl fmt::Display for ComputeError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    match *self {
+      ComputeError::TooManyWorkGroups { groups, max } => write!(
+        f,
+        "too many work groups: {:?} (maximum is {:?})",
+        groups, max
+      ),
+
+      ComputeError::InvalidStorageBinding { binding, max } => write!(
+        f,
+        "invalid storage binding {} ({} storage binding points)",
+        binding, max
+      ),
+    }
+  }
+}
+
+impl error::Error for ComputeError {}
+
+/// A compute program.
+///
+/// # Parametricity
+///
+/// - `B` is the backend type.
+/// - `Uni` is the [`UniformInterface`] type.
+pub struct ComputeProgram<B, Uni>
+where
+  B: Compute,
+{
+  repr: B::ProgramRepr,
+  uni: Uni,
+}
+
+impl<B, Uni> ComputeProgram<B, Uni>
+where
+  B: Compute,
+{
+  /// Create a new [`ComputeProgram`] from the source of its compute stage, by accessing a mutable environment
+  /// variable.
+  ///
+  /// If the backend doesn’t support compute shaders, [`StageError::UnsupportedType`] is returned, wrapped in
+  /// [`ProgramError::StageError`].
+  ///
+  /// # Parametricity
+  ///
+  /// - `E` is the mutable environment variable.
+  ///
+  /// [`StageError::UnsupportedType`]: crate::shader::StageError::UnsupportedType
+  pub fn new_env<C, R, E>(ctx: &mut C, src: R, env: &mut E) -> Result<Self, ProgramError>
+  where
+    C: GraphicsContext<Backend = B>,
+    R: AsRef<str>,
+    Uni: UniformInterface<B, E>,
+  {
+    let stage = Stage::new(ctx, StageType::ComputeShader, src)?;
+
+    unsafe {
+      let mut repr = ctx.backend().new_compute_program(&stage.repr)?;
+
+      let mut uniform_builder = B::new_uniform_builder(&mut repr).map(|repr| UniformBuilder {
+        repr,
+        warnings: Vec::new(),
+        _a: PhantomData,
+      })?;
+
+      let uni =
+        Uni::uniform_interface(&mut uniform_builder, env).map_err(ProgramWarning::Uniform)?;
+
+      Ok(ComputeProgram { repr, uni })
+    }
+  }
+
+  /// Create a new [`ComputeProgram`] from the source of its compute stage.
+  ///
+  /// See the documentation of [`ComputeProgram::new_env`] for further details.
+  pub fn new<C, R>(ctx: &mut C, src: R) -> Result<Self, ProgramError>
+  where
+    C: GraphicsContext<Backend = B>,
+    R: AsRef<str>,
+    Uni: UniformInterface<B>,
+  {
+    Self::new_env(ctx, src, &mut ())
+  }
+
+  /// Dispatch the [`ComputeProgram`] with `groups` work groups along each dimension.
+  ///
+  /// The argument closure is run before the dispatch and is given three arguments:
+  ///
+  /// - A [`ProgramInterface`], to set the uniforms of the [`ComputeProgram`].
+  /// - The [`UniformInterface`] of the [`ComputeProgram`].
+  /// - A [`ComputeGate`], to bind [`ShaderData`] as storage.
+  ///
+  /// If the closure fails, nothing is dispatched. [`ComputeError::TooManyWorkGroups`] is returned if the backend
+  /// cannot dispatch that many work groups. A dimension with no work group dispatches nothing.
+  pub fn dispatch<C, E, F>(&mut self, ctx: &mut C, groups: [u32; 3], f: F) -> Result<(), E>
+  where
+    C: GraphicsContext<Backend = B>,
+    E: From<ComputeError>,
+    F: for<'a> FnOnce(ProgramInterface<'a, B>, &'a Uni, ComputeGate<'a, B>) -> Result<(), E>,
+  {
+    let backend = ctx.backend();
+
+    unsafe {
+      backend.apply_compute_program(&self.repr);
+    }
+
+    let program_interface = ProgramInterface {
+      program: &mut self.repr,
+    };
+    let compute_gate = ComputeGate {
+      backend: &mut *backend,
+    };
+
+    f(program_interface, &self.uni, compute_gate)?;
+
+    unsafe { backend.dispatch_compute(groups).map_err(E::from) }
+  }
+}
+
+/// Gate to bind resources used by a dispatched [`ComputeProgram`].
+///
+/// This is obtained with [`ComputeProgram::dispatch`].
+pub struct ComputeGate<'a, B> {
+  backend: &'a mut B,
+}
+
+impl<'a, B> ComputeGate<'a, B>
+where
+  B: Compute,
+{
+  /// Bind a [`ShaderData`] to the storage binding point `binding`.
+  ///
+  /// The [`ComputeProgram`] can then read and write the [`ShaderData`] through the storage block declared with the same
+  /// binding (e.g. `layout(std140, binding = 0) buffer`). The storage block must use the `std140` layout. The
+  /// [`ShaderData`] remains bound to `binding` until another one is bound there.
+  ///
+  /// Values written by the [`ComputeProgram`] are meant to be used on the GPU, after a
+  /// [`GraphicsContext::memory_barrier`]: [`ShaderData::at`] might still return the previous values.
+  pub fn bind_storage<T>(
+    &mut self,
+    binding: u32,
+    shader_data: &ShaderData<B, T>,
+  ) -> Result<(), ComputeError>
+  where
+    B: ComputeStorage<T>,
+  {
+    unsafe { self.backend.bind_storage(&shader_data.repr, binding) }
+  }
+}
@@ -46,25 +46,43 @@
 use crate::{
   backend::{
     color_slot::ColorSlot,
+    compute::Compute,
+    debug::DebugGroup,
     depth_stencil_slot::DepthStencilSlot,
-    framebuffer::Framebuffer as FramebufferBackend,
+    fence::Fence as FenceBackend,
+    framebuffer::{ClearBackBuffer, Framebuffer as FramebufferBackend, FramebufferBlit},
+    indirect::IndirectBuffer as IndirectBufferBackend,
     query::Query as QueryBackend,
     shader::{Shader, ShaderData as ShaderDataBackend},
-    tess::Tess as TessBackend,
+    tess::{RawTess as RawTessBackend, Tess as TessBackend},
     texture::Texture as TextureBackend,
+    timer::TimerQuery as TimerQueryBackend,
   },
   texture::TexelUpload,
 };
 use crate::{
-  framebuffer::{Framebuffer, FramebufferError},
+  compute::{ComputeError, ComputeGate, ComputeProgram},
+  debug::Labelable,
+  fence::{Fence, FenceError},
+  framebuffer::{BlitFilter, BlitMask, Framebuffer, FramebufferError},
+  indirect::{IndirectBuffer, IndirectCommand, IndirectError},
   pipeline::PipelineGate,
   pixel::Pixel,
   query::Query,
-  shader::{ProgramBuilder, ShaderData, ShaderDataError, Stage, StageError, StageType},
-  tess::{Deinterleaved, Interleaved, TessBuilder, TessVertexData},
-  texture::{Dimensionable, Sampler, Texture, TextureError},
-  vertex::Semantics,
+  scissor::ScissorRegion,
+  shader::{
+    ProgramBuilder, ProgramError, ProgramInterface, ShaderData, ShaderDataError, Stage, StageError,
+    StageType, UniformInterface,
+  },
+  tess::{
+    Deinterleaved, Interleaved, Mode, RawTess, TessBuilder, TessError, TessIndexType,
+    TessVertexData,
+  },
+  texture::{Dim2, Dimensionable, Sampler, Texture, TextureError},
+  timer::{TimerQuery, TimerQueryError},
+  vertex::{Semantics, VertexDesc},
 };
+use std::time::Duration;
 
 /// Class of graphics context.
 ///
@@ -91,6 +109,110 @@ pub unsafe trait GraphicsContext: Sized {
     PipelineGate::new(self)
   }
 
+  /// Open a new debug group named `name`, nested in the current one, if any.
+  ///
+  /// Debug groups structure the commands shown by debugging tools, such as RenderDoc or Nsight. Every call to this
+  /// method must be matched by a call to [`GraphicsContext::pop_debug_group`]. See the [`debug`] module for further
+  /// details.
+  ///
+  /// [`debug`]: crate::debug
+  fn push_debug_group(&mut self, name: &str)
+  where
+    Self::Backend: DebugGroup,
+  {
+    unsafe { self.backend().push_debug_group(name) }
+  }
+
+  /// Close the current debug group.
+  ///
+  /// See [`GraphicsContext::push_debug_group`].
+  fn pop_debug_group(&mut self)
+  where
+    Self::Backend: DebugGroup,
+  {
+    unsafe { self.backend().pop_debug_group() }
+  }
+
+  /// Label a resource, so that debugging tools, such as RenderDoc or Nsight, show it by its label.
+  ///
+  /// See the [`debug`] module for further details.
+  ///
+  /// [`debug`]: crate::debug
+  fn set_label<R>(&mut self, resource: &R, label: &str)
+  where
+    R: Labelable<Self::Backend>,
+  {
+    resource.set_label(self.backend(), label)
+  }
+
+  /// Insert a new fence in the command stream.
+  ///
+  /// See the documentation of [`Fence::new`] for further details.
+  fn new_fence(&mut self) -> Result<Fence<Self::Backend>, FenceError>
+  where
+    Self::Backend: FenceBackend,
+  {
+    Fence::new(self)
+  }
+
+  /// Create a new timer query.
+  ///
+  /// See the documentation of [`TimerQuery::new`] for further details.
+  fn new_timer_query(&mut self) -> Result<TimerQuery<Self::Backend>, TimerQueryError>
+  where
+    Self::Backend: TimerQueryBackend,
+  {
+    TimerQuery::new(self)
+  }
+
+  /// Measure the time the GPU spends executing the commands issued by `f`.
+  ///
+  /// See the documentation of [`TimerQuery::time_elapsed`] for further details.
+  fn time_elapsed<R>(
+    &mut self,
+    query: &mut TimerQuery<Self::Backend>,
+    f: impl FnOnce(&mut Self) -> R,
+  ) -> Result<(R, Option<Duration>), TimerQueryError>
+  where
+    Self::Backend: TimerQueryBackend,
+  {
+    query.time_elapsed(self, f)
+  }
+
+  /// Clear the back buffer, outside of any pipeline.
+  ///
+  /// The color, depth and stencil buffers are cleared with `color`, `depth` and `stencil`, respectively; a `None` value
+  /// leaves the corresponding buffer untouched. The whole back buffer is cleared. This is useful to clear the screen on
+  /// frames where nothing is rendered, without having to run an empty pipeline.
+  fn clear_back_buffer(&mut self, color: Option<[f32; 4]>, depth: Option<f32>, stencil: Option<i32>)
+  where
+    Self::Backend: ClearBackBuffer,
+  {
+    unsafe { self.backend().clear_back_buffer(color, depth, stencil) }
+  }
+
+  /// Copy a region of a framebuffer to a region of another framebuffer.
+  ///
+  /// See the documentation of [`Framebuffer::blit`] for further details.
+  fn blit_framebuffer<SCS, SDS, DCS, DDS>(
+    &mut self,
+    src: &Framebuffer<Self::Backend, Dim2, SCS, SDS>,
+    dst: &Framebuffer<Self::Backend, Dim2, DCS, DDS>,
+    src_region: impl Into<Option<ScissorRegion>>,
+    dst_region: impl Into<Option<ScissorRegion>>,
+    mask: BlitMask,
+    filter: BlitFilter,
+  ) -> Result<(), FramebufferError>
+  where
+    Self::Backend: FramebufferBlit,
+    SCS: ColorSlot<Self::Backend, Dim2>,
+    SDS: DepthStencilSlot<Self::Backend, Dim2>,
+    DCS: ColorSlot<Self::Backend, Dim2>,
+    DDS: DepthStencilSlot<Self::Backend, Dim2>,
+  {
+    src.blit(self, dst, src_region, dst_region, mask, filter)
+  }
+
   /// Create a new framebuffer.
   ///
   /// See the documentation of [`Framebuffer::new`] for further details.
@@ -109,6 +231,23 @@ pub unsafe trait GraphicsContext: Sized {
     Framebuffer::new(self, size, mipmaps, sampler)
   }
 
+  /// Create a new framebuffer from already existing textures.
+  ///
+  /// See the documentation of [`Framebuffer::from_textures`] for further details.
+  fn new_framebuffer_from_textures<D, CS, DS>(
+    &mut self,
+    color_textures: CS::ColorTextures,
+    depth_stencil_texture: DS::DepthStencilTexture,
+  ) -> Result<Framebuffer<Self::Backend, D, CS, DS>, FramebufferError>
+  where
+    Self::Backend: FramebufferBackend<D>,
+    D: Dimensionable,
+    CS: ColorSlot<Self::Backend, D>,
+    DS: DepthStencilSlot<Self::Backend, D>,
+  {
+    Framebuffer::from_textures(self, color_textures, depth_stencil_texture)
+  }
+
   /// Create a new shader stage.
   ///
   /// See the documentation of [`Stage::new`] for further details.
@@ -135,6 +274,53 @@ pub unsafe trait GraphicsContext: Sized {
     ProgramBuilder::new(self)
   }
 
+  /// Create a new compute program from the source of its compute stage.
+  ///
+  /// See the documentation of [`ComputeProgram::new`] for further details.
+  fn new_compute_program<R, Uni>(
+    &mut self,
+    src: R,
+  ) -> Result<ComputeProgram<Self::Backend, Uni>, ProgramError>
+  where
+    Self::Backend: Compute,
+    R: AsRef<str>,
+    Uni: UniformInterface<Self::Backend>,
+  {
+    ComputeProgram::new(self, src)
+  }
+
+  /// Dispatch a compute program with `groups` work groups along each dimension.
+  ///
+  /// See the documentation of [`ComputeProgram::dispatch`] for further details.
+  fn dispatch_compute<E, Uni, F>(
+    &mut self,
+    program: &mut ComputeProgram<Self::Backend, Uni>,
+    groups: [u32; 3],
+    f: F,
+  ) -> Result<(), E>
+  where
+    Self::Backend: Compute,
+    E: From<ComputeError>,
+    F: for<'a> FnOnce(
+      ProgramInterface<'a, Self::Backend>,
+      &'a Uni,
+      ComputeGate<'a, Self::Backend>,
+    ) -> Result<(), E>,
+  {
+    program.dispatch(self, groups, f)
+  }
+
+  /// Make the memory written by the previously dispatched compute programs visible to subsequent commands.
+  ///
+  /// This must be called between a dispatch and the commands using what it wrote, such as a pipeline rendering with a
+  /// [`ShaderData`] written by a compute program.
+  fn memory_barrier(&mut self)
+  where
+    Self::Backend: Compute,
+  {
+    unsafe { self.backend().memory_barrier() }
+  }
+
   /// Create a new shader data.
   ///
   /// See the documentation of [`ShaderData::new`] for further details.
@@ -170,6 +356,36 @@ pub unsafe trait GraphicsContext: Sized {
     TessBuilder::new(self)
   }
 
+  /// Create a [`RawTess`] from raw vertex bytes described at runtime.
+  ///
+  /// See the documentation of [`RawTess::new`] for further details.
+  fn new_raw_tess(
+    &mut self,
+    vertices: &[u8],
+    desc: VertexDesc,
+    indices: Option<(&[u8], TessIndexType)>,
+    mode: Mode,
+  ) -> Result<RawTess<Self::Backend>, TessError>
+  where
+    Self::Backend: RawTessBackend,
+  {
+    RawTess::new(self, vertices, desc, indices, mode)
+  }
+
+  /// Create a new indirect buffer.
+  ///
+  /// See the documentation of [`IndirectBuffer::new`] for further details.
+  fn new_indirect_buffer<C>(
+    &mut self,
+    commands: impl IntoIterator<Item = C>,
+  ) -> Result<IndirectBuffer<Self::Backend, C>, IndirectError>
+  where
+    Self::Backend: IndirectBufferBackend<C>,
+    C: IndirectCommand,
+  {
+    IndirectBuffer::new(self, commands)
+  }
+
   /// Create a new texture from texels.
   ///
   /// Feel free to have a look at the documentation of [`Texture::new`] for further details.
@@ -203,4 +419,21 @@ pub unsafe trait GraphicsContext: Sized {
   {
     Texture::new_raw(self, size, sampler, texels)
   }
+
+  /// Create a new 2D texture from an image file.
+  ///
+  /// Feel free to have a look at the documentation of [`Texture::from_path`] for further details.
+  #[cfg(feature = "image")]
+  fn new_texture_from_path<P>(
+    &mut self,
+    path: impl AsRef<std::path::Path>,
+    sampler: Sampler,
+    mipmaps: usize,
+  ) -> Result<Texture<Self::Backend, Dim2, P>, TextureError>
+  where
+    Self::Backend: TextureBackend<Dim2, P>,
+    P: Pixel,
+  {
+    Texture::from_path(self, path, sampler, mipmaps)
+  }
 }
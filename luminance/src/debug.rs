@@ -0,0 +1,74 @@
+//! Debug annotations.
+//!
+//! Debugging tools, such as RenderDoc or Nsight, show the commands sent to the GPU and the resources they use. By
+//! default, nothing is named, which makes traces hard to read. This module allows to annotate both:
+//!
+//! - Commands can be grouped in named, nested _debug groups_, with [`GraphicsContext::push_debug_group`] and
+//!   [`GraphicsContext::pop_debug_group`].
+//! - Resources — [`Tess`], [`Framebuffer`] and [`Program`] — can be given a _label_ with
+//!   [`GraphicsContext::set_label`].
+//!
+//! Annotations are only hints: backends are free to ignore them, for instance when the underlying technology doesn’t
+//! support them or when they were disabled at compile-time.
+//!
+//! [`GraphicsContext::push_debug_group`]: crate::context::GraphicsContext::push_debug_group
+//! [`GraphicsContext::pop_debug_group`]: crate::context::GraphicsContext::pop_debug_group
+//! [`GraphicsContext::set_label`]: crate::context::GraphicsContext::set_label
+
+use crate::{
+  backend::{
+    color_slot::ColorSlot, debug::DebugLabel, depth_stencil_slot::DepthStencilSlot,
+    framebuffer::Framebuffer as FramebufferBackend, shader::Shader, tess::Tess as TessBackend,
+  },
+  framebuffer::Framebuffer,
+  shader::Program,
+  tess::{Tess, TessIndex, TessVertexData},
+  texture::Dimensionable,
+};
+
+/// Resources that can be labeled.
+///
+/// See [`GraphicsContext::set_label`].
+///
+/// [`GraphicsContext::set_label`]: crate::context::GraphicsContext::set_label
+pub trait Labelable<B>
+where
+  B: ?Sized,
+{
+  /// Label the resource with `label`.
+  fn set_label(&self, backend: &mut B, label: &str);
+}
+
+impl<B, V, I, W, S> Labelable<B> for Tess<B, V, I, W, S>
+where
+  B: ?Sized + TessBackend<V, I, W, S> + DebugLabel<B::TessRepr>,
+  V: TessVertexData<S>,
+  I: TessIndex,
+  W: TessVertexData<S>,
+  S: ?Sized,
+{
+  fn set_label(&self, backend: &mut B, label: &str) {
+    unsafe { backend.set_label(&self.repr, label) }
+  }
+}
+
+impl<B, D, CS, DS> Labelable<B> for Framebuffer<B, D, CS, DS>
+where
+  B: ?Sized + FramebufferBackend<D> + DebugLabel<B::FramebufferRepr>,
+  D: Dimensionable,
+  CS: ColorSlot<B, D>,
+  DS: DepthStencilSlot<B, D>,
+{
+  fn set_label(&self, backend: &mut B, label: &str) {
+    unsafe { backend.set_label(&self.repr, label) }
+  }
+}
+
+impl<B, Sem, Out, Uni> Labelable<B> for Program<B, Sem, Out, Uni>
+where
+  B: Shader + DebugLabel<B::ProgramRepr>,
+{
+  fn set_label(&self, backend: &mut B, label: &str) {
+    unsafe { backend.set_label(&self.repr, label) }
+  }
+}
@@ -12,7 +12,7 @@ pub struct FaceCulling {
 
 impl FaceCulling {
   /// Create a new [`FaceCulling`].
-  pub fn new(order: FaceCullingOrder, mode: FaceCullingMode) -> Self {
+  pub const fn new(order: FaceCullingOrder, mode: FaceCullingMode) -> Self {
     FaceCulling { order, mode }
   }
 }
@@ -0,0 +1,75 @@
+//! GPU fences.
+//!
+//! Commands sent to the GPU are executed asynchronously: when a function such as [`TessGate::render`] returns, the
+//! GPU might not have performed the render yet. Most of the time, that is not a problem, as the backend synchronizes
+//! reads of GPU data (e.g. texels of a [`Texture`]) with the commands that write them. However, when reading data
+//! written by the GPU through other means, or when you want to wait for some work without stalling the whole
+//! pipeline, you need to synchronize explicitly with the GPU.
+//!
+//! A [`Fence`] is inserted in the command stream and gets signaled once all the commands issued before it have
+//! completed. [`Fence::wait`] blocks until that happens, or until a timeout elapses.
+//!
+//! [`TessGate::render`]: crate::tess_gate::TessGate::render
+//! [`Texture`]: crate::texture::Texture
+
+use crate::{backend::fence::Fence as FenceBackend, context::GraphicsContext};
+use std::{error, fmt, time::Duration};
+
+/// Fence error.
+#[non_exhaustive]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FenceError {
+  /// Cannot create the fence on the GPU.
+  CannotCreate,
+}
+
+impl FenceError {
+  /// Cannot create the fence on the GPU.
+  pub fn cannot_create() -> Self {
+    FenceError::CannotCreate
+  }
+}
+
+impl fmt::Display for FenceError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    match *self {
+      FenceError::CannotCreate => f.write_str("cannot create the fence on the GPU side"),
+    }
+  }
+}
+
+impl error::Error for FenceError {}
+
+/// A GPU fence.
+///
+/// A fence is signaled once all the commands issued before its creation have completed on the GPU.
+pub struct Fence<B>
+where
+  B: ?Sized + FenceBackend,
+{
+  pub(crate) repr: B::FenceRepr,
+}
+
+impl<B> Fence<B>
+where
+  B: ?Sized + FenceBackend,
+{
+  /// Insert a new [`Fence`] in the command stream of a graphics context.
+  pub fn new<C>(ctx: &mut C) -> Result<Self, FenceError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    unsafe { ctx.backend().new_fence().map(|repr| Fence { repr }) }
+  }
+
+  /// Wait for the [`Fence`] to be signaled.
+  ///
+  /// This blocks until all the commands issued before the creation of the fence have completed, or until `timeout`
+  /// elapses. Return `true` if the fence was signaled, `false` if the timeout elapsed (or the backend failed to wait).
+  ///
+  /// A zero `timeout` can be used to check whether the fence is signaled without blocking. Backends might cap the
+  /// timeout to a maximum value.
+  pub fn wait(&self, timeout: Duration) -> bool {
+    unsafe { B::wait_fence(&self.repr, timeout) }
+  }
+}
@@ -21,10 +21,12 @@
 //! You are limited in which types you can choose — the list is visible as implementors of traits
 //! in [backend::color_slot] and [backend::depth_stencil_slot].
 //!
-//! Once a [`Framebuffer`] is created, you can do basically two main operations on it:
+//! Once a [`Framebuffer`] is created, you can do basically three main operations on it:
 //!
 //! - Render things to it.
 //! - Retreive color and depth slots to perform further operations.
+//! - Read its pixels back, with [`Framebuffer::read_color_slot`] and [`Framebuffer::read_depth`].
+//! - Copy a region of it to another framebuffer, with [`Framebuffer::blit`].
 //!
 //! # Rendering to a framebuffer
 //!
@@ -46,6 +48,13 @@
 //! slot via [`Framebuffer::depth_stencil_slot`]. Once you get textures from the color slots, you
 //! can use them as regular textures as input of next renders, for instance.
 //!
+//! Framebuffers can also be created from textures you already own, with
+//! [`Framebuffer::from_textures`]. Combined with [`Framebuffer::into_slots`], that allows to move
+//! textures from a framebuffer to another one — for instance, to render into a new color texture
+//! while keeping the depth texture of a previous pass. Keep in mind that a texture must never be
+//! sampled while rendering into a framebuffer it is attached to, as that creates a _feedback
+//! loop_.
+//!
 //! ## Note on type generation
 //!
 //! Because framebuffers are highly subject to refinement typing, types are transformed at
@@ -68,11 +77,16 @@ use std::{error, fmt};
 
 use crate::{
   backend::{
-    color_slot::ColorSlot,
+    color_slot::{ColorSlot, ColorSlotAt},
     depth_stencil_slot::DepthStencilSlot,
-    framebuffer::{Framebuffer as FramebufferBackend, FramebufferBackBuffer},
+    framebuffer::{
+      Framebuffer as FramebufferBackend, FramebufferBackBuffer, FramebufferBlit,
+      FramebufferReadback,
+    },
   },
   context::GraphicsContext,
+  pixel::Pixel,
+  scissor::ScissorRegion,
   texture::{Dim2, Dimensionable, Sampler, TextureError},
 };
 
@@ -163,6 +177,74 @@ where
     &mut self.depth_stencil_slot
   }
 
+  /// Create a new [`Framebuffer`] from already existing textures.
+  ///
+  /// Instead of creating new textures for its slots, as [`Framebuffer::new`] does, the [`Framebuffer`] is created by
+  /// attaching `color_textures` and `depth_stencil_texture`. The textures are moved into the [`Framebuffer`] and can be
+  /// accessed via [`Framebuffer::color_slot`] and [`Framebuffer::depth_stencil_slot`], or given back with
+  /// [`Framebuffer::into_slots`] — for instance, to attach them to another [`Framebuffer`] afterwards.
+  ///
+  /// All the textures must have the same size, which becomes the size of the [`Framebuffer`].
+  ///
+  /// # Errors
+  ///
+  /// [`FramebufferError::AttachmentSizeMismatch`] is returned if the textures don’t have the same size, and
+  /// [`IncompleteReason::MissingAttachment`] if there is no texture at all. The backend might fail to create the
+  /// [`Framebuffer`] for other reasons, described by the [`FramebufferError`].
+  ///
+  /// # Aliasing
+  ///
+  /// A texture must not be sampled in a pipeline rendering into a [`Framebuffer`] it is attached to. Doing so creates a
+  /// _feedback loop_, in which the result of reading the texture is undefined. If you need to read a texture you are
+  /// rendering to, use two textures and alternate between them — ping-pong — by moving them from a [`Framebuffer`] to
+  /// another one.
+  ///
+  /// # Notes
+  ///
+  /// You might be interested in the [`GraphicsContext::new_framebuffer_from_textures`] function instead, which is the
+  /// exact same function, but benefits from more type inference (based on `&mut C`).
+  pub fn from_textures<C>(
+    ctx: &mut C,
+    color_textures: CS::ColorTextures,
+    depth_stencil_texture: DS::DepthStencilTexture,
+  ) -> Result<Self, FramebufferError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    let mut sizes = CS::color_textures_sizes(&color_textures)
+      .into_iter()
+      .chain(DS::depth_texture_size(&depth_stencil_texture));
+    let size = sizes
+      .next()
+      .ok_or_else(|| FramebufferError::incomplete(IncompleteReason::MissingAttachment))?;
+
+    let same_size = |other: D::Size| {
+      D::width(size) == D::width(other)
+        && D::height(size) == D::height(other)
+        && D::depth(size) == D::depth(other)
+    };
+
+    if !sizes.all(same_size) {
+      return Err(FramebufferError::attachment_size_mismatch());
+    }
+
+    unsafe {
+      let mut repr = ctx
+        .backend()
+        .new_framebuffer::<CS, DS>(size, 0, &Sampler::default())?;
+      CS::attach_color_textures(&color_textures, &mut repr, 0)?;
+      DS::attach_depth_texture(&depth_stencil_texture, &mut repr)?;
+
+      let repr = B::validate_framebuffer(repr)?;
+
+      Ok(Framebuffer {
+        repr,
+        color_slot: color_textures,
+        depth_stencil_slot: depth_stencil_texture,
+      })
+    }
+  }
+
   /// Consume this framebuffer and return the carried slots' texture(s).
   pub fn into_slots(self) -> (CS::ColorTextures, DS::DepthStencilTexture) {
     (self.color_slot, self.depth_stencil_slot)
@@ -179,6 +261,137 @@ where
   }
 }
 
+impl<B, CS, DS> Framebuffer<B, Dim2, CS, DS>
+where
+  B: ?Sized + FramebufferBackend<Dim2> + FramebufferReadback,
+  CS: ColorSlot<B, Dim2>,
+  DS: DepthStencilSlot<B, Dim2>,
+{
+  /// Read back the pixels of the color attachment at index `IDX` of the color slot.
+  ///
+  /// `region` selects the pixels to read, with the origin at the bottom-left corner of the framebuffer; `None` reads
+  /// the whole framebuffer. Pixels are returned row by row, starting with the bottom row, and rows are tightly packed
+  /// whatever the width of the region.
+  ///
+  /// # Errors
+  ///
+  /// [`FramebufferError::RegionOutOfBounds`] is returned if `region` is not fully contained in the framebuffer.
+  pub fn read_color_slot<const IDX: usize>(
+    &self,
+    region: impl Into<Option<ScissorRegion>>,
+  ) -> Result<Vec<<CS::Pixel as Pixel>::RawEncoding>, FramebufferError>
+  where
+    CS: ColorSlotAt<IDX>,
+    <CS::Pixel as Pixel>::RawEncoding: Default,
+  {
+    let region = self.check_region(region.into())?;
+    unsafe { B::read_color_pixels::<CS::Pixel>(&self.repr, IDX, &region) }
+  }
+
+  /// Read back the depth values of the framebuffer.
+  ///
+  /// `region` selects the depth values to read, with the origin at the bottom-left corner of the framebuffer; `None`
+  /// reads the whole framebuffer. Depth values are returned row by row, starting with the bottom row.
+  ///
+  /// # Errors
+  ///
+  /// [`FramebufferError::RegionOutOfBounds`] is returned if `region` is not fully contained in the framebuffer.
+  /// [`FramebufferError::CannotReadPixels`] is returned by backends not supporting reading depth values back, and if
+  /// the framebuffer has no depth buffer to read from. With `DS = ()`, the depth values come from the depth buffer the
+  /// backend might provide on its own, such as the one of the back buffer.
+  pub fn read_depth(
+    &self,
+    region: impl Into<Option<ScissorRegion>>,
+  ) -> Result<Vec<f32>, FramebufferError> {
+    let region = self.check_region(region.into())?;
+    unsafe { B::read_depth_pixels(&self.repr, &region) }
+  }
+}
+
+impl<B, CS, DS> Framebuffer<B, Dim2, CS, DS>
+where
+  B: ?Sized + FramebufferBackend<Dim2>,
+  CS: ColorSlot<B, Dim2>,
+  DS: DepthStencilSlot<B, Dim2>,
+{
+  // Check that a region fits in the framebuffer; None is the whole framebuffer.
+  fn check_region(&self, region: Option<ScissorRegion>) -> Result<ScissorRegion, FramebufferError> {
+    let [width, height] = self.size();
+
+    match region {
+      None => Ok(ScissorRegion::new(0, 0, width, height)),
+
+      Some(region) => {
+        let fits =
+          |start: u32, len: u32, max: u32| start.checked_add(len).map_or(false, |end| end <= max);
+
+        if fits(region.x, region.width, width) && fits(region.y, region.height, height) {
+          Ok(region)
+        } else {
+          Err(FramebufferError::region_out_of_bounds(region))
+        }
+      }
+    }
+  }
+}
+
+impl<B, CS, DS> Framebuffer<B, Dim2, CS, DS>
+where
+  B: ?Sized + FramebufferBackend<Dim2> + FramebufferBlit,
+  CS: ColorSlot<B, Dim2>,
+  DS: DepthStencilSlot<B, Dim2>,
+{
+  /// Copy a region of this framebuffer to a region of `dst`.
+  ///
+  /// `src_region` and `dst_region` select the regions to copy from and to, with the origin at the bottom-left corner of
+  /// the framebuffers; `None` selects the whole framebuffer. If the regions have different sizes, the copied pixels are
+  /// scaled with `filter`. `mask` selects which of the color, depth and stencil buffers are copied. Blitting is the way
+  /// to resolve multisampled framebuffers and to downsample render targets.
+  ///
+  /// # Errors
+  ///
+  /// [`FramebufferError::RegionOutOfBounds`] is returned if a region is not fully contained in its framebuffer.
+  /// [`FramebufferError::UnsupportedBlitFilter`] is returned if depth or stencil values are copied with
+  /// [`BlitFilter::Linear`], as those can only be copied with [`BlitFilter::Nearest`].
+  ///
+  /// # Notes
+  ///
+  /// You might be interested in the [`GraphicsContext::blit_framebuffer`] function instead, which is the exact same
+  /// function.
+  pub fn blit<C, DCS, DDS>(
+    &self,
+    ctx: &mut C,
+    dst: &Framebuffer<B, Dim2, DCS, DDS>,
+    src_region: impl Into<Option<ScissorRegion>>,
+    dst_region: impl Into<Option<ScissorRegion>>,
+    mask: BlitMask,
+    filter: BlitFilter,
+  ) -> Result<(), FramebufferError>
+  where
+    C: GraphicsContext<Backend = B>,
+    DCS: ColorSlot<B, Dim2>,
+    DDS: DepthStencilSlot<B, Dim2>,
+  {
+    if (mask.depth || mask.stencil) && filter != BlitFilter::Nearest {
+      return Err(FramebufferError::unsupported_blit_filter(filter));
+    }
+
+    let src_region = self.check_region(src_region.into())?;
+    let dst_region = dst.check_region(dst_region.into())?;
+
+    unsafe {
+      ctx.backend().blit_framebuffer(
+        &self.repr,
+        &dst.repr,
+        &src_region,
+        &dst_region,
+        mask,
+        filter,
+      )
+    }
+  }
+}
+
 impl<B> Framebuffer<B, Dim2, (), ()>
 where
   B: ?Sized + FramebufferBackend<Dim2> + FramebufferBackBuffer,
@@ -215,6 +428,14 @@ pub enum FramebufferError {
   Incomplete(IncompleteReason),
   /// Cannot attach something to a framebuffer.
   UnsupportedAttachment,
+  /// A region to read pixels from is not fully contained in the framebuffer.
+  RegionOutOfBounds(ScissorRegion),
+  /// Cannot read pixels back from the framebuffer.
+  CannotReadPixels(String),
+  /// The filter cannot be used to blit the selected buffers.
+  UnsupportedBlitFilter(BlitFilter),
+  /// The textures to attach don’t have the same size.
+  AttachmentSizeMismatch,
 }
 
 impl FramebufferError {
@@ -237,6 +458,26 @@ impl FramebufferError {
   pub fn unsupported_attachment() -> Self {
     FramebufferError::UnsupportedAttachment
   }
+
+  /// A region to read pixels from is not fully contained in the framebuffer.
+  pub fn region_out_of_bounds(region: ScissorRegion) -> Self {
+    FramebufferError::RegionOutOfBounds(region)
+  }
+
+  /// Cannot read pixels back from the framebuffer.
+  pub fn cannot_read_pixels(reason: impl Into<String>) -> Self {
+    FramebufferError::CannotReadPixels(reason.into())
+  }
+
+  /// The filter cannot be used to blit the selected buffers.
+  pub fn unsupported_blit_filter(filter: BlitFilter) -> Self {
+    FramebufferError::UnsupportedBlitFilter(filter)
+  }
+
+  /// The textures to attach don’t have the same size.
+  pub fn attachment_size_mismatch() -> Self {
+    FramebufferError::AttachmentSizeMismatch
+  }
 }
 
 impl fmt::Display for FramebufferError {
@@ -251,6 +492,24 @@ impl fmt::Display for FramebufferError {
       FramebufferError::Incomplete(ref e) => write!(f, "incomplete framebuffer: {}", e),
 
       FramebufferError::UnsupportedAttachment => f.write_str("unsupported framebuffer attachment"),
+
+      FramebufferError::RegionOutOfBounds(ref region) => {
+        write!(f, "region out of framebuffer bounds: {:?}", region)
+      }
+
+      FramebufferError::CannotReadPixels(ref reason) => {
+        write!(f, "cannot read framebuffer pixels: {}", reason)
+      }
+
+      FramebufferError::UnsupportedBlitFilter(ref filter) => write!(
+        f,
+        "depth and stencil values cannot be blitted with the {:?} filter",
+        filter
+      ),
+
+      FramebufferError::AttachmentSizeMismatch => {
+        f.write_str("framebuffer attachments don’t have the same size")
+      }
     }
   }
 }
@@ -262,6 +521,10 @@ impl std::error::Error for FramebufferError {
       FramebufferError::TextureError(e) => Some(e),
       FramebufferError::Incomplete(e) => Some(e),
       FramebufferError::UnsupportedAttachment => None,
+      FramebufferError::RegionOutOfBounds(_) => None,
+      FramebufferError::CannotReadPixels(_) => None,
+      FramebufferError::UnsupportedBlitFilter(_) => None,
+      FramebufferError::AttachmentSizeMismatch => None,
     }
   }
 }
@@ -278,6 +541,62 @@ impl From<IncompleteReason> for FramebufferError {
   }
 }
 
+/// Buffers to copy when blitting framebuffers.
+///
+/// See [`Framebuffer::blit`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct BlitMask {
+  /// Copy the color buffers.
+  pub color: bool,
+  /// Copy the depth buffer.
+  pub depth: bool,
+  /// Copy the stencil buffer.
+  pub stencil: bool,
+}
+
+impl BlitMask {
+  /// Copy only the color buffers.
+  pub const COLOR: Self = BlitMask {
+    color: true,
+    depth: false,
+    stencil: false,
+  };
+
+  /// Copy only the depth buffer.
+  pub const DEPTH: Self = BlitMask {
+    color: false,
+    depth: true,
+    stencil: false,
+  };
+
+  /// Copy only the stencil buffer.
+  pub const STENCIL: Self = BlitMask {
+    color: false,
+    depth: false,
+    stencil: true,
+  };
+
+  /// Copy the color, depth and stencil buffers.
+  pub const ALL: Self = BlitMask {
+    color: true,
+    depth: true,
+    stencil: true,
+  };
+}
+
+/// Filter used to scale the pixels when blitting framebuffers.
+///
+/// See [`Framebuffer::blit`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum BlitFilter {
+  /// Nearest interpolation.
+  Nearest,
+  /// Linear interpolation between surrounding pixels.
+  ///
+  /// Only color buffers can be blitted with linear interpolation.
+  Linear,
+}
+
 /// Reason a framebuffer is incomplete.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum IncompleteReason {
@@ -0,0 +1,204 @@
+//! Indirect draw commands.
+//!
+//! When rendering a [`TessView`], the vertex range and the number of instances are provided by the CPU. Indirect
+//! rendering moves those parameters to a GPU buffer, the [`IndirectBuffer`], so that they can be written by the GPU
+//! itself (e.g. by a culling pass) without any round-trip to the CPU.
+//!
+//! An [`IndirectBuffer`] holds draw commands. Two kinds of commands exist:
+//!
+//! - [`DrawArraysCommand`], to render [`Tess`] without indices.
+//! - [`DrawElementsCommand`], to render [`Tess`] with indices.
+//!
+//! Commands are rendered with [`TessGate::render_indirect`]. Indirect rendering is an optional feature of backends:
+//! creating an [`IndirectBuffer`] on a backend that doesn’t support it fails with [`IndirectError::Unsupported`].
+//!
+//! [`Tess`]: crate::tess::Tess
+//! [`TessView`]: crate::tess::TessView
+//! [`TessGate::render_indirect`]: crate::tess_gate::TessGate::render_indirect
+
+use crate::{backend::indirect::IndirectBuffer as IndirectBufferBackend, context::GraphicsContext};
+use std::{error, fmt};
+
+/// Class of indirect draw commands.
+///
+/// You shouldn’t have to worry too much about that trait. Have a look at the current implementors for an exhaustive
+/// list of commands you can use.
+///
+/// # Safety
+///
+/// Backends read the commands straight from the memory layout of the implementors, which must then match the layout
+/// expected by the GPU.
+pub unsafe trait IndirectCommand: Copy {
+  /// Whether the command renders indexed [`Tess`](crate::tess::Tess).
+  const INDEXED: bool;
+}
+
+/// Command to render [`Tess`](crate::tess::Tess) without indices.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct DrawArraysCommand {
+  /// Number of vertices to render.
+  pub vert_nb: u32,
+  /// Number of instances to render.
+  pub inst_nb: u32,
+  /// Index of the first vertex to render.
+  pub start_index: u32,
+  /// Index of the first instance to render.
+  ///
+  /// Backends not supporting rendering from a base instance require it to be zero.
+  pub inst_start_index: u32,
+}
+
+impl DrawArraysCommand {
+  /// Create a command rendering `vert_nb` vertices, starting at `start_index`, for `inst_nb` instances.
+  pub fn new(start_index: u32, vert_nb: u32, inst_nb: u32) -> Self {
+    DrawArraysCommand {
+      vert_nb,
+      inst_nb,
+      start_index,
+      inst_start_index: 0,
+    }
+  }
+}
+
+unsafe impl IndirectCommand for DrawArraysCommand {
+  const INDEXED: bool = false;
+}
+
+/// Command to render [`Tess`](crate::tess::Tess) with indices.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct DrawElementsCommand {
+  /// Number of indices to render.
+  pub vert_nb: u32,
+  /// Number of instances to render.
+  pub inst_nb: u32,
+  /// Position of the first index to render in the index buffer.
+  pub start_index: u32,
+  /// Value added to every index before fetching vertices.
+  pub base_vertex: i32,
+  /// Index of the first instance to render.
+  ///
+  /// Backends not supporting rendering from a base instance require it to be zero.
+  pub inst_start_index: u32,
+}
+
+impl DrawElementsCommand {
+  /// Create a command rendering `vert_nb` indices, starting at `start_index`, for `inst_nb` instances.
+  pub fn new(start_index: u32, vert_nb: u32, inst_nb: u32) -> Self {
+    DrawElementsCommand {
+      vert_nb,
+      inst_nb,
+      start_index,
+      base_vertex: 0,
+      inst_start_index: 0,
+    }
+  }
+}
+
+unsafe impl IndirectCommand for DrawElementsCommand {
+  const INDEXED: bool = true;
+}
+
+/// Indirect buffer error.
+#[non_exhaustive]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum IndirectError {
+  /// Indirect rendering is not supported by the backend.
+  Unsupported,
+  /// Index out of bounds.
+  OutOfBounds {
+    /// Faulty index.
+    index: usize,
+  },
+  /// Cannot set a command in the indirect buffer.
+  CannotSetData {
+    /// Faulty index.
+    index: usize,
+  },
+}
+
+impl IndirectError {
+  /// Indirect rendering is not supported by the backend.
+  pub fn unsupported() -> Self {
+    IndirectError::Unsupported
+  }
+
+  /// Index out of bounds.
+  pub fn out_of_bounds(index: usize) -> Self {
+    IndirectError::OutOfBounds { index }
+  }
+
+  /// Cannot set a command in the indirect buffer.
+  pub fn cannot_set_data(index: usize) -> Self {
+    IndirectError::CannotSetData { index }
+  }
+}
+
+impl fmt::Display for IndirectError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    match *self {
+      IndirectError::Unsupported => f.write_str("indirect rendering is not supported"),
+      IndirectError::OutOfBounds { index } => {
+        write!(f, "cannot access indirect command at index {}", index)
+      }
+      IndirectError::CannotSetData { index } => {
+        write!(f, "cannot set indirect command at index {}", index)
+      }
+    }
+  }
+}
+
+impl error::Error for IndirectError {}
+
+/// A GPU buffer of indirect draw commands.
+///
+/// `C` is the type of commands held by the buffer; see [`IndirectCommand`].
+pub struct IndirectBuffer<B, C>
+where
+  B: ?Sized + IndirectBufferBackend<C>,
+  C: IndirectCommand,
+{
+  pub(crate) repr: B::IndirectBufferRepr,
+}
+
+impl<B, C> IndirectBuffer<B, C>
+where
+  B: ?Sized + IndirectBufferBackend<C>,
+  C: IndirectCommand,
+{
+  /// Create a new [`IndirectBuffer`] holding `commands`.
+  pub fn new<G>(ctx: &mut G, commands: impl IntoIterator<Item = C>) -> Result<Self, IndirectError>
+  where
+    G: GraphicsContext<Backend = B>,
+  {
+    unsafe {
+      ctx
+        .backend()
+        .new_indirect_buffer(commands.into_iter())
+        .map(|repr| IndirectBuffer { repr })
+    }
+  }
+
+  /// Number of commands held by the [`IndirectBuffer`].
+  pub fn len(&self) -> usize {
+    unsafe { B::indirect_buffer_len(&self.repr) }
+  }
+
+  /// Whether the [`IndirectBuffer`] holds no command.
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// Get the command at index `i`.
+  pub fn at(&self, i: usize) -> Result<C, IndirectError> {
+    unsafe { B::indirect_command_at(&self.repr, i) }
+  }
+
+  /// Set the command at index `i`.
+  ///
+  /// Return the previous command.
+  pub fn set(&mut self, i: usize, command: C) -> Result<C, IndirectError> {
+    unsafe { B::set_indirect_command_at(&mut self.repr, i, command) }
+  }
+}
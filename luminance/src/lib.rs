@@ -196,6 +196,12 @@
 //!   type family that associates a single constant (i.e. the semantics) that the vertex attribute
 //!   uses.
 //! - Each field's type must be different.
+//! - The size of each field's type must match the size of the [`VertexAttrib`] it represents. This is checked at
+//!   compile-time, as a mismatch would silently break the memory layout of the vertices.
+//! - The semantics of each field's type must be the semantics of the struct. This is checked at compile-time too, with
+//!   errors pointing at the faulty fields.
+//! - The [`VertexAttrib`] of each field's type must match the `repr` type declared for its semantics, if any (see
+//!   [`HasSemantics::REPR_VERTEX_ATTRIB_DESC`]). This is checked at compile-time too.
 //!
 //! Once all those requirements are met, you can derive [`Vertex`] pretty easily.
 //!
@@ -234,14 +240,76 @@
 //! - Create a type called `MyVertex`, a struct that will hold a single vertex.
 //! - Implement `Vertex for MyVertex`.
 //!
+//! With the optional `#[vertex(slice_set)]` struct attribute, the proc-macro also creates a type called
+//! `MyVertexSliceSet`, a struct holding one slice per field of `MyVertex`, used to read all the attributes of
+//! deinterleaved vertices at once (see [`Tess::vertices_all`]). Flattened fields must then have that attribute too.
+//!
 //! The proc-macro also supports an optional `#[vertex(instanced = "<bool>")]` struct attribute.
 //! This attribute allows you to specify whether the fields are to be instanced or not. For more
 //! about that, have a look at [`VertexInstancing`].
 //!
+//! Fields can also be assigned to a _buffer group_ with the `#[vertex(buffer = "<index>")]` field attribute. Fields
+//! sharing the same buffer group are interleaved together in their own vertex buffer, which is handy when — for
+//! instance — positions are stored apart from the rest of the attributes. Fields without that attribute go in the
+//! buffer group `0`. See [`VertexBufferDesc::buffer`] for further details.
+//!
+//! Fields are fed to vertex shaders at the index of their semantics. If your vertex shaders declare the locations of
+//! their inputs explicitly, with `layout (location = N)`, a field can be pinned to that location with the
+//! `#[vertex(location = N)]` field attribute, so that the same vertex type can be shared among shaders using fixed
+//! locations (see [`VertexBufferDesc::with_location`]). Matrix fields occupy one location per column; fields whose
+//! locations overlap fail to compile, with an error pointing at the faulty location. Locations must not overlap the
+//! semantics indices of the fields without that attribute either, which makes getting the [`VertexDesc`] of the vertex
+//! type panic.
+//!
+//! Attributes shared by several vertex types can be gathered in a vertex type of their own and embedded with the
+//! `#[vertex(flatten)]` field attribute. The attributes of the field are then spliced into the vertex type at the
+//! position of the field, with the instancing of the vertex type, and the field holds the slice set of its type in the
+//! generated `<Name>SliceSet`. A flattened field can be assigned to a buffer group, which applies to all of its
+//! attributes, but cannot have a location nor be normalized. Its type must use the same semantics, must not have
+//! flattened fields itself, and must neither have trailing padding nor be more aligned than its first attribute, so
+//! that its attributes are laid out as if they were fields of the vertex type; this is checked at compile-time (see
+//! [`FlattenVertex`]). Its semantics must not be used by other fields, which is checked at compile-time too.
+//! Flattened attributes cannot be set one by one on deinterleaved tessellations:
+//!
+//! ```rust
+//! # use luminance::{Vertex, Semantics};
+//! # #[derive(Clone, Copy, Debug, PartialEq, Semantics)]
+//! # pub enum Semantics {
+//! #   #[sem(name = "position", repr = "[f32; 3]", wrapper = "VertexPosition")]
+//! #   Position,
+//! #   #[sem(name = "normal", repr = "[f32; 3]", wrapper = "VertexNormal")]
+//! #   Normal,
+//! #   #[sem(name = "color", repr = "[f32; 4]", wrapper = "VertexColor")]
+//! #   Color,
+//! # }
+//! #[derive(Clone, Copy, Debug, PartialEq, Vertex)]
+//! #[vertex(sem = "Semantics")]
+//! #[repr(C)]
+//! struct Transform {
+//!   position: VertexPosition,
+//!   normal: VertexNormal,
+//! }
+//!
+//! #[derive(Clone, Copy, Debug, PartialEq, Vertex)]
+//! #[vertex(sem = "Semantics")]
+//! #[repr(C)]
+//! struct ColoredVertex {
+//!   #[vertex(flatten)]
+//!   transform: Transform,
+//!   color: VertexColor,
+//! }
+//! ```
+//!
 //! ### `Semantics`
 //!
 //! The [`Semantics`] derive proc-macro.
 //!
+//! Semantics are given consecutive indices, in declaration order. Matrix attributes, such as
+//! `repr = "[[f32; 4]; 4]"`, occupy one index per column, shifting the indices of the next semantics accordingly.
+//!
+//! The generated wrapper types also set [`HasSemantics::REPR_VERTEX_ATTRIB_DESC`] to the [`VertexAttribDesc`] of the
+//! `repr` type declared for their semantics.
+//!
 //! ### `UniformInterface`
 //!
 //! The [`UniformInterface`] derive proc-macro.
@@ -305,17 +373,69 @@
 //! }
 //! ```
 //!
+//! Arrays of uniforms, such as `uniform vec3 light_positions[8];`, are mapped with the [`Arr`] type, which length is
+//! the length of the array in the shader program:
+//!
+//! ```
+//! # use luminance::{shader::{types::{Arr, Vec3}, Uniform}, UniformInterface};
+//! #[derive(Debug, UniformInterface)]
+//! struct MyIface {
+//!   light_positions: Uniform<Arr<Vec3<f32>, 8>>,
+//! }
+//! ```
+//!
+//! Setting such a uniform uploads all the elements of the array at once. The length of the [`Arr`] must match the
+//! length of the array in the shader program: if it doesn’t, the mapping fails with a `UniformWarning::SizeMismatch`
+//! (which makes the whole struct fail to build, unless the field is marked `#[uniform(unbound)]`). There is no
+//! partial upload: declare the exact length used in the shader.
+//!
+//! ### `Std140`
+//!
+//! The [`Std140`] derive proc-macro.
+//!
+//! Uniform blocks, backed by [`ShaderData`], store their data with the `std140` layout, which aligns every field to
+//! rules of its own. Deriving [`Std140`] for a struct gives it its `std140` representation, field by field, so that it
+//! can be used as the element type of a [`ShaderData`] and mapped to a struct of the same layout in the shader. The
+//! crate deriving [`Std140`] must depend on [luminance-std140], which provides the trait and the representations of
+//! the field types:
+//!
+//! ```ignore
+//! use luminance::{shader::types::{Mat44, Vec3}, Std140};
+//!
+//! #[derive(Clone, Copy, Debug, Std140)]
+//! struct Light {
+//!   view_projection: Mat44<f32>,
+//!   color: Vec3<f32>,
+//! }
+//! ```
+//!
+//! Only structs with named fields and without generic parameters are supported. `std140` packs a scalar following a
+//! `vec3` in the padding of the vector; such a field layout is rejected, so put the scalar before the vector instead,
+//! both in the struct and in the shader. The representation is a hidden struct named after the deriving struct, with
+//! the `Std140` suffix (e.g. `LightStd140`), declared next to it.
+//!
 //! [luminance]: https://crates.io/crates/luminance
 //! [luminance-gl]: https://crates.io/crates/luminance-gl
 //! [luminance-front]: https://crates.io/crates/luminance-front
 //! [luminance::backend]: crate::backend
 //! [`Semantics`]: https://docs.rs/luminance/latest/luminance/vertex/trait.Semantics.html
+//! [`HasSemantics::REPR_VERTEX_ATTRIB_DESC`]: https://docs.rs/luminance/latest/luminance/vertex/trait.HasSemantics.html#associatedconstant.REPR_VERTEX_ATTRIB_DESC
 //! [`HasSemantics`]: https://docs.rs/luminance/latest/luminance/vertex/trait.HasSemantics.html
 //! [`Tess`]: https://docs.rs/luminance/latest/luminance/tess/struct.Tess.html
+//! [`Tess::vertices_all`]: https://docs.rs/luminance/latest/luminance/tess/struct.Tess.html#method.vertices_all
 //! [`Vertex`]: https://docs.rs/luminance/latest/luminance/vertex/trait.Vertex.html
 //! [`VertexAttrib`]: https://docs.rs/luminance/latest/luminance/vertex/trait.VertexAttrib.html
+//! [`VertexAttribDesc`]: https://docs.rs/luminance/latest/luminance/vertex/struct.VertexAttribDesc.html
+//! [`VertexDesc`]: https://docs.rs/luminance/latest/luminance/vertex/type.VertexDesc.html
 //! [`VertexInstancing`]: https://docs.rs/luminance/latest/luminance/vertex/enum.VertexInstancing.html
+//! [`VertexBufferDesc::buffer`]: https://docs.rs/luminance/latest/luminance/vertex/struct.VertexBufferDesc.html#structfield.buffer
+//! [`VertexBufferDesc::with_location`]: https://docs.rs/luminance/latest/luminance/vertex/struct.VertexBufferDesc.html#method.with_location
+//! [`FlattenVertex`]: https://docs.rs/luminance/latest/luminance/vertex/trait.FlattenVertex.html
 //! [`UniformInterface`]: https://docs.rs/luminance/latest/luminance/shader/program/trait.UniformInterface.html
+//! [`Arr`]: crate::shader::types::Arr
+//! [`Std140`]: https://docs.rs/luminance-std140/latest/luminance_std140/trait.Std140.html
+//! [`ShaderData`]: crate::shader::ShaderData
+//! [luminance-std140]: https://crates.io/crates/luminance-std140
 
 #![doc(
   html_logo_url = "https://raw.githubusercontent.com/phaazon/luminance-rs/master/docs/imgs/luminance_alt.svg"
@@ -327,10 +447,14 @@ pub use luminance_derive::*;
 
 pub mod backend;
 pub mod blending;
+pub mod compute;
 pub mod context;
+pub mod debug;
 pub mod depth_stencil;
 pub mod face_culling;
+pub mod fence;
 pub mod framebuffer;
+pub mod indirect;
 pub mod pipeline;
 pub mod pixel;
 pub mod query;
@@ -339,7 +463,9 @@ pub mod render_state;
 pub mod scissor;
 pub mod shader;
 pub mod shading_gate;
+pub mod surface;
 pub mod tess;
 pub mod tess_gate;
 pub mod texture;
+pub mod timer;
 pub mod vertex;
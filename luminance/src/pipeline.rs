@@ -120,6 +120,7 @@
 use std::{
   error, fmt,
   marker::PhantomData,
+  mem,
   ops::{Deref, DerefMut},
 };
 
@@ -132,25 +133,60 @@ use crate::{
   },
   context::GraphicsContext,
   framebuffer::Framebuffer,
-  pixel::Pixel,
+  pixel::{DepthPixel, Pixel, PixelFormat, Shadow, Type},
   scissor::ScissorRegion,
   shader::ShaderData,
   shading_gate::ShadingGate,
+  tess::TessError,
   texture::{Dimensionable, Texture},
 };
 
 /// Possible errors that might occur in a graphics [`Pipeline`].
 #[non_exhaustive]
 #[derive(Debug, Eq, PartialEq)]
-pub enum PipelineError {}
+pub enum PipelineError {
+  /// An integer clear color was set for an encoding that none of the color slots of the framebuffer has.
+  ///
+  /// See [`PipelineState::clear_color_i`] and [`PipelineState::clear_color_u`].
+  IncompatibleClearColor(Type),
+  /// A tessellation failed to render.
+  TessError(TessError),
+}
+
+impl PipelineError {
+  /// An integer clear color was set for an encoding that none of the color slots of the framebuffer has.
+  pub fn incompatible_clear_color(encoding: Type) -> Self {
+    PipelineError::IncompatibleClearColor(encoding)
+  }
+
+  /// A tessellation failed to render.
+  pub fn tess_error(e: TessError) -> Self {
+    PipelineError::TessError(e)
+  }
+}
 
 impl fmt::Display for PipelineError {
-  fn fmt(&self, _: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-    Ok(())
+  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    match *self {
+      PipelineError::IncompatibleClearColor(ref encoding) => write!(
+        f,
+        "cannot use an integer clear color: no color slot with {:?} encoding",
+        encoding
+      ),
+
+      PipelineError::TessError(ref e) => write!(f, "tessellation failed to render: {}", e),
+    }
   }
 }
 
-impl error::Error for PipelineError {}
+impl error::Error for PipelineError {
+  fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+    match self {
+      PipelineError::TessError(e) => Some(e),
+      _ => None,
+    }
+  }
+}
 
 /// The viewport being part of the [`PipelineState`].
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -169,6 +205,50 @@ pub enum Viewport {
     /// The height of the viewport.
     height: u32,
   },
+  /// The viewport is specific and the rectangle area is user-defined, with its origin at the top-left corner of the
+  /// framebuffer.
+  ///
+  /// This is the same as [`Viewport::Specific`], but the Y axis goes down from the top of the framebuffer, as in most
+  /// UI toolkits.
+  SpecificTopLeft {
+    /// The left position on the X axis to start the viewport rectangle at.
+    x: u32,
+    /// The upper position on the Y axis to start the viewport rectangle at, from the top of the framebuffer.
+    y: u32,
+    /// The width of the viewport.
+    width: u32,
+    /// The height of the viewport.
+    height: u32,
+  },
+}
+
+impl Viewport {
+  /// Compute the `[x, y, width, height]` rectangle of the viewport for a framebuffer of size `framebuffer_width` ×
+  /// `framebuffer_height`, with its origin at the bottom-left corner of the framebuffer.
+  ///
+  /// A [`Viewport::SpecificTopLeft`] rectangle going past the bottom of the framebuffer gets a `y` of `0`.
+  pub fn bottom_left_rect(self, framebuffer_width: u32, framebuffer_height: u32) -> [u32; 4] {
+    match self {
+      Viewport::Whole => [0, 0, framebuffer_width, framebuffer_height],
+
+      Viewport::Specific {
+        x,
+        y,
+        width,
+        height,
+      } => [x, y, width, height],
+
+      Viewport::SpecificTopLeft {
+        x,
+        y,
+        width,
+        height,
+      } => {
+        let y = framebuffer_height.saturating_sub(y.saturating_add(height));
+        [x, y, width, height]
+      }
+    }
+  }
 }
 
 /// Various customization options for pipelines.
@@ -182,21 +262,64 @@ pub struct PipelineState {
   ///
   /// An example of not setting the clear color is if you want to accumulate renders in a [`Framebuffer`] (for instance
   /// for a paint-like application).
+  ///
+  /// Color slots with integral pixel formats (see [`PixelFormat::is_integral`]) are cleared with the clear color
+  /// converted to integers, so that a clear color of `[0., 0., 0., 0.]` clears an integer identifier target to `0`.
+  ///
+  /// [`PixelFormat::is_integral`]: crate::pixel::PixelFormat::is_integral
   pub clear_color: Option<[f32; 4]>,
 
+  /// Colors to use when clearing each color buffer, in color slot order.
+  ///
+  /// This is useful with _multiple render targets_, when each color slot needs a different clear color. Color slots
+  /// without a color in this list are cleared with [`PipelineState::clear_color`], if any. See
+  /// [`PipelineState::attachment_clear_color`].
+  pub clear_colors: Option<Vec<[f32; 4]>>,
+
+  /// Color to use when clearing color buffers with a signed integral pixel format ([`Type::Integral`]).
+  ///
+  /// When set to `None`, such color buffers are cleared with their floating-point clear color converted to integers.
+  /// When set, the framebuffer must have at least one color slot with a signed integral pixel format, or
+  /// [`PipelineError::IncompatibleClearColor`] is returned when running the [`PipelineGate`].
+  pub clear_color_i: Option<[i32; 4]>,
+
+  /// Color to use when clearing color buffers with an unsigned integral pixel format ([`Type::Unsigned`]).
+  ///
+  /// When set to `None`, such color buffers are cleared with their floating-point clear color converted to integers.
+  /// When set, the framebuffer must have at least one color slot with an unsigned integral pixel format, or
+  /// [`PipelineError::IncompatibleClearColor`] is returned when running the [`PipelineGate`].
+  pub clear_color_u: Option<[u32; 4]>,
+
   /// Depth value to use when clearing the depth buffer.
   ///
-  /// Set this to `Some(depth)` to use that depth to clear the [`Framebuffer`] depth buffer.
+  /// Set this to `Some(depth)` to use that depth to clear the [`Framebuffer`] depth buffer. Set it to `None` not to
+  /// clear the depth buffer when running the [`PipelineGate`]: its contents are then preserved from the previous
+  /// pipelines, independently of [`PipelineState::clear_color`]. This is useful to clear colors every frame while
+  /// keeping depth around, for instance.
+  ///
+  /// The contents of the back buffer are only guaranteed to be preserved until it is presented: once the buffers of a
+  /// surface are swapped, whether the depth buffer still holds its previous contents depends on the platform.
+  ///
+  /// The clear depth is expressed in window-space depth and is not remapped by [`PipelineState::depth_range`]: with a
+  /// reversed depth range of `(1., 0.)`, for instance, the far plane is cleared with `0.`.
   pub clear_depth: Option<f32>,
 
   /// Stencil value to use when clearing the stencil buffer.
   ///
-  /// Set this to `Some(stencil)` to use that stencil to clear the [`Framebuffer`] stencil buffer.
+  /// Set this to `Some(stencil)` to use that stencil to clear the [`Framebuffer`] stencil buffer. Set it to `None` not
+  /// to clear the stencil buffer when running the [`PipelineGate`], preserving its contents.
   pub clear_stencil: Option<i32>,
 
   /// Viewport to use when rendering.
   pub viewport: Viewport,
 
+  /// Depth range to use when rendering, as `(near, far)`.
+  ///
+  /// Normalized device depth is mapped to window-space depth so that the near plane ends up at `near` and the far plane
+  /// at `far`. Both values are clamped to `[0, 1]` by the backend. Setting `near` greater than `far` is allowed and
+  /// useful for reversed-Z depth buffers.
+  pub depth_range: (f32, f32),
+
   /// Whether [sRGB](https://en.wikipedia.org/wiki/SRGB) support should be enabled.
   ///
   /// When this is set to `true`, shader outputs that go in [`Framebuffer`] for each of the color slots have sRGB pixel
@@ -206,9 +329,19 @@ pub struct PipelineState {
   /// Typical examples are when you are rendering into an image that is to be displayed to on screen: the
   /// [`Framebuffer`] can use sRGB color pixel formats and the shader doesn’t have to worry about converting from linear
   /// color space into sRGB color space, as the pipeline will do that for you.
+  ///
+  /// The conversion applies to clear colors too: with sRGB enabled, [`PipelineState::clear_color`] is in the linear
+  /// RGB color space, so that clearing with a given color and outputting that same color from a shader end up with the
+  /// same value stored in sRGB color slots.
   pub srgb_enabled: bool,
 
-  /// Whether to use scissor test when clearing buffers.
+  /// Region of the framebuffer to clear.
+  ///
+  /// When set to `Some(region)`, only the texels in `region` are cleared; `None` clears the whole framebuffer. This
+  /// region only applies to clearing: it doesn’t restrict the draws performed in the pipeline, which use the scissor
+  /// region of their [`RenderState`] instead.
+  ///
+  /// [`RenderState`]: crate::render_state::RenderState
   pub clear_scissor: Option<ScissorRegion>,
 }
 
@@ -216,17 +349,24 @@ impl Default for PipelineState {
   /// Default [`PipelineState`]:
   ///
   /// - Clear color is `Some([0., 0., 0., 1.])`.
+  /// - No per-color slot clear colors.
+  /// - No integer clear colors.
   /// - Depth value is `Some(1.)`.
   /// - Stencil value is `Some(0)`.
   /// - The viewport uses the whole framebuffer’s.
+  /// - Depth range is `(0., 1.)`.
   /// - sRGB encoding is disabled.
-  /// - No scissor test is performed.
+  /// - The whole framebuffer is cleared.
   fn default() -> Self {
     PipelineState {
       clear_color: Some([0., 0., 0., 1.]),
+      clear_colors: None,
+      clear_color_i: None,
+      clear_color_u: None,
       clear_depth: Some(1.),
       clear_stencil: Some(0),
       viewport: Viewport::Whole,
+      depth_range: (0., 1.),
       srgb_enabled: false,
       clear_scissor: None,
     }
@@ -241,6 +381,25 @@ impl PipelineState {
     Self::default()
   }
 
+  /// Change a [`PipelineState`] in place with the by-value setters.
+  ///
+  /// This is useful to tweak a stored [`PipelineState`] (e.g. once per frame) without cloning it and reassigning it
+  /// afterwards:
+  ///
+  /// ```
+  /// # use luminance::pipeline::PipelineState;
+  /// let mut state = PipelineState::new().set_clear_depth(None);
+  ///
+  /// state.with(|state| state.set_clear_color([1., 0., 0., 1.]).enable_srgb(true));
+  ///
+  /// assert_eq!(state.clear_color(), Some(&[1., 0., 0., 1.]));
+  /// assert_eq!(state.clear_depth(), None);
+  /// ```
+  pub fn with(&mut self, f: impl FnOnce(Self) -> Self) -> &mut Self {
+    *self = f(mem::take(self));
+    self
+  }
+
   /// Get the clear color, if any.
   pub fn clear_color(&self) -> Option<&[f32; 4]> {
     self.clear_color.as_ref()
@@ -254,6 +413,73 @@ impl PipelineState {
     }
   }
 
+  /// Get the per-color slot clear colors, if any.
+  pub fn clear_colors(&self) -> Option<&[[f32; 4]]> {
+    self.clear_colors.as_deref()
+  }
+
+  /// Set the per-color slot clear colors.
+  pub fn set_clear_colors(self, clear_colors: impl Into<Option<Vec<[f32; 4]>>>) -> Self {
+    Self {
+      clear_colors: clear_colors.into(),
+      ..self
+    }
+  }
+
+  /// Get the color to clear the color slot at index `attachment` with, if any.
+  ///
+  /// The color is taken from [`PipelineState::clear_colors`] if it has a color for that slot, and from
+  /// [`PipelineState::clear_color`] otherwise.
+  pub fn attachment_clear_color(&self, attachment: usize) -> Option<[f32; 4]> {
+    self
+      .clear_colors
+      .as_ref()
+      .and_then(|colors| colors.get(attachment).copied())
+      .or(self.clear_color)
+  }
+
+  /// Get the signed integer clear color, if any.
+  pub fn clear_color_i(&self) -> Option<&[i32; 4]> {
+    self.clear_color_i.as_ref()
+  }
+
+  /// Set the signed integer clear color.
+  pub fn set_clear_color_i(self, clear_color_i: impl Into<Option<[i32; 4]>>) -> Self {
+    Self {
+      clear_color_i: clear_color_i.into(),
+      ..self
+    }
+  }
+
+  /// Get the unsigned integer clear color, if any.
+  pub fn clear_color_u(&self) -> Option<&[u32; 4]> {
+    self.clear_color_u.as_ref()
+  }
+
+  /// Set the unsigned integer clear color.
+  pub fn set_clear_color_u(self, clear_color_u: impl Into<Option<[u32; 4]>>) -> Self {
+    Self {
+      clear_color_u: clear_color_u.into(),
+      ..self
+    }
+  }
+
+  /// Check that the integer clear colors match the color slots described by `color_formats`.
+  fn check_clear_colors(&self, color_formats: &[PixelFormat]) -> Result<(), PipelineError> {
+    let requested = [
+      (self.clear_color_i.is_some(), Type::Integral),
+      (self.clear_color_u.is_some(), Type::Unsigned),
+    ];
+
+    for (set, encoding) in requested {
+      if set && !color_formats.iter().any(|f| f.encoding == encoding) {
+        return Err(PipelineError::incompatible_clear_color(encoding));
+      }
+    }
+
+    Ok(())
+  }
+
   /// Get the clear depth, if any.
   pub fn clear_depth(&self) -> Option<f32> {
     self.clear_depth
@@ -290,6 +516,19 @@ impl PipelineState {
     Self { viewport, ..self }
   }
 
+  /// Get the depth range.
+  pub fn depth_range(&self) -> (f32, f32) {
+    self.depth_range
+  }
+
+  /// Set the depth range.
+  pub fn set_depth_range(self, depth_range: (f32, f32)) -> Self {
+    Self {
+      depth_range,
+      ..self
+    }
+  }
+
   /// Check whether sRGB linearization is enabled.
   pub fn is_srgb_enabled(&self) -> bool {
     self.srgb_enabled
@@ -304,17 +543,42 @@ impl PipelineState {
   }
 
   /// Get the scissor configuration, if any.
+  ///
+  /// This is the same as [`PipelineState::clear_region`].
   pub fn scissor(&self) -> &Option<ScissorRegion> {
     &self.clear_scissor
   }
 
   /// Set the scissor configuration.
+  ///
+  /// This is the same as [`PipelineState::set_clear_region`].
   pub fn set_scissor(self, scissor: impl Into<Option<ScissorRegion>>) -> Self {
     Self {
       clear_scissor: scissor.into(),
       ..self
     }
   }
+
+  /// Get the region of the framebuffer to clear, if any.
+  pub fn clear_region(&self) -> Option<&ScissorRegion> {
+    self.clear_scissor.as_ref()
+  }
+
+  /// Set the region of the framebuffer to clear.
+  ///
+  /// `None` clears the whole framebuffer. The clear region doesn’t apply to draws; use
+  /// [`RenderState::set_scissor`] to restrict them to a region.
+  ///
+  /// The region is clamped to the framebuffer when running the pipeline (see [`ScissorRegion::clamp_to`]). A region
+  /// not overlapping the framebuffer, or with a zero area, clears nothing.
+  ///
+  /// [`RenderState::set_scissor`]: crate::render_state::RenderState::set_scissor
+  pub fn set_clear_region(self, region: impl Into<Option<ScissorRegion>>) -> Self {
+    Self {
+      clear_scissor: region.into(),
+      ..self
+    }
+  }
 }
 
 /// A GPU pipeline handle.
@@ -410,27 +674,47 @@ impl<'a, B> PipelineGate<'a, B> {
   /// graph). The closure is passed a [`Pipeline`] for you to dynamically alter the pipeline and a
   /// [`ShadingGate`] to enter shading nodes.
   ///
+  /// The value returned by the closure is carried by the returned [`Render`], so that data computed in the pipeline
+  /// (e.g. a value read back for picking) can flow out of it. Most of the time, the closure returns `()`.
+  ///
   /// # Errors
   ///
   /// [`PipelineError`] might be thrown for various reasons, depending on the backend you use.
   /// However, this method doesn’t return [`PipelineError`] directly: instead, it returns
   /// `E: From<PipelineError>`. This allows you to inject your own error type in the argument
   /// closure, allowing for a grainer control of errors inside the pipeline.
-  pub fn pipeline<E, D, CS, DS, F>(
+  pub fn pipeline<E, D, CS, DS, F, T>(
     &mut self,
     framebuffer: &Framebuffer<B, D, CS, DS>,
     pipeline_state: &PipelineState,
     f: F,
-  ) -> Render<E>
+  ) -> Render<E, T>
   where
     B: FramebufferBackend<D> + PipelineBackend<D>,
     D: Dimensionable,
     CS: ColorSlot<B, D>,
     DS: DepthStencilSlot<B, D>,
-    F: for<'b> FnOnce(Pipeline<'b, B>, ShadingGate<'b, B>) -> Result<(), E>,
+    F: for<'b> FnOnce(Pipeline<'b, B>, ShadingGate<'b, B>) -> Result<T, E>,
     E: From<PipelineError>,
   {
     let render = || {
+      pipeline_state.check_clear_colors(&CS::color_formats())?;
+
+      // backends expect a clear region lying in the framebuffer
+      let size = framebuffer.size();
+      let fb_size = [D::width(size), D::height(size)];
+      let clamped_state;
+      let pipeline_state = match pipeline_state.clear_region() {
+        Some(region) if region.clamp_to(fb_size) != *region => {
+          clamped_state = pipeline_state
+            .clone()
+            .set_clear_region(region.clamp_to(fb_size));
+          &clamped_state
+        }
+
+        _ => pipeline_state,
+      };
+
       unsafe {
         self
           .backend
@@ -457,21 +741,21 @@ impl<'a, B> PipelineGate<'a, B> {
 
 /// Output of a [`PipelineGate`].
 ///
-/// This type is used as a proxy over `Result<(), E>`, which it defers to. It is needed so that
-/// you can seamlessly call the [`assume`] method
+/// This type is used as a proxy over `Result<T, E>`, which it defers to. It is needed so that
+/// you can seamlessly call the [`assume`] method. `T` is the type of the value returned by the pipeline closure.
 ///
 /// [`assume`]: crate::pipeline::Render::assume
-pub struct Render<E>(Result<(), E>);
+pub struct Render<E, T = ()>(Result<T, E>);
 
-impl<E> Render<E> {
+impl<E, T> Render<E, T> {
   /// Turn a [`Render`] into a [`Result`].
   #[inline]
-  pub fn into_result(self) -> Result<(), E> {
+  pub fn into_result(self) -> Result<T, E> {
     self.0
   }
 }
 
-impl Render<PipelineError> {
+impl<T> Render<PipelineError, T> {
   /// Assume the error type is [`PipelineError`].
   ///
   /// Most of the time, users will not provide their own error types for pipelines. Rust doesn’t
@@ -483,21 +767,21 @@ impl Render<PipelineError> {
   }
 }
 
-impl<E> From<Render<E>> for Result<(), E> {
-  fn from(render: Render<E>) -> Self {
+impl<E, T> From<Render<E, T>> for Result<T, E> {
+  fn from(render: Render<E, T>) -> Self {
     render.0
   }
 }
 
-impl<E> Deref for Render<E> {
-  type Target = Result<(), E>;
+impl<E, T> Deref for Render<E, T> {
+  type Target = Result<T, E>;
 
   fn deref(&self) -> &Self::Target {
     &self.0
   }
 }
 
-impl<E> DerefMut for Render<E> {
+impl<E, T> DerefMut for Render<E, T> {
   fn deref_mut(&mut self) -> &mut Self::Target {
     &mut self.0
   }
@@ -660,4 +944,22 @@ where
       _phantom: PhantomData,
     }
   }
+
+  /// Obtain a [`TextureBinding`] object to refer to this bound depth texture with a shadow sampler in shader stages.
+  ///
+  /// Shadow samplers (e.g. `sampler2DShadow`) perform depth comparison. The texture must then have been created with
+  /// [`Sampler::depth_comparison`] set; otherwise, the result of sampling it is undefined. See [`Shadow`] for further
+  /// details.
+  ///
+  /// [`Sampler::depth_comparison`]: crate::texture::Sampler::depth_comparison
+  pub fn shadow_binding(&self) -> TextureBinding<D, Shadow>
+  where
+    P: DepthPixel,
+  {
+    let binding = unsafe { B::texture_binding(&self.repr) };
+    TextureBinding {
+      binding,
+      _phantom: PhantomData,
+    }
+  }
 }
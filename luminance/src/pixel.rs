@@ -44,6 +44,13 @@ pub unsafe trait RenderablePixel: Pixel {}
 pub unsafe trait SamplerType {
   /// Underlying type of the sampler.
   fn sample_type() -> Type;
+
+  /// Whether the sampler performs depth comparison.
+  ///
+  /// See [`Shadow`] for further details.
+  fn is_shadow() -> bool {
+    false
+  }
 }
 
 /// A `PixelFormat` gathers a `Type` along with a `Format`.
@@ -69,6 +76,21 @@ impl PixelFormat {
     !self.is_color_pixel()
   }
 
+  /// Does a [`PixelFormat`] represent non-normalized integers?
+  ///
+  /// Such formats must be read and written as integers in shader stages, and must be cleared with integer values.
+  pub fn is_integral(self) -> bool {
+    matches!(self.encoding, Type::Integral | Type::Unsigned)
+  }
+
+  /// Does a [`PixelFormat`] support filtering?
+  ///
+  /// Only filterable formats can be sampled with linear filters or get their mipmaps generated. Depth and integral
+  /// formats are not filterable.
+  pub fn is_filterable(self) -> bool {
+    !matches!(self.format, Format::Depth(_) | Format::DepthStencil(..)) && !self.is_integral()
+  }
+
   /// Return the number of channels.
   pub fn channels_len(self) -> usize {
     match self.format {
@@ -227,6 +249,30 @@ unsafe impl SamplerType for Floating {
   }
 }
 
+/// The depth comparison sampler type.
+///
+/// Depth textures are sampled with that sampler type in shaders declaring _shadow samplers_, such as
+/// `sampler2DShadow`. Sampling such textures doesn’t return their depth, but the result of the comparison of a
+/// reference value with it, as set with [`Sampler::depth_comparison`]. With a linear filter, the results of the
+/// comparisons of neighboring texels are interpolated, which is known as _percentage-closer filtering_ (PCF).
+///
+/// Bound textures get bindings with that sampler type with [`BoundTexture::shadow_binding`].
+///
+/// [`Sampler::depth_comparison`]: crate::texture::Sampler::depth_comparison
+/// [`BoundTexture::shadow_binding`]: crate::pipeline::BoundTexture::shadow_binding
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Shadow;
+
+unsafe impl SamplerType for Shadow {
+  fn sample_type() -> Type {
+    Type::Floating
+  }
+
+  fn is_shadow() -> bool {
+    true
+  }
+}
+
 macro_rules! impl_Pixel {
   ($t:ty, $encoding:ty, $raw_encoding:ty, $encoding_ty:ident, $format:expr) => {
     unsafe impl Pixel for $t {
@@ -3,7 +3,7 @@
 //! GPU queries allow to get information about the backend and the GPU in a straight-forward way.
 
 use crate::{
-  backend::query::{Query as QueryBackend, QueryError},
+  backend::query::{BackendLimits, GpuMemoryInfo, Query as QueryBackend, QueryError},
   context::GraphicsContext,
 };
 
@@ -53,4 +53,36 @@ where
   pub fn max_texture_array_elements(&self) -> Result<usize, QueryError> {
     self.backend.max_texture_array_elements()
   }
+
+  /// Limits of the backend, such as the maximum size of textures or the maximum number of color attachments.
+  ///
+  /// Those limits can be used to adapt resources before creating them, such as downscaling textures too big for the
+  /// backend, instead of failing to create them.
+  pub fn limits(&self) -> Result<BackendLimits, QueryError> {
+    self.backend.limits()
+  }
+
+  /// Memory information about the GPU, if available.
+  ///
+  /// This information is vendor-specific and is provided on a best-effort basis. For instance, OpenGL backends
+  /// require the `GL_NVX_gpu_memory_info` or `GL_ATI_meminfo` extension. `None` is returned when the backend cannot
+  /// provide that information.
+  ///
+  /// Available memory can be used to decide whether to evict resources when streaming large assets.
+  pub fn gpu_memory_info(&self) -> Option<GpuMemoryInfo> {
+    self.backend.gpu_memory_info()
+  }
+
+  /// Whether the back buffer is sRGB-capable.
+  ///
+  /// An sRGB-capable back buffer encodes the colors written to it when sRGB conversion is enabled (see
+  /// [`PipelineState::enable_srgb`]), so that linear colors output by shaders are displayed correctly. Whether the back
+  /// buffer is sRGB-capable depends on how the windowing crate created it; if it’s not, enabling sRGB conversion has no
+  /// effect, and colors must be encoded in shaders instead. Mismatches typically show as washed-out or too dark
+  /// pictures.
+  ///
+  /// [`PipelineState::enable_srgb`]: crate::pipeline::PipelineState::enable_srgb
+  pub fn back_buffer_is_srgb(&self) -> bool {
+    self.backend.back_buffer_is_srgb()
+  }
 }
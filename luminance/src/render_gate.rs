@@ -6,7 +6,10 @@
 //! [`Tess`]: crate::tess::Tess
 
 use crate::backend::render_gate::RenderGate as RenderGateBackend;
+use crate::backend::tess_gate::TessGate as TessGateBackend;
+use crate::pipeline::PipelineError;
 use crate::render_state::RenderState;
+use crate::tess::{TessIndex, TessVertexData, TessView};
 use crate::tess_gate::TessGate;
 
 /// A render gate.
@@ -40,4 +43,34 @@ where
 
     f(tess_gate)
   }
+
+  /// Enter a [`RenderGate`] and render all the [`TessView`]s of `tess_views`.
+  ///
+  /// This is equivalent to calling [`TessGate::render`] for each [`TessView`] in the closure of [`RenderGate::render`]:
+  /// the [`RenderState`] is applied once, then all the [`TessView`]s are rendered with it. It makes batches of objects
+  /// sharing the same [`RenderState`] explicit, and lets the backend skip redundant state changes between the draws.
+  ///
+  /// Rendering stops at the first [`TessView`] failing to render, which error is returned.
+  pub fn render_many<'b, E, T, V, I, W, S>(
+    &'b mut self,
+    rdr_st: &RenderState,
+    tess_views: impl IntoIterator<Item = T>,
+  ) -> Result<(), E>
+  where
+    E: From<PipelineError>,
+    B: TessGateBackend<V, I, W, S>,
+    T: Into<TessView<'b, B, V, I, W, S>>,
+    V: TessVertexData<S> + 'b,
+    I: TessIndex + 'b,
+    W: TessVertexData<S> + 'b,
+    S: ?Sized + 'b,
+  {
+    self.render(rdr_st, |mut tess_gate| {
+      for tess_view in tess_views {
+        tess_gate.render(tess_view.into())?;
+      }
+
+      Ok(())
+    })
+  }
 }
@@ -4,35 +4,180 @@
 //! blending, depth test or face culling operations.
 
 use crate::{
-  blending::{Blending, BlendingMode},
-  depth_stencil::{Comparison, StencilOperations, StencilTest, Write},
-  face_culling::FaceCulling,
+  blending::{Blending, BlendingMode, Equation, Factor},
+  depth_stencil::{Comparison, StencilOp, StencilOperations, StencilTest, Write},
+  face_culling::{FaceCulling, FaceCullingMode, FaceCullingOrder},
   scissor::ScissorRegion,
 };
 
+/// Number of draw buffers which blending configuration can be overridden with
+/// [`RenderState::set_draw_buffer_blending`].
+///
+/// That’s the minimum number of draw buffers guaranteed by OpenGL.
+pub const MAX_DRAW_BUFFER_BLENDING: usize = 8;
+
+/// Blending configuration of a draw buffer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DrawBufferBlending {
+  /// The draw buffer is blended with [`RenderState::blending`].
+  Inherit,
+  /// Blending is disabled for the draw buffer.
+  Disabled,
+  /// The draw buffer is blended with its own configuration.
+  Set(BlendingMode),
+}
+
+impl Default for DrawBufferBlending {
+  fn default() -> Self {
+    DrawBufferBlending::Inherit
+  }
+}
+
+impl From<Option<BlendingMode>> for DrawBufferBlending {
+  fn from(blending: Option<BlendingMode>) -> Self {
+    match blending {
+      Some(blending) => DrawBufferBlending::Set(blending),
+      None => DrawBufferBlending::Disabled,
+    }
+  }
+}
+
 /// GPU render state.
 ///
 /// You can get a default value with `RenderState::default` and set the operations you want with the
-/// various `RenderState::set_*` methods.
+/// various `RenderState::set_*` methods. Presets for the most common combinations are also available, such as
+/// [`RenderState::opaque`] or [`RenderState::alpha_blended`]; they can be customized with the `RenderState::set_*`
+/// methods as well.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct RenderState {
   /// Blending configuration.
   pub blending: Option<BlendingMode>,
+  /// Blending configuration of draw buffers, overriding [`RenderState::blending`] when set.
+  pub draw_buffer_blending: [DrawBufferBlending; MAX_DRAW_BUFFER_BLENDING],
   /// Depth test configuration.
   pub depth_test: Option<Comparison>,
   /// Depth write configuration.
   pub depth_write: Write,
+  /// Color write mask configuration.
+  pub color_mask: [bool; 4],
   /// Stencil test configuration.
   pub stencil_test: Option<StencilTest>,
   /// Stencil operations.
   pub stencil_operations: StencilOperations,
+  /// Stencil test configuration of back-facing primitives, if different from [`RenderState::stencil_test`].
+  pub back_stencil_test: Option<StencilTest>,
+  /// Stencil operations of back-facing primitives, if different from [`RenderState::stencil_operations`].
+  pub back_stencil_operations: Option<StencilOperations>,
+  /// Stencil write mask configuration.
+  pub stencil_write_mask: u8,
   /// Face culling configuration.
   pub face_culling: Option<FaceCulling>,
   /// Scissor region configuration.
   pub scissor: Option<ScissorRegion>,
+  /// Sample shading configuration.
+  pub sample_shading: Option<SampleShading>,
+  /// Alpha-to-coverage configuration.
+  pub alpha_to_coverage: bool,
+  /// Line width configuration.
+  pub line_width: LineWidth,
+  /// Point size configuration.
+  pub point_size: Option<PointSize>,
+  /// Polygon rasterization mode.
+  pub polygon_mode: PolygonMode,
+  /// Depth clamp configuration.
+  pub depth_clamp: bool,
+  /// Conservative rasterization configuration.
+  pub conservative_raster: bool,
 }
 
 impl RenderState {
+  /// The default render state, usable in a `const` context; see [`RenderState::default`].
+  const DEFAULT: Self = RenderState {
+    blending: None,
+    draw_buffer_blending: [DrawBufferBlending::Inherit; MAX_DRAW_BUFFER_BLENDING],
+    depth_test: Some(Comparison::Less),
+    depth_write: Write::On,
+    color_mask: [true; 4],
+    stencil_test: None,
+    stencil_operations: StencilOperations {
+      depth_passes_stencil_fails: StencilOp::Keep,
+      depth_fails_stencil_passes: StencilOp::Keep,
+      depth_stencil_pass: StencilOp::Keep,
+    },
+    back_stencil_test: None,
+    back_stencil_operations: None,
+    stencil_write_mask: 0xFF,
+    face_culling: None,
+    scissor: None,
+    sample_shading: None,
+    alpha_to_coverage: false,
+    line_width: LineWidth::DEFAULT,
+    point_size: Some(PointSize::DEFAULT),
+    polygon_mode: PolygonMode::Fill,
+    depth_clamp: false,
+    conservative_raster: false,
+  };
+
+  /// Render state for opaque objects.
+  ///
+  /// This is the [`RenderState::default`] with back faces culled: depth test with [`Comparison::Less`], depth
+  /// write on and blending off. Opaque objects are better rendered first, before any blended objects.
+  pub const fn opaque() -> Self {
+    RenderState {
+      face_culling: Some(FaceCulling::new(
+        FaceCullingOrder::CCW,
+        FaceCullingMode::Back,
+      )),
+      ..Self::DEFAULT
+    }
+  }
+
+  /// Render state for alpha-blended (transparent) objects.
+  ///
+  /// Fragments are blended with the alpha of their color ([`Factor::SrcAlpha`] and [`Factor::SrcAlphaComplement`]).
+  /// They are depth tested against opaque objects but don’t write to the depth buffer, so that transparent objects
+  /// don’t hide each other. No faces are culled, as the back faces of transparent objects are visible. Such objects
+  /// must be rendered after the opaque ones, sorted back to front.
+  pub const fn alpha_blended() -> Self {
+    RenderState {
+      blending: Some(BlendingMode::Combined(Blending {
+        equation: Equation::Additive,
+        src: Factor::SrcAlpha,
+        dst: Factor::SrcAlphaComplement,
+      })),
+      depth_write: Write::Off,
+      ..Self::DEFAULT
+    }
+  }
+
+  /// Render state for additive objects, such as particles, glows or lights.
+  ///
+  /// Fragments are added to the colors already in the framebuffer ([`Factor::One`] for both the source and destination
+  /// factors). As with [`RenderState::alpha_blended`], objects are depth tested but don’t write to the depth buffer and
+  /// no faces are culled. Addition being commutative, additive objects don’t need to be sorted.
+  pub const fn additive() -> Self {
+    RenderState {
+      blending: Some(BlendingMode::Combined(Blending {
+        equation: Equation::Additive,
+        src: Factor::One,
+        dst: Factor::One,
+      })),
+      depth_write: Write::Off,
+      ..Self::DEFAULT
+    }
+  }
+
+  /// Render state for UI overlays.
+  ///
+  /// Fragments are alpha-blended as with [`RenderState::alpha_blended`], but without depth test nor depth write: UI
+  /// elements are rendered atop of everything else, in the order they are rendered.
+  pub const fn ui_overlay() -> Self {
+    RenderState {
+      depth_test: None,
+      ..Self::alpha_blended()
+    }
+  }
+
   /// Override the blending configuration.
   pub fn set_blending<B>(self, blending: B) -> Self
   where
@@ -60,6 +205,62 @@ impl RenderState {
     self.blending
   }
 
+  /// Override the blending configuration of a single draw buffer.
+  ///
+  /// When rendering to several color slots (e.g. a G-buffer and an accumulation buffer), the draw buffer at index
+  /// `draw_buffer` — i.e. the color slot at that position — is blended with `blending` instead of
+  /// [`RenderState::blending`]. Passing `None` disables blending for that draw buffer only. The other draw buffers
+  /// keep using [`RenderState::blending`].
+  ///
+  /// Per-draw-buffer blending requires OpenGL 4.0. Backends not supporting it ignore this configuration and blend all
+  /// the draw buffers with [`RenderState::blending`]. Draw buffers beyond the number of draw buffers supported by the
+  /// backend are ignored as well.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `draw_buffer` is not lesser than [`MAX_DRAW_BUFFER_BLENDING`].
+  pub fn set_draw_buffer_blending<B>(mut self, draw_buffer: usize, blending: B) -> Self
+  where
+    B: Into<Option<BlendingMode>>,
+  {
+    assert!(
+      draw_buffer < MAX_DRAW_BUFFER_BLENDING,
+      "cannot override the blending of draw buffer {}; only the first {} draw buffers can be",
+      draw_buffer,
+      MAX_DRAW_BUFFER_BLENDING
+    );
+
+    self.draw_buffer_blending[draw_buffer] = DrawBufferBlending::from(blending.into());
+    self
+  }
+
+  /// Blending configuration of the draw buffer at index `draw_buffer`.
+  ///
+  /// This is the configuration set with [`RenderState::set_draw_buffer_blending`] if any, or [`RenderState::blending`]
+  /// otherwise.
+  pub fn draw_buffer_blending(&self, draw_buffer: usize) -> Option<BlendingMode> {
+    match self.draw_buffer_blending.get(draw_buffer) {
+      Some(DrawBufferBlending::Set(blending)) => Some(*blending),
+      Some(DrawBufferBlending::Disabled) => None,
+      Some(DrawBufferBlending::Inherit) | None => self.blending,
+    }
+  }
+
+  /// Draw buffers which blending configuration overrides [`RenderState::blending`], along with that configuration.
+  pub fn draw_buffer_blending_overrides(
+    &self,
+  ) -> impl Iterator<Item = (usize, Option<BlendingMode>)> + '_ {
+    self
+      .draw_buffer_blending
+      .iter()
+      .enumerate()
+      .filter_map(|(draw_buffer, blending)| match *blending {
+        DrawBufferBlending::Inherit => None,
+        DrawBufferBlending::Disabled => Some((draw_buffer, None)),
+        DrawBufferBlending::Set(blending) => Some((draw_buffer, Some(blending))),
+      })
+  }
+
   /// Override the depth test configuration.
   pub fn set_depth_test<D>(self, depth_test: D) -> Self
   where
@@ -87,7 +288,26 @@ impl RenderState {
     self.depth_write
   }
 
+  /// Override the color write mask configuration.
+  ///
+  /// Each of the red, green, blue and alpha channels of the color buffers is written to only if its flag is `true`.
+  /// Disabling all the channels is typically used along with [`Write::On`] to perform a depth pre-pass.
+  pub fn set_color_mask(self, color_mask: [bool; 4]) -> Self {
+    RenderState { color_mask, ..self }
+  }
+
+  /// Color write mask configuration.
+  pub fn color_mask(&self) -> [bool; 4] {
+    self.color_mask
+  }
+
   /// Override the stencil test configuration.
+  ///
+  /// `None` disables the stencil test, which is the default. The stencil test requires the framebuffer to have a
+  /// stencil buffer: without one, the test always passes and the stencil operations have no effect.
+  ///
+  /// The configuration applies to both front-facing and back-facing primitives, unless overridden for back-facing
+  /// primitives with [`RenderState::set_back_stencil_test`].
   pub fn set_stencil_test(self, stencil_test: impl Into<Option<StencilTest>>) -> Self {
     let stencil_test = stencil_test.into();
 
@@ -103,6 +323,9 @@ impl RenderState {
   }
 
   /// Override the stencil operations.
+  ///
+  /// The operations apply to both front-facing and back-facing primitives, unless overridden for back-facing
+  /// primitives with [`RenderState::set_back_stencil_operations`].
   pub fn set_stencil_operations(self, stencil_operations: StencilOperations) -> Self {
     RenderState {
       stencil_operations,
@@ -115,6 +338,56 @@ impl RenderState {
     &self.stencil_operations
   }
 
+  /// Override the stencil test configuration of back-facing primitives.
+  ///
+  /// `None`, the default, uses the configuration set with [`RenderState::set_stencil_test`]. The configuration only
+  /// applies when the stencil test is enabled with [`RenderState::set_stencil_test`].
+  pub fn set_back_stencil_test(self, stencil_test: impl Into<Option<StencilTest>>) -> Self {
+    RenderState {
+      back_stencil_test: stencil_test.into(),
+      ..self
+    }
+  }
+
+  /// Stencil test configuration of back-facing primitives, if different from the one of front-facing primitives.
+  pub fn back_stencil_test(&self) -> Option<&StencilTest> {
+    self.back_stencil_test.as_ref()
+  }
+
+  /// Override the stencil operations of back-facing primitives.
+  ///
+  /// `None`, the default, uses the operations set with [`RenderState::set_stencil_operations`].
+  pub fn set_back_stencil_operations(
+    self,
+    stencil_operations: impl Into<Option<StencilOperations>>,
+  ) -> Self {
+    RenderState {
+      back_stencil_operations: stencil_operations.into(),
+      ..self
+    }
+  }
+
+  /// Stencil operations of back-facing primitives, if different from the ones of front-facing primitives.
+  pub fn back_stencil_operations(&self) -> Option<&StencilOperations> {
+    self.back_stencil_operations.as_ref()
+  }
+
+  /// Override the stencil write mask.
+  ///
+  /// Only the bits set in the mask are written to the stencil buffer by the stencil operations. The default mask
+  /// (`0xFF`) writes all the bits.
+  pub fn set_stencil_write_mask(self, stencil_write_mask: u8) -> Self {
+    RenderState {
+      stencil_write_mask,
+      ..self
+    }
+  }
+
+  /// Stencil write mask configuration.
+  pub fn stencil_write_mask(&self) -> u8 {
+    self.stencil_write_mask
+  }
+
   /// Override the face culling configuration.
   pub fn set_face_culling<FC>(self, face_culling: FC) -> Self
   where
@@ -132,6 +405,9 @@ impl RenderState {
   }
 
   /// Override the scissor configuration.
+  ///
+  /// Parts of the region lying outside of the framebuffer are ignored. A region with a zero area discards all
+  /// fragments.
   pub fn set_scissor<SR>(self, scissor: SR) -> Self
   where
     SR: Into<Option<ScissorRegion>>,
@@ -146,27 +422,314 @@ impl RenderState {
   pub fn scissor(&self) -> &Option<ScissorRegion> {
     &self.scissor
   }
+
+  /// Override the sample shading configuration.
+  ///
+  /// `Some(rate)` runs the fragment shader for (at least) `rate` times the number of samples of each covered fragment,
+  /// `rate` being clamped to `[0; 1]`; `None` disables sample shading. Sample shading reduces shader aliasing (e.g.
+  /// specular aliasing) on multisampled framebuffers, but is very costly: with a rate of `1.`, the fragment shader
+  /// runs once per sample instead of once per fragment.
+  ///
+  /// Sample shading requires OpenGL 4.0 (or the `GL_ARB_sample_shading` extension); backends not supporting it ignore
+  /// this configuration.
+  pub fn set_sample_shading<SS>(self, rate: SS) -> Self
+  where
+    SS: Into<Option<f32>>,
+  {
+    RenderState {
+      sample_shading: rate.into().map(SampleShading::new),
+      ..self
+    }
+  }
+
+  /// Sample shading configuration.
+  pub fn sample_shading(&self) -> Option<SampleShading> {
+    self.sample_shading
+  }
+
+  /// Override the alpha-to-coverage configuration.
+  ///
+  /// When enabled, the alpha channel of the fragment color written to the first color buffer is used to compute a
+  /// coverage mask, so that only a proportion of the samples of each fragment is written to. This smoothes the edges
+  /// of alpha-tested geometry (e.g. foliage) without having to sort it, as blending would require.
+  ///
+  /// Alpha-to-coverage only has an effect when rendering to a multisampled framebuffer.
+  pub fn set_alpha_to_coverage(self, alpha_to_coverage: bool) -> Self {
+    RenderState {
+      alpha_to_coverage,
+      ..self
+    }
+  }
+
+  /// Alpha-to-coverage configuration.
+  pub fn alpha_to_coverage(&self) -> bool {
+    self.alpha_to_coverage
+  }
+
+  /// Override the line width configuration.
+  ///
+  /// The width, in pixels, is used when rasterizing lines (e.g. [`Mode::Line`] and [`Mode::LineStrip`]).
+  ///
+  /// Support for wide lines is very limited: OpenGL core profile contexts created with forward compatibility (as done
+  /// by most windowing backends) only support a width of `1.`, and so do most WebGL implementations. Backends never fail
+  /// when a width is not supported; instead, they clamp it to the range of widths they support, which might be
+  /// reduced to `1.`. If you need wide lines portably, render them as triangles.
+  ///
+  /// [`Mode::Line`]: crate::tess::Mode::Line
+  /// [`Mode::LineStrip`]: crate::tess::Mode::LineStrip
+  pub fn set_line_width(self, width: f32) -> Self {
+    RenderState {
+      line_width: LineWidth::new(width),
+      ..self
+    }
+  }
+
+  /// Line width configuration.
+  pub fn line_width(&self) -> LineWidth {
+    self.line_width
+  }
+
+  /// Override the point size configuration.
+  ///
+  /// `Some(size)` rasterizes points (i.e. [`Mode::Point`]) with a fixed `size`, in pixels, ignoring any size written by
+  /// the vertex shader; `None` uses the size written by the vertex shader to `gl_PointSize`, in which case the vertex
+  /// shader must write it. Points have a fixed size of `1.` by default.
+  ///
+  /// Backends clamp the size to the range of sizes they support. Backends not supporting fixed point sizes (e.g.
+  /// WebGL2) ignore this configuration and always use the size written by the vertex shader.
+  ///
+  /// [`Mode::Point`]: crate::tess::Mode::Point
+  pub fn set_point_size<PS>(self, size: PS) -> Self
+  where
+    PS: Into<Option<f32>>,
+  {
+    RenderState {
+      point_size: size.into().map(PointSize::new),
+      ..self
+    }
+  }
+
+  /// Point size configuration.
+  pub fn point_size(&self) -> Option<PointSize> {
+    self.point_size
+  }
+
+  /// Override the polygon rasterization mode.
+  ///
+  /// The mode applies to both front-facing and back-facing polygons: separate front and back modes are not available
+  /// in OpenGL core profile. It only affects polygons (i.e. triangles); points and lines are always rasterized as such.
+  /// Face culling still applies to polygons rasterized as lines or points.
+  ///
+  /// Backends not supporting polygon modes (e.g. WebGL2) ignore this configuration and always fill polygons.
+  pub fn set_polygon_mode(self, polygon_mode: PolygonMode) -> Self {
+    RenderState {
+      polygon_mode,
+      ..self
+    }
+  }
+
+  /// Polygon rasterization mode.
+  pub fn polygon_mode(&self) -> PolygonMode {
+    self.polygon_mode
+  }
+
+  /// Override the depth clamp configuration.
+  ///
+  /// When enabled, primitives are not clipped against the near and far planes anymore: the depth of their fragments is
+  /// clamped to the [`PipelineState::depth_range`] instead. This is typically used when rendering shadow maps, so that
+  /// occluders placed between the light and the near plane still cast shadows, or skyboxes, which then never get
+  /// clipped by the far plane.
+  ///
+  /// The depth test is performed with the clamped depth, which is also the depth written to the depth buffer. Clamped
+  /// fragments then all share the depth of the near or far plane.
+  ///
+  /// Backends not supporting depth clamping (e.g. WebGL2 without the `EXT_depth_clamp` extension) ignore this
+  /// configuration and always clip primitives.
+  ///
+  /// [`PipelineState::depth_range`]: crate::pipeline::PipelineState::depth_range
+  pub fn set_depth_clamp(self, depth_clamp: bool) -> Self {
+    RenderState {
+      depth_clamp,
+      ..self
+    }
+  }
+
+  /// Depth clamp configuration.
+  pub fn depth_clamp(&self) -> bool {
+    self.depth_clamp
+  }
+
+  /// Override the conservative rasterization configuration.
+  ///
+  /// When enabled, every pixel a primitive touches, even partially, is rasterized, instead of only the pixels which
+  /// center is covered by the primitive. This is typically used for voxelization or coverage-based algorithms, which
+  /// must not miss thin or small primitives.
+  ///
+  /// Conservative rasterization is a vendor extension that most hardware lacks. Backends not supporting it ignore this
+  /// configuration and rasterize primitives as usual: check [`BackendLimits::conservative_raster`] beforehand to fall
+  /// back to another technique (e.g. supersampling).
+  ///
+  /// [`BackendLimits::conservative_raster`]: crate::backend::query::BackendLimits::conservative_raster
+  pub fn set_conservative_raster(self, conservative_raster: bool) -> Self {
+    RenderState {
+      conservative_raster,
+      ..self
+    }
+  }
+
+  /// Conservative rasterization configuration.
+  pub fn conservative_raster(&self) -> bool {
+    self.conservative_raster
+  }
+}
+
+/// Sample shading configuration.
+///
+/// See [`RenderState::set_sample_shading`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SampleShading {
+  rate: f32,
+}
+
+impl SampleShading {
+  /// Create a new [`SampleShading`] with the minimum rate of samples to shade, clamped to `[0; 1]`.
+  ///
+  /// A `NaN` rate is interpreted as `0.`.
+  pub fn new(rate: f32) -> Self {
+    let rate = if rate.is_nan() {
+      0.
+    } else {
+      rate.clamp(0., 1.)
+    };
+
+    SampleShading { rate }
+  }
+
+  /// Minimum rate of samples to shade, in `[0; 1]`.
+  pub fn rate(&self) -> f32 {
+    self.rate
+  }
+}
+
+// the rate is never NaN
+impl Eq for SampleShading {}
+
+/// Line width configuration.
+///
+/// See [`RenderState::set_line_width`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LineWidth {
+  width: f32,
+}
+
+impl LineWidth {
+  /// Default line width, `1.`.
+  const DEFAULT: Self = LineWidth { width: 1. };
+
+  /// Create a new [`LineWidth`], in pixels.
+  ///
+  /// A `NaN` or non-positive width is interpreted as `1.`.
+  pub fn new(width: f32) -> Self {
+    let width = if width > 0. { width } else { 1. };
+    LineWidth { width }
+  }
+
+  /// Width of lines, in pixels.
+  pub fn width(&self) -> f32 {
+    self.width
+  }
+}
+
+impl Default for LineWidth {
+  fn default() -> Self {
+    Self::DEFAULT
+  }
+}
+
+// the width is never NaN
+impl Eq for LineWidth {}
+
+/// Point size configuration.
+///
+/// See [`RenderState::set_point_size`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PointSize {
+  size: f32,
+}
+
+impl PointSize {
+  /// Default point size, `1.`.
+  const DEFAULT: Self = PointSize { size: 1. };
+
+  /// Create a new [`PointSize`], in pixels.
+  ///
+  /// A `NaN` or non-positive size is interpreted as `1.`.
+  pub fn new(size: f32) -> Self {
+    let size = if size > 0. { size } else { 1. };
+    PointSize { size }
+  }
+
+  /// Size of points, in pixels.
+  pub fn size(&self) -> f32 {
+    self.size
+  }
+}
+
+impl Default for PointSize {
+  fn default() -> Self {
+    Self::DEFAULT
+  }
+}
+
+// the size is never NaN
+impl Eq for PointSize {}
+
+/// Polygon rasterization mode.
+///
+/// See [`RenderState::set_polygon_mode`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum PolygonMode {
+  /// Fill the inside of polygons.
+  Fill,
+  /// Only rasterize the edges of polygons, as lines (_wireframe_).
+  ///
+  /// Lines are rasterized with the [`RenderState::line_width`].
+  Line,
+  /// Only rasterize the vertices of polygons, as points.
+  ///
+  /// Points are rasterized with the [`RenderState::point_size`].
+  Point,
+}
+
+impl Default for PolygonMode {
+  fn default() -> Self {
+    PolygonMode::Fill
+  }
 }
 
 impl Default for RenderState {
   /// The default `RenderState`.
   ///
   ///   - `blending`: `None`
+  ///   - `draw_buffer_blending`: `[DrawBufferBlending::Inherit; MAX_DRAW_BUFFER_BLENDING]`
   ///   - `depth_test`: `Some(Comparison::Less)`
   ///   - `depth_write`: `Write::On`
+  ///   - `color_mask`: `[true, true, true, true]`
   ///   - `stencil_test`: `None`
   ///   - `stencil_operations`: `StencilOperations::default()`
+  ///   - `back_stencil_test`: `None`
+  ///   - `back_stencil_operations`: `None`
+  ///   - `stencil_write_mask`: `0xFF`
   ///   - `face_culling`: `None`
   ///   - 'scissor_region`: `None`
+  ///   - `sample_shading`: `None`
+  ///   - `alpha_to_coverage`: `false`
+  ///   - `line_width`: `1.`
+  ///   - `point_size`: `Some(PointSize::default())`, i.e. `1.`
+  ///   - `polygon_mode`: `PolygonMode::Fill`
+  ///   - `depth_clamp`: `false`
+  ///   - `conservative_raster`: `false`
   fn default() -> Self {
-    RenderState {
-      blending: None,
-      depth_test: Some(Comparison::Less),
-      depth_write: Write::On,
-      stencil_test: None,
-      stencil_operations: StencilOperations::default(),
-      face_culling: None,
-      scissor: None,
-    }
+    Self::DEFAULT
   }
 }
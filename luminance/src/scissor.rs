@@ -2,6 +2,9 @@
 //!
 //! The scissor test is a special test performed at rendering time. It allows to define a region of the screen for which
 //! fragments will be discarded.
+//!
+//! Scissor regions are expressed in texels, with the origin at the bottom-left corner of the framebuffer. A region
+//! with a zero width or height is valid and discards all the fragments.
 
 /// The region outside of which fragments will be discarded.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -18,3 +21,36 @@ pub struct ScissorRegion {
   /// The screen height of the scissor region.
   pub height: u32,
 }
+
+impl ScissorRegion {
+  /// Create a new [`ScissorRegion`] from its bottom-left corner position and its size.
+  pub fn new(x: u32, y: u32, width: u32, height: u32) -> Self {
+    ScissorRegion {
+      x,
+      y,
+      width,
+      height,
+    }
+  }
+
+  /// Clamp the [`ScissorRegion`] so that it fits in a framebuffer of size `[width, height]`.
+  ///
+  /// Parts of the region lying outside of the framebuffer are removed. If the region doesn’t overlap the framebuffer
+  /// at all, a zero-area region — which discards all fragments — is returned.
+  pub fn clamp_to(self, [fb_width, fb_height]: [u32; 2]) -> Self {
+    let x = self.x.min(fb_width);
+    let y = self.y.min(fb_height);
+
+    ScissorRegion {
+      x,
+      y,
+      width: self.width.min(fb_width - x),
+      height: self.height.min(fb_height - y),
+    }
+  }
+
+  /// Check whether the [`ScissorRegion`] has a zero area, discarding all fragments.
+  pub fn is_empty(&self) -> bool {
+    self.width == 0 || self.height == 0
+  }
+}
@@ -28,6 +28,9 @@
 //! Those are not all mandatory: only the _vertex_ stage and _fragment_ stages are mandatory. If
 //! you want tessellation shaders, you have to provide both of them.
 //!
+//! A sixth stage, [`StageType::ComputeShader`], is not part of the graphics pipeline and is used on its own, in
+//! compute programs (see the [`compute`](crate::compute) module).
+//!
 //! Shader stages — [`Stage`] — are compiled independently at runtime by your GPU driver, and then
 //! _linked_ into a shader program. The creation of a [`Stage`] implies using an input string,
 //! representing the _source code_ of the stage. This is an opaque [`String`] that must represent
@@ -129,11 +132,13 @@
 pub mod types;
 
 use crate::{
-  backend::shader::{Shader, ShaderData as ShaderDataBackend, Uniformable},
+  backend::shader::{
+    Shader, ShaderBinary, ShaderData as ShaderDataBackend, Uniformable, UniformableTransposed,
+  },
   context::GraphicsContext,
   vertex::Semantics,
 };
-use std::{error, fmt, marker::PhantomData};
+use std::{error, fmt, iter, marker::PhantomData};
 
 /// A shader stage type.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -148,6 +153,11 @@ pub enum StageType {
   GeometryShader,
   /// Fragment shader.
   FragmentShader,
+  /// Compute shader.
+  ///
+  /// Compute shaders are not part of the graphics pipeline: they are used to create
+  /// [`ComputeProgram`](crate::compute::ComputeProgram)s.
+  ComputeShader,
 }
 
 impl fmt::Display for StageType {
@@ -158,6 +168,7 @@ impl fmt::Display for StageType {
       StageType::TessellationEvaluationShader => f.write_str("tessellation evaluation shader"),
       StageType::GeometryShader => f.write_str("geometry shader"),
       StageType::FragmentShader => f.write_str("fragment shader"),
+      StageType::ComputeShader => f.write_str("compute shader"),
     }
   }
 }
@@ -236,6 +247,13 @@ pub enum ProgramError {
   LinkFailed(String),
   /// A program warning.
   Warning(ProgramWarning),
+  /// The backend doesn’t support shader program binaries.
+  BinaryUnsupported,
+  /// A shader program binary was rejected by the backend.
+  ///
+  /// This typically happens when the binary was created by another driver or another version of the driver. The
+  /// shader program must then be built from its sources again.
+  IncompatibleBinary(String),
 }
 
 impl ProgramError {
@@ -258,6 +276,16 @@ impl ProgramError {
   pub fn warning(w: ProgramWarning) -> Self {
     ProgramError::Warning(w)
   }
+
+  /// The backend doesn’t support shader program binaries.
+  pub fn binary_unsupported() -> Self {
+    ProgramError::BinaryUnsupported
+  }
+
+  /// A shader program binary was rejected by the backend.
+  pub fn incompatible_binary(reason: impl Into<String>) -> Self {
+    ProgramError::IncompatibleBinary(reason.into())
+  }
 }
 
 impl fmt::Display for ProgramError {
@@ -270,6 +298,12 @@ impl fmt::Display for ProgramError {
       ProgramError::LinkFailed(ref s) => write!(f, "shader program failed to link: {}", s),
 
       ProgramError::Warning(ref e) => write!(f, "shader program warning: {}", e),
+
+      ProgramError::BinaryUnsupported => f.write_str("shader program binaries are not supported"),
+
+      ProgramError::IncompatibleBinary(ref e) => {
+        write!(f, "incompatible shader program binary: {}", e)
+      }
     }
   }
 }
@@ -291,6 +325,9 @@ pub enum ProgramWarning {
   Uniform(UniformWarning),
   /// Some vertex attribute is ill-formed.
   VertexAttrib(VertexAttribWarning),
+  /// A shader stage compiled with a non-empty log, which is the [`String`]. It typically contains warnings, such as
+  /// use of deprecated features or implicit conversions.
+  Stage(StageType, String),
 }
 
 impl fmt::Display for ProgramWarning {
@@ -298,6 +335,7 @@ impl fmt::Display for ProgramWarning {
     match *self {
       ProgramWarning::Uniform(ref e) => write!(f, "uniform warning: {}", e),
       ProgramWarning::VertexAttrib(ref e) => write!(f, "vertex attribute warning: {}", e),
+      ProgramWarning::Stage(ty, ref log) => write!(f, "{} compilation log: {}", ty, log),
     }
   }
 }
@@ -307,6 +345,7 @@ impl error::Error for ProgramWarning {
     match self {
       ProgramWarning::Uniform(e) => Some(e),
       ProgramWarning::VertexAttrib(e) => Some(e),
+      ProgramWarning::Stage(..) => None,
     }
   }
 }
@@ -613,6 +652,16 @@ pub enum UniformType {
   UICubemap,
   /// Floating-point cubemap sampler.
   Cubemap,
+  /// 1D depth texture sampler with depth comparison.
+  Sampler1DShadow,
+  /// 2D depth texture sampler with depth comparison.
+  Sampler2DShadow,
+  /// 1D array depth texture sampler with depth comparison.
+  Sampler1DArrayShadow,
+  /// 2D array depth texture sampler with depth comparison.
+  Sampler2DArrayShadow,
+  /// Depth cubemap sampler with depth comparison.
+  CubemapShadow,
 
   /// Shader data binding.
   ShaderDataBinding,
@@ -665,6 +714,11 @@ impl fmt::Display for UniformType {
       UniformType::ICubemap => f.write_str("isamplerCube"),
       UniformType::UICubemap => f.write_str("usamplerCube"),
       UniformType::Cubemap => f.write_str("samplerCube"),
+      UniformType::Sampler1DShadow => f.write_str("sampler1DShadow"),
+      UniformType::Sampler2DShadow => f.write_str("sampler2DShadow"),
+      UniformType::Sampler1DArrayShadow => f.write_str("sampler1DArrayShadow"),
+      UniformType::Sampler2DArrayShadow => f.write_str("sampler2DArrayShadow"),
+      UniformType::CubemapShadow => f.write_str("samplerCubeShadow"),
       UniformType::ShaderDataBinding => f.write_str("shader data binding"),
     }
   }
@@ -682,6 +736,7 @@ where
   B: ?Sized + Shader,
 {
   pub repr: B::StageRepr,
+  ty: StageType,
 }
 
 impl<B> Stage<B>
@@ -710,7 +765,7 @@ where
       ctx
         .backend()
         .new_stage(ty, src.as_ref())
-        .map(|repr| Stage { repr })
+        .map(|repr| Stage { repr, ty })
     }
   }
 }
@@ -840,6 +895,23 @@ where
   pub fn ignore_warnings(self) -> Program<B, Sem, Out, Uni> {
     self.program
   }
+
+  /// Get the program along with its warnings.
+  ///
+  /// Warnings include the logs of the compiler for each shader stage (see [`ProgramWarning::Stage`]), which are
+  /// worth showing to catch driver-specific GLSL issues early.
+  pub fn collect_warnings(self) -> (Program<B, Sem, Out, Uni>, Vec<ProgramWarning>) {
+    let warnings = self
+      .warnings
+      .into_iter()
+      .filter_map(|e| match e {
+        ProgramError::Warning(w) => Some(w),
+        _ => None,
+      })
+      .collect();
+
+    (self.program, warnings)
+  }
 }
 
 /// A [`Program`] uniform adaptation that has failed.
@@ -908,6 +980,18 @@ where
     unsafe { B::update(self.program, uniform, value) };
   }
 
+  /// Set a row-major matrix value on a [`Uniform`].
+  ///
+  /// This is the same as [`ProgramInterface::set`], but the matrix is transposed when uploaded, so that matrices stored
+  /// in row-major order (as some math libraries do) can be passed directly. It is only available for matrix uniforms
+  /// (and arrays of matrices); other uniform types are rejected at compile time.
+  pub fn set_transposed<'u, T>(&'u mut self, uniform: &'u Uniform<T>, value: B::Target)
+  where
+    B: UniformableTransposed<'u, T>,
+  {
+    unsafe { B::update_transposed(self.program, uniform, value) };
+  }
+
   /// Get back a [`UniformBuilder`] to dynamically access [`Uniform`] objects.
   pub fn query(&mut self) -> Result<UniformBuilder<'a, B>, ProgramError> {
     unsafe {
@@ -924,6 +1008,27 @@ where
 ///
 /// This type allows to create shader programs without having to worry too much about the highly
 /// generic API.
+///
+/// # Geometry shaders
+///
+/// All the `from_*` methods accept an optional geometry shader, right before the fragment shader. The input primitive
+/// declared in the geometry shader (e.g. `layout (triangles) in;`) must match the [`Mode`] of the tessellations
+/// rendered with the program:
+///
+/// - `points` for [`Mode::Point`].
+/// - `lines` for [`Mode::Line`] and [`Mode::LineStrip`].
+/// - `triangles` for [`Mode::Triangle`], [`Mode::TriangleFan`] and [`Mode::TriangleStrip`].
+///
+/// Rendering with a mismatched [`Mode`] is an error detected by the backend at draw time and yields no primitives.
+/// Backends not supporting geometry shaders fail with [`StageError::UnsupportedType`] when creating the stage.
+///
+/// [`Mode`]: crate::tess::Mode
+/// [`Mode::Point`]: crate::tess::Mode::Point
+/// [`Mode::Line`]: crate::tess::Mode::Line
+/// [`Mode::LineStrip`]: crate::tess::Mode::LineStrip
+/// [`Mode::Triangle`]: crate::tess::Mode::Triangle
+/// [`Mode::TriangleFan`]: crate::tess::Mode::TriangleFan
+/// [`Mode::TriangleStrip`]: crate::tess::Mode::TriangleStrip
 pub struct ProgramBuilder<'a, C, Sem, Out, Uni> {
   ctx: &'a mut C,
   _phantom: PhantomData<(Sem, Out, Uni)>,
@@ -971,6 +1076,21 @@ where
     let tess = tess.into();
     let geometry = geometry.into();
 
+    let stages = iter::once(vertex)
+      .chain(
+        tess
+          .iter()
+          .flat_map(|stages| [stages.control, stages.evaluation]),
+      )
+      .chain(geometry)
+      .chain(iter::once(fragment));
+    let stage_warnings: Vec<_> = stages
+      .filter_map(|stage| unsafe {
+        C::Backend::stage_info_log(&stage.repr)
+          .map(|log| ProgramError::Warning(ProgramWarning::Stage(stage.ty, log)))
+      })
+      .collect();
+
     unsafe {
       let mut repr = self.ctx.backend().new_program(
         &vertex.repr,
@@ -982,9 +1102,13 @@ where
         &fragment.repr,
       )?;
 
-      let warnings = C::Backend::apply_semantics::<Sem>(&mut repr)?
+      let warnings = stage_warnings
         .into_iter()
-        .map(|w| ProgramError::Warning(w.into()))
+        .chain(
+          C::Backend::apply_semantics::<Sem>(&mut repr)?
+            .into_iter()
+            .map(|w| ProgramError::Warning(w.into())),
+        )
         .collect();
 
       let mut uniform_builder =
@@ -1040,7 +1164,7 @@ where
   ///
   /// - `C` is the graphics context.
   /// - `T` is an [`Option`] containing a [`TessellationStages`] with [`&str`] inside.
-  /// - `G` is an [`Option`] containing a [`Stage`] inside (geometry shader).
+  /// - `G` is an [`Option`] containing a [`&str`] inside (geometry shader).
   /// - `E` is the mutable environment variable.
   ///
   /// # Notes
@@ -1110,7 +1234,7 @@ where
   ///
   /// - `C` is the graphics context.
   /// - `T` is an [`Option`] containing a [`TessellationStages`] with [`&str`] inside.
-  /// - `G` is an [`Option`] containing a [`Stage`] inside (geometry shader).
+  /// - `G` is an [`Option`] containing a [`&str`] inside (geometry shader).
   ///
   /// # Notes
   ///
@@ -1132,6 +1256,142 @@ where
   {
     Self::from_strings_env(self, vertex, tess, geometry, fragment, &mut ())
   }
+
+  /// Build a new [`Program`] from [`&str`]s and replace `program` with it, by accessing a mutable environment variable.
+  ///
+  /// This is typically used to hot-reload shaders. The backend program is not recompiled in place: a whole new program
+  /// is compiled and linked, along with a new [`UniformInterface`], so that uniforms are resolved again. Only if that
+  /// succeeds is it assigned to `program`, dropping — and thus deleting — the old backend program. If anything fails,
+  /// `program` is left untouched and still usable, and the error is returned — compilation errors carry the log of
+  /// the compiler (see [`StageError::CompilationFailed`]). On success, the warnings of the new program are returned.
+  ///
+  /// # Parametricity
+  ///
+  /// - `T` is an [`Option`] containing a [`TessellationStages`] with [`&str`] inside.
+  /// - `G` is an [`Option`] containing a [`&str`] inside (geometry shader).
+  /// - `E` is the mutable environment variable.
+  ///
+  /// [`&str`]: str
+  pub fn reload_from_strings_env<'b, T, G, E>(
+    &mut self,
+    program: &mut Program<C::Backend, Sem, Out, Uni>,
+    vertex: &'b str,
+    tess: T,
+    geometry: G,
+    fragment: &'b str,
+    env: &mut E,
+  ) -> Result<Vec<ProgramError>, ProgramError>
+  where
+    Uni: UniformInterface<C::Backend, E>,
+    T: Into<Option<TessellationStages<'b, str>>>,
+    G: Into<Option<&'b str>>,
+  {
+    let built = self.from_strings_env(vertex, tess, geometry, fragment, env)?;
+    *program = built.program;
+
+    Ok(built.warnings)
+  }
+
+  /// Build a new [`Program`] from [`&str`]s and replace `program` with it.
+  ///
+  /// See the documentation of [`ProgramBuilder::reload_from_strings_env`] for further details.
+  ///
+  /// # Parametricity
+  ///
+  /// - `T` is an [`Option`] containing a [`TessellationStages`] with [`&str`] inside.
+  /// - `G` is an [`Option`] containing a [`&str`] inside (geometry shader).
+  ///
+  /// [`&str`]: str
+  pub fn reload_from_strings<'b, T, G>(
+    &mut self,
+    program: &mut Program<C::Backend, Sem, Out, Uni>,
+    vertex: &'b str,
+    tess: T,
+    geometry: G,
+    fragment: &'b str,
+  ) -> Result<Vec<ProgramError>, ProgramError>
+  where
+    Uni: UniformInterface<C::Backend>,
+    T: Into<Option<TessellationStages<'b, str>>>,
+    G: Into<Option<&'b str>>,
+  {
+    Self::reload_from_strings_env(self, program, vertex, tess, geometry, fragment, &mut ())
+  }
+}
+
+impl<'a, C, Sem, Out, Uni> ProgramBuilder<'a, C, Sem, Out, Uni>
+where
+  C: GraphicsContext,
+  C::Backend: ShaderBinary,
+  Sem: Semantics,
+{
+  /// Create a [`Program`] from a binary returned by [`Program::to_binary`], by accessing a mutable environment
+  /// variable.
+  ///
+  /// Loading a binary is much faster than compiling and linking shader stages, so binaries can be cached — on disk,
+  /// for instance — to speed up subsequent launches. The binary must have been created with the same [`Semantics`] and
+  /// render target types, and `format` must be the one returned along with it. The [`UniformInterface`] is created as
+  /// usual.
+  ///
+  /// If the backend rejects the binary, [`ProgramError::IncompatibleBinary`] is returned: this happens when the driver
+  /// changed since the binary was created. The [`Program`] must then be built from its sources again, with
+  /// [`ProgramBuilder::from_strings_env`] for instance.
+  ///
+  /// # Parametricity
+  ///
+  /// - `E` is the mutable environment variable.
+  pub fn from_binary_env<E>(
+    &mut self,
+    format: u32,
+    binary: &[u8],
+    env: &mut E,
+  ) -> Result<BuiltProgram<C::Backend, Sem, Out, Uni>, ProgramError>
+  where
+    Uni: UniformInterface<C::Backend, E>,
+  {
+    unsafe {
+      let mut repr = self.ctx.backend().new_program_from_binary(format, binary)?;
+
+      let mut uniform_builder =
+        C::Backend::new_uniform_builder(&mut repr).map(|repr| UniformBuilder {
+          repr,
+          warnings: Vec::new(),
+          _a: PhantomData,
+        })?;
+
+      let uni =
+        Uni::uniform_interface(&mut uniform_builder, env).map_err(ProgramWarning::Uniform)?;
+
+      let warnings = uniform_builder
+        .warnings
+        .into_iter()
+        .map(|w| ProgramError::Warning(w.into()))
+        .collect();
+
+      let program = Program {
+        repr,
+        uni,
+        _sem: PhantomData,
+        _out: PhantomData,
+      };
+
+      Ok(BuiltProgram { program, warnings })
+    }
+  }
+
+  /// Create a [`Program`] from a binary returned by [`Program::to_binary`].
+  ///
+  /// See the documentation of [`ProgramBuilder::from_binary_env`] for further details.
+  pub fn from_binary(
+    &mut self,
+    format: u32,
+    binary: &[u8],
+  ) -> Result<BuiltProgram<C::Backend, Sem, Out, Uni>, ProgramError>
+  where
+    Uni: UniformInterface<C::Backend>,
+  {
+    Self::from_binary_env(self, format, binary, &mut ())
+  }
 }
 
 /// A shader program.
@@ -1239,6 +1499,20 @@ where
   }
 }
 
+impl<B, Sem, Out, Uni> Program<B, Sem, Out, Uni>
+where
+  B: ShaderBinary,
+{
+  /// Get the binary of the [`Program`], along with its backend-specific format.
+  ///
+  /// Both are needed to create the [`Program`] back with [`ProgramBuilder::from_binary`]. Binaries are specific to a
+  /// driver and might be rejected once the driver is updated, so sources must be kept around to build the [`Program`]
+  /// again if needed.
+  pub fn to_binary(&self) -> Result<(u32, Vec<u8>), ProgramError> {
+    unsafe { B::program_binary(&self.repr) }
+  }
+}
+
 /// Shader data.
 ///
 /// # Parametricity
@@ -35,15 +35,23 @@ where
   /// - A [`ProgramInterface`], that allows to pass values (via [`ProgramInterface::set`]) to the
   ///   in-use shader [`Program`] and/or perform dynamic lookup of uniforms.
   /// - A [`RenderGate`], allowing to create deeper nodes in the graphics pipeline.
-  pub fn shade<E, Sem, Out, Uni, F>(
+  ///
+  /// The value returned by the closure is returned by this method, so that values computed while shading (e.g. a
+  /// number of draws) can be extracted.
+  ///
+  /// Entering several shading gates in a row with the same [`Program`] (e.g. once per object of a scene graph) is
+  /// cheap: backends track the in-use shader program and don’t apply it again if it’s already in-use. However, the
+  /// uniforms set via the [`ProgramInterface`] are part of the program state, so values set for a previous object
+  /// are still set for the next one if they’re not overridden.
+  pub fn shade<E, Sem, Out, Uni, F, T>(
     &mut self,
     program: &mut Program<B, Sem, Out, Uni>,
     f: F,
-  ) -> Result<(), E>
+  ) -> Result<T, E>
   where
     Sem: Semantics,
     Uni: UniformInterface<B>,
-    F: for<'b> FnOnce(ProgramInterface<'b, B>, &'b Uni, RenderGate<'b, B>) -> Result<(), E>,
+    F: for<'b> FnOnce(ProgramInterface<'b, B>, &'b Uni, RenderGate<'b, B>) -> Result<T, E>,
   {
     unsafe {
       self.backend.apply_shader_program(&mut program.repr);
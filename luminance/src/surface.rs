@@ -0,0 +1,263 @@
+//! Surface events.
+//!
+//! Windowing backends (also called _surfaces_) all come with their own event types. Applications handling those
+//! events directly are tied to a single windowing backend. This module provides [`SurfaceEvent`], a common
+//! representation of the events most applications care about, that windowing backends convert their events into.
+//! Applications written against [`SurfaceEvent`] can then swap windowing backends without having to change their
+//! event handling.
+//!
+//! Likewise, windowing backends implement the [`Surface`] trait, so that rendering code can be written generically
+//! over the windowing backend.
+
+use crate::{
+  backend::framebuffer::{Framebuffer as FramebufferBackend, FramebufferBackBuffer},
+  context::GraphicsContext,
+  framebuffer::{Framebuffer, FramebufferError},
+  texture::Dim2,
+};
+
+/// State of a key or a button.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum InputState {
+  /// The key or button was pressed.
+  Pressed,
+  /// The key or button was released.
+  Released,
+  /// The key was held down long enough to repeat.
+  Repeated,
+}
+
+/// Keyboard key.
+///
+/// Keys are named after their location on a US keyboard layout. Keys not listed here are reported as [`Key::Other`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Key {
+  /// The A key.
+  A,
+  /// The B key.
+  B,
+  /// The C key.
+  C,
+  /// The D key.
+  D,
+  /// The E key.
+  E,
+  /// The F key.
+  F,
+  /// The G key.
+  G,
+  /// The H key.
+  H,
+  /// The I key.
+  I,
+  /// The J key.
+  J,
+  /// The K key.
+  K,
+  /// The L key.
+  L,
+  /// The M key.
+  M,
+  /// The N key.
+  N,
+  /// The O key.
+  O,
+  /// The P key.
+  P,
+  /// The Q key.
+  Q,
+  /// The R key.
+  R,
+  /// The S key.
+  S,
+  /// The T key.
+  T,
+  /// The U key.
+  U,
+  /// The V key.
+  V,
+  /// The W key.
+  W,
+  /// The X key.
+  X,
+  /// The Y key.
+  Y,
+  /// The Z key.
+  Z,
+  /// The 0 key of the main keyboard section.
+  Num0,
+  /// The 1 key of the main keyboard section.
+  Num1,
+  /// The 2 key of the main keyboard section.
+  Num2,
+  /// The 3 key of the main keyboard section.
+  Num3,
+  /// The 4 key of the main keyboard section.
+  Num4,
+  /// The 5 key of the main keyboard section.
+  Num5,
+  /// The 6 key of the main keyboard section.
+  Num6,
+  /// The 7 key of the main keyboard section.
+  Num7,
+  /// The 8 key of the main keyboard section.
+  Num8,
+  /// The 9 key of the main keyboard section.
+  Num9,
+  /// The F1 function key.
+  F1,
+  /// The F2 function key.
+  F2,
+  /// The F3 function key.
+  F3,
+  /// The F4 function key.
+  F4,
+  /// The F5 function key.
+  F5,
+  /// The F6 function key.
+  F6,
+  /// The F7 function key.
+  F7,
+  /// The F8 function key.
+  F8,
+  /// The F9 function key.
+  F9,
+  /// The F10 function key.
+  F10,
+  /// The F11 function key.
+  F11,
+  /// The F12 function key.
+  F12,
+  /// The space key.
+  Space,
+  /// The escape key.
+  Escape,
+  /// The enter key.
+  Enter,
+  /// The tab key.
+  Tab,
+  /// The backspace key.
+  Backspace,
+  /// The insert key.
+  Insert,
+  /// The delete key.
+  Delete,
+  /// The home key.
+  Home,
+  /// The end key.
+  End,
+  /// The page up key.
+  PageUp,
+  /// The page down key.
+  PageDown,
+  /// The left arrow key.
+  Left,
+  /// The right arrow key.
+  Right,
+  /// The up arrow key.
+  Up,
+  /// The down arrow key.
+  Down,
+  /// The left shift key.
+  LeftShift,
+  /// The right shift key.
+  RightShift,
+  /// The left control key.
+  LeftControl,
+  /// The right control key.
+  RightControl,
+  /// The left alt key.
+  LeftAlt,
+  /// The right alt key.
+  RightAlt,
+  /// Any other key.
+  Other,
+}
+
+/// Mouse button.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum MouseButton {
+  /// Left button.
+  Left,
+  /// Right button.
+  Right,
+  /// Middle button.
+  Middle,
+  /// Any other button, identified by its backend-specific number.
+  Other(u16),
+}
+
+/// Event emitted by a surface.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SurfaceEvent {
+  /// A keyboard key changed state.
+  Key(Key, InputState),
+  /// A mouse button changed state.
+  MouseButton(MouseButton, InputState),
+  /// The cursor moved, in screen coordinates relative to the top-left corner of the surface.
+  CursorMoved {
+    /// Horizontal position of the cursor.
+    x: f64,
+    /// Vertical position of the cursor.
+    y: f64,
+  },
+  /// The framebuffer of the surface was resized, in pixels.
+  Resized {
+    /// New width of the framebuffer.
+    width: u32,
+    /// New height of the framebuffer.
+    height: u32,
+  },
+  /// Scrolling happened, either with a mouse wheel or a touchpad.
+  Scroll {
+    /// Horizontal scroll offset.
+    x: f64,
+    /// Vertical scroll offset.
+    y: f64,
+  },
+  /// The surface was asked to close.
+  Close,
+  /// Any event not mapped to the variants above.
+  ///
+  /// Applications needing such events must use the events of their windowing backend directly.
+  Other,
+}
+
+/// Back buffer of a [`Surface`].
+pub type BackBuffer<S> =
+  Framebuffer<<<S as Surface>::Context as GraphicsContext>::Backend, Dim2, (), ()>;
+
+/// A surface, which luminance renders to.
+///
+/// This trait is implemented by windowing backends, giving access to their [`GraphicsContext`] and to their back
+/// buffer. Events are not part of this trait, as some windowing backends don’t own the event loop of the application;
+/// they can, however, be converted into [`SurfaceEvent`].
+pub trait Surface {
+  /// Graphics context of the surface.
+  type Context: GraphicsContext;
+
+  /// Error that can occur when swapping the buffers.
+  type SwapError;
+
+  /// Get the graphics context of the surface.
+  fn context(&mut self) -> &mut Self::Context;
+
+  /// Get the size of the surface, in pixels.
+  ///
+  /// This is the size of the back buffer, which might be bigger than the size of the window on HiDPI displays.
+  fn size(&self) -> [u32; 2];
+
+  /// Swap the back and front buffers, presenting what was rendered to the back buffer.
+  fn swap_buffers(&mut self) -> Result<(), Self::SwapError>;
+
+  /// Get the back buffer, with the size of the surface.
+  fn back_buffer(&mut self) -> Result<BackBuffer<Self>, FramebufferError>
+  where
+    <Self::Context as GraphicsContext>::Backend: FramebufferBackend<Dim2> + FramebufferBackBuffer,
+  {
+    let size = self.size();
+    Framebuffer::back_buffer(self.context(), size)
+  }
+}
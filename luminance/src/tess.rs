@@ -64,20 +64,29 @@
 //! > will not help you with resizing a [`Tess`], as this is not currently supported. Creating a large
 //! > enough [`Tess`] is preferable for now.
 //!
+//! # Raw tessellations
+//!
+//! [`Tess`] is typed with its vertex type, which must then be known at compile time. When vertex layouts are only
+//! known at runtime — for instance when they are described by assets — a [`RawTess`] can be built from raw vertex
+//! bytes and a [`VertexDesc`] instead.
+//!
 //! [`TessGate`]: crate::tess_gate::TessGate
 
 use crate::{
   backend::tess::{
-    IndexSlice as IndexSliceBackend, InstanceSlice as InstanceSliceBackend, Tess as TessBackend,
-    VertexSlice as VertexSliceBackend,
+    IndexSlice as IndexSliceBackend, InstanceSlice as InstanceSliceBackend,
+    RawTess as RawTessBackend, Tess as TessBackend, VertexSlice as VertexSliceBackend,
+    VertexSliceSet as VertexSliceSetBackend, VertexUpdate as VertexUpdateBackend,
   },
   context::GraphicsContext,
-  vertex::{Deinterleave, Vertex, VertexDesc},
+  vertex::{interleaved_layout, Deinterleave, DeinterleaveAll, Vertex, VertexDesc},
 };
 use std::{
   error, fmt,
   marker::PhantomData,
+  mem,
   ops::{Deref, DerefMut, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive},
+  ptr, slice,
 };
 
 /// Primitive mode.
@@ -93,13 +102,15 @@ use std::{
 /// _Primitive restart_ should be used as much as possible as it will decrease the number of GPU
 /// commands you have to issue.
 ///
-/// > Deprecation notice: the next version of luminance will not support setting the primitive restart index: you will
-/// then must provide the maximum value of index type.
+/// That feature is encoded with a special _vertex index_, the _primitive restart index_, which is the maximum value of
+/// the index type (see [`TessIndexType::fixed_restart_index`]). You enable primitive restart with
+/// [`TessBuilder::enable_primitive_restart_fixed`]. Whenever a vertex index is set to the same value as the
+/// _primitive restart index_, the value is not interpreted as a vertex index but just a marker / hint to start a new
+/// primitive.
 ///
-/// That feature is encoded with a special _vertex index_. You can setup the value of the _primitive
-/// restart index_ with [`TessBuilder::set_primitive_restart_index`]. Whenever a vertex index is set
-/// to the same value as the _primitive restart index_, the value is not interpreted as a vertex
-/// index but just a marker / hint to start a new primitive.
+/// > Deprecation notice: setting a custom primitive restart index with
+/// > [`TessBuilder::set_primitive_restart_index`] is deprecated and will not be supported in the next version of
+/// > luminance. Use [`TessBuilder::enable_primitive_restart_fixed`] instead.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum Mode {
   /// A single point.
@@ -158,6 +169,47 @@ pub enum Mode {
   Patch(usize),
 }
 
+impl Mode {
+  /// Whether the primitive mode allows the usage of _primitive restart_.
+  pub fn allows_primitive_restart(self) -> bool {
+    matches!(
+      self,
+      Mode::LineStrip | Mode::TriangleFan | Mode::TriangleStrip
+    )
+  }
+
+  /// Whether `vert_nb` vertices form whole primitives with this primitive mode.
+  ///
+  /// Lines, triangles and patches require a multiple of their number of vertices, while strips and fans require
+  /// enough vertices to form at least one primitive. Zero vertices is always accepted, except for `Mode::Patch(0)`,
+  /// which cannot form any primitive.
+  pub fn accepts_vertex_nb(self, vert_nb: usize) -> bool {
+    match self {
+      Mode::Point => true,
+      Mode::Line => vert_nb % 2 == 0,
+      Mode::LineStrip => vert_nb != 1,
+      Mode::Triangle => vert_nb % 3 == 0,
+      Mode::TriangleFan | Mode::TriangleStrip => vert_nb == 0 || vert_nb >= 3,
+      Mode::Patch(n) => n != 0 && vert_nb % n == 0,
+    }
+  }
+
+  /// Number of primitives formed by `vert_nb` vertices with this primitive mode.
+  ///
+  /// Vertices not forming a whole primitive are not counted, as they are discarded when rendering.
+  pub fn primitive_count(self, vert_nb: usize) -> usize {
+    match self {
+      Mode::Point => vert_nb,
+      Mode::Line => vert_nb / 2,
+      Mode::LineStrip => vert_nb.saturating_sub(1),
+      Mode::Triangle => vert_nb / 3,
+      Mode::TriangleFan | Mode::TriangleStrip => vert_nb.saturating_sub(2),
+      Mode::Patch(0) => 0,
+      Mode::Patch(n) => vert_nb / n,
+    }
+  }
+}
+
 impl fmt::Display for Mode {
   fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
     match *self {
@@ -178,6 +230,14 @@ impl fmt::Display for Mode {
 pub enum TessMapError {
   /// Cannot obtain a slice on the backend.
   CannotMap,
+  /// The backend ran out of memory while allocating the memory required to map a buffer.
+  ///
+  /// Contrary to [`TessMapError::CannotMap`], this error is transient: mapping again later, or mapping a smaller
+  /// tessellation, might succeed.
+  BufferAllocationFailed {
+    /// Number of bytes that were requested.
+    requested_bytes: usize,
+  },
   /// Vertex target type is not the same as the one stored in the buffer.
   VertexTypeMismatch(VertexDesc, VertexDesc),
   /// Index target type is not the same as the one stored in the buffer.
@@ -188,6 +248,17 @@ pub enum TessMapError {
   /// The CPU mapping failed because currently, mapping deinterleaved buffers is not supported via
   /// a single slice.
   ForbiddenDeinterleavedMapping,
+  /// No vertex data could be read back because the tessellation is empty.
+  NoData,
+  /// The updated vertex range doesn’t fit in the vertices of the tessellation.
+  VertexRangeOutOfBounds {
+    /// Index of the first updated vertex.
+    start: usize,
+    /// Number of updated vertices.
+    len: usize,
+    /// Number of vertices of the tessellation.
+    vert_nb: usize,
+  },
 }
 
 impl TessMapError {
@@ -196,6 +267,11 @@ impl TessMapError {
     TessMapError::CannotMap
   }
 
+  /// The backend ran out of memory while allocating the memory required to map a buffer.
+  pub fn buffer_allocation_failed(requested_bytes: usize) -> Self {
+    TessMapError::BufferAllocationFailed { requested_bytes }
+  }
+
   /// Vertex target type is not the same as the one stored in the buffer.
   pub fn vertex_type_mismatch(a: VertexDesc, b: VertexDesc) -> Self {
     TessMapError::VertexTypeMismatch(a, b)
@@ -217,6 +293,20 @@ impl TessMapError {
   pub fn forbidden_deinterleaved_mapping() -> Self {
     TessMapError::ForbiddenDeinterleavedMapping
   }
+
+  /// No vertex data could be read back because the tessellation is empty.
+  pub fn no_data() -> Self {
+    TessMapError::NoData
+  }
+
+  /// The updated vertex range doesn’t fit in the vertices of the tessellation.
+  pub fn vertex_range_out_of_bounds(start: usize, len: usize, vert_nb: usize) -> Self {
+    TessMapError::VertexRangeOutOfBounds {
+      start,
+      len,
+      vert_nb,
+    }
+  }
 }
 
 impl fmt::Display for TessMapError {
@@ -224,6 +314,12 @@ impl fmt::Display for TessMapError {
     match *self {
       TessMapError::CannotMap => f.write_str("cannot map on the backend"),
 
+      TessMapError::BufferAllocationFailed { requested_bytes } => write!(
+        f,
+        "cannot map on the backend: failed to allocate {} bytes",
+        requested_bytes
+      ),
+
       TessMapError::VertexTypeMismatch(ref a, ref b) => write!(
         f,
         "cannot map tessellation: vertex type mismatch between {:?} and {:?}",
@@ -243,12 +339,84 @@ impl fmt::Display for TessMapError {
       TessMapError::ForbiddenDeinterleavedMapping => {
         f.write_str("cannot map a deinterleaved buffer as interleaved")
       }
+
+      TessMapError::NoData => f.write_str("no data or empty tessellation"),
+
+      TessMapError::VertexRangeOutOfBounds {
+        start,
+        len,
+        vert_nb,
+      } => write!(
+        f,
+        "cannot update {} vertices at {}: the tessellation has {} vertices",
+        len, start, vert_nb
+      ),
     }
   }
 }
 
 impl error::Error for TessMapError {}
 
+/// Possible errors that might occur when exporting a [`Tess`] to Wavefront OBJ.
+///
+/// See [`Tess::export_obj`].
+#[cfg(feature = "obj-export")]
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum ObjExportError {
+  /// The [`Tess`] could not be mapped.
+  MapError(TessMapError),
+  /// The OBJ data could not be written.
+  IoError(std::io::Error),
+}
+
+#[cfg(feature = "obj-export")]
+impl ObjExportError {
+  /// The [`Tess`] could not be mapped.
+  pub fn map_error(e: TessMapError) -> Self {
+    ObjExportError::MapError(e)
+  }
+
+  /// The OBJ data could not be written.
+  pub fn io_error(e: std::io::Error) -> Self {
+    ObjExportError::IoError(e)
+  }
+}
+
+#[cfg(feature = "obj-export")]
+impl fmt::Display for ObjExportError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    match *self {
+      ObjExportError::MapError(ref e) => write!(f, "cannot export tessellation: {}", e),
+      ObjExportError::IoError(ref e) => write!(f, "cannot write OBJ data: {}", e),
+    }
+  }
+}
+
+#[cfg(feature = "obj-export")]
+impl error::Error for ObjExportError {
+  fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+    match self {
+      ObjExportError::MapError(e) => Some(e),
+      ObjExportError::IoError(e) => Some(e),
+    }
+  }
+}
+
+#[cfg(feature = "obj-export")]
+impl From<TessMapError> for ObjExportError {
+  fn from(e: TessMapError) -> Self {
+    ObjExportError::map_error(e)
+  }
+}
+
+#[cfg(feature = "obj-export")]
+impl From<std::io::Error> for ObjExportError {
+  fn from(e: std::io::Error) -> Self {
+    ObjExportError::io_error(e)
+  }
+}
+
 /// Possible errors that might occur when dealing with [`Tess`].
 #[non_exhaustive]
 #[derive(Debug, Eq, PartialEq)]
@@ -263,6 +431,36 @@ pub enum TessError {
   ForbiddenPrimitiveMode(Mode),
   /// No data provided and empty tessellation.
   NoData,
+  /// Rendering from a base instance is not supported by the backend.
+  UnsupportedBaseInstance,
+  /// Rendering with a base vertex is not supported by the backend.
+  UnsupportedBaseVertex,
+  /// An index references a vertex that doesn’t exist.
+  IndexOutOfRange {
+    /// The faulty index.
+    index: u32,
+    /// Number of vertices the index should be smaller than.
+    vertex_count: usize,
+  },
+  /// Attributes of deinterleaved vertices were not provided.
+  MissingAttributes {
+    /// Ranks of the missing attributes.
+    ranks: Vec<usize>,
+  },
+  /// An indirect command doesn’t match the tessellation it renders.
+  ///
+  /// Indexed tessellations must be rendered with indexed commands, and non-indexed tessellations with non-indexed
+  /// commands.
+  IndirectCommandMismatch,
+  /// An indirect command index is out of the bounds of the indirect buffer.
+  IndirectIndexOutOfBounds {
+    /// The faulty index.
+    index: usize,
+    /// Number of commands in the indirect buffer.
+    len: usize,
+  },
+  /// The data of another tessellation could not be mapped.
+  MapError(TessMapError),
 }
 
 impl TessError {
@@ -290,6 +488,44 @@ impl TessError {
   pub fn no_data() -> Self {
     TessError::NoData
   }
+
+  /// Rendering from a base instance is not supported by the backend.
+  pub fn unsupported_base_instance() -> Self {
+    TessError::UnsupportedBaseInstance
+  }
+
+  /// Rendering with a base vertex is not supported by the backend.
+  pub fn unsupported_base_vertex() -> Self {
+    TessError::UnsupportedBaseVertex
+  }
+
+  /// An index references a vertex that doesn’t exist.
+  pub fn index_out_of_range(index: u32, vertex_count: usize) -> Self {
+    TessError::IndexOutOfRange {
+      index,
+      vertex_count,
+    }
+  }
+
+  /// Attributes of deinterleaved vertices were not provided.
+  pub fn missing_attributes(ranks: Vec<usize>) -> Self {
+    TessError::MissingAttributes { ranks }
+  }
+
+  /// An indirect command doesn’t match the tessellation it renders.
+  pub fn indirect_command_mismatch() -> Self {
+    TessError::IndirectCommandMismatch
+  }
+
+  /// An indirect command index is out of the bounds of the indirect buffer.
+  pub fn indirect_index_out_of_bounds(index: usize, len: usize) -> Self {
+    TessError::IndirectIndexOutOfBounds { index, len }
+  }
+
+  /// The data of another tessellation could not be mapped.
+  pub fn map_error(e: TessMapError) -> Self {
+    TessError::MapError(e)
+  }
 }
 
 impl fmt::Display for TessError {
@@ -302,11 +538,49 @@ impl fmt::Display for TessError {
       }
       TessError::ForbiddenPrimitiveMode(ref e) => write!(f, "forbidden primitive mode: {}", e),
       TessError::NoData => f.write_str("no data or empty tessellation"),
+      TessError::UnsupportedBaseInstance => {
+        f.write_str("rendering from a base instance is not supported")
+      }
+      TessError::UnsupportedBaseVertex => {
+        f.write_str("rendering with a base vertex is not supported")
+      }
+      TessError::IndexOutOfRange {
+        index,
+        vertex_count,
+      } => write!(
+        f,
+        "index {} out of range (only {} vertices)",
+        index, vertex_count
+      ),
+      TessError::MissingAttributes { ref ranks } => {
+        let ranks: Vec<_> = ranks.iter().map(usize::to_string).collect();
+        write!(
+          f,
+          "missing deinterleaved attributes at rank(s) {}",
+          ranks.join(", ")
+        )
+      }
+      TessError::IndirectCommandMismatch => {
+        f.write_str("indirect command doesn’t match the indexing of the tessellation")
+      }
+      TessError::IndirectIndexOutOfBounds { index, len } => write!(
+        f,
+        "indirect command index {} out of bounds (only {} commands)",
+        index, len
+      ),
+      TessError::MapError(ref e) => write!(f, "cannot map tessellation: {}", e),
     }
   }
 }
 
-impl error::Error for TessError {}
+impl error::Error for TessError {
+  fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+    match self {
+      TessError::MapError(e) => Some(e),
+      _ => None,
+    }
+  }
+}
 
 /// Possible tessellation index types.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -328,6 +602,15 @@ impl TessIndexType {
       TessIndexType::U32 => 4,
     }
   }
+
+  /// Get the fixed primitive restart index of the type described by the variant, which is its maximum value.
+  pub fn fixed_restart_index(self) -> u32 {
+    match self {
+      TessIndexType::U8 => u8::MAX.into(),
+      TessIndexType::U16 => u16::MAX.into(),
+      TessIndexType::U32 => u32::MAX,
+    }
+  }
 }
 
 /// Class of tessellation indices.
@@ -350,6 +633,14 @@ pub unsafe trait TessIndex: Copy {
 
   /// Get and convert the index to [`u32`], if possible.
   fn try_into_u32(self) -> Option<u32>;
+
+  /// Convert a [`u32`] to an index, if possible.
+  ///
+  /// The default implementation never converts, which disables the features that need to create indices, such as
+  /// [`TessBuilder::append_indices`] rebasing indices and fixed primitive restart indices.
+  fn try_from_u32(_: u32) -> Option<Self> {
+    None
+  }
 }
 
 unsafe impl TessIndex for () {
@@ -358,6 +649,10 @@ unsafe impl TessIndex for () {
   fn try_into_u32(self) -> Option<u32> {
     None
   }
+
+  fn try_from_u32(_: u32) -> Option<Self> {
+    None
+  }
 }
 
 /// Boop.
@@ -367,6 +662,10 @@ unsafe impl TessIndex for u8 {
   fn try_into_u32(self) -> Option<u32> {
     Some(self.into())
   }
+
+  fn try_from_u32(index: u32) -> Option<Self> {
+    u8::try_from(index).ok()
+  }
 }
 
 /// Boop.
@@ -376,6 +675,10 @@ unsafe impl TessIndex for u16 {
   fn try_into_u32(self) -> Option<u32> {
     Some(self.into())
   }
+
+  fn try_from_u32(index: u32) -> Option<Self> {
+    u16::try_from(index).ok()
+  }
 }
 
 /// Wuuuuuuha.
@@ -385,6 +688,10 @@ unsafe impl TessIndex for u32 {
   fn try_into_u32(self) -> Option<u32> {
     Some(self.into())
   }
+
+  fn try_from_u32(index: u32) -> Option<Self> {
+    Some(index)
+  }
 }
 
 /// Interleaved memory marker.
@@ -450,6 +757,17 @@ where
   type Data = Vec<DeinterleavedData>;
 
   fn coherent_len(data: &Self::Data) -> Result<usize, TessError> {
+    let missing: Vec<_> = data
+      .iter()
+      .enumerate()
+      .filter(|(_, attr)| !attr.populated)
+      .map(|(rank, _)| rank)
+      .collect();
+
+    if !missing.is_empty() {
+      return Err(TessError::missing_attributes(missing));
+    }
+
     if data.is_empty() {
       Ok(0)
     } else {
@@ -472,6 +790,8 @@ where
 pub struct DeinterleavedData {
   raw: Vec<u8>,
   len: usize,
+  // whether the attributes were provided; used to detect missing attributes
+  populated: bool,
 }
 
 impl DeinterleavedData {
@@ -479,6 +799,24 @@ impl DeinterleavedData {
     DeinterleavedData {
       raw: Vec::new(),
       len: 0,
+      populated: false,
+    }
+  }
+
+  /// Create a populated [`DeinterleavedData`] from a set of attributes.
+  fn from_attributes<A>(attributes: Vec<A>) -> Self {
+    let len = attributes.len();
+    let len_bytes = len * mem::size_of::<A>();
+
+    // the bytes are copied into their own allocation instead of reinterpreting the allocation of the attributes, as it
+    // would then be freed with a different layout than the one it was allocated with
+    let raw =
+      unsafe { slice::from_raw_parts(attributes.as_ptr() as *const u8, len_bytes) }.to_vec();
+
+    DeinterleavedData {
+      raw,
+      len,
+      populated: true,
     }
   }
 
@@ -562,6 +900,10 @@ where
   render_vert_nb: usize,
   render_inst_nb: usize,
   restart_index: Option<I>,
+  // whether the fixed primitive restart index was asked for; kept so that it survives a change of index type
+  restart_fixed: bool,
+  index_base: usize,
+  append_mode: Option<Mode>,
   _phantom: PhantomData<&'a mut ()>,
 }
 
@@ -594,7 +936,9 @@ where
 
   /// Set the default number of instances to render.
   ///
-  /// Calling that function twice replaces the previously set value.
+  /// Calling that function twice replaces the previously set value. If instance data is provided, the number of
+  /// instances must not exceed its length. Without instance data, any number of instances is accepted
+  /// (_attributeless instancing_): shaders then tell instances apart with their instance ID only.
   pub fn set_render_instance_nb(mut self, inst_nb: usize) -> Self {
     self.render_inst_nb = inst_nb;
     self
@@ -603,10 +947,44 @@ where
   /// Set the primitive restart index.
   ///
   /// Calling that function twice replaces the previously set value.
+  ///
+  /// > Deprecation notice: this function will be removed in the next version of luminance. Use
+  /// > [`TessBuilder::enable_primitive_restart_fixed`] instead. Custom primitive restart indices are not supported by
+  /// > all backends (e.g. WebGL2 always uses the maximum value of the index type).
   pub fn set_primitive_restart_index(mut self, restart_index: I) -> Self {
     self.restart_index = Some(restart_index);
+    self.restart_fixed = false;
+    self
+  }
+
+  /// Enable primitive restart with the maximum value of the index type as primitive restart index.
+  ///
+  /// The primitive restart index is then `0xFF`, `0xFFFF` or `0xFFFFFFFF`, depending on the [`TessIndexType`] of `I`
+  /// (see [`TessIndexType::fixed_restart_index`]). This is the only primitive restart index supported by modern
+  /// graphics APIs and must be preferred over [`TessBuilder::set_primitive_restart_index`]. This function has no
+  /// effect if the [`Tess`] is not indexed.
+  ///
+  /// That function can be called before [`TessBuilder::set_indices`]: the primitive restart index is then picked
+  /// according to the type of the indices, once they are set.
+  ///
+  /// Calling that function replaces the primitive restart index set with
+  /// [`TessBuilder::set_primitive_restart_index`].
+  pub fn enable_primitive_restart_fixed(mut self) -> Self {
+    self.restart_index = fixed_restart_index();
+    self.restart_fixed = true;
     self
   }
+
+  /// Ensure that merged meshes were all appended with the current [`Mode`].
+  fn check_append_mode(&self) -> Result<(), TessError> {
+    match self.append_mode {
+      Some(mode) if mode != self.mode => Err(TessError::cannot_create(format!(
+        "cannot merge {} primitives with {} primitives",
+        mode, self.mode
+      ))),
+      _ => Ok(()),
+    }
+  }
 }
 
 impl<'a, B, V, I, W, S> TessBuilder<'a, B, V, I, W, S>
@@ -637,11 +1015,22 @@ where
       render_vert_nb: 0,
       render_inst_nb: 0,
       restart_index: None,
+      restart_fixed: false,
+      index_base: 0,
+      append_mode: None,
       _phantom: PhantomData,
     }
   }
 }
 
+/// Fixed primitive restart index of `I`, if `I` is an index type.
+fn fixed_restart_index<I>() -> Option<I>
+where
+  I: TessIndex,
+{
+  I::INDEX_TYPE.and_then(|ty| I::try_from_u32(ty.fixed_restart_index()))
+}
+
 // set_indices, which works only if I = ()
 impl<'a, B, V, W, S> TessBuilder<'a, B, V, (), W, S>
 where
@@ -654,10 +1043,30 @@ where
   ///
   /// Every time you call that function, the set of indices is replaced by the one you provided.
   /// The type of expected indices is ruled by the `II` type variable you chose.
+  ///
+  /// Indices can be passed as a [`Vec`] or as a slice (`&[I]`), which is copied once (see
+  /// [`TessBuilder::set_vertices`]).
+  ///
+  /// # Primitive restart
+  ///
+  /// Setting the indices changes the index type, so the primitive restart index is handled as follows:
+  ///
+  /// - If [`TessBuilder::enable_primitive_restart_fixed`] was called, primitive restart remains enabled, with the
+  ///   fixed primitive restart index of `I`.
+  /// - Otherwise, the primitive restart index is **reset**: a value passed to
+  ///   [`TessBuilder::set_primitive_restart_index`] before setting the indices has type `()` and cannot be converted
+  ///   to an index. Set the primitive restart index after the indices instead.
   pub fn set_indices<I, X>(self, indices: X) -> TessBuilder<'a, B, V, I, W, S>
   where
+    I: TessIndex,
     X: Into<Vec<I>>,
   {
+    let restart_index = if self.restart_fixed {
+      fixed_restart_index()
+    } else {
+      None
+    };
+
     TessBuilder {
       backend: self.backend,
       vertex_data: self.vertex_data,
@@ -666,7 +1075,10 @@ where
       mode: self.mode,
       render_vert_nb: self.render_vert_nb,
       render_inst_nb: self.render_inst_nb,
-      restart_index: None,
+      restart_index,
+      restart_fixed: self.restart_fixed,
+      index_base: self.index_base,
+      append_mode: self.append_mode,
       _phantom: PhantomData,
     }
   }
@@ -682,6 +1094,11 @@ where
   /// Add vertices to be bundled in the [`Tess`].
   ///
   /// Every time you call that function, the set of vertices is replaced by the one you provided.
+  ///
+  /// Vertices can be passed as a [`Vec`], which is moved without copying, or as a slice (`&[V]`), which is copied
+  /// once. Backends keep the data they upload as a CPU-side mirror of the GPU buffer (used to map and read back the
+  /// [`Tess`]), so the [`Tess`] always ends up owning its data; borrowing it for the upload only would not spare that
+  /// copy.
   pub fn set_vertices<V, X>(self, vertices: X) -> TessBuilder<'a, B, V, I, W, Interleaved>
   where
     X: Into<Vec<V>>,
@@ -696,6 +1113,9 @@ where
       render_vert_nb: self.render_vert_nb,
       render_inst_nb: self.render_inst_nb,
       restart_index: self.restart_index,
+      restart_fixed: self.restart_fixed,
+      index_base: self.index_base,
+      append_mode: self.append_mode,
       _phantom: PhantomData,
     }
   }
@@ -710,6 +1130,9 @@ where
   /// Add instances to be bundled in the [`Tess`].
   ///
   /// Every time you call that function, the set of instances is replaced by the one you provided.
+  ///
+  /// Instances can be passed as a [`Vec`] or as a slice (`&[W]`), which is copied once (see
+  /// [`TessBuilder::set_vertices`]).
   pub fn set_instances<W, X>(self, instances: X) -> TessBuilder<'a, B, V, I, W, Interleaved>
   where
     X: Into<Vec<W>>,
@@ -724,11 +1147,98 @@ where
       render_vert_nb: self.render_vert_nb,
       render_inst_nb: self.render_inst_nb,
       restart_index: self.restart_index,
+      restart_fixed: self.restart_fixed,
+      index_base: self.index_base,
+      append_mode: self.append_mode,
       _phantom: PhantomData,
     }
   }
 }
 
+// mesh merging, interleaved version
+impl<'a, B, V, I, W> TessBuilder<'a, B, V, I, W, Interleaved>
+where
+  B: ?Sized,
+  V: TessVertexData<Interleaved, Data = Vec<V>>,
+  I: TessIndex,
+  W: TessVertexData<Interleaved>,
+{
+  /// Append vertices to the ones already bundled in the [`Tess`].
+  ///
+  /// Contrary to [`TessBuilder::set_vertices`], the vertices are added after the ones already present. Indices
+  /// appended with [`TessBuilder::append_indices`] afterwards will refer to those newly appended vertices. This is
+  /// useful to merge several meshes into a single [`Tess`], so that they can be rendered with a single draw call.
+  pub fn append_vertices<X>(mut self, vertices: X) -> Self
+  where
+    X: Into<Vec<V>>,
+  {
+    let vertex_data = self.vertex_data.get_or_insert_with(Vec::new);
+    self.index_base = vertex_data.len();
+    vertex_data.extend(vertices.into());
+    self
+  }
+
+  /// Append indices to the ones already bundled in the [`Tess`].
+  ///
+  /// Contrary to [`TessBuilder::set_indices`], the indices are added after the ones already present. They are rebased
+  /// so that they refer to the vertices appended by the last call to [`TessBuilder::append_vertices`]. If the [`Mode`]
+  /// allows primitive restart, the primitive restart index is inserted before the appended indices so that the merged
+  /// primitives don’t get connected to each other; it must then be set before appending indices to existing ones.
+  /// Primitive restart indices are not rebased.
+  ///
+  /// This method fails with [`TessError::CannotCreate`] if the [`Mode`] is not the same as the one used to append the
+  /// previous indices, if the [`Mode`] allows primitive restart but no primitive restart index is set, if a rebased
+  /// index cannot be represented with `I`, or if a rebased index is equal to the primitive restart index, as it would
+  /// restart the primitive instead of referring to a vertex.
+  pub fn append_indices<X>(mut self, indices: X) -> Result<Self, TessError>
+  where
+    X: Into<Vec<I>>,
+  {
+    self.check_append_mode()?;
+    self.append_mode = Some(self.mode);
+
+    let base = u32::try_from(self.index_base)
+      .map_err(|_| TessError::cannot_create("too many vertices to rebase indices"))?;
+    let restart_index = self.restart_index.and_then(I::try_into_u32);
+
+    if !self.index_data.is_empty() && self.mode.allows_primitive_restart() {
+      let restart_index = self.restart_index.ok_or_else(|| {
+        TessError::cannot_create(format!(
+          "cannot merge {} primitives without a primitive restart index",
+          self.mode
+        ))
+      })?;
+
+      self.index_data.push(restart_index);
+    }
+
+    for index in indices.into() {
+      let index = match index.try_into_u32() {
+        Some(index) if Some(index) != restart_index => {
+          let rebased = index.checked_add(base);
+
+          if rebased.is_some() && rebased == restart_index {
+            return Err(TessError::cannot_create(format!(
+              "rebased index {} + {} collides with the primitive restart index",
+              index, base
+            )));
+          }
+
+          rebased.and_then(I::try_from_u32).ok_or_else(|| {
+            TessError::cannot_create(format!("rebased index {} + {} overflows", index, base))
+          })?
+        }
+
+        _ => index,
+      };
+
+      self.index_data.push(index);
+    }
+
+    Ok(self)
+  }
+}
+
 impl<'a, B, V, I, W> TessBuilder<'a, B, V, I, W, Deinterleaved>
 where
   B: ?Sized,
@@ -745,15 +1255,7 @@ where
     V: Deinterleave<A>,
   {
     let build_raw = |deinterleaved: &mut Vec<DeinterleavedData>| {
-      // turn the attribute into a raw vector (Vec<u8>)
-      let boxed_slice = attributes.into().into_boxed_slice();
-      let len = boxed_slice.len();
-      let len_bytes = len * std::mem::size_of::<A>();
-      let ptr = Box::into_raw(boxed_slice);
-      // please Dog pardon me
-      let raw = unsafe { Vec::from_raw_parts(ptr as _, len_bytes, len_bytes) };
-
-      deinterleaved[V::RANK] = DeinterleavedData { raw, len };
+      deinterleaved[V::RANK] = DeinterleavedData::from_attributes(attributes.into());
     };
 
     match self.vertex_data {
@@ -782,15 +1284,7 @@ where
     W: Deinterleave<A>,
   {
     let build_raw = |deinterleaved: &mut Vec<DeinterleavedData>| {
-      // turn the attribute into a raw vector (Vec<u8>)
-      let boxed_slice = attributes.into().into_boxed_slice();
-      let len = boxed_slice.len();
-      let len_bytes = len * std::mem::size_of::<A>();
-      let ptr = Box::into_raw(boxed_slice);
-      // please Dog pardon me
-      let raw = unsafe { Vec::from_raw_parts(ptr as _, len_bytes, len_bytes) };
-
-      deinterleaved[W::RANK] = DeinterleavedData { raw, len };
+      deinterleaved[W::RANK] = DeinterleavedData::from_attributes(attributes.into());
     };
 
     match self.instance_data {
@@ -821,17 +1315,27 @@ where
   /// Build a [`Tess`] if the [`TessBuilder`] has enough data and is in a valid state. What is
   /// needed is backend-dependent but most of the time, you will want to:
   ///
-  /// - Set a [`Mode`].
+  /// - Set a [`Mode`]. [`Mode::Patch`] must hold at least one vertex and no more than the backend supports, or
+  ///   [`TessError::ForbiddenPrimitiveMode`] is returned.
   /// - Give vertex data and optionally indices, or give none of them but only a number of vertices
   ///   (attributeless objects).
   /// - If you provide vertex data by submitting several sets with [`TessBuilder::set_attributes`]
   ///   and/or [`TessBuilder::set_instances`], do not forget that you must submit sets with the
   ///   same size. Otherwise, the GPU will not know what values use for missing attributes in
   ///   vertices.
+  /// - If you provide both vertex data and indices, every index (but the primitive restart one) must reference an
+  ///   existing vertex. With `debug-validate`, out-of-range indices make `build` fail with
+  ///   [`TessError::IndexOutOfRange`]; without it they’re unchecked and yield undefined rendering.
   pub fn build(self) -> Result<Tess<B, V, I, W, S>, TessError> {
     // validate input data before giving it to the backend
+    self.check_append_mode()?;
+    self.check_mode()?;
     let render_vert_nb = self.guess_render_vertex_len()?;
+    #[cfg(feature = "debug-validate")]
+    self.check_indices()?;
     let render_inst_nb = self.guess_render_instance_len()?;
+    let mode = self.mode;
+    let restart_index = self.restart_index;
 
     unsafe {
       self
@@ -847,33 +1351,129 @@ where
           repr,
           render_vert_nb,
           render_inst_nb,
+          mode,
+          restart_index,
           _phantom: PhantomData,
         })
     }
   }
 
+  /// Validate the [`TessBuilder`] without building the [`Tess`].
+  ///
+  /// This method runs the same checks as [`TessBuilder::build`], but instead of stopping at the first failure, it
+  /// collects all of them. On top of that, it checks that the [`Mode`] is valid, that the number of vertices to render
+  /// forms whole primitives (see [`Mode::accepts_vertex_nb`]) and that every index references an existing vertex,
+  /// whether the `debug-validate` feature is enabled or not.
+  ///
+  /// No GPU resource is allocated by this method.
+  pub fn validate(&self) -> Result<(), Vec<TessError>> {
+    let mut errors = Vec::new();
+
+    if let Err(e) = self.check_append_mode() {
+      errors.push(e);
+    }
+
+    if let Err(e) = self.check_mode() {
+      errors.push(e);
+    }
+
+    match self.guess_render_vertex_len() {
+      Ok(render_vert_nb) => {
+        if let Err(e) = self.check_mode_vertex_nb(render_vert_nb) {
+          errors.push(e);
+        }
+      }
+
+      Err(e) => errors.push(e),
+    }
+
+    if let Err(e) = self.guess_render_instance_len() {
+      errors.push(e);
+    }
+
+    if let Err(e) = self.check_indices() {
+      errors.push(e);
+    }
+
+    if errors.is_empty() {
+      Ok(())
+    } else {
+      Err(errors)
+    }
+  }
+
+  /// Check that the primitive mode can be used at all.
+  ///
+  /// Patches must hold at least one vertex and no more than what the backend supports.
+  fn check_mode(&self) -> Result<(), TessError> {
+    match self.mode {
+      Mode::Patch(nb) if nb == 0 || nb > self.backend.max_patch_vertex_nb() => {
+        Err(TessError::forbidden_primitive_mode(self.mode))
+      }
+      _ => Ok(()),
+    }
+  }
+
+  /// Check that every index references an existing vertex.
+  ///
+  /// Primitive restart indices are skipped. Nothing is checked if no vertex data was provided (attributeless
+  /// tessellations) or if its length is incoherent, which is reported by [`TessBuilder::guess_render_vertex_len`].
+  fn check_indices(&self) -> Result<(), TessError> {
+    let vertex_count = match self.vertex_data.as_ref().map(V::coherent_len) {
+      Some(Ok(vertex_count)) => vertex_count,
+      _ => return Ok(()),
+    };
+    let restart_index = self.restart_index.and_then(I::try_into_u32);
+
+    for index in self
+      .index_data
+      .iter()
+      .filter_map(|index| index.try_into_u32())
+    {
+      if Some(index) != restart_index && index as usize >= vertex_count {
+        return Err(TessError::index_out_of_range(index, vertex_count));
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Check that the number of vertices to render forms whole primitives.
+  fn check_mode_vertex_nb(&self, render_vert_nb: usize) -> Result<(), TessError> {
+    // a patch mode that cannot form any primitive is already reported by check_mode
+    if self.mode.accepts_vertex_nb(render_vert_nb) || self.mode == Mode::Patch(0) {
+      Ok(())
+    } else {
+      Err(TessError::cannot_create(format!(
+        "{} vertices cannot form whole {} primitives",
+        render_vert_nb, self.mode
+      )))
+    }
+  }
+
   /// Guess how many vertices we want to render by default.
   fn guess_render_vertex_len(&self) -> Result<usize, TessError> {
+    // the vertex data must be coherent even if the length is taken from the index data
+    let coherent_len = match self.vertex_data {
+      Some(ref data) => Some(V::coherent_len(data)?),
+      None => None,
+    };
+
     // if we don’t have an explicit number of vertex to render, we rely on the vertex data coherent
     // length
     if self.render_vert_nb == 0 {
       // if we don’t have index data, get the length from the vertex data; otherwise, get it from
       // the index data
       if self.index_data.is_empty() {
-        match self.vertex_data {
-          Some(ref data) => V::coherent_len(data),
-          None => Err(TessError::NoData),
-        }
+        coherent_len.ok_or(TessError::NoData)
       } else {
         Ok(self.index_data.len())
       }
     } else {
       // ensure the length is okay regarding what we have in the index / vertex data
       if self.index_data.is_empty() {
-        match self.vertex_data {
-          Some(ref data) => {
-            let coherent_len = V::coherent_len(data)?;
-
+        match coherent_len {
+          Some(coherent_len) => {
             if self.render_vert_nb <= coherent_len {
               Ok(self.render_vert_nb)
             } else {
@@ -902,21 +1502,126 @@ where
         None => Ok(0),
       }
     } else {
-      let coherent_len = self
-        .instance_data
-        .as_ref()
-        .ok_or_else(|| TessError::attributeless_error("missing number of instances"))
-        .and_then(W::coherent_len)?;
-
-      if self.render_inst_nb <= coherent_len {
-        Ok(self.render_inst_nb)
-      } else {
-        Err(TessError::length_incoherency(self.render_inst_nb))
+      match self.instance_data {
+        Some(ref data) => {
+          if self.render_inst_nb <= W::coherent_len(data)? {
+            Ok(self.render_inst_nb)
+          } else {
+            Err(TessError::length_incoherency(self.render_inst_nb))
+          }
+        }
+
+        // attributeless instancing, always accept
+        None => Ok(self.render_inst_nb),
       }
     }
   }
 }
 
+// build_with_minimal_index_type, which works only if I = u32
+impl<'a, B, V, W, S> TessBuilder<'a, B, V, u32, W, S>
+where
+  B: ?Sized + TessBackend<V, u8, W, S> + TessBackend<V, u16, W, S> + TessBackend<V, u32, W, S>,
+  V: TessVertexData<S>,
+  W: TessVertexData<S>,
+{
+  /// Build a [`Tess`] with the narrowest index type able to reference all of its vertices.
+  ///
+  /// Indices are provided as [`u32`] — typically when merging many meshes with [`TessBuilder::append_indices`] —
+  /// and converted to [`u8`] or [`u16`] when possible, which saves memory. The index type is picked at runtime, so the
+  /// [`Tess`] is returned wrapped in a [`MinimalIndexTess`].
+  ///
+  /// The picked type must represent the number of vertices — or the greatest index, if bigger or if there is no
+  /// vertex data. If primitive restart is enabled, the maximum value of the type is reserved for the primitive restart
+  /// index: for instance, 256 vertices need [`u16`] indices with primitive restart, but fit [`u8`] indices without.
+  /// A primitive restart index set with [`TessBuilder::set_primitive_restart_index`] must be representable by the
+  /// picked type too.
+  ///
+  /// Apart from that, this method behaves like [`TessBuilder::build`].
+  pub fn build_with_minimal_index_type(self) -> Result<MinimalIndexTess<B, V, W, S>, TessError> {
+    match self.minimal_index_type() {
+      TessIndexType::U8 => self
+        .narrow_indices::<u8>()?
+        .build()
+        .map(MinimalIndexTess::U8),
+      TessIndexType::U16 => self
+        .narrow_indices::<u16>()?
+        .build()
+        .map(MinimalIndexTess::U16),
+      TessIndexType::U32 => self.build().map(MinimalIndexTess::U32),
+    }
+  }
+
+  /// Find the narrowest index type able to represent the indices and the vertices.
+  fn minimal_index_type(&self) -> TessIndexType {
+    let restart_index = self.restart_index;
+    let vertex_count = self
+      .vertex_data
+      .as_ref()
+      .and_then(|data| V::coherent_len(data).ok())
+      .unwrap_or(0);
+    let index_count = self
+      .index_data
+      .iter()
+      .filter(|&&index| Some(index) != restart_index)
+      .max()
+      .map_or(0, |&index| index as usize + 1);
+    let count = vertex_count.max(index_count);
+
+    [TessIndexType::U8, TessIndexType::U16]
+      .iter()
+      .copied()
+      .find(|ty| {
+        let max = ty.fixed_restart_index();
+
+        match restart_index {
+          // the fixed primitive restart index is the maximum value, which cannot be used as a regular index
+          Some(_) if self.restart_fixed => count <= max as usize,
+          Some(restart_index) => count <= max as usize + 1 && restart_index <= max,
+          None => count <= max as usize + 1,
+        }
+      })
+      .unwrap_or(TessIndexType::U32)
+  }
+
+  /// Convert the indices and the primitive restart index to `I`.
+  fn narrow_indices<I>(self) -> Result<TessBuilder<'a, B, V, I, W, S>, TessError>
+  where
+    I: TessIndex,
+  {
+    let restart_index = if self.restart_fixed {
+      fixed_restart_index()
+    } else {
+      self.restart_index.and_then(I::try_from_u32)
+    };
+    let index_data = self
+      .index_data
+      .into_iter()
+      .map(|index| match restart_index {
+        // the fixed primitive restart index is the maximum value of the type, so it changes with the type
+        Some(restart_index) if Some(index) == self.restart_index => Ok(restart_index),
+        _ => I::try_from_u32(index)
+          .ok_or_else(|| TessError::cannot_create(format!("index {} cannot be narrowed", index))),
+      })
+      .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(TessBuilder {
+      backend: self.backend,
+      vertex_data: self.vertex_data,
+      index_data,
+      instance_data: self.instance_data,
+      mode: self.mode,
+      render_vert_nb: self.render_vert_nb,
+      render_inst_nb: self.render_inst_nb,
+      restart_index,
+      restart_fixed: self.restart_fixed,
+      index_base: self.index_base,
+      append_mode: self.append_mode,
+      _phantom: PhantomData,
+    })
+  }
+}
+
 /// A GPU vertex set.
 ///
 /// Vertex set are the only way to represent space data. The dimension you choose is up to you, but
@@ -948,8 +1653,14 @@ where
   // default number of instances to render
   render_inst_nb: usize,
 
-  _phantom: PhantomData<*const S>,
-}
+  // primitive mode the tessellation was built with
+  mode: Mode,
+
+  // primitive restart index the tessellation was built with
+  restart_index: Option<I>,
+
+  _phantom: PhantomData<*const S>,
+}
 
 impl<B, V, I, W, S> Tess<B, V, I, W, S>
 where
@@ -970,6 +1681,9 @@ where
   }
 
   /// Get the number of instances.
+  ///
+  /// This is the number of instance data held by the [`Tess`], which is `0` without instance data — even if instances
+  /// are rendered (see [`Tess::render_inst_nb`]).
   pub fn inst_nb(&self) -> usize {
     unsafe { B::tess_instances_nb(&self.repr) }
   }
@@ -990,6 +1704,11 @@ where
     self.render_inst_nb
   }
 
+  /// Primitive mode the [`Tess`] was built with.
+  pub fn mode(&self) -> Mode {
+    self.mode
+  }
+
   /// Slice the [`Tess`] in order to read its content via usual slices.
   ///
   /// This method gives access to the underlying _index storage_.
@@ -1009,6 +1728,17 @@ where
   {
     unsafe { B::indices_mut(&mut self.repr).map(|repr| IndicesMut { repr }) }
   }
+
+  /// Copy the indices of the [`Tess`] into an owned [`Vec`].
+  ///
+  /// This method maps the _index storage_, copies it and unmaps it right away, so that the returned indices are not
+  /// tied to any borrow of the [`Tess`].
+  pub fn read_indices<'a>(&'a mut self) -> Result<Vec<I>, TessMapError>
+  where
+    B: IndexSliceBackend<'a, V, I, W, S>,
+  {
+    self.indices().map(|indices| indices.to_vec())
+  }
 }
 
 impl<B, V, I, W> Tess<B, V, I, W, Interleaved>
@@ -1065,6 +1795,243 @@ where
   {
     unsafe { B::instances_mut(&mut self.repr).map(|repr| InstancesMut { repr }) }
   }
+
+  /// Replace the vertices starting at `start` with `data`.
+  ///
+  /// Only the updated range is uploaded, which makes this method much cheaper than [`Tess::vertices_mut`] when a few
+  /// vertices of a large [`Tess`] change. [`TessMapError::VertexRangeOutOfBounds`] is returned if `start + data.len()`
+  /// exceeds [`Tess::vert_nb`].
+  pub fn update_vertices_range(&mut self, start: usize, data: &[V]) -> Result<(), TessMapError>
+  where
+    B: VertexUpdateBackend<V, I, W>,
+  {
+    let vert_nb = self.vert_nb();
+
+    match start.checked_add(data.len()) {
+      Some(end) if end <= vert_nb => unsafe { B::update_vertices(&mut self.repr, start, data) },
+      _ => Err(TessMapError::vertex_range_out_of_bounds(
+        start,
+        data.len(),
+        vert_nb,
+      )),
+    }
+  }
+
+  /// Copy the vertices of the [`Tess`] into an owned [`Vec`].
+  ///
+  /// This method maps the _vertex storage_, copies it and unmaps it right away, so that the returned vertices are not
+  /// tied to any borrow of the [`Tess`].
+  pub fn read_vertices<'a>(&'a mut self) -> Result<Vec<V>, TessMapError>
+  where
+    B: VertexSliceBackend<'a, V, I, W, Interleaved, V>,
+    V: Clone,
+  {
+    self.vertices().map(|vertices| vertices.to_vec())
+  }
+
+  /// Convert the [`Tess`] into a [`Tess`] with a deinterleaved memory layout.
+  ///
+  /// The vertices, indices and instances are mapped and copied back on the CPU, split into one set per attribute and
+  /// used to build a new [`Tess`], which has the same mode, primitive restart index and default numbers of vertices
+  /// and instances to render. `self` is left untouched.
+  ///
+  /// Vertex types must be `#[repr(C)]`, so that the location of their attributes is known. Mapping errors are reported
+  /// as [`TessError::MapError`].
+  pub fn to_deinterleaved<C>(
+    &mut self,
+    ctx: &mut C,
+  ) -> Result<Tess<B, V, I, W, Deinterleaved>, TessError>
+  where
+    C: GraphicsContext<Backend = B>,
+    B: TessBackend<V, I, W, Deinterleaved>
+      + for<'a> VertexSliceBackend<'a, V, I, W, Interleaved, V>
+      + for<'a> IndexSliceBackend<'a, V, I, W, Interleaved>
+      + for<'a> InstanceSliceBackend<'a, V, I, W, Interleaved, W>,
+  {
+    let vertex_data = if self.vert_nb() == 0 {
+      None
+    } else {
+      let vertices = self.vertices().map_err(TessError::map_error)?;
+      Some(deinterleave(&vertices))
+    };
+
+    let index_data = if self.idx_nb() == 0 {
+      Vec::new()
+    } else {
+      self.read_indices().map_err(TessError::map_error)?
+    };
+
+    let instance_data = if self.inst_nb() == 0 {
+      None
+    } else {
+      let instances = self.instances().map_err(TessError::map_error)?;
+      Some(deinterleave(&instances))
+    };
+
+    TessBuilder {
+      backend: ctx.backend(),
+      vertex_data,
+      index_data,
+      instance_data,
+      mode: self.mode,
+      render_vert_nb: self.render_vert_nb,
+      render_inst_nb: self.render_inst_nb,
+      restart_index: self.restart_index,
+      restart_fixed: false,
+      index_base: 0,
+      append_mode: None,
+      _phantom: PhantomData,
+    }
+    .build()
+  }
+
+  /// Iterate over the primitives of the [`Tess`].
+  ///
+  /// The primitives are decoded from the [`Mode`] of the [`Tess`]: strips and fans are expanded into independent
+  /// lines and triangles, indices are resolved and the primitive restart index is honored. The [`Tess`] is iterated up
+  /// to its default number of vertices to render ([`Tess::render_vert_nb`]).
+  ///
+  /// This method maps the _vertex storage_ and, for indexed geometry, the _index storage_, and copies them, so that
+  /// the returned iterator doesn’t borrow the [`Tess`]. Primitives referencing out-of-range vertices are skipped.
+  pub fn primitives(&mut self) -> Result<PrimitiveIter<V>, TessMapError>
+  where
+    B: for<'a> VertexSliceBackend<'a, V, I, W, Interleaved, V>
+      + for<'a> IndexSliceBackend<'a, V, I, W, Interleaved>,
+    V: Clone,
+  {
+    let (vertices, primitives) = self.index_primitives()?;
+    let primitives = primitives
+      .into_iter()
+      .map(|primitive| primitive.map(|i| vertices[i]))
+      .collect::<Vec<_>>();
+
+    Ok(PrimitiveIter {
+      primitives: primitives.into_iter(),
+    })
+  }
+
+  /// Read the vertices of the [`Tess`] along with its primitives, made of indices into these vertices.
+  ///
+  /// See [`Tess::primitives`].
+  fn index_primitives(&mut self) -> Result<(Vec<V>, Vec<Primitive<usize>>), TessMapError>
+  where
+    B: for<'a> VertexSliceBackend<'a, V, I, W, Interleaved, V>
+      + for<'a> IndexSliceBackend<'a, V, I, W, Interleaved>,
+    V: Clone,
+  {
+    let vertices = self.read_vertices()?;
+    let restart_index = self.restart_index.and_then(I::try_into_u32);
+
+    // sequence of vertex indices to walk, None being a primitive restart
+    let sequence: Vec<_> = if I::INDEX_TYPE.is_some() && self.idx_nb() > 0 {
+      self
+        .read_indices()?
+        .into_iter()
+        .take(self.render_vert_nb)
+        .map(|index| {
+          index
+            .try_into_u32()
+            .filter(|&index| Some(index) != restart_index)
+            .map(|index| index as usize)
+        })
+        .collect()
+    } else {
+      (0..self.render_vert_nb.min(vertices.len()))
+        .map(Some)
+        .collect()
+    };
+
+    // decode primitives of vertex indices; out-of-range indices are dropped by the decoding
+    let vertex_indices: Vec<_> = (0..vertices.len()).collect();
+    let mut primitives = Vec::new();
+    for segment in sequence.split(Option::is_none) {
+      let segment: Vec<_> = segment.iter().flatten().copied().collect();
+      decode_primitives(self.mode, &segment, &vertex_indices, &mut primitives);
+    }
+
+    Ok((vertices, primitives))
+  }
+
+  /// Export the geometry of the [`Tess`] to the [Wavefront OBJ] format.
+  ///
+  /// `position` extracts the position of each vertex, written as `v` records. `normal` extracts the normal of each
+  /// vertex, if any; `vn` records are written only if `normal` returns a normal for every vertex, so pass `|_| None`
+  /// to export positions only.
+  ///
+  /// Primitives are decoded as with [`Tess::primitives`]: strips and fans are expanded, indices are resolved and the
+  /// primitive restart index is honored. Triangles are written as `f` records, lines as `l` records and points as `p`
+  /// records. Patches are skipped, as their geometry depends on tessellation shaders. No material is written.
+  ///
+  /// This is mostly useful to debug generated geometry by opening it in a 3D modeling software.
+  ///
+  /// [Wavefront OBJ]: https://en.wikipedia.org/wiki/Wavefront_.obj_file
+  #[cfg(feature = "obj-export")]
+  pub fn export_obj<Wr, P, N>(
+    &mut self,
+    w: &mut Wr,
+    position: P,
+    normal: N,
+  ) -> Result<(), ObjExportError>
+  where
+    B: for<'a> VertexSliceBackend<'a, V, I, W, Interleaved, V>
+      + for<'a> IndexSliceBackend<'a, V, I, W, Interleaved>,
+    V: Clone,
+    Wr: std::io::Write,
+    P: Fn(&V) -> [f32; 3],
+    N: Fn(&V) -> Option<[f32; 3]>,
+  {
+    let (vertices, primitives) = self.index_primitives()?;
+    let normals: Option<Vec<_>> = vertices.iter().map(normal).collect();
+
+    for [x, y, z] in vertices.iter().map(position) {
+      writeln!(w, "v {} {} {}", x, y, z)?;
+    }
+
+    if let Some(ref normals) = normals {
+      for [x, y, z] in normals {
+        writeln!(w, "vn {} {} {}", x, y, z)?;
+      }
+    }
+
+    for primitive in primitives {
+      match primitive {
+        Primitive::Point([a]) => writeln!(w, "p {}", a + 1)?,
+        Primitive::Line([a, b]) => writeln!(w, "l {} {}", a + 1, b + 1)?,
+
+        Primitive::Triangle([a, b, c]) => {
+          let (a, b, c) = (a + 1, b + 1, c + 1);
+
+          if normals.is_some() {
+            writeln!(w, "f {}//{} {}//{} {}//{}", a, a, b, b, c, c)?;
+          } else {
+            writeln!(w, "f {} {} {}", a, b, c)?;
+          }
+        }
+
+        Primitive::Patch(_) => (),
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Compute the axis-aligned bounding box of the vertices.
+  ///
+  /// `position` extracts the position of each vertex. The bounding box is returned as a `(min, max)` pair.
+  ///
+  /// This method maps the _vertex storage_ and fails with [`TessMapError::NoData`] if the [`Tess`] doesn’t have any
+  /// vertex.
+  pub fn bounding_box<'a, F>(
+    &'a mut self,
+    position: F,
+  ) -> Result<([f32; 3], [f32; 3]), TessMapError>
+  where
+    B: VertexSliceBackend<'a, V, I, W, Interleaved, V>,
+    F: Fn(&V) -> [f32; 3],
+  {
+    let vertices = self.vertices()?;
+    bounding_box(vertices.iter().map(position))
+  }
 }
 
 impl<B, V, I, W> Tess<B, V, I, W, Deinterleaved>
@@ -1087,6 +2054,22 @@ where
     unsafe { B::vertices(&mut self.repr).map(|repr| Vertices { repr }) }
   }
 
+  /// Slice all the attributes of the [`Tess`] at once in order to read its content via usual slices.
+  ///
+  /// This method gives access to the underlying _vertex storage_, without having to map each attribute with
+  /// [`Tess::vertices`]. Use [`VerticesAll::slices`] to get the slices:
+  ///
+  /// ```ignore
+  /// let vertices = tess.vertices_all()?;
+  /// let VertexSliceSet { position, color } = vertices.slices();
+  /// ```
+  pub fn vertices_all<'a>(&'a mut self) -> Result<VerticesAll<'a, B, V, I, W>, TessMapError>
+  where
+    B: VertexSliceSetBackend<'a, V, I, W>,
+  {
+    unsafe { B::vertices_all(&mut self.repr).map(|repr| VerticesAll { repr }) }
+  }
+
   /// Slice the [`Tess`] in order to read its content via usual slices.
   ///
   /// This method gives access to the underlying _vertex storage_.
@@ -1125,6 +2108,488 @@ where
   {
     unsafe { B::instances_mut(&mut self.repr).map(|repr| InstancesMut { repr }) }
   }
+
+  /// Convert the [`Tess`] into a [`Tess`] with an interleaved memory layout.
+  ///
+  /// The attributes and indices are mapped and copied back on the CPU, gathered into vertices and used to build a new
+  /// [`Tess`], which has the same mode, primitive restart index and default numbers of vertices and instances to
+  /// render. `self` is left untouched.
+  ///
+  /// Vertex types must be `#[repr(C)]`, so that the location of their attributes is known. Instance attributes cannot
+  /// be mapped all at once: a [`Tess`] with instances fails to convert with [`TessError::MapError`] holding
+  /// [`TessMapError::ForbiddenDeinterleavedMapping`], as do other mapping errors.
+  pub fn to_interleaved<C>(
+    &mut self,
+    ctx: &mut C,
+  ) -> Result<Tess<B, V, I, W, Interleaved>, TessError>
+  where
+    C: GraphicsContext<Backend = B>,
+    B: TessBackend<V, I, W, Interleaved>
+      + for<'a> VertexSliceSetBackend<'a, V, I, W>
+      + for<'a> IndexSliceBackend<'a, V, I, W, Deinterleaved>,
+  {
+    if self.inst_nb() != 0 {
+      return Err(TessError::map_error(
+        TessMapError::forbidden_deinterleaved_mapping(),
+      ));
+    }
+
+    let vert_nb = self.vert_nb();
+    let vertex_data = if vert_nb == 0 {
+      None
+    } else {
+      // attributes have a coherent length, so every vertex gets all of its attributes
+      let vertices = self.vertices_all().map_err(TessError::map_error)?;
+      Some(interleave(vert_nb, |rank| unsafe {
+        B::attribute_bytes(&vertices.repr, rank)
+      }))
+    };
+
+    let index_data = if self.idx_nb() == 0 {
+      Vec::new()
+    } else {
+      self.read_indices().map_err(TessError::map_error)?
+    };
+
+    TessBuilder {
+      backend: ctx.backend(),
+      vertex_data,
+      index_data,
+      instance_data: None,
+      mode: self.mode,
+      render_vert_nb: self.render_vert_nb,
+      render_inst_nb: self.render_inst_nb,
+      restart_index: self.restart_index,
+      restart_fixed: false,
+      index_base: 0,
+      append_mode: None,
+      _phantom: PhantomData,
+    }
+    .build()
+  }
+
+  /// Compute the axis-aligned bounding box of the vertices.
+  ///
+  /// `T` is the position attribute. The bounding box is returned as a `(min, max)` pair.
+  ///
+  /// This method maps the `T` attribute of the _vertex storage_ and fails with [`TessMapError::NoData`] if the
+  /// [`Tess`] doesn’t have any vertex.
+  pub fn bounding_box<'a, T>(&'a mut self) -> Result<([f32; 3], [f32; 3]), TessMapError>
+  where
+    B: VertexSliceBackend<'a, V, I, W, Deinterleaved, T>,
+    V: Deinterleave<T>,
+    T: Deref<Target = [f32; 3]>,
+  {
+    let positions = self.vertices::<T>()?;
+    bounding_box(positions.iter().map(|p| **p))
+  }
+}
+
+/// A [`Tess`] which index type was picked at runtime.
+///
+/// Such tessellations are built with [`TessBuilder::build_with_minimal_index_type`]. Match on the variants to get the
+/// typed [`Tess`] back, for instance to render it.
+pub enum MinimalIndexTess<B, V, W = (), S = Interleaved>
+where
+  B: ?Sized + TessBackend<V, u8, W, S> + TessBackend<V, u16, W, S> + TessBackend<V, u32, W, S>,
+  V: TessVertexData<S>,
+  W: TessVertexData<S>,
+  S: ?Sized,
+{
+  /// Tessellation with 8-bit indices.
+  U8(Tess<B, V, u8, W, S>),
+  /// Tessellation with 16-bit indices.
+  U16(Tess<B, V, u16, W, S>),
+  /// Tessellation with 32-bit indices.
+  U32(Tess<B, V, u32, W, S>),
+}
+
+impl<B, V, W, S> MinimalIndexTess<B, V, W, S>
+where
+  B: ?Sized + TessBackend<V, u8, W, S> + TessBackend<V, u16, W, S> + TessBackend<V, u32, W, S>,
+  V: TessVertexData<S>,
+  W: TessVertexData<S>,
+  S: ?Sized,
+{
+  /// Index type of the wrapped [`Tess`].
+  pub fn index_type(&self) -> TessIndexType {
+    match self {
+      MinimalIndexTess::U8(_) => TessIndexType::U8,
+      MinimalIndexTess::U16(_) => TessIndexType::U16,
+      MinimalIndexTess::U32(_) => TessIndexType::U32,
+    }
+  }
+
+  /// Number of vertices of the wrapped [`Tess`].
+  pub fn vert_nb(&self) -> usize {
+    match self {
+      MinimalIndexTess::U8(tess) => tess.vert_nb(),
+      MinimalIndexTess::U16(tess) => tess.vert_nb(),
+      MinimalIndexTess::U32(tess) => tess.vert_nb(),
+    }
+  }
+
+  /// Number of indices of the wrapped [`Tess`].
+  pub fn idx_nb(&self) -> usize {
+    match self {
+      MinimalIndexTess::U8(tess) => tess.idx_nb(),
+      MinimalIndexTess::U16(tess) => tess.idx_nb(),
+      MinimalIndexTess::U32(tess) => tess.idx_nb(),
+    }
+  }
+}
+
+/// A tessellation built from raw bytes, with a vertex layout known at runtime.
+///
+/// Contrary to [`Tess`], a [`RawTess`] is not typed with its vertex type: its vertices are provided as interleaved
+/// bytes, along with a [`VertexDesc`] describing their attributes. Vertices must be laid out the same way as the
+/// vertices of a [`Tess`] with the same [`VertexDesc`]: each attribute is aligned on its alignment, in the order of the
+/// [`VertexDesc`], as with `#[repr(C)]` vertex types. Indices, if any, are provided as bytes too, along with their
+/// [`TessIndexType`].
+///
+/// A [`RawTess`] is created with [`RawTess::new`] or [`GraphicsContext::new_raw_tess`], and rendered as a whole with
+/// [`TessGate::render_raw`](crate::tess_gate::TessGate::render_raw).
+#[derive(Debug)]
+pub struct RawTess<B>
+where
+  B: ?Sized + RawTessBackend,
+{
+  pub(crate) repr: B::RawTessRepr,
+
+  // primitive mode the tessellation was built with
+  mode: Mode,
+}
+
+impl<B> RawTess<B>
+where
+  B: ?Sized + RawTessBackend,
+{
+  /// Create a [`RawTess`] from interleaved vertex bytes described by `desc`, optional indices and a [`Mode`].
+  ///
+  /// `indices` holds the raw bytes of the indices along with their type. [`TessError::AttributelessError`] is returned
+  /// if `desc` has no attribute, [`TessError::ForbiddenPrimitiveMode`] if `mode` is `Mode::Patch(0)`, and
+  /// [`TessError::LengthIncoherency`] if the length of `vertices` or of the indices is not a multiple of the size of a
+  /// vertex or of an index. If the `debug-validate` feature is enabled, every index must reference an existing vertex,
+  /// or [`TessError::IndexOutOfRange`] is returned.
+  pub fn new<C>(
+    ctx: &mut C,
+    vertices: &[u8],
+    desc: VertexDesc,
+    indices: Option<(&[u8], TessIndexType)>,
+    mode: Mode,
+  ) -> Result<Self, TessError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    if desc.is_empty() {
+      return Err(TessError::attributeless_error(
+        "raw tessellations require at least one vertex attribute",
+      ));
+    }
+
+    // patches must hold at least one vertex
+    if mode == Mode::Patch(0) {
+      return Err(TessError::forbidden_primitive_mode(mode));
+    }
+
+    if let Some((bytes, ty)) = indices {
+      if bytes.len() % ty.bytes() != 0 {
+        return Err(TessError::length_incoherency(bytes.len()));
+      }
+    }
+
+    let repr = unsafe { ctx.backend().new_raw_tess(vertices, &desc, indices, mode)? };
+    let tess = RawTess { repr, mode };
+
+    #[cfg(feature = "debug-validate")]
+    if let Some((bytes, ty)) = indices {
+      check_raw_indices(bytes, ty, tess.vert_nb())?;
+    }
+
+    Ok(tess)
+  }
+
+  /// Get the number of vertices.
+  pub fn vert_nb(&self) -> usize {
+    unsafe { B::raw_tess_vertices_nb(&self.repr) }
+  }
+
+  /// Get the number of vertex indices.
+  pub fn idx_nb(&self) -> usize {
+    unsafe { B::raw_tess_indices_nb(&self.repr) }
+  }
+
+  /// Number of vertices rendered by [`TessGate::render_raw`](crate::tess_gate::TessGate::render_raw).
+  ///
+  /// This is the number of indices if the [`RawTess`] has indices, and the number of vertices otherwise.
+  pub fn render_vert_nb(&self) -> usize {
+    match self.idx_nb() {
+      0 => self.vert_nb(),
+      idx_nb => idx_nb,
+    }
+  }
+
+  /// Primitive mode the [`RawTess`] was built with.
+  pub fn mode(&self) -> Mode {
+    self.mode
+  }
+}
+
+/// Check that every raw index of type `ty` references one of the `vertex_count` vertices.
+#[cfg(feature = "debug-validate")]
+fn check_raw_indices(
+  bytes: &[u8],
+  ty: TessIndexType,
+  vertex_count: usize,
+) -> Result<(), TessError> {
+  for raw in bytes.chunks_exact(ty.bytes()) {
+    let index = match ty {
+      TessIndexType::U8 => raw[0].into(),
+      TessIndexType::U16 => u16::from_ne_bytes([raw[0], raw[1]]).into(),
+      TessIndexType::U32 => u32::from_ne_bytes([raw[0], raw[1], raw[2], raw[3]]),
+    };
+
+    if index as usize >= vertex_count {
+      return Err(TessError::index_out_of_range(index, vertex_count));
+    }
+  }
+
+  Ok(())
+}
+
+/// A primitive, decoded from a [`Tess`].
+///
+/// See [`Tess::primitives`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum Primitive<V> {
+  /// A point, made of a single vertex.
+  Point([V; 1]),
+  /// A line, made of two vertices.
+  Line([V; 2]),
+  /// A triangle, made of three vertices.
+  Triangle([V; 3]),
+  /// A patch, made of as many vertices as set in [`Mode::Patch`].
+  Patch(Vec<V>),
+}
+
+/// Iterator over the primitives of a [`Tess`].
+///
+/// See [`Tess::primitives`].
+#[derive(Debug)]
+pub struct PrimitiveIter<V> {
+  primitives: std::vec::IntoIter<Primitive<V>>,
+}
+
+impl<V> Iterator for PrimitiveIter<V> {
+  type Item = Primitive<V>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    self.primitives.next()
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    self.primitives.size_hint()
+  }
+}
+
+impl<V> ExactSizeIterator for PrimitiveIter<V> {}
+
+impl<V> Primitive<V> {
+  /// Map the vertices of the primitive.
+  fn map<U>(self, mut f: impl FnMut(V) -> U) -> Primitive<U> {
+    match self {
+      Primitive::Point([a]) => Primitive::Point([f(a)]),
+      Primitive::Line([a, b]) => Primitive::Line([f(a), f(b)]),
+      Primitive::Triangle([a, b, c]) => Primitive::Triangle([f(a), f(b), f(c)]),
+      Primitive::Patch(vertices) => Primitive::Patch(vertices.into_iter().map(f).collect()),
+    }
+  }
+}
+
+/// Decode the primitives formed by a sequence of vertex indices (without primitive restart) for a given [`Mode`].
+fn decode_primitives<V>(mode: Mode, indices: &[usize], vertices: &[V], out: &mut Vec<Primitive<V>>)
+where
+  V: Clone,
+{
+  // resolve the vertices of a primitive, or None if one of them is out of range
+  let get = |primitive: &[usize]| -> Option<Vec<V>> {
+    primitive
+      .iter()
+      .map(|&i| vertices.get(i).cloned())
+      .collect()
+  };
+
+  match mode {
+    Mode::Point => out.extend(
+      indices
+        .chunks_exact(1)
+        .filter_map(get)
+        .map(|v| Primitive::Point(to_array(v))),
+    ),
+
+    Mode::Line => out.extend(
+      indices
+        .chunks_exact(2)
+        .filter_map(get)
+        .map(|v| Primitive::Line(to_array(v))),
+    ),
+
+    Mode::LineStrip => out.extend(
+      indices
+        .windows(2)
+        .filter_map(get)
+        .map(|v| Primitive::Line(to_array(v))),
+    ),
+
+    Mode::Triangle => out.extend(
+      indices
+        .chunks_exact(3)
+        .filter_map(get)
+        .map(|v| Primitive::Triangle(to_array(v))),
+    ),
+
+    Mode::TriangleStrip => out.extend(indices.windows(3).enumerate().filter_map(|(k, w)| {
+      // every other triangle has its first two vertices swapped to preserve the winding order
+      let w = if k % 2 == 0 {
+        [w[0], w[1], w[2]]
+      } else {
+        [w[1], w[0], w[2]]
+      };
+
+      get(&w).map(|v| Primitive::Triangle(to_array(v)))
+    })),
+
+    Mode::TriangleFan => {
+      if let Some((&center, rest)) = indices.split_first() {
+        out.extend(
+          rest
+            .windows(2)
+            .filter_map(|w| get(&[center, w[0], w[1]]))
+            .map(|v| Primitive::Triangle(to_array(v))),
+        );
+      }
+    }
+
+    Mode::Patch(0) => (),
+
+    Mode::Patch(n) => out.extend(
+      indices
+        .chunks_exact(n)
+        .filter_map(get)
+        .map(Primitive::Patch),
+    ),
+  }
+}
+
+/// Convert a [`Vec`] known to have `N` elements into an array.
+fn to_array<V, const N: usize>(v: Vec<V>) -> [V; N] {
+  v.try_into()
+    .unwrap_or_else(|_| unreachable!("primitive with the wrong number of vertices"))
+}
+
+/// Offsets of the attributes of `V` in memory.
+///
+/// # Panics
+///
+/// Panics if the size of `V` doesn’t match the layout described by its [`Vertex::vertex_desc`] (see
+/// [`interleaved_layout`]), as reading or writing attributes at the computed offsets would then be undefined behavior.
+fn vertex_offsets<V>() -> Vec<usize>
+where
+  V: Vertex,
+{
+  let (offsets, size) = interleaved_layout(&V::vertex_desc());
+  assert_eq!(
+    size,
+    mem::size_of::<V>(),
+    "the vertex descriptors don’t describe the memory layout of the vertices"
+  );
+
+  offsets
+}
+
+/// Split interleaved vertices into one [`DeinterleavedData`] per attribute.
+///
+/// Attributes are copied one by one from their offsets, so that the padding bytes of the vertices are never read.
+fn deinterleave<V>(vertices: &[V]) -> Vec<DeinterleavedData>
+where
+  V: Vertex,
+{
+  V::vertex_desc()
+    .iter()
+    .zip(vertex_offsets::<V>())
+    .map(|(desc, off)| {
+      let size = desc.attrib_desc.size();
+      let mut raw = Vec::with_capacity(size * vertices.len());
+
+      for vertex in vertices {
+        let attrib =
+          unsafe { slice::from_raw_parts((vertex as *const V as *const u8).add(off), size) };
+        raw.extend_from_slice(attrib);
+      }
+
+      DeinterleavedData {
+        raw,
+        len: vertices.len(),
+        populated: true,
+      }
+    })
+    .collect()
+}
+
+/// Gather the attributes of `len` deinterleaved vertices into interleaved vertices.
+///
+/// `attribute` returns the raw bytes of the attribute at the given rank, which must hold at least `len` attributes.
+/// Every attribute of every vertex is written at its offset; as [`Vertex`] implementors are laid out as described by
+/// their descriptors, this initializes all the fields of the vertices.
+fn interleave<'a, V>(len: usize, mut attribute: impl FnMut(usize) -> &'a [u8]) -> Vec<V>
+where
+  V: Vertex,
+{
+  let mut vertices = Vec::<V>::with_capacity(len);
+  let dst = vertices.as_mut_ptr() as *mut u8;
+
+  for (rank, (desc, off)) in V::vertex_desc()
+    .iter()
+    .zip(vertex_offsets::<V>())
+    .enumerate()
+  {
+    let size = desc.attrib_desc.size();
+    let src = attribute(rank);
+    assert!(
+      src.len() >= len * size,
+      "missing attributes to interleave vertices"
+    );
+
+    for (i, attrib) in src.chunks_exact(size).take(len).enumerate() {
+      unsafe {
+        ptr::copy_nonoverlapping(
+          attrib.as_ptr(),
+          dst.add(i * mem::size_of::<V>() + off),
+          size,
+        )
+      };
+    }
+  }
+
+  // every attribute of every vertex has been written
+  unsafe { vertices.set_len(len) };
+  vertices
+}
+
+/// Fold positions into a `(min, max)` axis-aligned bounding box.
+fn bounding_box(
+  mut positions: impl Iterator<Item = [f32; 3]>,
+) -> Result<([f32; 3], [f32; 3]), TessMapError> {
+  let first = positions.next().ok_or_else(TessMapError::no_data)?;
+
+  Ok(positions.fold((first, first), |(mut min, mut max), p| {
+    for i in 0..3 {
+      min[i] = min[i].min(p[i]);
+      max[i] = max[i].max(p[i]);
+    }
+
+    (min, max)
+  }))
 }
 
 /// TODO
@@ -1196,6 +2661,37 @@ where
   }
 }
 
+/// All the attributes of deinterleaved vertices, mapped at once.
+///
+/// See [`Tess::vertices_all`].
+pub struct VerticesAll<'a, B, V, I, W>
+where
+  B: ?Sized + TessBackend<V, I, W, Deinterleaved> + VertexSliceSetBackend<'a, V, I, W>,
+  V: TessVertexData<Deinterleaved>,
+  I: TessIndex,
+  W: TessVertexData<Deinterleaved>,
+{
+  repr: B::VertexSliceSetRepr,
+}
+
+impl<'a, B, V, I, W> VerticesAll<'a, B, V, I, W>
+where
+  B: ?Sized + TessBackend<V, I, W, Deinterleaved> + VertexSliceSetBackend<'a, V, I, W>,
+  V: TessVertexData<Deinterleaved>,
+  I: TessIndex,
+  W: TessVertexData<Deinterleaved>,
+{
+  /// Get one slice per attribute.
+  ///
+  /// The returned set is the `<Name>SliceSet` struct generated by the `Vertex` derive with the `#[vertex(slice_set)]`
+  /// attribute.
+  pub fn slices<'b>(&'b self) -> <V as DeinterleaveAll<'b>>::SliceSet
+  where
+    V: DeinterleaveAll<'b>,
+  {
+    unsafe { V::slice_set(|rank| B::attribute_bytes(&self.repr, rank)) }
+  }
+}
 /// TODO
 #[derive(Debug)]
 pub struct Indices<'a, B, V, I, W, S>
@@ -1349,6 +2845,14 @@ pub enum TessViewError {
     /// Requested number.
     nb: usize,
   },
+
+  /// The number of vertices of the view doesn’t form whole primitives with the requested primitive mode.
+  IncompatibleMode {
+    /// Requested primitive mode.
+    mode: Mode,
+    /// Number of vertices of the view.
+    vert_nb: usize,
+  },
 }
 
 impl fmt::Display for TessViewError {
@@ -1362,6 +2866,12 @@ impl fmt::Display for TessViewError {
         write!(f, "TessView incorrect window error: requested slice size {} starting at {}, but capacity is only {}",
           nb, start, capacity)
       }
+
+      TessViewError::IncompatibleMode { mode, vert_nb } => write!(
+        f,
+        "TessView incompatible mode error: {} vertices cannot form whole {} primitives",
+        vert_nb, mode
+      ),
     }
   }
 }
@@ -1384,8 +2894,14 @@ where
   pub(crate) start_index: usize,
   /// Number of vertices to pick from the tessellation.
   pub(crate) vert_nb: usize,
+  /// Value added to every index before fetching vertices.
+  pub(crate) base_vertex: i32,
   /// Number of instances to render.
   pub(crate) inst_nb: usize,
+  /// Index of the first instance to render.
+  pub(crate) inst_start_index: usize,
+  /// Primitive mode to render with instead of the one of the tessellation, if any.
+  pub(crate) mode: Option<Mode>,
 }
 
 impl<'a, B, V, I, W, S> TessView<'a, B, V, I, W, S>
@@ -1402,7 +2918,10 @@ where
       tess,
       start_index: 0,
       vert_nb: tess.render_vert_nb(),
+      base_vertex: 0,
       inst_nb: tess.render_inst_nb(),
+      inst_start_index: 0,
+      mode: None,
     }
   }
 
@@ -1412,7 +2931,10 @@ where
       tess,
       start_index: 0,
       vert_nb: tess.render_vert_nb(),
+      base_vertex: 0,
       inst_nb,
+      inst_start_index: 0,
+      mode: None,
     }
   }
 
@@ -1433,7 +2955,10 @@ where
       tess,
       start_index: 0,
       vert_nb,
+      base_vertex: 0,
       inst_nb: tess.render_inst_nb(),
+      inst_start_index: 0,
+      mode: None,
     })
   }
 
@@ -1458,7 +2983,10 @@ where
       tess,
       start_index: 0,
       vert_nb,
+      base_vertex: 0,
       inst_nb,
+      inst_start_index: 0,
+      mode: None,
     })
   }
 
@@ -1471,7 +2999,7 @@ where
   ) -> Result<Self, TessViewError> {
     let capacity = tess.render_vert_nb();
 
-    if start > capacity || nb + start > capacity {
+    if !matches!(start.checked_add(nb), Some(end) if end <= capacity) {
       return Err(TessViewError::IncorrectViewWindow {
         capacity,
         start,
@@ -1483,10 +3011,38 @@ where
       tess,
       start_index: start,
       vert_nb: nb,
+      base_vertex: 0,
       inst_nb: tess.render_inst_nb(),
+      inst_start_index: 0,
+      mode: None,
     })
   }
 
+  /// Create a view that is using only a subpart of the input [`Tess`], starting from `start`, with at most `nb`
+  /// vertices.
+  ///
+  /// Contrary to [`TessView::slice`], this function never fails: the window is clamped to the vertices of the [`Tess`]
+  /// instead. `start` is clamped first, to [`Tess::render_vert_nb`]; `nb` is then clamped to the number of vertices
+  /// remaining after the clamped `start`. A window completely out of the [`Tess`] then yields an empty view, which
+  /// renders nothing.
+  ///
+  /// Prefer [`TessView::slice`] when an out-of-range window is a bug.
+  pub fn clamped_slice(tess: &'a Tess<B, V, I, W, S>, start: usize, nb: usize) -> Self {
+    let capacity = tess.render_vert_nb();
+    let start = start.min(capacity);
+    let nb = nb.min(capacity - start);
+
+    TessView {
+      tess,
+      start_index: start,
+      vert_nb: nb,
+      base_vertex: 0,
+      inst_nb: tess.render_inst_nb(),
+      inst_start_index: 0,
+      mode: None,
+    }
+  }
+
   /// Create a view that is using only a subpart of the input [`Tess`], starting from `start`, with
   /// `nb` vertices and `inst_nb` instances.
   pub fn inst_slice(
@@ -1497,7 +3053,7 @@ where
   ) -> Result<Self, TessViewError> {
     let capacity = tess.render_vert_nb();
 
-    if start > capacity || nb + start > capacity {
+    if !matches!(start.checked_add(nb), Some(end) if end <= capacity) {
       return Err(TessViewError::IncorrectViewWindow {
         capacity,
         start,
@@ -1509,9 +3065,179 @@ where
       tess,
       start_index: start,
       vert_nb: nb,
+      base_vertex: 0,
       inst_nb,
+      inst_start_index: 0,
+      mode: None,
+    })
+  }
+
+  /// Create a view that is using only a subpart of the input [`Tess`], picking the vertices in `vert_range`, with
+  /// `inst_nb` instances starting at instance `inst_start`.
+  ///
+  /// This is useful to render a range of instances packed with many others in the same instance data, such as a
+  /// crowd of meshes. Per-instance attributes are fetched starting from instance `inst_start`. However, the built-in
+  /// instance index of shaders (e.g. `gl_InstanceID`) still starts at `0`.
+  ///
+  /// # Backend support
+  ///
+  /// Rendering from a base instance requires backend support (e.g. OpenGL 4.2 or `GL_ARB_base_instance`). If the
+  /// backend doesn’t support it, rendering a view with `inst_start` different from `0` fails with
+  /// [`TessError::UnsupportedBaseInstance`] and nothing is rendered. Views with `inst_start` set to `0` are always
+  /// supported.
+  pub fn inst_range(
+    tess: &'a Tess<B, V, I, W, S>,
+    vert_range: Range<usize>,
+    inst_start: usize,
+    inst_nb: usize,
+  ) -> Result<Self, TessViewError> {
+    let start = vert_range.start;
+    let nb = vert_range.end.saturating_sub(start);
+
+    if start > vert_range.end {
+      return Err(TessViewError::IncorrectViewWindow {
+        capacity: tess.render_vert_nb(),
+        start,
+        nb,
+      });
+    }
+
+    let view = Self::inst_slice(tess, start, nb, inst_nb)?;
+
+    Ok(TessView {
+      inst_start_index: inst_start,
+      ..view
+    })
+  }
+
+  /// Create a view that is using only a subpart of the index set of the input [`Tess`], picking the indices in
+  /// `index_range`.
+  ///
+  /// Contrary to [`TessView::slice`], which checks against the default number of vertices to render,
+  /// `index_range` is validated against the number of indices of the [`Tess`] ([`Tess::idx_nb`]). This is useful to
+  /// render the part of the index set belonging to a given sub-mesh.
+  pub fn index_slice(
+    tess: &'a Tess<B, V, I, W, S>,
+    index_range: Range<usize>,
+  ) -> Result<Self, TessViewError> {
+    let capacity = tess.idx_nb();
+    let start = index_range.start;
+    let nb = index_range.end.saturating_sub(start);
+
+    if start > index_range.end || index_range.end > capacity {
+      return Err(TessViewError::IncorrectViewWindow {
+        capacity,
+        start,
+        nb,
+      });
+    }
+
+    Ok(TessView {
+      tess,
+      start_index: start,
+      vert_nb: nb,
+      base_vertex: 0,
+      inst_nb: tess.render_inst_nb(),
+      inst_start_index: 0,
+      mode: None,
     })
   }
+
+  /// Create a view that is using only a subpart of the index set of the input [`Tess`], picking the indices in
+  /// `index_range` and adding `base_vertex` to every index before fetching vertices.
+  ///
+  /// This is useful when the vertices and indices of several meshes are packed in the same [`Tess`]: each mesh keeps
+  /// its indices relative to its own first vertex, and `base_vertex` is the position of that vertex in the vertex
+  /// buffer. `index_range` is validated the same way as with [`TessView::index_slice`].
+  ///
+  /// # Backend support
+  ///
+  /// Rendering with a base vertex requires backend support. If the backend doesn’t support it (e.g. WebGL2), rendering
+  /// a view with `base_vertex` different from `0` renders nothing and [`TessGate::render`] fails with
+  /// [`PipelineError::TessError`] wrapping [`TessError::UnsupportedBaseVertex`].
+  ///
+  /// [`TessGate::render`]: crate::tess_gate::TessGate::render
+  /// [`PipelineError::TessError`]: crate::pipeline::PipelineError::TessError
+  pub fn base_vertex_slice(
+    tess: &'a Tess<B, V, I, W, S>,
+    index_range: Range<usize>,
+    base_vertex: i32,
+  ) -> Result<Self, TessViewError> {
+    let view = Self::index_slice(tess, index_range)?;
+
+    Ok(TessView {
+      base_vertex,
+      ..view
+    })
+  }
+
+  /// Render the view with `mode` instead of the [`Mode`] the [`Tess`] was built with.
+  ///
+  /// This is useful to render the same vertices with different primitives without building another [`Tess`], for
+  /// instance to render a wireframe overlay of a mesh. The number of vertices of the view must form whole primitives
+  /// with `mode` (see [`Mode::accepts_vertex_nb`]).
+  pub fn with_mode(self, mode: Mode) -> Result<Self, TessViewError> {
+    if !mode.accepts_vertex_nb(self.vert_nb) {
+      return Err(TessViewError::IncompatibleMode {
+        mode,
+        vert_nb: self.vert_nb,
+      });
+    }
+
+    Ok(TessView {
+      mode: Some(mode),
+      ..self
+    })
+  }
+
+  /// Number of primitives (points, lines, triangles or patches) rendered by the view, for all its instances.
+  ///
+  /// The count is derived from the number of vertices and instances of the view and its [`Mode`] (see
+  /// [`Mode::primitive_count`]). Primitive restart is not accounted for, as the indices are not available on the CPU:
+  /// for a [`Tess`] with a primitive restart index, this is an upper bound; use
+  /// [`TessView::primitive_count_with_indices`] to get the exact count.
+  pub fn primitive_count(&self) -> usize {
+    self.mode().primitive_count(self.vert_nb) * self.inst_nb.max(1)
+  }
+
+  /// Number of primitives rendered by the view, for all its instances, accounting for primitive restart.
+  ///
+  /// `indices` is the whole index set of the [`Tess`], as read with [`Tess::indices`]; the indices of the view are
+  /// picked from it. Every primitive restart index starts new primitives, which count is computed as with
+  /// [`TessView::primitive_count`]. If the [`Tess`] has no primitive restart index, this is the same as
+  /// [`TessView::primitive_count`].
+  pub fn primitive_count_with_indices(&self, indices: &[I]) -> usize {
+    let restart_index = match self.tess.restart_index.and_then(I::try_into_u32) {
+      Some(restart_index) => restart_index,
+      None => return self.primitive_count(),
+    };
+
+    let mode = self.mode();
+    let view_indices = indices
+      .get(self.start_index..)
+      .unwrap_or(&[])
+      .iter()
+      .take(self.vert_nb);
+    let mut count = 0;
+    let mut segment_vert_nb = 0;
+
+    for index in view_indices {
+      if index.try_into_u32() == Some(restart_index) {
+        count += mode.primitive_count(segment_vert_nb);
+        segment_vert_nb = 0;
+      } else {
+        segment_vert_nb += 1;
+      }
+    }
+
+    count += mode.primitive_count(segment_vert_nb);
+    count * self.inst_nb.max(1)
+  }
+
+  // primitive mode the view is rendered with
+  fn mode(&self) -> Mode {
+    self.mode.unwrap_or(self.tess.mode)
+  }
 }
 
 impl<'a, B, V, I, W, S> From<&'a Tess<B, V, I, W, S>> for TessView<'a, B, V, I, W, S>
@@ -1603,7 +3329,11 @@ where
   S: ?Sized,
 {
   fn view(&self, from: RangeFrom<usize>) -> Result<TessView<B, V, I, W, S>, TessViewError> {
-    TessView::slice(self, from.start, self.render_vert_nb() - from.start)
+    TessView::slice(
+      self,
+      from.start,
+      self.render_vert_nb().saturating_sub(from.start),
+    )
   }
 
   fn inst_view(
@@ -1614,7 +3344,7 @@ where
     TessView::inst_slice(
       self,
       from.start,
-      self.render_vert_nb() - from.start,
+      self.render_vert_nb().saturating_sub(from.start),
       inst_nb,
     )
   }
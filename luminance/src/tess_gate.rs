@@ -4,8 +4,11 @@
 //!
 //! [`Tess`]: crate::tess::Tess
 
-use crate::backend::tess_gate::TessGate as TessGateBackend;
-use crate::tess::{TessIndex, TessVertexData, TessView};
+use crate::backend::tess::RawTess as RawTessBackend;
+use crate::backend::tess_gate::{TessGate as TessGateBackend, TessGateIndirect};
+use crate::indirect::{IndirectBuffer, IndirectCommand};
+use crate::pipeline::PipelineError;
+use crate::tess::{RawTess, Tess, TessError, TessIndex, TessVertexData, TessView};
 
 /// Tessellation gate.
 pub struct TessGate<'a, B>
@@ -20,8 +23,14 @@ where
   B: ?Sized,
 {
   /// Enter the [`TessGate`] by sharing a [`TessView`].
+  ///
+  /// If the backend cannot render the [`TessView`] (e.g. [`TessError::UnsupportedBaseInstance`]), nothing is rendered
+  /// and the error is returned as a [`PipelineError::TessError`].
+  ///
+  /// [`PipelineError::TessError`]: crate::pipeline::PipelineError::TessError
   pub fn render<'b, E, T, V, I, W, S>(&'b mut self, tess_view: T) -> Result<(), E>
   where
+    E: From<PipelineError>,
     B: TessGateBackend<V, I, W, S>,
     T: Into<TessView<'b, B, V, I, W, S>>,
     V: TessVertexData<S> + 'b,
@@ -36,10 +45,100 @@ where
         &tess_view.tess.repr,
         tess_view.start_index,
         tess_view.vert_nb,
+        tess_view.base_vertex,
         tess_view.inst_nb,
+        tess_view.inst_start_index,
+        tess_view.mode,
+      )
+    }
+    .map_err(|e| PipelineError::tess_error(e).into())
+  }
+
+  /// Enter the [`TessGate`] by sharing a [`TessView`], rendered `inst_nb` times.
+  ///
+  /// This is the same as [`TessGate::render`] with the number of instances of the view replaced by `inst_nb`. The
+  /// [`Tess`] doesn’t need any instance data for that: with `W = ()`, every instance renders the same vertices, and
+  /// shaders tell instances apart with their instance ID (e.g. `gl_InstanceID`), typically to fetch per-instance data
+  /// from a [`ShaderData`](crate::shader::ShaderData).
+  pub fn render_instanced<'b, E, T, V, I, W, S>(
+    &'b mut self,
+    tess_view: T,
+    inst_nb: usize,
+  ) -> Result<(), E>
+  where
+    E: From<PipelineError>,
+    B: TessGateBackend<V, I, W, S>,
+    T: Into<TessView<'b, B, V, I, W, S>>,
+    V: TessVertexData<S> + 'b,
+    I: TessIndex + 'b,
+    W: TessVertexData<S> + 'b,
+    S: ?Sized + 'b,
+  {
+    let tess_view = TessView {
+      inst_nb,
+      ..tess_view.into()
+    };
+
+    self.render(tess_view)
+  }
+
+  /// Enter the [`TessGate`] by sharing a [`Tess`], rendered with the command at index `index` in `indirect_buffer`.
+  ///
+  /// Instead of being provided by a [`TessView`], the range of vertices and the number of instances to render are
+  /// read by the GPU from the [`IndirectBuffer`]. The [`Mode`] the [`Tess`] was built with is used.
+  ///
+  /// Indexed [`Tess`] must be rendered with
+  /// [`DrawElementsCommand`](crate::indirect::DrawElementsCommand), and [`Tess`] without indices with
+  /// [`DrawArraysCommand`](crate::indirect::DrawArraysCommand); otherwise, [`TessError::IndirectCommandMismatch`] is
+  /// returned. An `index` out of the bounds of `indirect_buffer` results in [`TessError::IndirectIndexOutOfBounds`].
+  /// Errors are forwarded as [`PipelineError::TessError`].
+  ///
+  /// [`Mode`]: crate::tess::Mode
+  /// [`PipelineError::TessError`]: crate::pipeline::PipelineError::TessError
+  pub fn render_indirect<E, V, I, W, S, C>(
+    &mut self,
+    tess: &Tess<B, V, I, W, S>,
+    indirect_buffer: &IndirectBuffer<B, C>,
+    index: usize,
+  ) -> Result<(), E>
+  where
+    E: From<PipelineError>,
+    B: TessGateIndirect<V, I, W, S, C>,
+    V: TessVertexData<S>,
+    I: TessIndex,
+    W: TessVertexData<S>,
+    S: ?Sized,
+    C: IndirectCommand,
+  {
+    let len = indirect_buffer.len();
+    if index >= len {
+      return Err(
+        PipelineError::tess_error(TessError::indirect_index_out_of_bounds(index, len)).into(),
       );
+    }
+
+    unsafe {
+      self
+        .backend
+        .render_indirect(&tess.repr, &indirect_buffer.repr, index)
+        .map_err(|e| PipelineError::tess_error(e).into())
+    }
+  }
 
-      Ok(())
+  /// Enter the [`TessGate`] by sharing a [`RawTess`], rendered as a whole.
+  ///
+  /// All the indices of the [`RawTess`] are rendered if it has some; all its vertices are rendered otherwise (see
+  /// [`RawTess::render_vert_nb`]). Errors are forwarded as [`PipelineError::TessError`].
+  ///
+  /// [`PipelineError::TessError`]: crate::pipeline::PipelineError::TessError
+  pub fn render_raw<E>(&mut self, raw_tess: &RawTess<B>) -> Result<(), E>
+  where
+    E: From<PipelineError>,
+    B: RawTessBackend,
+  {
+    unsafe {
+      B::render_raw_tess(&raw_tess.repr, 0, raw_tess.render_vert_nb(), 1)
+        .map_err(|e| PipelineError::tess_error(e).into())
     }
   }
 }
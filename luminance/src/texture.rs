@@ -125,12 +125,12 @@ pub trait Dimensionable {
 
   /// Y offset. If it doesn’t have one, set it to 0.
   fn y_offset(_: Self::Offset) -> u32 {
-    1
+    0
   }
 
   /// Z offset. If it doesn’t have one, set it to 0.
   fn z_offset(_: Self::Offset) -> u32 {
-    1
+    0
   }
 
   /// Amount of pixels this size represents.
@@ -441,6 +441,12 @@ pub struct Sampler {
   /// Magnification filter.
   pub mag_filter: MagFilter,
   /// For depth textures, should we perform depth comparison and if so, how?
+  ///
+  /// When set, sampling the texture compares a reference value with the depth stored in the texture, using the given
+  /// [`Comparison`], and returns the result of the comparison instead of the depth. Such textures must be sampled with
+  /// shadow samplers (e.g. `sampler2DShadow`); see [`Shadow`].
+  ///
+  /// [`Shadow`]: crate::pixel::Shadow
   pub depth_comparison: Option<Comparison>,
 }
 
@@ -572,6 +578,27 @@ pub enum TextureError {
 
   /// Failed to upload texels.
   CannotUploadTexels(String),
+
+  /// The region formed by an offset and a size doesn’t fit in the texture.
+  RegionOutOfBounds,
+
+  /// The pixel format of the texture is not filterable, so mipmaps cannot be generated for it.
+  ///
+  /// See [`PixelFormat::is_filterable`].
+  UnfilterablePixelFormat(PixelFormat),
+
+  /// An image file cannot be loaded.
+  ///
+  /// The carried [`String`] gives the reason of the failure.
+  CannotLoadImage(String),
+
+  /// The number of channels of an image doesn’t match the number of channels of the pixel format of the texture.
+  ImageChannelsMismatch {
+    /// Number of channels of the pixel format.
+    expected_channels: usize,
+    /// Number of channels of the image.
+    provided_channels: usize,
+  },
 }
 
 impl TextureError {
@@ -602,6 +629,29 @@ impl TextureError {
   pub fn cannot_upload_texels(reason: impl Into<String>) -> Self {
     TextureError::CannotUploadTexels(reason.into())
   }
+
+  /// The region formed by an offset and a size doesn’t fit in the texture.
+  pub fn region_out_of_bounds() -> Self {
+    TextureError::RegionOutOfBounds
+  }
+
+  /// The pixel format of the texture is not filterable.
+  pub fn unfilterable_pixel_format(pf: PixelFormat) -> Self {
+    TextureError::UnfilterablePixelFormat(pf)
+  }
+
+  /// An image file cannot be loaded.
+  pub fn cannot_load_image(reason: impl Into<String>) -> Self {
+    TextureError::CannotLoadImage(reason.into())
+  }
+
+  /// The number of channels of an image doesn’t match the number of channels of the pixel format.
+  pub fn image_channels_mismatch(expected_channels: usize, provided_channels: usize) -> Self {
+    TextureError::ImageChannelsMismatch {
+      expected_channels,
+      provided_channels,
+    }
+  }
 }
 
 impl fmt::Display for TextureError {
@@ -631,6 +681,27 @@ impl fmt::Display for TextureError {
       TextureError::CannotUploadTexels(ref e) => {
         write!(f, "cannot upload texels to texture: {}", e)
       }
+
+      TextureError::RegionOutOfBounds => f.write_str("region out of texture bounds"),
+
+      TextureError::UnfilterablePixelFormat(ref fmt) => {
+        write!(
+          f,
+          "cannot generate mipmaps of unfilterable pixel format: {:?}",
+          fmt
+        )
+      }
+
+      TextureError::CannotLoadImage(ref e) => write!(f, "cannot load image: {}", e),
+
+      TextureError::ImageChannelsMismatch {
+        expected_channels,
+        provided_channels,
+      } => write!(
+        f,
+        "image channels mismatch: expected {} channels, image has {}",
+        expected_channels, provided_channels
+      ),
     }
   }
 }
@@ -808,12 +879,20 @@ where
 
   /// Upload pixels to a region of the texture described by the rectangle made with `size` and
   /// `offset`.
+  ///
+  /// The rest of the texture is left untouched, which makes this function suitable to stream parts of a texture, such
+  /// as glyphs in a font atlas. Mipmaps are regenerated if `texels` asks for it.
+  ///
+  /// # Errors
+  ///
+  /// [`TextureError::RegionOutOfBounds`] is returned if the region doesn’t fit in the texture.
   pub fn upload_part(
     &mut self,
     offset: D::Offset,
     size: D::Size,
     texels: TexelUpload<[P::Encoding]>,
   ) -> Result<(), TextureError> {
+    self.check_region(offset, size)?;
     unsafe { B::upload_part(&mut self.repr, offset, size, texels) }
   }
 
@@ -824,12 +903,19 @@ where
 
   /// Upload raw data to a region of the texture described by the rectangle made with `size` and
   /// `offset`.
+  ///
+  /// See [`Texture::upload_part`] for further details.
+  ///
+  /// # Errors
+  ///
+  /// [`TextureError::RegionOutOfBounds`] is returned if the region doesn’t fit in the texture.
   pub fn upload_part_raw(
     &mut self,
     offset: D::Offset,
     size: D::Size,
     texels: TexelUpload<[P::RawEncoding]>,
   ) -> Result<(), TextureError> {
+    self.check_region(offset, size)?;
     unsafe { B::upload_part_raw(&mut self.repr, offset, size, texels) }
   }
 
@@ -838,6 +924,29 @@ where
     unsafe { B::upload_raw(&mut self.repr, self.size, texels) }
   }
 
+  /// Generate the mipmaps of the texture from its base level.
+  ///
+  /// This is useful to build the mipmap chain of a texture which base level was uploaded without generating mipmaps,
+  /// or of a texture that was rendered to (e.g. a color slot of a [`Framebuffer`], for bloom or screen-space
+  /// reflections). Only the levels allocated when creating the texture are generated (see [`Texture::mipmaps`] and
+  /// [`TexelUpload`]). Those levels are only read when sampling the texture with a mipmap minification filter, such as
+  /// [`MinFilter::LinearMipmapLinear`].
+  ///
+  /// # Errors
+  ///
+  /// [`TextureError::UnfilterablePixelFormat`] is returned if the pixel format of the texture is not filterable.
+  ///
+  /// [`Framebuffer`]: crate::framebuffer::Framebuffer
+  pub fn generate_mipmaps(&mut self) -> Result<(), TextureError> {
+    let pf = P::pixel_format();
+
+    if !pf.is_filterable() {
+      return Err(TextureError::unfilterable_pixel_format(pf));
+    }
+
+    unsafe { B::generate_mipmaps(&mut self.repr) }
+  }
+
   /// Get a copy of all the pixels from the texture.
   pub fn get_raw_texels(&self) -> Result<Vec<P::RawEncoding>, TextureError>
   where
@@ -845,4 +954,152 @@ where
   {
     unsafe { B::get_raw_texels(&self.repr, self.size) }
   }
+
+  // Check that the region formed by offset and size fits in the texture.
+  fn check_region(&self, offset: D::Offset, size: D::Size) -> Result<(), TextureError> {
+    let fits =
+      |start: u32, len: u32, max: u32| start.checked_add(len).map_or(false, |end| end <= max);
+
+    // cubemap regions are uploaded to a single face, selected by the offset
+    let depth = if D::dim() == Dim::Cubemap {
+      1
+    } else {
+      D::depth(size)
+    };
+
+    let in_bounds = fits(D::x_offset(offset), D::width(size), D::width(self.size))
+      && fits(D::y_offset(offset), D::height(size), D::height(self.size))
+      && fits(D::z_offset(offset), depth, D::depth(self.size));
+
+    if in_bounds {
+      Ok(())
+    } else {
+      Err(TextureError::region_out_of_bounds())
+    }
+  }
+}
+
+#[cfg(feature = "image")]
+impl<B, P> Texture<B, Dim2, P>
+where
+  B: ?Sized + TextureBackend<Dim2, P>,
+  P: Pixel,
+{
+  /// Create a new 2D [`Texture`] from an image file.
+  ///
+  /// The image is decoded with the [image](https://crates.io/crates/image) crate, which guesses its format from the
+  /// extension of `path`. BMP, JPEG, PNG and TGA files are supported. Images are stored from their top-left corner
+  /// while textures start at their bottom-left one, so rows are flipped before being uploaded: the image appears
+  /// upright when sampled with texture coordinates going upwards.
+  ///
+  /// `P` must be a color pixel format with either 8-bit unsigned channels or 32-bit floating-point channels, such as
+  /// [`NormRGBA8UI`] or [`RGB32F`]. The channels of the image are converted to the channel type of `P`, but the number
+  /// of channels of the image and of `P` must be the same.
+  ///
+  /// `mipmaps` is the number of mipmap levels to generate from the image.
+  ///
+  /// This function is only available with the `image` feature.
+  ///
+  /// # Errors
+  ///
+  /// - [`TextureError::UnsupportedPixelFormat`] is returned if `P` is not a supported pixel format.
+  /// - [`TextureError::CannotLoadImage`] is returned if the image cannot be read or decoded.
+  /// - [`TextureError::ImageChannelsMismatch`] is returned if the number of channels of the image is not the same as
+  ///   the one of `P`.
+  ///
+  /// [`NormRGBA8UI`]: crate::pixel::NormRGBA8UI
+  /// [`RGB32F`]: crate::pixel::RGB32F
+  pub fn from_path<C>(
+    ctx: &mut C,
+    path: impl AsRef<std::path::Path>,
+    sampler: Sampler,
+    mipmaps: usize,
+  ) -> Result<Self, TextureError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    let pf = P::pixel_format();
+    let floating =
+      image_channel_type(pf).ok_or_else(|| TextureError::unsupported_pixel_format(pf))?;
+
+    let img = image::open(path).map_err(|e| TextureError::cannot_load_image(e.to_string()))?;
+
+    let expected_channels = pf.channels_len();
+    let provided_channels = img.color().channel_count() as usize;
+    if expected_channels != provided_channels {
+      return Err(TextureError::image_channels_mismatch(
+        expected_channels,
+        provided_channels,
+      ));
+    }
+
+    let img = img.flipv();
+    let size = [img.width(), img.height()];
+
+    if floating {
+      let texels = match provided_channels {
+        1 => img.to_luma32f().into_raw(),
+        2 => img.to_luma_alpha32f().into_raw(),
+        3 => img.to_rgb32f().into_raw(),
+        _ => img.to_rgba32f().into_raw(),
+      };
+
+      Self::from_image_texels(ctx, size, sampler, &texels, mipmaps)
+    } else {
+      let texels = match provided_channels {
+        1 => img.to_luma8().into_raw(),
+        2 => img.to_luma_alpha8().into_raw(),
+        3 => img.to_rgb8().into_raw(),
+        _ => img.to_rgba8().into_raw(),
+      };
+
+      Self::from_image_texels(ctx, size, sampler, &texels, mipmaps)
+    }
+  }
+
+  // Upload channels decoded from an image as raw texels.
+  fn from_image_texels<C, T>(
+    ctx: &mut C,
+    size: [u32; 2],
+    sampler: Sampler,
+    texels: &[T],
+    mipmaps: usize,
+  ) -> Result<Self, TextureError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    // the raw encoding of the supported pixel formats is the type of their channels; it’s still checked, as texels
+    // get reinterpreted
+    if std::mem::size_of::<T>() != std::mem::size_of::<P::RawEncoding>()
+      || std::mem::align_of::<T>() < std::mem::align_of::<P::RawEncoding>()
+    {
+      return Err(TextureError::unsupported_pixel_format(P::pixel_format()));
+    }
+
+    let texels =
+      unsafe { std::slice::from_raw_parts(texels.as_ptr() as *const P::RawEncoding, texels.len()) };
+
+    Self::new_raw(ctx, size, sampler, TexelUpload::base_level(texels, mipmaps))
+  }
+}
+
+// Type of the channels of images loaded into textures of a given pixel format: true for 32-bit floating-point channels,
+// false for 8-bit unsigned ones, and None if images cannot be loaded into such textures.
+#[cfg(feature = "image")]
+fn image_channel_type(pf: PixelFormat) -> Option<bool> {
+  use crate::pixel::{Format, Size, Type};
+
+  let size = match pf.format {
+    Format::R(r) => r,
+    Format::RG(r, g) if r == g => r,
+    Format::RGB(r, g, b) | Format::SRGB(r, g, b) if r == g && g == b => r,
+    Format::RGBA(r, g, b, a) | Format::SRGBA(r, g, b, a) if r == g && g == b && b == a => r,
+    _ => return None,
+  };
+
+  match (pf.encoding, size) {
+    (Type::NormUnsigned | Type::Unsigned, Size::Eight) => Some(false),
+    (Type::Floating, Size::ThirtyTwo) => Some(true),
+    _ => None,
+  }
 }
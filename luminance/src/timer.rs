@@ -0,0 +1,144 @@
+//! GPU timer queries.
+//!
+//! Commands sent to the GPU are executed asynchronously, so measuring the CPU time spent calling functions such as
+//! [`TessGate::render`] doesn’t tell anything about the cost of rendering. A [`TimerQuery`] measures the time the GPU
+//! spends executing commands, which is useful to profile a pipeline, pass by pass.
+//!
+//! Time is measured with [`TimerQuery::time_elapsed`], which runs a closure and measures the time the GPU spends
+//! executing the commands it issues. The GPU might not have executed them when the closure returns, so the result is
+//! often not available right away: [`TimerQuery::elapsed`] can be used to poll it later — typically on the next frame
+//! — without stalling the GPU.
+//!
+//! [`TessGate::render`]: crate::tess_gate::TessGate::render
+
+use crate::{backend::timer::TimerQuery as TimerQueryBackend, context::GraphicsContext};
+use std::{
+  error, fmt,
+  panic::{self, AssertUnwindSafe},
+  time::Duration,
+};
+
+/// Timer query error.
+#[non_exhaustive]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TimerQueryError {
+  /// Timer queries are not supported by the backend.
+  Unsupported,
+  /// Cannot create the timer query on the GPU.
+  CannotCreate,
+  /// Another timer query is already active.
+  ///
+  /// Timer queries cannot be nested.
+  AlreadyActive,
+}
+
+impl TimerQueryError {
+  /// Timer queries are not supported by the backend.
+  pub fn unsupported() -> Self {
+    TimerQueryError::Unsupported
+  }
+
+  /// Cannot create the timer query on the GPU.
+  pub fn cannot_create() -> Self {
+    TimerQueryError::CannotCreate
+  }
+
+  /// Another timer query is already active.
+  pub fn already_active() -> Self {
+    TimerQueryError::AlreadyActive
+  }
+}
+
+impl fmt::Display for TimerQueryError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    match *self {
+      TimerQueryError::Unsupported => f.write_str("timer queries are not supported"),
+      TimerQueryError::CannotCreate => f.write_str("cannot create the timer query on the GPU side"),
+      TimerQueryError::AlreadyActive => f.write_str("another timer query is already active"),
+    }
+  }
+}
+
+impl error::Error for TimerQueryError {}
+
+/// A GPU timer query.
+///
+/// A timer query holds the result of a single measurement: measuring again discards the previous result. Use several
+/// timer queries to measure several passes, or to measure a pass again while the result of the previous measurement
+/// is not available yet.
+pub struct TimerQuery<B>
+where
+  B: ?Sized + TimerQueryBackend,
+{
+  pub(crate) repr: B::TimerQueryRepr,
+}
+
+impl<B> TimerQuery<B>
+where
+  B: ?Sized + TimerQueryBackend,
+{
+  /// Create a new [`TimerQuery`].
+  ///
+  /// # Errors
+  ///
+  /// [`TimerQueryError::Unsupported`] is returned if the backend doesn’t support timer queries.
+  pub fn new<C>(ctx: &mut C) -> Result<Self, TimerQueryError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    unsafe {
+      ctx
+        .backend()
+        .new_timer_query()
+        .map(|repr| TimerQuery { repr })
+    }
+  }
+
+  /// Measure the time the GPU spends executing the commands issued by `f`.
+  ///
+  /// The value returned by `f` is returned along with the measured duration, if it is already available — which is
+  /// rarely the case, as that requires the GPU to have completed the commands. If the duration is not available, it
+  /// can be polled later with [`TimerQuery::elapsed`].
+  ///
+  /// # Errors
+  ///
+  /// Timer queries cannot be nested: [`TimerQueryError::AlreadyActive`] is returned, without calling `f`, if `f` is
+  /// itself measuring time.
+  ///
+  /// # Panics
+  ///
+  /// If `f` panics, the measurement is stopped before the panic is propagated, so that the timer query doesn’t stay
+  /// active.
+  ///
+  /// # Notes
+  ///
+  /// You might be interested in the [`GraphicsContext::time_elapsed`] function instead, which is the exact same
+  /// function, but with the [`TimerQuery`] as argument.
+  pub fn time_elapsed<C, R>(
+    &mut self,
+    ctx: &mut C,
+    f: impl FnOnce(&mut C) -> R,
+  ) -> Result<(R, Option<Duration>), TimerQueryError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    unsafe { ctx.backend().begin_timer_query(&mut self.repr)? };
+
+    let r = panic::catch_unwind(AssertUnwindSafe(|| f(ctx)));
+
+    unsafe { ctx.backend().end_timer_query(&mut self.repr) };
+
+    let r = r.unwrap_or_else(|payload| panic::resume_unwind(payload));
+
+    Ok((r, self.elapsed()))
+  }
+
+  /// Get the duration of the last measurement, if available.
+  ///
+  /// This never blocks: `None` is returned if the GPU hasn’t completed the measured commands yet. It is also returned
+  /// if nothing was measured, or if the measurement got invalidated by the backend (e.g. because the GPU clock
+  /// changed while measuring).
+  pub fn elapsed(&self) -> Option<Duration> {
+    unsafe { B::timer_query_elapsed(&self.repr) }
+  }
+}
@@ -8,7 +8,7 @@
 //! [`VertexAttribDim`]: crate::vertex::VertexAttribDim
 //! [`VertexAttribType`]: crate::vertex::VertexAttribType
 
-use std::fmt::Debug;
+use std::{fmt::Debug, mem, ptr};
 
 /// A type that can be used as a [`Vertex`] has to implement that trait – it must provide an
 /// associated [`VertexDesc`] value via a function call. This associated value gives enough
@@ -18,7 +18,11 @@ use std::fmt::Debug;
 /// In theory, you should never have to implement that trait directly. Instead, feel free to use the
 /// [luminance-derive] [`Vertex`] proc-macro-derive instead.
 ///
-/// > Note: implementing this trait is `unsafe`.
+/// # Safety
+///
+/// Implementors must be laid out as described by [`Vertex::vertex_desc`]: their fields are the attributes of the
+/// descriptors, in the same order, at the offsets computed by [`interleaved_layout`] — which is the case of
+/// `#[repr(C)]` structs. Vertices are read and written attribute by attribute at those offsets.
 pub unsafe trait Vertex: Copy {
   /// The associated vertex format.
   fn vertex_desc() -> VertexDesc;
@@ -36,6 +40,71 @@ pub trait Deinterleave<T> {
   const RANK: usize;
 }
 
+/// Vertex types which attributes can all be sliced at once when stored deinterleaved.
+///
+/// This trait is implemented by the `Vertex` derive for structs with the `#[vertex(slice_set)]` attribute, which
+/// generates a `<Name>SliceSet` struct holding one slice per field of the vertex type, with the same names (or
+/// positions, for tuple structs) and visibilities as the fields. The generated implementation panics if the bytes of an
+/// attribute are misaligned.
+///
+/// See [`Tess::vertices_all`](crate::tess::Tess::vertices_all).
+///
+/// # Safety
+///
+/// [`DeinterleaveAll::slice_set`] must only interpret the bytes of an attribute as the type at the same rank in
+/// [`Vertex::vertex_desc`].
+pub unsafe trait DeinterleaveAll<'a>: Vertex {
+  /// Set of slices, one per attribute.
+  type SliceSet;
+
+  /// Build the set of slices.
+  ///
+  /// `attribute` returns the raw bytes of the attribute at the given rank.
+  ///
+  /// # Safety
+  ///
+  /// The bytes returned by `attribute` must hold correctly aligned values of the type of the attribute.
+  unsafe fn slice_set(attribute: impl FnMut(usize) -> &'a [u8]) -> Self::SliceSet;
+}
+
+/// Vertex types which can be flattened into other vertex types.
+///
+/// This trait is implemented by the `Vertex` derive for vertex types without flattened fields, so that they can be
+/// embedded into other vertex types with the `#[vertex(flatten)]` field attribute. Their attributes are then spliced
+/// into the [`VertexDesc`] of the embedding type, at the position of the field.
+///
+/// # Safety
+///
+/// [`FlattenVertex::ATTRIB_NB`] must be the number of attributes in [`Vertex::vertex_desc`], and
+/// [`FlattenVertex::SEMANTICS`] their semantics, in the same order.
+/// [`FlattenVertex::ATTRIBS_SIZE`] and [`FlattenVertex::FIRST_ATTRIB_ALIGN`] must describe the attributes as they are
+/// laid out in the vertex type.
+pub unsafe trait FlattenVertex: Vertex {
+  /// Semantics of the attributes.
+  type Sem: Semantics + 'static;
+
+  /// Number of attributes.
+  const ATTRIB_NB: usize;
+
+  /// Semantics of each attribute.
+  ///
+  /// The `Vertex` derive checks at compile-time that the semantics of a flattened vertex type are not used by the
+  /// other attributes of the embedding type.
+  const SEMANTICS: &'static [Self::Sem];
+
+  /// Size of the attributes, laid out as the fields of a `#[repr(C)]` struct, without trailing padding.
+  ///
+  /// A vertex type can be flattened only if its size is [`FlattenVertex::ATTRIBS_SIZE`]; otherwise, its trailing
+  /// padding would shift the attributes following it in the embedding type.
+  const ATTRIBS_SIZE: usize;
+
+  /// Alignment of the first attribute.
+  ///
+  /// A vertex type can be flattened only if its alignment is [`FlattenVertex::FIRST_ATTRIB_ALIGN`]; otherwise, it could
+  /// be placed further than its first attribute in the embedding type.
+  const FIRST_ATTRIB_ALIGN: usize;
+}
+
 /// A [`VertexDesc`] is a list of [`VertexBufferDesc`]s.
 pub type VertexDesc = Vec<VertexBufferDesc>;
 
@@ -57,6 +126,11 @@ pub struct VertexBufferDesc {
   pub instancing: VertexInstancing,
   /// Vertex attribute descriptor.
   pub attrib_desc: VertexAttribDesc,
+  /// Index of the buffer group the attribute is stored in.
+  ///
+  /// Attributes sharing the same buffer group are interleaved together in the same vertex buffer. By default, all
+  /// attributes are in the buffer group `0`, which yields a fully interleaved vertex buffer.
+  pub buffer: usize,
 }
 
 impl VertexBufferDesc {
@@ -72,10 +146,112 @@ impl VertexBufferDesc {
       name,
       instancing,
       attrib_desc,
+      buffer: 0,
+    }
+  }
+
+  /// Change the buffer group of a [`VertexBufferDesc`].
+  pub fn with_buffer(self, buffer: usize) -> Self {
+    VertexBufferDesc { buffer, ..self }
+  }
+
+  /// Change the location of a [`VertexBufferDesc`], which defaults to the index of its semantics.
+  ///
+  /// This is useful with vertex shaders declaring the locations of their inputs explicitly, with
+  /// `layout (location = N)`: the attribute is then fed at location `N`, whatever its semantics. Matrix attributes
+  /// occupy one location per column, starting at `location`.
+  pub fn with_location(self, location: usize) -> Self {
+    VertexBufferDesc {
+      index: location,
+      ..self
     }
   }
 }
 
+/// Offsets of the attributes described by `desc` in an interleaved vertex, along with the size of such a vertex.
+///
+/// Attributes are laid out as the fields of a `#[repr(C)]` struct: each attribute is aligned on its alignment and
+/// follows the previous one, and the size is rounded up to the largest alignment of the attributes. This is the layout
+/// of [`Vertex`] types, and the one backends use for interleaved vertex buffers.
+pub fn interleaved_layout(desc: &[VertexBufferDesc]) -> (Vec<usize>, usize) {
+  let mut offsets = Vec::with_capacity(desc.len());
+  let mut off = 0;
+  let mut max_align = 1;
+
+  for desc in desc {
+    let align = desc.attrib_desc.align;
+    off = align_offset(off, align);
+    offsets.push(off);
+    off += desc.attrib_desc.size();
+    max_align = max_align.max(align);
+  }
+
+  (offsets, align_offset(off, max_align))
+}
+
+/// Align an offset.
+fn align_offset(off: usize, align: usize) -> usize {
+  (off + align - 1) / align * align
+}
+
+/// Split interleaved vertices into one vertex buffer per buffer group.
+///
+/// `desc` describes the attributes of `V` (typically [`Vertex::vertex_desc`]), along with the buffer group of each
+/// attribute (see [`VertexBufferDesc::buffer`]). Groups are sorted by buffer index. Each group comes with the
+/// descriptors of the attributes it contains, and the bytes of its vertices, laid out as described by
+/// [`interleaved_layout`] for those descriptors.
+///
+/// This is meant to be used by backends supporting buffer groups.
+///
+/// # Panics
+///
+/// Panics if the size of `V` doesn’t match the size [`interleaved_layout`] computes for `desc`.
+pub fn split_buffer_groups<V>(
+  vertices: &[V],
+  desc: &[VertexBufferDesc],
+) -> Vec<(VertexDesc, Vec<u8>)>
+where
+  V: Vertex,
+{
+  let (src_offsets, src_size) = interleaved_layout(desc);
+  assert_eq!(
+    src_size,
+    mem::size_of::<V>(),
+    "the vertex descriptors don’t describe the memory layout of the vertices"
+  );
+
+  let mut groups: Vec<_> = desc.iter().map(|desc| desc.buffer).collect();
+  groups.sort_unstable();
+  groups.dedup();
+
+  groups
+    .into_iter()
+    .map(|group| {
+      let (descs, src_offsets): (VertexDesc, Vec<_>) = desc
+        .iter()
+        .zip(&src_offsets)
+        .filter(|(desc, _)| desc.buffer == group)
+        .map(|(desc, off)| (*desc, *off))
+        .unzip();
+      let (dst_offsets, stride) = interleaved_layout(&descs);
+      let mut raw = vec![0; stride * vertices.len()];
+
+      for (vertex, dst) in vertices.iter().zip(raw.chunks_exact_mut(stride)) {
+        let src = vertex as *const V as *const u8;
+
+        // only the bytes of the attributes are read, never the padding between them
+        for ((desc, src_off), dst_off) in descs.iter().zip(&src_offsets).zip(&dst_offsets) {
+          let size = desc.attrib_desc.size();
+          let dst = &mut dst[*dst_off..dst_off + size];
+          unsafe { ptr::copy_nonoverlapping(src.add(*src_off), dst.as_mut_ptr(), size) };
+        }
+      }
+
+      (descs, raw)
+    })
+    .collect()
+}
+
 /// Should vertex instancing be used for a vertex attribute?
 ///
 /// Enabling this is done per attribute but if you enable it for a single attribute of a struct, it
@@ -102,7 +278,7 @@ pub struct VertexAttribDesc {
   /// Type of the attribute. See [`VertexAttribType`] for further details.
   pub ty: VertexAttribType,
 
-  /// Dimension of the attribute. It should be in 1–4. See [`VertexAttribDim`] for further details.
+  /// Dimension of the attribute. See [`VertexAttribDim`] for further details.
   pub dim: VertexAttribDim,
 
   /// Size in bytes that a single element of the attribute takes. That is, if your attribute has
@@ -122,6 +298,26 @@ impl VertexAttribDesc {
       ..self
     }
   }
+
+  /// Size in bytes of the whole attribute (i.e. all of its components).
+  pub const fn size(&self) -> usize {
+    self.unit_size * self.dim.size()
+  }
+
+  /// Number of consecutive attribute locations the attribute occupies.
+  pub const fn locations(&self) -> usize {
+    self.dim.locations()
+  }
+
+  /// Whether two descriptors describe the same attribute format.
+  ///
+  /// This is the same as comparing them with `==`, but usable in a `const` context.
+  pub const fn same_format(&self, other: &Self) -> bool {
+    self.ty.same_type(other.ty)
+      && self.dim as u8 == other.dim as u8
+      && self.unit_size == other.unit_size
+      && self.align == other.align
+  }
 }
 
 /// Possible type of vertex attributes.
@@ -153,6 +349,16 @@ impl VertexAttribType {
       _ => self,
     }
   }
+
+  const fn same_type(self, other: Self) -> bool {
+    match (self, other) {
+      (VertexAttribType::Integral(a), VertexAttribType::Integral(b))
+      | (VertexAttribType::Unsigned(a), VertexAttribType::Unsigned(b)) => a as u8 == b as u8,
+      (VertexAttribType::Floating, VertexAttribType::Floating)
+      | (VertexAttribType::Boolean, VertexAttribType::Boolean) => true,
+      _ => false,
+    }
+  }
 }
 
 /// Whether an integral vertex type should be normalized when fetched from a shader program.
@@ -168,6 +374,9 @@ pub enum Normalized {
 }
 
 /// Possible dimension of vertex attributes.
+///
+/// Matrices are stored column by column, and each column occupies its own attribute location. A matrix attribute
+/// whose semantics index is `i` then occupies the locations `i` to `i + n - 1`, where `n` is its number of columns.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum VertexAttribDim {
   /// 1D.
@@ -178,6 +387,46 @@ pub enum VertexAttribDim {
   Dim3,
   /// 4D.
   Dim4,
+  /// 2×2 matrix.
+  Mat2,
+  /// 3×3 matrix.
+  Mat3,
+  /// 4×4 matrix.
+  Mat4,
+}
+
+impl VertexAttribDim {
+  /// Number of components of the dimension.
+  pub const fn size(self) -> usize {
+    self.column_size() * self.locations()
+  }
+
+  /// Number of components of a single column of the dimension.
+  ///
+  /// For vectors, this is the same as [`VertexAttribDim::size`].
+  pub const fn column_size(self) -> usize {
+    match self {
+      VertexAttribDim::Dim1 => 1,
+      VertexAttribDim::Dim2 | VertexAttribDim::Mat2 => 2,
+      VertexAttribDim::Dim3 | VertexAttribDim::Mat3 => 3,
+      VertexAttribDim::Dim4 | VertexAttribDim::Mat4 => 4,
+    }
+  }
+
+  /// Number of consecutive attribute locations occupied by the dimension.
+  ///
+  /// This is `1` for vectors and the number of columns for matrices.
+  pub const fn locations(self) -> usize {
+    match self {
+      VertexAttribDim::Dim1
+      | VertexAttribDim::Dim2
+      | VertexAttribDim::Dim3
+      | VertexAttribDim::Dim4 => 1,
+      VertexAttribDim::Mat2 => 2,
+      VertexAttribDim::Mat3 => 3,
+      VertexAttribDim::Mat4 => 4,
+    }
+  }
 }
 
 /// Class of vertex attributes.
@@ -208,6 +457,9 @@ pub unsafe trait VertexAttrib {
 /// several tessellations – that allows better composition with shaders. Basically, the best advice
 /// to follow: define your semantics once, and keep to them.
 ///
+/// Matrix attributes occupy one index per column (see [`VertexAttribDim`]). The indices following the index of a
+/// matrix semantics must then not be used by other semantics.
+///
 /// > Note: feel free to use the [luminance-derive] crate to automatically derive this trait from
 /// > an `enum`.
 pub trait Semantics: Sized + Copy + Clone + Debug {
@@ -257,6 +509,12 @@ pub trait HasSemantics {
 
   /// The aforementioned vertex semantics for the attribute type.
   const SEMANTICS: Self::Sem;
+
+  /// Vertex attribute descriptor of the representation type declared for [`HasSemantics::SEMANTICS`], if any.
+  ///
+  /// When set, the `Vertex` derive checks at compile-time that the attribute type has the same format. No
+  /// representation type is declared by default.
+  const REPR_VERTEX_ATTRIB_DESC: Option<VertexAttribDesc> = None;
 }
 
 /// A local version of size_of that depends on the state of the std feature.
@@ -318,3 +576,23 @@ impl_vertex_attribute!(u32, VertexAttribType::Unsigned(Normalized::No));
 impl_vertex_attribute!(f32, VertexAttribType::Floating);
 impl_vertex_attribute!(f64, VertexAttribType::Floating);
 impl_vertex_attribute!(bool, VertexAttribType::Boolean);
+
+// matrices, stored column by column
+impl_vertex_attribute!(
+  [[f32; 2]; 2],
+  f32,
+  VertexAttribType::Floating,
+  VertexAttribDim::Mat2
+);
+impl_vertex_attribute!(
+  [[f32; 3]; 3],
+  f32,
+  VertexAttribType::Floating,
+  VertexAttribDim::Mat3
+);
+impl_vertex_attribute!(
+  [[f32; 4]; 4],
+  f32,
+  VertexAttribType::Floating,
+  VertexAttribDim::Mat4
+);
@@ -0,0 +1,983 @@
+use luminance::backend::compute::Compute;
+use luminance::backend::debug::{DebugGroup, DebugLabel};
+use luminance::backend::framebuffer::{
+  ClearBackBuffer, Framebuffer as FramebufferBackend, FramebufferBackBuffer, FramebufferBlit,
+  FramebufferReadback,
+};
+use luminance::backend::pipeline::{Pipeline as PipelineBackend, PipelineBase};
+use luminance::backend::render_gate::RenderGate as RenderGateBackend;
+use luminance::backend::shader::{Shader, ShaderBinary, Uniformable, UniformableTransposed};
+use luminance::backend::shading_gate::ShadingGate as ShadingGateBackend;
+use luminance::backend::tess::Tess as TessBackend;
+use luminance::backend::tess_gate::TessGate as TessGateBackend;
+use luminance::backend::texture::TextureBase;
+use luminance::backend::{color_slot::ColorSlot, depth_stencil_slot::DepthStencilSlot};
+use luminance::compute::ComputeError;
+use luminance::context::GraphicsContext;
+use luminance::framebuffer::{BlitFilter, BlitMask, Framebuffer, FramebufferError};
+use luminance::pipeline::{PipelineError, PipelineState, Viewport};
+use luminance::pixel::{ColorPixel, RenderablePixel};
+use luminance::render_state::RenderState;
+use luminance::scissor::ScissorRegion;
+use luminance::shader::{
+  types::Mat22, ProgramError, ProgramWarning, StageError, StageType, TessellationStages, Uniform,
+  UniformType, UniformWarning, VertexAttribWarning,
+};
+use luminance::tess::{Interleaved, Mode, TessError, TessView};
+use luminance::texture::{Dim2, Dimensionable, Sampler};
+use luminance::vertex::Semantics;
+use std::{cell::RefCell, rc::Rc};
+
+/// Color, depth and stencil clear values.
+type BackBufferClear = (Option<[f32; 4]>, Option<f32>, Option<i32>);
+
+/// A backend recording the pipeline commands it receives.
+#[derive(Default)]
+struct Recorder {
+  started_pipelines: usize,
+  applied_programs: usize,
+  linked_programs: usize,
+  clear_region: Option<ScissorRegion>,
+  // clear color and clear depth of every started pipeline
+  clears: Vec<(Option<[f32; 4]>, Option<f32>)>,
+  // clear values of every back buffer clear outside of pipelines
+  back_buffer_clears: Vec<BackBufferClear>,
+  // source and destination regions of every blit
+  blits: Vec<(ScissorRegion, ScissorRegion)>,
+  debug_log: Vec<String>,
+  // matrices uploaded to shader programs, as seen by shaders
+  uploaded_matrices: Rc<RefCell<Vec<[[f32; 2]; 2]>>>,
+  // number of vertices and instances of every draw
+  draws: Vec<(usize, usize)>,
+  entered_render_states: usize,
+  // work groups of every compute dispatch
+  dispatches: Vec<[u32; 3]>,
+  memory_barriers: usize,
+}
+
+struct Context(Recorder);
+
+unsafe impl GraphicsContext for Context {
+  type Backend = Recorder;
+
+  fn backend(&mut self) -> &mut Self::Backend {
+    &mut self.0
+  }
+}
+
+unsafe impl TextureBase for Recorder {
+  type TextureRepr = ();
+}
+
+unsafe impl FramebufferBackend<Dim2> for Recorder {
+  type FramebufferRepr = [u32; 2];
+
+  unsafe fn new_framebuffer<CS, DS>(
+    &mut self,
+    size: [u32; 2],
+    _: usize,
+    _: &Sampler,
+  ) -> Result<Self::FramebufferRepr, FramebufferError>
+  where
+    CS: ColorSlot<Self, Dim2>,
+    DS: DepthStencilSlot<Self, Dim2>,
+  {
+    Ok(size)
+  }
+
+  unsafe fn attach_color_texture(
+    _: &mut Self::FramebufferRepr,
+    _: &Self::TextureRepr,
+    _: usize,
+  ) -> Result<(), FramebufferError> {
+    Ok(())
+  }
+
+  unsafe fn attach_depth_texture(
+    _: &mut Self::FramebufferRepr,
+    _: &Self::TextureRepr,
+  ) -> Result<(), FramebufferError> {
+    Ok(())
+  }
+
+  unsafe fn validate_framebuffer(
+    framebuffer: Self::FramebufferRepr,
+  ) -> Result<Self::FramebufferRepr, FramebufferError> {
+    Ok(framebuffer)
+  }
+
+  unsafe fn framebuffer_size(framebuffer: &Self::FramebufferRepr) -> [u32; 2] {
+    *framebuffer
+  }
+}
+
+unsafe impl FramebufferBackBuffer for Recorder {
+  unsafe fn back_buffer(
+    &mut self,
+    size: <Dim2 as Dimensionable>::Size,
+  ) -> Result<Self::FramebufferRepr, FramebufferError> {
+    Ok(size)
+  }
+}
+
+unsafe impl ClearBackBuffer for Recorder {
+  unsafe fn clear_back_buffer(
+    &mut self,
+    color: Option<[f32; 4]>,
+    depth: Option<f32>,
+    stencil: Option<i32>,
+  ) {
+    self.back_buffer_clears.push((color, depth, stencil));
+  }
+}
+
+unsafe impl FramebufferBlit for Recorder {
+  unsafe fn blit_framebuffer(
+    &mut self,
+    _: &Self::FramebufferRepr,
+    _: &Self::FramebufferRepr,
+    src_region: &ScissorRegion,
+    dst_region: &ScissorRegion,
+    _: BlitMask,
+    _: BlitFilter,
+  ) -> Result<(), FramebufferError> {
+    self.blits.push((*src_region, *dst_region));
+    Ok(())
+  }
+}
+
+unsafe impl FramebufferReadback for Recorder {
+  unsafe fn read_color_pixels<P>(
+    _: &Self::FramebufferRepr,
+    _: usize,
+    region: &ScissorRegion,
+  ) -> Result<Vec<P::RawEncoding>, FramebufferError>
+  where
+    P: ColorPixel + RenderablePixel,
+    P::RawEncoding: Copy + Default,
+  {
+    let len = (region.width * region.height) as usize * P::pixel_format().channels_len();
+    Ok(vec![Default::default(); len])
+  }
+
+  // depth values are the y coordinate of the pixels
+  unsafe fn read_depth_pixels(
+    _: &Self::FramebufferRepr,
+    region: &ScissorRegion,
+  ) -> Result<Vec<f32>, FramebufferError> {
+    let depths = (region.y..region.y + region.height)
+      .flat_map(|y| (0..region.width).map(move |_| y as f32))
+      .collect();
+    Ok(depths)
+  }
+}
+
+unsafe impl Shader for Recorder {
+  // compilation log of the stage
+  type StageRepr = Option<String>;
+
+  type ProgramRepr = Rc<RefCell<Vec<[[f32; 2]; 2]>>>;
+
+  type UniformBuilderRepr = ();
+
+  unsafe fn new_stage(&mut self, ty: StageType, src: &str) -> Result<Self::StageRepr, StageError> {
+    if src.contains("syntax error") {
+      Err(StageError::compilation_failed(ty, src))
+    } else if src.contains("warning") {
+      Ok(Some(src.to_owned()))
+    } else {
+      Ok(None)
+    }
+  }
+
+  unsafe fn stage_info_log(stage: &Self::StageRepr) -> Option<String> {
+    stage.clone()
+  }
+
+  unsafe fn new_program(
+    &mut self,
+    _: &Self::StageRepr,
+    _: Option<TessellationStages<Self::StageRepr>>,
+    _: Option<&Self::StageRepr>,
+    _: &Self::StageRepr,
+  ) -> Result<Self::ProgramRepr, ProgramError> {
+    self.linked_programs += 1;
+    Ok(self.uploaded_matrices.clone())
+  }
+
+  unsafe fn apply_semantics<Sem>(
+    _: &mut Self::ProgramRepr,
+  ) -> Result<Vec<VertexAttribWarning>, ProgramError>
+  where
+    Sem: Semantics,
+  {
+    Ok(Vec::new())
+  }
+
+  unsafe fn new_uniform_builder(
+    _: &mut Self::ProgramRepr,
+  ) -> Result<Self::UniformBuilderRepr, ProgramError> {
+    Ok(())
+  }
+
+  unsafe fn ask_uniform<T>(
+    _: &mut Self::UniformBuilderRepr,
+    name: &str,
+  ) -> Result<Uniform<T>, UniformWarning>
+  where
+    Self: for<'u> Uniformable<'u, T>,
+  {
+    // the recorder backend has no uniforms
+    Err(UniformWarning::inactive(name))
+  }
+
+  unsafe fn unbound<T>(_: &mut Self::UniformBuilderRepr) -> Uniform<T>
+  where
+    Self: for<'u> Uniformable<'u, T>,
+  {
+    Uniform::new(-1)
+  }
+}
+
+// binaries are tagged with the format 1 and hold no data
+unsafe impl ShaderBinary for Recorder {
+  unsafe fn program_binary(_: &Self::ProgramRepr) -> Result<(u32, Vec<u8>), ProgramError> {
+    Ok((1, b"recorded".to_vec()))
+  }
+
+  unsafe fn new_program_from_binary(
+    &mut self,
+    format: u32,
+    binary: &[u8],
+  ) -> Result<Self::ProgramRepr, ProgramError> {
+    if format == 1 && binary == b"recorded" {
+      Ok(self.uploaded_matrices.clone())
+    } else {
+      Err(ProgramError::incompatible_binary("not recorded"))
+    }
+  }
+}
+
+// at most 16 work groups along each dimension
+unsafe impl Compute for Recorder {
+  unsafe fn new_compute_program(
+    &mut self,
+    _: &Self::StageRepr,
+  ) -> Result<Self::ProgramRepr, ProgramError> {
+    self.linked_programs += 1;
+    Ok(self.uploaded_matrices.clone())
+  }
+
+  unsafe fn apply_compute_program(&mut self, _: &Self::ProgramRepr) {
+    self.applied_programs += 1;
+  }
+
+  unsafe fn dispatch_compute(&mut self, groups: [u32; 3]) -> Result<(), ComputeError> {
+    if groups.iter().any(|&groups| groups > 16) {
+      return Err(ComputeError::too_many_work_groups(groups, [16; 3]));
+    }
+
+    self.dispatches.push(groups);
+    Ok(())
+  }
+
+  unsafe fn memory_barrier(&mut self) {
+    self.memory_barriers += 1;
+  }
+}
+
+unsafe impl<'a> Uniformable<'a, Mat22<f32>> for Recorder {
+  type Target = Mat22<f32>;
+
+  const SIZE: usize = 1;
+
+  unsafe fn ty() -> UniformType {
+    UniformType::M22
+  }
+
+  unsafe fn update(program: &mut Self::ProgramRepr, _: &'a Uniform<Mat22<f32>>, value: Mat22<f32>) {
+    program.borrow_mut().push(value.0);
+  }
+}
+
+unsafe impl<'a> UniformableTransposed<'a, Mat22<f32>> for Recorder {
+  unsafe fn update_transposed(
+    program: &mut Self::ProgramRepr,
+    _: &'a Uniform<Mat22<f32>>,
+    value: Mat22<f32>,
+  ) {
+    let [[a, b], [c, d]] = value.0;
+    program.borrow_mut().push([[a, c], [b, d]]);
+  }
+}
+
+unsafe impl ShadingGateBackend for Recorder {
+  unsafe fn apply_shader_program(&mut self, _: &Self::ProgramRepr) {
+    self.applied_programs += 1;
+  }
+}
+
+unsafe impl DebugGroup for Recorder {
+  unsafe fn push_debug_group(&mut self, name: &str) {
+    self.debug_log.push(format!("push {}", name));
+  }
+
+  unsafe fn pop_debug_group(&mut self) {
+    self.debug_log.push("pop".to_owned());
+  }
+}
+
+unsafe impl DebugLabel<[u32; 2]> for Recorder {
+  unsafe fn set_label(&mut self, framebuffer: &[u32; 2], label: &str) {
+    self
+      .debug_log
+      .push(format!("label {:?} {}", framebuffer, label));
+  }
+}
+
+unsafe impl PipelineBase for Recorder {
+  type PipelineRepr = ();
+
+  unsafe fn new_pipeline(&mut self) -> Result<Self::PipelineRepr, PipelineError> {
+    Ok(())
+  }
+}
+
+unsafe impl PipelineBackend<Dim2> for Recorder {
+  unsafe fn start_pipeline(&mut self, _: &Self::FramebufferRepr, pipeline_state: &PipelineState) {
+    self.started_pipelines += 1;
+    self.clear_region = pipeline_state.clear_region().copied();
+    self.clears.push((
+      pipeline_state.clear_color().copied(),
+      pipeline_state.clear_depth(),
+    ));
+  }
+}
+
+unsafe impl RenderGateBackend for Recorder {
+  unsafe fn enter_render_state(&mut self, _: &RenderState) {
+    self.entered_render_states += 1;
+  }
+}
+
+// attributeless tessellations, represented by their number of vertices
+unsafe impl TessBackend<(), (), (), Interleaved> for Recorder {
+  type TessRepr = usize;
+
+  unsafe fn build(
+    &mut self,
+    _: Option<Vec<()>>,
+    _: Vec<()>,
+    _: Option<Vec<()>>,
+    _: Mode,
+    _: Option<()>,
+  ) -> Result<Self::TessRepr, TessError> {
+    Ok(0)
+  }
+
+  fn max_patch_vertex_nb(&self) -> usize {
+    32
+  }
+
+  unsafe fn tess_vertices_nb(tess: &Self::TessRepr) -> usize {
+    *tess
+  }
+
+  unsafe fn tess_indices_nb(_: &Self::TessRepr) -> usize {
+    0
+  }
+
+  unsafe fn tess_instances_nb(_: &Self::TessRepr) -> usize {
+    0
+  }
+
+  unsafe fn render(
+    _: &Self::TessRepr,
+    _: usize,
+    _: usize,
+    _: i32,
+    _: usize,
+    _: usize,
+    _: Option<Mode>,
+  ) -> Result<(), TessError> {
+    Ok(())
+  }
+}
+
+unsafe impl TessGateBackend<(), (), (), Interleaved> for Recorder {
+  unsafe fn render(
+    &mut self,
+    _: &Self::TessRepr,
+    _: usize,
+    vert_nb: usize,
+    _: i32,
+    inst_nb: usize,
+    inst_start_index: usize,
+    _: Option<Mode>,
+  ) -> Result<(), TessError> {
+    // the recorder doesn’t support rendering from a base instance
+    if inst_start_index != 0 {
+      return Err(TessError::unsupported_base_instance());
+    }
+
+    self.draws.push((vert_nb, inst_nb));
+    Ok(())
+  }
+}
+
+#[test]
+fn viewport_whole_bottom_left_rect() {
+  assert_eq!(Viewport::Whole.bottom_left_rect(800, 600), [0, 0, 800, 600]);
+}
+
+#[test]
+fn viewport_specific_bottom_left_rect() {
+  let viewport = Viewport::Specific {
+    x: 10,
+    y: 20,
+    width: 100,
+    height: 50,
+  };
+
+  assert_eq!(viewport.bottom_left_rect(800, 600), [10, 20, 100, 50]);
+}
+
+#[test]
+fn viewport_specific_top_left_bottom_left_rect() {
+  let viewport = Viewport::SpecificTopLeft {
+    x: 10,
+    y: 20,
+    width: 100,
+    height: 50,
+  };
+
+  assert_eq!(viewport.bottom_left_rect(800, 600), [10, 530, 100, 50]);
+}
+
+#[test]
+fn viewport_specific_top_left_past_bottom() {
+  let viewport = Viewport::SpecificTopLeft {
+    x: 0,
+    y: 580,
+    width: 100,
+    height: 50,
+  };
+
+  assert_eq!(viewport.bottom_left_rect(800, 600), [0, 0, 100, 50]);
+}
+
+#[test]
+fn shade_returns_closure_value() {
+  let mut ctx = Context(Recorder::default());
+  let back_buffer = Framebuffer::back_buffer(&mut ctx, [800, 600]).unwrap();
+  let mut program = ctx
+    .new_shader_program::<(), (), ()>()
+    .from_strings("", None, None, "")
+    .unwrap()
+    .ignore_warnings();
+
+  let mut draw_nb = 0;
+  ctx
+    .new_pipeline_gate()
+    .pipeline(
+      &back_buffer,
+      &PipelineState::default(),
+      |_, mut shd_gate| -> Result<(), PipelineError> {
+        for _ in 0..3 {
+          draw_nb += shd_gate.shade(&mut program, |_, _, _| Ok(2))?;
+        }
+
+        Ok(())
+      },
+    )
+    .assume()
+    .into_result()
+    .unwrap();
+
+  assert_eq!(draw_nb, 6);
+  assert_eq!(ctx.0.started_pipelines, 1);
+  assert_eq!(ctx.0.applied_programs, 3);
+}
+
+#[test]
+fn pipeline_returns_closure_value() {
+  let mut ctx = Context(Recorder::default());
+  let back_buffer = Framebuffer::back_buffer(&mut ctx, [800, 600]).unwrap();
+
+  let picked = ctx
+    .new_pipeline_gate()
+    .pipeline(&back_buffer, &PipelineState::default(), |_, _| {
+      Ok([1u8, 2, 3, 4])
+    })
+    .assume()
+    .into_result()
+    .unwrap();
+
+  assert_eq!(picked, [1, 2, 3, 4]);
+  assert_eq!(ctx.0.started_pipelines, 1);
+}
+
+#[test]
+fn reload_program() {
+  let mut ctx = Context(Recorder::default());
+  let mut program = ctx
+    .new_shader_program::<(), (), ()>()
+    .from_strings("", None, None, "")
+    .unwrap()
+    .ignore_warnings();
+
+  let warnings = ctx
+    .new_shader_program()
+    .reload_from_strings(&mut program, "", None, None, "")
+    .unwrap();
+
+  assert!(warnings.is_empty());
+  assert_eq!(ctx.0.linked_programs, 2);
+
+  let error = ctx
+    .new_shader_program()
+    .reload_from_strings(&mut program, "", None, None, "syntax error")
+    .unwrap_err();
+
+  assert_eq!(
+    error,
+    ProgramError::StageError(StageError::compilation_failed(
+      StageType::FragmentShader,
+      "syntax error"
+    ))
+  );
+  assert_eq!(ctx.0.linked_programs, 2);
+}
+
+#[test]
+fn collect_stage_warnings() {
+  let mut ctx = Context(Recorder::default());
+  let (_, warnings) = ctx
+    .new_shader_program::<(), (), ()>()
+    .from_strings("", None, None, "warning: implicit conversion")
+    .unwrap()
+    .collect_warnings();
+
+  assert_eq!(
+    warnings,
+    vec![ProgramWarning::Stage(
+      StageType::FragmentShader,
+      "warning: implicit conversion".to_owned()
+    )]
+  );
+}
+
+#[test]
+fn pipeline_clamps_clear_region() {
+  let mut ctx = Context(Recorder::default());
+  let back_buffer = Framebuffer::back_buffer(&mut ctx, [800, 600]).unwrap();
+  let pipeline_state =
+    PipelineState::default().set_clear_region(ScissorRegion::new(700, 500, 200, 200));
+
+  ctx
+    .new_pipeline_gate()
+    .pipeline(&back_buffer, &pipeline_state, |_, _| Ok(()))
+    .assume()
+    .into_result()
+    .unwrap();
+
+  assert_eq!(
+    ctx.0.clear_region,
+    Some(ScissorRegion::new(700, 500, 100, 100))
+  );
+}
+
+#[test]
+fn pipeline_without_clear_depth() {
+  let mut ctx = Context(Recorder::default());
+  let back_buffer = Framebuffer::back_buffer(&mut ctx, [800, 600]).unwrap();
+  let first_frame = PipelineState::default();
+  let next_frames = PipelineState::default().set_clear_depth(None);
+
+  for pipeline_state in &[first_frame, next_frames] {
+    ctx
+      .new_pipeline_gate()
+      .pipeline(&back_buffer, pipeline_state, |_, _| Ok(()))
+      .assume()
+      .into_result()
+      .unwrap();
+  }
+
+  // the color is cleared every frame, while the depth is only cleared on the first one
+  assert_eq!(
+    ctx.0.clears,
+    vec![
+      (Some([0., 0., 0., 1.]), Some(1.)),
+      (Some([0., 0., 0., 1.]), None)
+    ]
+  );
+}
+
+#[test]
+fn set_transposed_uniform() {
+  let mut ctx = Context(Recorder::default());
+  let back_buffer = Framebuffer::back_buffer(&mut ctx, [800, 600]).unwrap();
+  let mut program = ctx
+    .new_shader_program::<(), (), ()>()
+    .from_strings("", None, None, "")
+    .unwrap()
+    .ignore_warnings();
+  let uniform = unsafe { Uniform::<Mat22<f32>>::new(0) };
+  let matrix = Mat22::new([[1., 2.], [3., 4.]]);
+
+  ctx
+    .new_pipeline_gate()
+    .pipeline(
+      &back_buffer,
+      &PipelineState::default(),
+      |_, mut shd_gate| -> Result<(), PipelineError> {
+        shd_gate.shade(&mut program, |mut iface, _, _| {
+          iface.set(&uniform, matrix);
+          iface.set_transposed(&uniform, matrix);
+          Ok(())
+        })
+      },
+    )
+    .assume()
+    .into_result()
+    .unwrap();
+
+  assert_eq!(
+    *ctx.0.uploaded_matrices.borrow(),
+    vec![[[1., 2.], [3., 4.]], [[1., 3.], [2., 4.]]]
+  );
+}
+
+#[test]
+fn debug_annotations() {
+  let mut ctx = Context(Recorder::default());
+  let back_buffer = Framebuffer::back_buffer(&mut ctx, [800, 600]).unwrap();
+
+  ctx.push_debug_group("frame");
+  ctx.set_label(&back_buffer, "back buffer");
+  ctx.pop_debug_group();
+
+  assert_eq!(
+    ctx.0.debug_log,
+    vec!["push frame", "label [800, 600] back buffer", "pop"]
+  );
+}
+
+#[test]
+fn read_depth() {
+  let mut ctx = Context(Recorder::default());
+  let back_buffer = Framebuffer::back_buffer(&mut ctx, [4, 3]).unwrap();
+
+  assert_eq!(back_buffer.read_depth(None).unwrap().len(), 12);
+  assert_eq!(
+    back_buffer.read_depth(ScissorRegion::new(1, 1, 3, 2)),
+    Ok(vec![1., 1., 1., 2., 2., 2.])
+  );
+
+  let region = ScissorRegion::new(2, 0, 3, 1);
+  assert_eq!(
+    back_buffer.read_depth(region),
+    Err(FramebufferError::region_out_of_bounds(region))
+  );
+}
+
+#[test]
+fn clear_back_buffer_without_pipeline() {
+  let mut ctx = Context(Recorder::default());
+
+  ctx.clear_back_buffer(Some([0., 0., 0., 1.]), Some(1.), None);
+
+  assert_eq!(ctx.0.started_pipelines, 0);
+  assert_eq!(
+    ctx.0.back_buffer_clears,
+    vec![(Some([0., 0., 0., 1.]), Some(1.), None)]
+  );
+}
+
+#[test]
+fn blit_framebuffers() {
+  let mut ctx = Context(Recorder::default());
+  let src = Framebuffer::back_buffer(&mut ctx, [8, 4]).unwrap();
+  let dst = Framebuffer::back_buffer(&mut ctx, [4, 2]).unwrap();
+
+  ctx
+    .blit_framebuffer(&src, &dst, None, None, BlitMask::COLOR, BlitFilter::Linear)
+    .unwrap();
+  ctx
+    .blit_framebuffer(
+      &src,
+      &dst,
+      ScissorRegion::new(4, 2, 4, 2),
+      None,
+      BlitMask::ALL,
+      BlitFilter::Nearest,
+    )
+    .unwrap();
+
+  assert_eq!(
+    ctx.0.blits,
+    vec![
+      (
+        ScissorRegion::new(0, 0, 8, 4),
+        ScissorRegion::new(0, 0, 4, 2)
+      ),
+      (
+        ScissorRegion::new(4, 2, 4, 2),
+        ScissorRegion::new(0, 0, 4, 2)
+      ),
+    ]
+  );
+
+  assert_eq!(
+    ctx.blit_framebuffer(&src, &dst, None, None, BlitMask::DEPTH, BlitFilter::Linear),
+    Err(FramebufferError::unsupported_blit_filter(
+      BlitFilter::Linear
+    ))
+  );
+
+  let region = ScissorRegion::new(0, 0, 5, 2);
+  assert_eq!(
+    ctx.blit_framebuffer(
+      &src,
+      &dst,
+      None,
+      region,
+      BlitMask::COLOR,
+      BlitFilter::Nearest
+    ),
+    Err(FramebufferError::region_out_of_bounds(region))
+  );
+  assert_eq!(ctx.0.blits.len(), 2);
+}
+
+#[test]
+fn render_instanced_without_instance_data() {
+  let mut ctx = Context(Recorder::default());
+  let back_buffer = Framebuffer::back_buffer(&mut ctx, [800, 600]).unwrap();
+  let mut program = ctx
+    .new_shader_program::<(), (), ()>()
+    .from_strings("", None, None, "")
+    .unwrap()
+    .ignore_warnings();
+  let tess = ctx
+    .new_tess()
+    .set_render_vertex_nb(3)
+    .set_mode(Mode::Triangle)
+    .build()
+    .unwrap();
+
+  ctx
+    .new_pipeline_gate()
+    .pipeline(
+      &back_buffer,
+      &PipelineState::default(),
+      |_, mut shd_gate| -> Result<(), PipelineError> {
+        shd_gate.shade(&mut program, |_, _, mut rdr_gate| {
+          rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+            tess_gate.render_instanced(&tess, 16)?;
+            tess_gate.render(TessView::inst_whole(&tess, 4))
+          })
+        })
+      },
+    )
+    .assume()
+    .into_result()
+    .unwrap();
+
+  assert_eq!(ctx.0.draws, vec![(3, 16), (3, 4)]);
+}
+
+#[test]
+fn render_many() {
+  let mut ctx = Context(Recorder::default());
+  let back_buffer = Framebuffer::back_buffer(&mut ctx, [800, 600]).unwrap();
+  let mut program = ctx
+    .new_shader_program::<(), (), ()>()
+    .from_strings("", None, None, "")
+    .unwrap()
+    .ignore_warnings();
+  let tess = ctx
+    .new_tess()
+    .set_render_vertex_nb(6)
+    .set_mode(Mode::Triangle)
+    .build()
+    .unwrap();
+
+  ctx
+    .new_pipeline_gate()
+    .pipeline(
+      &back_buffer,
+      &PipelineState::default(),
+      |_, mut shd_gate| -> Result<(), PipelineError> {
+        shd_gate.shade(&mut program, |_, _, mut rdr_gate| {
+          rdr_gate.render_many(
+            &RenderState::default(),
+            vec![
+              TessView::whole(&tess),
+              TessView::sub(&tess, 3).unwrap(),
+              TessView::inst_whole(&tess, 2),
+            ],
+          )
+        })
+      },
+    )
+    .assume()
+    .into_result()
+    .unwrap();
+
+  assert_eq!(ctx.0.entered_render_states, 1);
+  assert_eq!(ctx.0.draws, vec![(6, 0), (3, 0), (6, 2)]);
+}
+
+#[test]
+fn render_error_is_forwarded() {
+  let mut ctx = Context(Recorder::default());
+  let back_buffer = Framebuffer::back_buffer(&mut ctx, [800, 600]).unwrap();
+  let mut program = ctx
+    .new_shader_program::<(), (), ()>()
+    .from_strings("", None, None, "")
+    .unwrap()
+    .ignore_warnings();
+  let tess = ctx
+    .new_tess()
+    .set_render_vertex_nb(6)
+    .set_mode(Mode::Triangle)
+    .build()
+    .unwrap();
+
+  let result = ctx
+    .new_pipeline_gate()
+    .pipeline(
+      &back_buffer,
+      &PipelineState::default(),
+      |_, mut shd_gate| -> Result<(), PipelineError> {
+        shd_gate.shade(&mut program, |_, _, mut rdr_gate| {
+          rdr_gate.render_many(
+            &RenderState::default(),
+            vec![
+              TessView::whole(&tess),
+              TessView::inst_range(&tess, 0..3, 2, 4).unwrap(),
+              TessView::whole(&tess),
+            ],
+          )
+        })
+      },
+    )
+    .assume()
+    .into_result();
+
+  assert_eq!(
+    result,
+    Err(PipelineError::tess_error(
+      TessError::unsupported_base_instance()
+    ))
+  );
+  assert_eq!(ctx.0.draws, vec![(6, 0)]);
+}
+
+#[test]
+fn program_binary_roundtrip() {
+  let mut ctx = Context(Recorder::default());
+  let program = ctx
+    .new_shader_program::<(), (), ()>()
+    .from_strings("", None, None, "")
+    .unwrap()
+    .ignore_warnings();
+  let (format, binary) = program.to_binary().unwrap();
+
+  assert!(ctx
+    .new_shader_program::<(), (), ()>()
+    .from_binary(format, &binary)
+    .is_ok());
+  assert_eq!(ctx.0.linked_programs, 1);
+
+  let error = ctx
+    .new_shader_program::<(), (), ()>()
+    .from_binary(format + 1, &binary)
+    .err();
+  assert_eq!(
+    error,
+    Some(ProgramError::incompatible_binary("not recorded"))
+  );
+}
+
+#[test]
+fn dispatch_compute() {
+  let mut ctx = Context(Recorder::default());
+  let mut program = ctx.new_compute_program::<_, ()>("").unwrap();
+
+  ctx
+    .dispatch_compute(&mut program, [4, 2, 1], |_, _, _| Ok::<_, ComputeError>(()))
+    .unwrap();
+  ctx.memory_barrier();
+
+  assert_eq!(ctx.0.linked_programs, 1);
+  assert_eq!(ctx.0.applied_programs, 1);
+  assert_eq!(ctx.0.dispatches, vec![[4, 2, 1]]);
+  assert_eq!(ctx.0.memory_barriers, 1);
+
+  // nothing is dispatched if the setup fails or if the backend rejects the work groups
+  let error = ctx.dispatch_compute(&mut program, [1, 1, 1], |_, _, _| {
+    Err(ComputeError::invalid_storage_binding(8, 8))
+  });
+  assert_eq!(error, Err(ComputeError::invalid_storage_binding(8, 8)));
+
+  let error = ctx.dispatch_compute(&mut program, [32, 1, 1], |_, _, _| Ok(()));
+  assert_eq!(
+    error,
+    Err(ComputeError::too_many_work_groups([32, 1, 1], [16; 3]))
+  );
+  assert_eq!(ctx.0.dispatches, vec![[4, 2, 1]]);
+}
+
+#[test]
+fn compute_program_stage_error() {
+  let mut ctx = Context(Recorder::default());
+  let error = ctx
+    .new_compute_program::<_, ()>("syntax error")
+    .err()
+    .unwrap();
+
+  assert_eq!(
+    error,
+    ProgramError::stage_error(StageError::compilation_failed(
+      StageType::ComputeShader,
+      "syntax error"
+    ))
+  );
+}
+
+#[test]
+fn const_render_state_presets() {
+  use luminance::blending::{Blending, Equation, Factor};
+  use luminance::depth_stencil::Write;
+  use luminance::face_culling::FaceCulling;
+
+  const OPAQUE: RenderState = RenderState::opaque();
+  const ALPHA_BLENDED: RenderState = RenderState::alpha_blended();
+  const ADDITIVE: RenderState = RenderState::additive();
+  const UI_OVERLAY: RenderState = RenderState::ui_overlay();
+
+  let alpha_blended = RenderState::default()
+    .set_blending(Blending {
+      equation: Equation::Additive,
+      src: Factor::SrcAlpha,
+      dst: Factor::SrcAlphaComplement,
+    })
+    .set_depth_write(Write::Off);
+
+  assert_eq!(
+    OPAQUE,
+    RenderState::default().set_face_culling(FaceCulling::default())
+  );
+  assert_eq!(ALPHA_BLENDED, alpha_blended);
+  assert_eq!(
+    ADDITIVE,
+    alpha_blended.clone().set_blending(Blending {
+      equation: Equation::Additive,
+      src: Factor::One,
+      dst: Factor::One,
+    })
+  );
+  assert_eq!(UI_OVERLAY, alpha_blended.set_depth_test(None));
+}
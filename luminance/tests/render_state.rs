@@ -0,0 +1,111 @@
+use luminance::blending::{Blending, BlendingMode, Equation, Factor};
+use luminance::render_state::{
+  DrawBufferBlending, LineWidth, PointSize, RenderState, MAX_DRAW_BUFFER_BLENDING,
+};
+
+const ADDITIVE: Blending = Blending {
+  equation: Equation::Additive,
+  src: Factor::One,
+  dst: Factor::One,
+};
+
+const ALPHA: Blending = Blending {
+  equation: Equation::Additive,
+  src: Factor::SrcAlpha,
+  dst: Factor::SrcAlphaComplement,
+};
+
+#[test]
+fn draw_buffer_blending_inherits_by_default() {
+  let rdr_st = RenderState::default().set_blending(ADDITIVE);
+
+  assert_eq!(
+    rdr_st.draw_buffer_blending,
+    [DrawBufferBlending::Inherit; MAX_DRAW_BUFFER_BLENDING]
+  );
+  assert_eq!(
+    rdr_st.draw_buffer_blending(0),
+    Some(BlendingMode::Combined(ADDITIVE))
+  );
+  assert_eq!(rdr_st.draw_buffer_blending_overrides().count(), 0);
+}
+
+#[test]
+fn set_draw_buffer_blending() {
+  let rdr_st = RenderState::default()
+    .set_blending(ADDITIVE)
+    .set_draw_buffer_blending(1, BlendingMode::Combined(ALPHA))
+    .set_draw_buffer_blending(3, None);
+
+  assert_eq!(
+    rdr_st.draw_buffer_blending[1],
+    DrawBufferBlending::Set(BlendingMode::Combined(ALPHA))
+  );
+  assert_eq!(rdr_st.draw_buffer_blending[3], DrawBufferBlending::Disabled);
+
+  assert_eq!(
+    rdr_st.draw_buffer_blending(0),
+    Some(BlendingMode::Combined(ADDITIVE))
+  );
+  assert_eq!(
+    rdr_st.draw_buffer_blending(1),
+    Some(BlendingMode::Combined(ALPHA))
+  );
+  assert_eq!(rdr_st.draw_buffer_blending(3), None);
+  assert_eq!(
+    rdr_st.draw_buffer_blending(MAX_DRAW_BUFFER_BLENDING),
+    Some(BlendingMode::Combined(ADDITIVE))
+  );
+
+  assert_eq!(
+    rdr_st.draw_buffer_blending_overrides().collect::<Vec<_>>(),
+    vec![(1, Some(BlendingMode::Combined(ALPHA))), (3, None)]
+  );
+}
+
+#[test]
+#[should_panic(expected = "cannot override the blending of draw buffer 8")]
+fn set_draw_buffer_blending_out_of_bounds() {
+  let _ = RenderState::default().set_draw_buffer_blending(MAX_DRAW_BUFFER_BLENDING, None);
+}
+
+#[test]
+fn line_width() {
+  assert_eq!(LineWidth::new(2.5).width(), 2.5);
+
+  assert_eq!(LineWidth::new(0.).width(), 1.);
+  assert_eq!(LineWidth::new(-3.).width(), 1.);
+  assert_eq!(LineWidth::new(f32::NAN).width(), 1.);
+  assert_eq!(LineWidth::new(f32::NEG_INFINITY).width(), 1.);
+
+  assert_eq!(LineWidth::default(), LineWidth::new(1.));
+  assert_eq!(RenderState::default().line_width(), LineWidth::default());
+  assert_eq!(
+    RenderState::default().set_line_width(f32::NAN).line_width(),
+    LineWidth::default()
+  );
+}
+
+#[test]
+fn point_size() {
+  assert_eq!(PointSize::new(4.).size(), 4.);
+
+  assert_eq!(PointSize::new(0.).size(), 1.);
+  assert_eq!(PointSize::new(-3.).size(), 1.);
+  assert_eq!(PointSize::new(f32::NAN).size(), 1.);
+  assert_eq!(PointSize::new(f32::NEG_INFINITY).size(), 1.);
+
+  assert_eq!(PointSize::default(), PointSize::new(1.));
+  assert_eq!(
+    RenderState::default().point_size(),
+    Some(PointSize::default())
+  );
+  assert_eq!(
+    RenderState::default().set_point_size(-1.).point_size(),
+    Some(PointSize::default())
+  );
+  assert_eq!(
+    RenderState::default().set_point_size(None).point_size(),
+    None
+  );
+}
@@ -0,0 +1,51 @@
+use luminance::scissor::ScissorRegion;
+
+#[test]
+fn clamp_inside() {
+  let region = ScissorRegion::new(10, 20, 30, 40);
+
+  assert_eq!(region.clamp_to([800, 600]), region);
+}
+
+#[test]
+fn clamp_overlapping_edges() {
+  let region = ScissorRegion::new(780, 590, 30, 40);
+
+  assert_eq!(
+    region.clamp_to([800, 600]),
+    ScissorRegion::new(780, 590, 20, 10)
+  );
+}
+
+#[test]
+fn clamp_exactly_on_edges() {
+  let region = ScissorRegion::new(0, 0, 800, 600);
+
+  assert_eq!(region.clamp_to([800, 600]), region);
+  assert!(!region.clamp_to([800, 600]).is_empty());
+}
+
+#[test]
+fn clamp_outside() {
+  let region = ScissorRegion::new(900, 10, 30, 40).clamp_to([800, 600]);
+
+  assert_eq!(region, ScissorRegion::new(800, 10, 0, 40));
+  assert!(region.is_empty());
+}
+
+#[test]
+fn clamp_huge() {
+  let region = ScissorRegion::new(0, 0, u32::MAX, u32::MAX);
+
+  assert_eq!(
+    region.clamp_to([800, 600]),
+    ScissorRegion::new(0, 0, 800, 600)
+  );
+}
+
+#[test]
+fn zero_area() {
+  assert!(ScissorRegion::new(10, 10, 0, 5).is_empty());
+  assert!(ScissorRegion::new(10, 10, 5, 0).is_empty());
+  assert!(!ScissorRegion::new(10, 10, 5, 5).is_empty());
+}
@@ -0,0 +1,1364 @@
+#![cfg(feature = "derive")]
+
+use luminance::backend::indirect::IndirectBuffer as IndirectBufferBackend;
+use luminance::backend::tess::{
+  IndexSlice as IndexSliceBackend, InstanceSlice as InstanceSliceBackend,
+  RawTess as RawTessBackend, Tess as TessBackend, VertexSlice as VertexSliceBackend,
+  VertexSliceSet as VertexSliceSetBackend, VertexUpdate as VertexUpdateBackend,
+};
+use luminance::context::GraphicsContext;
+use luminance::indirect::{DrawElementsCommand, IndirectCommand, IndirectError};
+use luminance::tess::{
+  Deinterleaved, DeinterleavedData, Interleaved, MinimalIndexTess, Mode, Primitive, TessError,
+  TessIndex, TessIndexType, TessMapError, TessVertexData, TessView, TessViewError, View,
+};
+use luminance::vertex::{Vertex as _, VertexBufferDesc};
+use luminance::{Semantics, Vertex};
+use std::{mem, ops::Range, slice};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Semantics)]
+pub enum Semantics {
+  #[sem(name = "position", repr = "[f32; 3]", wrapper = "VertexPosition")]
+  Position,
+  #[sem(name = "color", repr = "[f32; 3]", wrapper = "VertexColor")]
+  Color,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Vertex)]
+#[vertex(sem = "Semantics")]
+struct Vertex {
+  pos: VertexPosition,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Vertex)]
+#[vertex(sem = "Semantics", slice_set)]
+struct ColoredVertex {
+  pos: VertexPosition,
+  color: VertexColor,
+}
+
+impl Vertex {
+  fn at(x: f32, y: f32, z: f32) -> Self {
+    Vertex::new(VertexPosition::new([x, y, z]))
+  }
+}
+
+/// A backend storing tessellations in host memory.
+struct Memory;
+
+struct Context(Memory);
+
+unsafe impl GraphicsContext for Context {
+  type Backend = Memory;
+
+  fn backend(&mut self) -> &mut Self::Backend {
+    &mut self.0
+  }
+}
+
+struct MemoryTess<V, I, W> {
+  vertices: Vec<V>,
+  indices: Vec<I>,
+  instances: Vec<W>,
+}
+
+unsafe impl<V, I, W> TessBackend<V, I, W, Interleaved> for Memory
+where
+  V: TessVertexData<Interleaved, Data = Vec<V>>,
+  I: TessIndex,
+  W: TessVertexData<Interleaved, Data = Vec<W>>,
+{
+  type TessRepr = MemoryTess<V, I, W>;
+
+  unsafe fn build(
+    &mut self,
+    vertex_data: Option<Vec<V>>,
+    index_data: Vec<I>,
+    instance_data: Option<Vec<W>>,
+    _: Mode,
+    _: Option<I>,
+  ) -> Result<Self::TessRepr, TessError> {
+    Ok(MemoryTess {
+      vertices: vertex_data.unwrap_or_default(),
+      indices: index_data,
+      instances: instance_data.unwrap_or_default(),
+    })
+  }
+
+  fn max_patch_vertex_nb(&self) -> usize {
+    32
+  }
+
+  unsafe fn tess_vertices_nb(tess: &Self::TessRepr) -> usize {
+    tess.vertices.len()
+  }
+
+  unsafe fn tess_indices_nb(tess: &Self::TessRepr) -> usize {
+    tess.indices.len()
+  }
+
+  unsafe fn tess_instances_nb(tess: &Self::TessRepr) -> usize {
+    tess.instances.len()
+  }
+
+  unsafe fn render(
+    _: &Self::TessRepr,
+    _: usize,
+    _: usize,
+    _: i32,
+    _: usize,
+    _: usize,
+    _: Option<Mode>,
+  ) -> Result<(), TessError> {
+    Ok(())
+  }
+}
+
+unsafe impl<V, I, W> TessBackend<V, I, W, Deinterleaved> for Memory
+where
+  V: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+  I: TessIndex,
+  W: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+{
+  type TessRepr = MemoryTess<DeinterleavedData, I, DeinterleavedData>;
+
+  unsafe fn build(
+    &mut self,
+    vertex_data: Option<Vec<DeinterleavedData>>,
+    index_data: Vec<I>,
+    instance_data: Option<Vec<DeinterleavedData>>,
+    _: Mode,
+    _: Option<I>,
+  ) -> Result<Self::TessRepr, TessError> {
+    Ok(MemoryTess {
+      vertices: vertex_data.unwrap_or_default(),
+      indices: index_data,
+      instances: instance_data.unwrap_or_default(),
+    })
+  }
+
+  fn max_patch_vertex_nb(&self) -> usize {
+    32
+  }
+
+  unsafe fn tess_vertices_nb(tess: &Self::TessRepr) -> usize {
+    V::coherent_len(&tess.vertices).unwrap_or(0)
+  }
+
+  unsafe fn tess_indices_nb(tess: &Self::TessRepr) -> usize {
+    tess.indices.len()
+  }
+
+  unsafe fn tess_instances_nb(tess: &Self::TessRepr) -> usize {
+    W::coherent_len(&tess.instances).unwrap_or(0)
+  }
+
+  unsafe fn render(
+    _: &Self::TessRepr,
+    _: usize,
+    _: usize,
+    _: i32,
+    _: usize,
+    _: usize,
+    _: Option<Mode>,
+  ) -> Result<(), TessError> {
+    Ok(())
+  }
+}
+
+unsafe impl<'a, V, I, W> VertexSliceBackend<'a, V, I, W, Interleaved, V> for Memory
+where
+  V: 'a + TessVertexData<Interleaved, Data = Vec<V>>,
+  I: TessIndex,
+  W: TessVertexData<Interleaved, Data = Vec<W>>,
+{
+  type VertexSliceRepr = &'a [V];
+  type VertexSliceMutRepr = &'a mut [V];
+
+  unsafe fn vertices(tess: &'a mut Self::TessRepr) -> Result<Self::VertexSliceRepr, TessMapError> {
+    Ok(&tess.vertices)
+  }
+
+  unsafe fn vertices_mut(
+    tess: &'a mut Self::TessRepr,
+  ) -> Result<Self::VertexSliceMutRepr, TessMapError> {
+    Ok(&mut tess.vertices)
+  }
+}
+
+unsafe impl<V, I, W> VertexUpdateBackend<V, I, W> for Memory
+where
+  V: TessVertexData<Interleaved, Data = Vec<V>>,
+  I: TessIndex,
+  W: TessVertexData<Interleaved, Data = Vec<W>>,
+{
+  unsafe fn update_vertices(
+    tess: &mut Self::TessRepr,
+    start: usize,
+    data: &[V],
+  ) -> Result<(), TessMapError> {
+    tess.vertices[start..start + data.len()].copy_from_slice(data);
+    Ok(())
+  }
+}
+
+unsafe impl<'a, V, I, W> IndexSliceBackend<'a, V, I, W, Interleaved> for Memory
+where
+  V: TessVertexData<Interleaved, Data = Vec<V>>,
+  I: 'a + TessIndex,
+  W: TessVertexData<Interleaved, Data = Vec<W>>,
+{
+  type IndexSliceRepr = &'a [I];
+  type IndexSliceMutRepr = &'a mut [I];
+
+  unsafe fn indices(tess: &'a mut Self::TessRepr) -> Result<Self::IndexSliceRepr, TessMapError> {
+    Ok(&tess.indices)
+  }
+
+  unsafe fn indices_mut(
+    tess: &'a mut Self::TessRepr,
+  ) -> Result<Self::IndexSliceMutRepr, TessMapError> {
+    Ok(&mut tess.indices)
+  }
+}
+
+unsafe impl<'a, V, I, W> InstanceSliceBackend<'a, V, I, W, Interleaved, W> for Memory
+where
+  V: TessVertexData<Interleaved, Data = Vec<V>>,
+  I: TessIndex,
+  W: 'a + TessVertexData<Interleaved, Data = Vec<W>>,
+{
+  type InstanceSliceRepr = &'a [W];
+  type InstanceSliceMutRepr = &'a mut [W];
+
+  unsafe fn instances(
+    tess: &'a mut Self::TessRepr,
+  ) -> Result<Self::InstanceSliceRepr, TessMapError> {
+    Ok(&tess.instances)
+  }
+
+  unsafe fn instances_mut(
+    tess: &'a mut Self::TessRepr,
+  ) -> Result<Self::InstanceSliceMutRepr, TessMapError> {
+    Ok(&mut tess.instances)
+  }
+}
+
+unsafe impl<'a, V, I, W> IndexSliceBackend<'a, V, I, W, Deinterleaved> for Memory
+where
+  V: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+  I: 'a + TessIndex,
+  W: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+{
+  type IndexSliceRepr = &'a [I];
+  type IndexSliceMutRepr = &'a mut [I];
+
+  unsafe fn indices(tess: &'a mut Self::TessRepr) -> Result<Self::IndexSliceRepr, TessMapError> {
+    Ok(&tess.indices)
+  }
+
+  unsafe fn indices_mut(
+    tess: &'a mut Self::TessRepr,
+  ) -> Result<Self::IndexSliceMutRepr, TessMapError> {
+    Ok(&mut tess.indices)
+  }
+}
+
+unsafe impl<'a, V, I, W> VertexSliceSetBackend<'a, V, I, W> for Memory
+where
+  V: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+  I: TessIndex,
+  W: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+{
+  // attributes are copied into u64 words, so that their bytes are aligned for any attribute type
+  type VertexSliceSetRepr = Vec<(Vec<u64>, usize)>;
+
+  unsafe fn vertices_all(
+    tess: &'a mut Self::TessRepr,
+  ) -> Result<Self::VertexSliceSetRepr, TessMapError> {
+    Ok(
+      tess
+        .vertices
+        .iter()
+        .cloned()
+        .map(|data| {
+          let bytes = data.into_vec();
+          let mut words = vec![0u64; (bytes.len() + 7) / 8];
+          slice::from_raw_parts_mut(words.as_mut_ptr() as *mut u8, bytes.len())
+            .copy_from_slice(&bytes);
+          (words, bytes.len())
+        })
+        .collect(),
+    )
+  }
+
+  unsafe fn attribute_bytes(slices: &Self::VertexSliceSetRepr, rank: usize) -> &[u8] {
+    let (words, len) = &slices[rank];
+    slice::from_raw_parts(words.as_ptr() as *const u8, *len)
+  }
+}
+
+unsafe impl<C> IndirectBufferBackend<C> for Memory
+where
+  C: IndirectCommand,
+{
+  type IndirectBufferRepr = Vec<C>;
+
+  unsafe fn new_indirect_buffer(
+    &mut self,
+    commands: impl Iterator<Item = C>,
+  ) -> Result<Self::IndirectBufferRepr, IndirectError> {
+    Ok(commands.collect())
+  }
+
+  unsafe fn indirect_buffer_len(buffer: &Self::IndirectBufferRepr) -> usize {
+    buffer.len()
+  }
+
+  unsafe fn indirect_command_at(
+    buffer: &Self::IndirectBufferRepr,
+    i: usize,
+  ) -> Result<C, IndirectError> {
+    buffer
+      .get(i)
+      .copied()
+      .ok_or_else(|| IndirectError::out_of_bounds(i))
+  }
+
+  unsafe fn set_indirect_command_at(
+    buffer: &mut Self::IndirectBufferRepr,
+    i: usize,
+    command: C,
+  ) -> Result<C, IndirectError> {
+    buffer
+      .get_mut(i)
+      .map(|c| std::mem::replace(c, command))
+      .ok_or_else(|| IndirectError::out_of_bounds(i))
+  }
+}
+
+unsafe impl RawTessBackend for Memory {
+  // vertex and index numbers
+  type RawTessRepr = (usize, usize);
+
+  unsafe fn new_raw_tess(
+    &mut self,
+    vertices: &[u8],
+    desc: &[VertexBufferDesc],
+    indices: Option<(&[u8], TessIndexType)>,
+    _: Mode,
+  ) -> Result<Self::RawTessRepr, TessError> {
+    let stride = desc.iter().map(|d| d.attrib_desc.size()).sum::<usize>();
+
+    if vertices.len() % stride != 0 {
+      return Err(TessError::length_incoherency(vertices.len()));
+    }
+
+    let idx_nb = indices.map_or(0, |(bytes, ty)| bytes.len() / ty.bytes());
+    Ok((vertices.len() / stride, idx_nb))
+  }
+
+  unsafe fn raw_tess_vertices_nb(tess: &Self::RawTessRepr) -> usize {
+    tess.0
+  }
+
+  unsafe fn raw_tess_indices_nb(tess: &Self::RawTessRepr) -> usize {
+    tess.1
+  }
+
+  unsafe fn render_raw_tess(
+    _: &Self::RawTessRepr,
+    _: usize,
+    _: usize,
+    _: usize,
+  ) -> Result<(), TessError> {
+    Ok(())
+  }
+}
+
+fn triangle() -> Vec<Vertex> {
+  vec![
+    Vertex::at(0., 0., 0.),
+    Vertex::at(1., 0., 0.),
+    Vertex::at(0., 1., 0.),
+  ]
+}
+
+#[test]
+fn append_rebases_indices() {
+  let mut ctx = Context(Memory);
+  let mut tess = ctx
+    .new_tess()
+    .set_mode(Mode::Triangle)
+    .set_vertices(triangle())
+    .set_indices(vec![0u8, 1, 2])
+    .append_vertices(triangle())
+    .append_indices(vec![0, 2, 1])
+    .unwrap()
+    .build()
+    .unwrap();
+
+  assert_eq!(tess.vert_nb(), 6);
+  assert_eq!(&*tess.indices().unwrap(), &[0, 1, 2, 3, 5, 4]);
+}
+
+#[test]
+fn append_inserts_primitive_restart() {
+  let mut ctx = Context(Memory);
+  let mut tess = ctx
+    .new_tess()
+    .set_mode(Mode::TriangleStrip)
+    .set_vertices(triangle())
+    .set_indices(vec![0u16, 1, 2])
+    .set_primitive_restart_index(u16::MAX)
+    .append_vertices(triangle())
+    .append_indices(vec![0, 1, u16::MAX, 2])
+    .unwrap()
+    .build()
+    .unwrap();
+
+  assert_eq!(
+    &*tess.indices().unwrap(),
+    &[0, 1, 2, u16::MAX, 3, 4, u16::MAX, 5]
+  );
+}
+
+#[test]
+fn append_rejects_strips_without_primitive_restart() {
+  let mut ctx = Context(Memory);
+  let builder = ctx
+    .new_tess()
+    .set_mode(Mode::TriangleStrip)
+    .set_vertices(triangle())
+    .set_indices(vec![0u16, 1, 2])
+    .append_vertices(triangle())
+    .append_indices(vec![0, 1, 2]);
+
+  assert!(matches!(builder, Err(TessError::CannotCreate(_))));
+}
+
+#[test]
+fn primitive_restart_fixed_before_indices() {
+  let mut ctx = Context(Memory);
+  let mut tess = ctx
+    .new_tess()
+    .set_mode(Mode::TriangleStrip)
+    .enable_primitive_restart_fixed()
+    .set_vertices(triangle())
+    .set_indices(vec![0u16, 1, 2])
+    .append_vertices(triangle())
+    .append_indices(vec![0, 1, 2])
+    .unwrap()
+    .build()
+    .unwrap();
+
+  assert_eq!(&*tess.indices().unwrap(), &[0, 1, 2, u16::MAX, 3, 4, 5]);
+}
+
+#[test]
+fn primitive_restart_fixed_after_indices() {
+  let mut ctx = Context(Memory);
+  let mut tess = ctx
+    .new_tess()
+    .set_mode(Mode::TriangleStrip)
+    .set_indices(vec![0u8, 1, 2])
+    .set_vertices(triangle())
+    .enable_primitive_restart_fixed()
+    .append_vertices(triangle())
+    .append_indices(vec![0, 1, 2])
+    .unwrap()
+    .build()
+    .unwrap();
+
+  assert_eq!(&*tess.indices().unwrap(), &[0, 1, 2, u8::MAX, 3, 4, 5]);
+}
+
+fn vertices(nb: usize) -> Vec<Vertex> {
+  (0..nb).map(|i| Vertex::at(i as f32, 0., 0.)).collect()
+}
+
+#[test]
+fn minimal_index_type() {
+  let mut ctx = Context(Memory);
+  let tess = ctx
+    .new_tess()
+    .set_mode(Mode::Triangle)
+    .set_vertices(triangle())
+    .set_indices(vec![0u32, 1, 2])
+    .append_vertices(triangle())
+    .append_indices(vec![0, 2, 1])
+    .unwrap()
+    .build_with_minimal_index_type()
+    .unwrap();
+
+  match tess {
+    MinimalIndexTess::U8(mut tess) => assert_eq!(&*tess.indices().unwrap(), &[0, 1, 2, 3, 5, 4]),
+    tess => panic!("unexpected index type: {:?}", tess.index_type()),
+  }
+
+  let tess = ctx
+    .new_tess()
+    .set_mode(Mode::Point)
+    .set_vertices(vertices(256))
+    .set_indices(vec![0u32, 255])
+    .build_with_minimal_index_type()
+    .unwrap();
+  assert_eq!(tess.index_type(), TessIndexType::U8);
+
+  let tess = ctx
+    .new_tess()
+    .set_mode(Mode::Point)
+    .set_vertices(vertices(70_000))
+    .set_indices(vec![0u32, 69_999])
+    .build_with_minimal_index_type()
+    .unwrap();
+  assert_eq!(tess.index_type(), TessIndexType::U32);
+  assert_eq!(tess.idx_nb(), 2);
+}
+
+#[test]
+fn minimal_index_type_primitive_restart() {
+  let mut ctx = Context(Memory);
+
+  // the maximum value is reserved for primitive restart, so 256 vertices don’t fit u8 indices anymore
+  let tess = ctx
+    .new_tess()
+    .set_mode(Mode::LineStrip)
+    .enable_primitive_restart_fixed()
+    .set_vertices(vertices(256))
+    .set_indices(vec![0u32, 1, u32::MAX, 254, 255])
+    .build_with_minimal_index_type()
+    .unwrap();
+
+  match tess {
+    MinimalIndexTess::U16(mut tess) => {
+      assert_eq!(&*tess.indices().unwrap(), &[0, 1, u16::MAX, 254, 255])
+    }
+    tess => panic!("unexpected index type: {:?}", tess.index_type()),
+  }
+
+  let tess = ctx
+    .new_tess()
+    .set_mode(Mode::LineStrip)
+    .enable_primitive_restart_fixed()
+    .set_vertices(vertices(255))
+    .set_indices(vec![0u32, 1, u32::MAX, 253, 254])
+    .build_with_minimal_index_type()
+    .unwrap();
+
+  match tess {
+    MinimalIndexTess::U8(mut tess) => {
+      assert_eq!(&*tess.indices().unwrap(), &[0, 1, u8::MAX, 253, 254])
+    }
+    tess => panic!("unexpected index type: {:?}", tess.index_type()),
+  }
+}
+
+#[test]
+fn append_rejects_mixed_modes() {
+  let mut ctx = Context(Memory);
+  let builder = ctx
+    .new_tess()
+    .set_mode(Mode::Triangle)
+    .set_vertices(triangle())
+    .set_indices(vec![0u32, 1, 2])
+    .append_vertices(triangle())
+    .append_indices(vec![0, 1, 2])
+    .unwrap()
+    .set_mode(Mode::Line)
+    .append_vertices(triangle())
+    .append_indices(vec![0, 1]);
+
+  assert!(matches!(builder, Err(TessError::CannotCreate(_))));
+}
+
+#[test]
+fn append_rejects_overflowing_indices() {
+  let mut ctx = Context(Memory);
+  let builder = ctx
+    .new_tess()
+    .set_mode(Mode::Point)
+    .set_vertices(vec![Vertex::at(0., 0., 0.); 256])
+    .set_indices(vec![0u8])
+    .append_vertices(triangle())
+    .append_indices(vec![0]);
+
+  assert!(matches!(builder, Err(TessError::CannotCreate(_))));
+}
+
+#[test]
+fn append_rejects_indices_colliding_with_primitive_restart() {
+  let mut ctx = Context(Memory);
+  let builder = ctx
+    .new_tess()
+    .set_mode(Mode::TriangleStrip)
+    .set_vertices(vec![Vertex::at(0., 0., 0.); 254])
+    .set_indices(vec![0u8, 1, 2])
+    .enable_primitive_restart_fixed()
+    .append_vertices(triangle())
+    .append_indices(vec![0, 1]);
+
+  assert!(matches!(builder, Err(TessError::CannotCreate(_))));
+}
+
+#[test]
+fn bounding_box() {
+  let mut ctx = Context(Memory);
+  let mut tess = ctx
+    .new_tess()
+    .set_vertices(vec![
+      Vertex::at(-1., 2., 0.5),
+      Vertex::at(3., -4., 0.),
+      Vertex::at(0., 0., -2.),
+    ])
+    .build()
+    .unwrap();
+
+  assert_eq!(
+    tess.bounding_box(|v| *v.pos),
+    Ok(([-1., -4., -2.], [3., 2., 0.5]))
+  );
+}
+
+#[test]
+fn read_back() {
+  let mut ctx = Context(Memory);
+  let mut tess = ctx
+    .new_tess()
+    .set_vertices(triangle())
+    .set_indices(vec![2u16, 1, 0])
+    .build()
+    .unwrap();
+
+  let vertices = tess.read_vertices().unwrap();
+  let indices = tess.read_indices().unwrap();
+
+  assert_eq!(vertices, triangle());
+  assert_eq!(indices, vec![2, 1, 0]);
+  assert_eq!(tess.vert_nb(), 3);
+}
+
+#[test]
+fn update_vertices_range() {
+  let mut ctx = Context(Memory);
+  let mut tess = ctx.new_tess().set_vertices(vertices(4)).build().unwrap();
+  let update = vertices(2);
+
+  tess.update_vertices_range(1, &update).unwrap();
+  tess.update_vertices_range(4, &[]).unwrap();
+
+  let mut expected = vertices(4);
+  expected[1..3].copy_from_slice(&update);
+  assert_eq!(tess.read_vertices().unwrap(), expected);
+
+  assert_eq!(
+    tess.update_vertices_range(3, &update),
+    Err(TessMapError::vertex_range_out_of_bounds(3, 2, 4))
+  );
+  assert_eq!(
+    tess.update_vertices_range(usize::MAX, &update),
+    Err(TessMapError::vertex_range_out_of_bounds(usize::MAX, 2, 4))
+  );
+  assert_eq!(tess.read_vertices().unwrap(), expected);
+}
+
+#[test]
+fn validate() {
+  let mut ctx = Context(Memory);
+  let builder = ctx
+    .new_tess()
+    .set_mode(Mode::Triangle)
+    .set_vertices(triangle());
+
+  assert_eq!(builder.validate().map_err(|e| e.len()), Ok(()));
+}
+
+#[test]
+fn validate_collects_all_errors() {
+  let mut ctx = Context(Memory);
+  let builder = ctx
+    .new_tess()
+    .set_mode(Mode::Triangle)
+    .set_vertices(triangle())
+    .set_instances(triangle())
+    .set_render_vertex_nb(2)
+    .set_render_instance_nb(4);
+  let errors = builder.validate().unwrap_err();
+
+  assert_eq!(errors.len(), 2);
+  assert!(matches!(errors[0], TessError::CannotCreate(_)));
+  assert_eq!(errors[1], TessError::length_incoherency(4));
+
+  let builder = ctx
+    .new_tess()
+    .set_mode(Mode::Patch(0))
+    .set_vertices(triangle());
+
+  assert_eq!(
+    builder.validate().unwrap_err(),
+    vec![TessError::ForbiddenPrimitiveMode(Mode::Patch(0))]
+  );
+}
+
+#[test]
+fn validate_index_out_of_range() {
+  let mut ctx = Context(Memory);
+  let builder = ctx
+    .new_tess()
+    .set_mode(Mode::TriangleStrip)
+    .set_vertices(triangle())
+    .set_indices(vec![0u8, 1, 2, u8::MAX, 2, 1, 3])
+    .set_primitive_restart_index(u8::MAX);
+
+  assert_eq!(
+    builder.validate().unwrap_err(),
+    vec![TessError::index_out_of_range(3, 3)]
+  );
+
+  #[cfg(feature = "debug-validate")]
+  assert_eq!(
+    builder.build().err(),
+    Some(TessError::index_out_of_range(3, 3))
+  );
+}
+
+#[test]
+fn patch_vertex_nb() {
+  let mut ctx = Context(Memory);
+
+  let tess = ctx
+    .new_tess()
+    .set_mode(Mode::Patch(3))
+    .set_vertices(triangle())
+    .build();
+  assert!(tess.is_ok());
+
+  for mode in [Mode::Patch(0), Mode::Patch(33)] {
+    let tess = ctx
+      .new_tess()
+      .set_mode(mode)
+      .set_vertices(triangle())
+      .build();
+    assert_eq!(tess.err(), Some(TessError::ForbiddenPrimitiveMode(mode)));
+  }
+}
+
+#[test]
+fn mode_accepts_vertex_nb() {
+  assert!(Mode::Point.accepts_vertex_nb(7));
+  assert!(Mode::Line.accepts_vertex_nb(4));
+  assert!(!Mode::Line.accepts_vertex_nb(3));
+  assert!(!Mode::LineStrip.accepts_vertex_nb(1));
+  assert!(Mode::Triangle.accepts_vertex_nb(0));
+  assert!(!Mode::TriangleStrip.accepts_vertex_nb(2));
+  assert!(Mode::Patch(4).accepts_vertex_nb(8));
+  assert!(!Mode::Patch(0).accepts_vertex_nb(0));
+}
+
+#[test]
+fn mode_primitive_count() {
+  assert_eq!(Mode::Point.primitive_count(7), 7);
+  assert_eq!(Mode::Line.primitive_count(5), 2);
+  assert_eq!(Mode::LineStrip.primitive_count(5), 4);
+  assert_eq!(Mode::LineStrip.primitive_count(0), 0);
+  assert_eq!(Mode::Triangle.primitive_count(7), 2);
+  assert_eq!(Mode::TriangleFan.primitive_count(6), 4);
+  assert_eq!(Mode::TriangleStrip.primitive_count(2), 0);
+  assert_eq!(Mode::Patch(4).primitive_count(8), 2);
+  assert_eq!(Mode::Patch(0).primitive_count(8), 0);
+}
+
+#[test]
+fn deinterleaved_complete() {
+  let mut ctx = Context(Memory);
+  let tess = ctx
+    .new_deinterleaved_tess::<ColoredVertex, ()>()
+    .set_attributes(vec![VertexPosition::new([0., 0., 0.]); 3])
+    .set_attributes(vec![VertexColor::new([1., 1., 1.]); 3])
+    .build()
+    .unwrap();
+
+  assert_eq!(tess.render_vert_nb(), 3);
+}
+
+#[test]
+fn deinterleaved_vertices_all() {
+  let mut ctx = Context(Memory);
+  let mut tess = ctx
+    .new_deinterleaved_tess::<ColoredVertex, ()>()
+    .set_attributes(vec![
+      VertexPosition::new([0., 0., 0.]),
+      VertexPosition::new([1., 0., 0.]),
+    ])
+    .set_attributes(vec![VertexColor::new([1., 0., 0.]); 2])
+    .build()
+    .unwrap();
+
+  let vertices = tess.vertices_all().unwrap();
+  let ColoredVertexSliceSet { pos, color } = vertices.slices();
+
+  assert_eq!(
+    pos,
+    &[
+      VertexPosition::new([0., 0., 0.]),
+      VertexPosition::new([1., 0., 0.])
+    ]
+  );
+  assert_eq!(color, &[VertexColor::new([1., 0., 0.]); 2]);
+}
+
+#[test]
+fn deinterleaved_set_attributes_twice() {
+  let mut ctx = Context(Memory);
+  let builder = ctx
+    .new_deinterleaved_tess::<ColoredVertex, ()>()
+    .set_attributes(vec![VertexPosition::new([0., 0., 0.]); 3])
+    .set_attributes(vec![VertexPosition::new([1., 1., 1.]); 4])
+    .set_attributes(vec![VertexColor::new([1., 1., 1.]); 4]);
+  drop(builder);
+
+  let tess = ctx
+    .new_deinterleaved_tess::<ColoredVertex, ()>()
+    .set_attributes(vec![VertexPosition::new([0., 0., 0.]); 3])
+    .set_attributes(vec![VertexColor::new([1., 1., 1.]); 3])
+    .set_attributes(vec![VertexColor::new([0., 1., 0.]); 3])
+    .build()
+    .unwrap();
+  assert_eq!(tess.render_vert_nb(), 3);
+}
+
+#[test]
+fn deinterleaved_missing_attributes() {
+  let mut ctx = Context(Memory);
+  let builder = ctx
+    .new_deinterleaved_tess::<ColoredVertex, ()>()
+    .set_attributes(vec![VertexColor::new([1., 1., 1.]); 3]);
+
+  assert_eq!(
+    builder.build().err(),
+    Some(TessError::missing_attributes(vec![0]))
+  );
+
+  let builder = ctx
+    .new_deinterleaved_tess::<ColoredVertex, ()>()
+    .set_attributes(vec![VertexPosition::new([0., 0., 0.]); 3])
+    .set_indices(vec![0u8, 1, 2]);
+
+  assert_eq!(
+    builder.build().err(),
+    Some(TessError::missing_attributes(vec![1]))
+  );
+}
+
+#[test]
+fn view_with_mode() {
+  let mut ctx = Context(Memory);
+  let tess = ctx
+    .new_tess()
+    .set_mode(Mode::Triangle)
+    .set_vertices(triangle())
+    .set_indices(vec![0u8, 1, 1, 2, 2, 0])
+    .build()
+    .unwrap();
+
+  assert!(TessView::whole(&tess).with_mode(Mode::Line).is_ok());
+  assert_eq!(
+    TessView::sub(&tess, 3).unwrap().with_mode(Mode::Line).err(),
+    Some(TessViewError::IncompatibleMode {
+      mode: Mode::Line,
+      vert_nb: 3
+    })
+  );
+}
+
+#[test]
+fn view_primitive_count() {
+  let mut ctx = Context(Memory);
+  let vertices: Vec<_> = (0..6).map(|i| Vertex::at(i as f32, 0., 0.)).collect();
+  let mut tess = ctx
+    .new_tess()
+    .set_mode(Mode::TriangleStrip)
+    .set_vertices(vertices)
+    .set_indices(vec![0u8, 1, 2, 3, u8::MAX, 4, 5, 0])
+    .enable_primitive_restart_fixed()
+    .build()
+    .unwrap();
+  let indices = tess.indices().unwrap().to_vec();
+
+  // without the indices, the restart index is counted as a vertex
+  assert_eq!(TessView::whole(&tess).primitive_count(), 6);
+  assert_eq!(
+    TessView::whole(&tess).primitive_count_with_indices(&indices),
+    3
+  );
+  assert_eq!(
+    TessView::index_slice(&tess, 3..8)
+      .unwrap()
+      .primitive_count_with_indices(&indices),
+    1
+  );
+  assert_eq!(
+    TessView::inst_whole(&tess, 4).primitive_count_with_indices(&indices),
+    12
+  );
+  assert_eq!(
+    TessView::sub(&tess, 4)
+      .unwrap()
+      .with_mode(Mode::Line)
+      .unwrap()
+      .primitive_count(),
+    2
+  );
+}
+
+#[test]
+fn clamped_slice() {
+  let mut ctx = Context(Memory);
+  let tess = ctx
+    .new_tess()
+    .set_mode(Mode::Point)
+    .set_vertices(vertices(6))
+    .build()
+    .unwrap();
+
+  // with points, the number of primitives is the number of vertices of the view
+  let vert_nb = |start, nb| TessView::clamped_slice(&tess, start, nb).primitive_count();
+
+  assert_eq!(vert_nb(1, 3), 3);
+  assert_eq!(vert_nb(4, 10), 2);
+  assert_eq!(vert_nb(10, 3), 0);
+  assert_eq!(vert_nb(usize::MAX, usize::MAX), 0);
+  assert_eq!(vert_nb(0, usize::MAX), 6);
+}
+
+#[test]
+fn base_vertex_slice() {
+  let mut ctx = Context(Memory);
+  let vertices: Vec<_> = triangle().into_iter().chain(triangle()).collect();
+  let tess = ctx
+    .new_tess()
+    .set_mode(Mode::Triangle)
+    .set_vertices(vertices)
+    .set_indices(vec![0u8, 1, 2, 0, 1, 2])
+    .build()
+    .unwrap();
+
+  assert!(TessView::base_vertex_slice(&tess, 3..6, 3).is_ok());
+  assert_eq!(
+    TessView::base_vertex_slice(&tess, 3..7, 3).err(),
+    Some(TessViewError::IncorrectViewWindow {
+      capacity: 6,
+      start: 3,
+      nb: 4
+    })
+  );
+}
+
+#[test]
+fn primitives_direct() {
+  let mut ctx = Context(Memory);
+  let vertices: Vec<_> = (0..4).map(|i| Vertex::at(i as f32, 0., 0.)).collect();
+  let mut tess = ctx
+    .new_tess()
+    .set_mode(Mode::LineStrip)
+    .set_vertices(vertices.clone())
+    .build()
+    .unwrap();
+
+  let lines: Vec<_> = tess.primitives().unwrap().collect();
+  assert_eq!(
+    lines,
+    vec![
+      Primitive::Line([vertices[0], vertices[1]]),
+      Primitive::Line([vertices[1], vertices[2]]),
+      Primitive::Line([vertices[2], vertices[3]]),
+    ]
+  );
+}
+
+#[test]
+fn primitives_strip_with_restart() {
+  let mut ctx = Context(Memory);
+  let vertices: Vec<_> = (0..5).map(|i| Vertex::at(i as f32, 0., 0.)).collect();
+  let mut tess = ctx
+    .new_tess()
+    .set_mode(Mode::TriangleStrip)
+    .set_vertices(vertices.clone())
+    .set_indices(vec![0u8, 1, 2, 3, u8::MAX, 2, 3, 4])
+    .set_primitive_restart_index(u8::MAX)
+    .build()
+    .unwrap();
+
+  let triangles: Vec<_> = tess.primitives().unwrap().collect();
+  let v = |i: usize| vertices[i];
+  assert_eq!(
+    triangles,
+    vec![
+      Primitive::Triangle([v(0), v(1), v(2)]),
+      Primitive::Triangle([v(2), v(1), v(3)]),
+      Primitive::Triangle([v(2), v(3), v(4)]),
+    ]
+  );
+}
+
+#[test]
+fn primitives_fan() {
+  let mut ctx = Context(Memory);
+  let vertices: Vec<_> = (0..4).map(|i| Vertex::at(i as f32, 0., 0.)).collect();
+  let mut tess = ctx
+    .new_tess()
+    .set_mode(Mode::TriangleFan)
+    .set_vertices(vertices.clone())
+    .set_indices(vec![3u32, 0, 1, 2])
+    .build()
+    .unwrap();
+
+  let triangles: Vec<_> = tess.primitives().unwrap().collect();
+  let v = |i: usize| vertices[i];
+  assert_eq!(
+    triangles,
+    vec![
+      Primitive::Triangle([v(3), v(0), v(1)]),
+      Primitive::Triangle([v(3), v(1), v(2)]),
+    ]
+  );
+}
+
+#[test]
+fn index_slice() {
+  let mut ctx = Context(Memory);
+  let tess = ctx
+    .new_tess()
+    .set_vertices(triangle())
+    .set_indices(vec![0u8, 1, 2, 2, 1, 0])
+    .set_render_vertex_nb(3)
+    .build()
+    .unwrap();
+
+  assert!(TessView::index_slice(&tess, 3..6).is_ok());
+  assert_eq!(
+    TessView::index_slice(&tess, 4..7).err(),
+    Some(TessViewError::IncorrectViewWindow {
+      capacity: 6,
+      start: 4,
+      nb: 3
+    })
+  );
+}
+
+#[test]
+fn slice_overflowing_window() {
+  let mut ctx = Context(Memory);
+  let tess = ctx.new_tess().set_vertices(triangle()).build().unwrap();
+
+  assert!(TessView::slice(&tess, 1, 2).is_ok());
+  assert_eq!(
+    TessView::slice(&tess, 1, usize::MAX).err(),
+    Some(TessViewError::IncorrectViewWindow {
+      capacity: 3,
+      start: 1,
+      nb: usize::MAX
+    })
+  );
+  assert_eq!(
+    TessView::inst_slice(&tess, usize::MAX, 1, 1).err(),
+    Some(TessViewError::IncorrectViewWindow {
+      capacity: 3,
+      start: usize::MAX,
+      nb: 1
+    })
+  );
+}
+
+#[test]
+fn view_range_from_past_end() {
+  let mut ctx = Context(Memory);
+  let tess = ctx.new_tess().set_vertices(triangle()).build().unwrap();
+
+  assert!(tess.view(3..).is_ok());
+  assert_eq!(
+    tess.view(100..).err(),
+    Some(TessViewError::IncorrectViewWindow {
+      capacity: 3,
+      start: 100,
+      nb: 0
+    })
+  );
+  assert_eq!(
+    tess.inst_view(100.., 2).err(),
+    Some(TessViewError::IncorrectViewWindow {
+      capacity: 3,
+      start: 100,
+      nb: 0
+    })
+  );
+}
+
+#[test]
+fn inst_range() {
+  let mut ctx = Context(Memory);
+  let tess = ctx.new_tess().set_vertices(triangle()).build().unwrap();
+
+  assert!(TessView::inst_range(&tess, 0..3, 1000, 1000).is_ok());
+  assert_eq!(
+    TessView::inst_range(&tess, 1..4, 1000, 1000).err(),
+    Some(TessViewError::IncorrectViewWindow {
+      capacity: 3,
+      start: 1,
+      nb: 3
+    })
+  );
+  assert_eq!(
+    TessView::inst_range(&tess, Range { start: 2, end: 1 }, 0, 1).err(),
+    Some(TessViewError::IncorrectViewWindow {
+      capacity: 3,
+      start: 2,
+      nb: 0
+    })
+  );
+}
+
+#[test]
+fn primitives_strip_with_fixed_restart() {
+  let mut ctx = Context(Memory);
+  let vertices: Vec<_> = (0..5).map(|i| Vertex::at(i as f32, 0., 0.)).collect();
+  let mut tess = ctx
+    .new_tess()
+    .set_mode(Mode::TriangleStrip)
+    .set_vertices(vertices.clone())
+    .set_indices(vec![0u16, 1, 2, u16::MAX, 2, 3, 4])
+    .enable_primitive_restart_fixed()
+    .build()
+    .unwrap();
+
+  let triangles: Vec<_> = tess.primitives().unwrap().collect();
+  let v = |i: usize| vertices[i];
+  assert_eq!(
+    triangles,
+    vec![
+      Primitive::Triangle([v(0), v(1), v(2)]),
+      Primitive::Triangle([v(2), v(3), v(4)]),
+    ]
+  );
+}
+
+#[cfg(feature = "obj-export")]
+#[test]
+fn export_obj() {
+  let mut ctx = Context(Memory);
+  let vertices: Vec<_> = (0..4).map(|i| Vertex::at(i as f32, 0., 0.)).collect();
+  let mut tess = ctx
+    .new_tess()
+    .set_mode(Mode::TriangleStrip)
+    .set_vertices(vertices)
+    .set_indices(vec![0u8, 1, 2, 3])
+    .build()
+    .unwrap();
+
+  let mut obj = Vec::new();
+  tess
+    .export_obj(&mut obj, |v| *v.pos, |_| Some([0., 0., 1.]))
+    .unwrap();
+
+  assert_eq!(
+    String::from_utf8(obj).unwrap(),
+    "v 0 0 0\nv 1 0 0\nv 2 0 0\nv 3 0 0\n\
+     vn 0 0 1\nvn 0 0 1\nvn 0 0 1\nvn 0 0 1\n\
+     f 1//1 2//2 3//3\nf 3//3 2//2 4//4\n"
+  );
+
+  let mut obj = Vec::new();
+  tess.export_obj(&mut obj, |v| *v.pos, |_| None).unwrap();
+
+  assert!(String::from_utf8(obj)
+    .unwrap()
+    .ends_with("f 1 2 3\nf 3 2 4\n"));
+}
+
+#[test]
+fn indirect_buffer() {
+  let mut ctx = Context(Memory);
+  let mut commands = ctx
+    .new_indirect_buffer(vec![
+      DrawElementsCommand::new(0, 3, 1),
+      DrawElementsCommand::new(3, 3, 2),
+    ])
+    .unwrap();
+
+  assert_eq!(commands.len(), 2);
+  assert_eq!(commands.at(1), Ok(DrawElementsCommand::new(3, 3, 2)));
+
+  let culled = DrawElementsCommand::new(3, 3, 0);
+  assert_eq!(
+    commands.set(1, culled),
+    Ok(DrawElementsCommand::new(3, 3, 2))
+  );
+  assert_eq!(commands.at(1), Ok(culled));
+  assert_eq!(commands.at(2), Err(IndirectError::out_of_bounds(2)));
+}
+
+#[test]
+fn interleaved_deinterleaved_round_trip() {
+  let mut ctx = Context(Memory);
+  let vertices = vec![
+    ColoredVertex::new(
+      VertexPosition::new([0., 0., 0.]),
+      VertexColor::new([1., 0., 0.]),
+    ),
+    ColoredVertex::new(
+      VertexPosition::new([1., 0., 0.]),
+      VertexColor::new([0., 1., 0.]),
+    ),
+    ColoredVertex::new(
+      VertexPosition::new([0., 1., 0.]),
+      VertexColor::new([0., 0., 1.]),
+    ),
+  ];
+  let mut tess = ctx
+    .new_tess()
+    .set_mode(Mode::TriangleStrip)
+    .set_vertices(vertices.clone())
+    .set_indices(vec![0u8, 1, 2, 255, 2, 1, 0])
+    .set_primitive_restart_index(255)
+    .build()
+    .unwrap();
+
+  let mut deinterleaved = tess.to_deinterleaved(&mut ctx).unwrap();
+
+  assert_eq!(deinterleaved.mode(), Mode::TriangleStrip);
+  assert_eq!(deinterleaved.render_vert_nb(), 7);
+  assert_eq!(
+    deinterleaved.vertices_all().unwrap().slices().color,
+    &[
+      VertexColor::new([1., 0., 0.]),
+      VertexColor::new([0., 1., 0.]),
+      VertexColor::new([0., 0., 1.]),
+    ]
+  );
+
+  let mut interleaved = deinterleaved.to_interleaved(&mut ctx).unwrap();
+
+  assert_eq!(interleaved.read_vertices().unwrap(), vertices);
+  assert_eq!(
+    interleaved.read_indices().unwrap(),
+    vec![0, 1, 2, 255, 2, 1, 0]
+  );
+}
+
+#[test]
+fn raw_tess() {
+  let mut ctx = Context(Memory);
+  let vertices = [
+    ColoredVertex::new(
+      VertexPosition::new([0., 0., 0.]),
+      VertexColor::new([1., 0., 0.]),
+    ),
+    ColoredVertex::new(
+      VertexPosition::new([1., 0., 0.]),
+      VertexColor::new([0., 1., 0.]),
+    ),
+    ColoredVertex::new(
+      VertexPosition::new([0., 1., 0.]),
+      VertexColor::new([0., 0., 1.]),
+    ),
+  ];
+  let bytes =
+    unsafe { slice::from_raw_parts(vertices.as_ptr() as *const u8, mem::size_of_val(&vertices)) };
+  let indices = [0u16, 1, 2, 2, 1, 0];
+  let index_bytes =
+    unsafe { slice::from_raw_parts(indices.as_ptr() as *const u8, mem::size_of_val(&indices)) };
+
+  let tess = ctx
+    .new_raw_tess(bytes, ColoredVertex::vertex_desc(), None, Mode::Triangle)
+    .unwrap();
+
+  assert_eq!(tess.mode(), Mode::Triangle);
+  assert_eq!(tess.vert_nb(), 3);
+  assert_eq!(tess.idx_nb(), 0);
+  assert_eq!(tess.render_vert_nb(), 3);
+
+  let tess = ctx
+    .new_raw_tess(
+      bytes,
+      ColoredVertex::vertex_desc(),
+      Some((index_bytes, TessIndexType::U16)),
+      Mode::Triangle,
+    )
+    .unwrap();
+
+  assert_eq!(tess.vert_nb(), 3);
+  assert_eq!(tess.idx_nb(), 6);
+  assert_eq!(tess.render_vert_nb(), 6);
+
+  assert!(matches!(
+    ctx.new_raw_tess(bytes, Vec::new(), None, Mode::Triangle),
+    Err(TessError::AttributelessError(_))
+  ));
+  assert_eq!(
+    ctx
+      .new_raw_tess(
+        &bytes[1..],
+        ColoredVertex::vertex_desc(),
+        None,
+        Mode::Triangle
+      )
+      .err(),
+    Some(TessError::length_incoherency(bytes.len() - 1))
+  );
+  assert_eq!(
+    ctx
+      .new_raw_tess(
+        bytes,
+        ColoredVertex::vertex_desc(),
+        Some((&index_bytes[1..], TessIndexType::U16)),
+        Mode::Triangle
+      )
+      .err(),
+    Some(TessError::length_incoherency(index_bytes.len() - 1))
+  );
+  assert_eq!(
+    ctx
+      .new_raw_tess(bytes, ColoredVertex::vertex_desc(), None, Mode::Patch(0))
+      .err(),
+    Some(TessError::forbidden_primitive_mode(Mode::Patch(0)))
+  );
+}
+
+#[cfg(feature = "debug-validate")]
+#[test]
+fn raw_tess_index_out_of_range() {
+  let mut ctx = Context(Memory);
+  let vertices = triangle();
+  let bytes =
+    unsafe { slice::from_raw_parts(vertices.as_ptr() as *const u8, mem::size_of_val(&*vertices)) };
+
+  assert_eq!(
+    ctx
+      .new_raw_tess(
+        bytes,
+        Vertex::vertex_desc(),
+        Some((&[0, 1, 3], TessIndexType::U8)),
+        Mode::Triangle
+      )
+      .err(),
+    Some(TessError::index_out_of_range(3, 3))
+  );
+}
+
+#[test]
+fn attributeless_instancing() {
+  let mut ctx = Context(Memory);
+  let tess = ctx
+    .new_tess()
+    .set_mode(Mode::Triangle)
+    .set_render_vertex_nb(3)
+    .set_render_instance_nb(100)
+    .build()
+    .unwrap();
+
+  assert_eq!(tess.vert_nb(), 0);
+  assert_eq!(tess.inst_nb(), 0);
+  assert_eq!(tess.render_vert_nb(), 3);
+  assert_eq!(tess.render_inst_nb(), 100);
+}
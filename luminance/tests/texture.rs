@@ -0,0 +1,389 @@
+use luminance::backend::framebuffer::Framebuffer as FramebufferBackend;
+use luminance::backend::texture::{Texture as TextureBackend, TextureBase};
+use luminance::backend::{color_slot::ColorSlot, depth_stencil_slot::DepthStencilSlot};
+use luminance::context::GraphicsContext;
+use luminance::framebuffer::{Framebuffer, FramebufferError, IncompleteReason};
+use luminance::pixel::{Depth32F, Depth32FStencil8, NormR8UI, NormRGBA8UI, Pixel, RGBA8UI};
+use luminance::texture::{
+  CubeFace, Cubemap, Dim2, Dim2Array, Dimensionable, Sampler, TexelUpload, Texture, TextureError,
+};
+
+/// A backend accepting any texture operation.
+struct Memory;
+
+struct Context(Memory);
+
+unsafe impl GraphicsContext for Context {
+  type Backend = Memory;
+
+  fn backend(&mut self) -> &mut Self::Backend {
+    &mut self.0
+  }
+}
+
+unsafe impl TextureBase for Memory {
+  type TextureRepr = ();
+}
+
+unsafe impl<D, P> TextureBackend<D, P> for Memory
+where
+  D: Dimensionable,
+  P: Pixel,
+{
+  unsafe fn new_texture(
+    &mut self,
+    _: D::Size,
+    _: Sampler,
+    _: TexelUpload<[P::Encoding]>,
+  ) -> Result<Self::TextureRepr, TextureError> {
+    Ok(())
+  }
+
+  unsafe fn new_texture_raw(
+    &mut self,
+    _: D::Size,
+    _: Sampler,
+    _: TexelUpload<[P::RawEncoding]>,
+  ) -> Result<Self::TextureRepr, TextureError> {
+    Ok(())
+  }
+
+  unsafe fn mipmaps(_: &Self::TextureRepr) -> usize {
+    0
+  }
+
+  unsafe fn generate_mipmaps(_: &mut Self::TextureRepr) -> Result<(), TextureError> {
+    Ok(())
+  }
+
+  unsafe fn upload_part(
+    _: &mut Self::TextureRepr,
+    _: D::Offset,
+    _: D::Size,
+    _: TexelUpload<[P::Encoding]>,
+  ) -> Result<(), TextureError> {
+    Ok(())
+  }
+
+  unsafe fn upload(
+    _: &mut Self::TextureRepr,
+    _: D::Size,
+    _: TexelUpload<[P::Encoding]>,
+  ) -> Result<(), TextureError> {
+    Ok(())
+  }
+
+  unsafe fn upload_part_raw(
+    _: &mut Self::TextureRepr,
+    _: D::Offset,
+    _: D::Size,
+    _: TexelUpload<[P::RawEncoding]>,
+  ) -> Result<(), TextureError> {
+    Ok(())
+  }
+
+  unsafe fn upload_raw(
+    _: &mut Self::TextureRepr,
+    _: D::Size,
+    _: TexelUpload<[P::RawEncoding]>,
+  ) -> Result<(), TextureError> {
+    Ok(())
+  }
+
+  unsafe fn get_raw_texels(
+    _: &Self::TextureRepr,
+    _: D::Size,
+  ) -> Result<Vec<P::RawEncoding>, TextureError>
+  where
+    P::RawEncoding: Copy + Default,
+  {
+    Ok(Vec::new())
+  }
+
+  unsafe fn resize(
+    _: &mut Self::TextureRepr,
+    _: D::Size,
+    _: TexelUpload<[P::Encoding]>,
+  ) -> Result<(), TextureError> {
+    Ok(())
+  }
+
+  unsafe fn resize_raw(
+    _: &mut Self::TextureRepr,
+    _: D::Size,
+    _: TexelUpload<[P::RawEncoding]>,
+  ) -> Result<(), TextureError> {
+    Ok(())
+  }
+}
+
+unsafe impl FramebufferBackend<Dim2> for Memory {
+  type FramebufferRepr = [u32; 2];
+
+  unsafe fn new_framebuffer<CS, DS>(
+    &mut self,
+    size: [u32; 2],
+    _: usize,
+    _: &Sampler,
+  ) -> Result<Self::FramebufferRepr, FramebufferError>
+  where
+    CS: ColorSlot<Self, Dim2>,
+    DS: DepthStencilSlot<Self, Dim2>,
+  {
+    Ok(size)
+  }
+
+  unsafe fn attach_color_texture(
+    _: &mut Self::FramebufferRepr,
+    _: &Self::TextureRepr,
+    _: usize,
+  ) -> Result<(), FramebufferError> {
+    Ok(())
+  }
+
+  unsafe fn attach_depth_texture(
+    _: &mut Self::FramebufferRepr,
+    _: &Self::TextureRepr,
+  ) -> Result<(), FramebufferError> {
+    Ok(())
+  }
+
+  unsafe fn validate_framebuffer(
+    framebuffer: Self::FramebufferRepr,
+  ) -> Result<Self::FramebufferRepr, FramebufferError> {
+    Ok(framebuffer)
+  }
+
+  unsafe fn framebuffer_size(framebuffer: &Self::FramebufferRepr) -> [u32; 2] {
+    *framebuffer
+  }
+}
+
+#[test]
+fn upload_part_in_bounds() {
+  let mut ctx = Context(Memory);
+  let mut texture: Texture<Memory, Dim2, NormRGBA8UI> = Texture::new(
+    &mut ctx,
+    [16, 8],
+    Sampler::default(),
+    TexelUpload::reserve(0),
+  )
+  .unwrap();
+  let texels = [[0; 4]; 16];
+
+  texture
+    .upload_part([12, 4], [4, 4], TexelUpload::base_level(&texels[..], 0))
+    .unwrap();
+  texture
+    .upload_part_raw([0, 0], [16, 8], TexelUpload::reserve(0))
+    .unwrap();
+}
+
+#[test]
+fn upload_part_out_of_bounds() {
+  let mut ctx = Context(Memory);
+  let mut texture: Texture<Memory, Dim2, NormRGBA8UI> = Texture::new(
+    &mut ctx,
+    [16, 8],
+    Sampler::default(),
+    TexelUpload::reserve(0),
+  )
+  .unwrap();
+  let texels = [[0; 4]; 16];
+
+  assert_eq!(
+    texture.upload_part([13, 4], [4, 4], TexelUpload::base_level(&texels[..], 0)),
+    Err(TextureError::RegionOutOfBounds)
+  );
+  assert_eq!(
+    texture.upload_part_raw([0, u32::MAX], [1, 1], TexelUpload::reserve(0)),
+    Err(TextureError::RegionOutOfBounds)
+  );
+}
+
+#[test]
+fn upload_part_layers_and_faces() {
+  let mut ctx = Context(Memory);
+  let mut array: Texture<Memory, Dim2Array, NormRGBA8UI> = Texture::new(
+    &mut ctx,
+    ([4, 4], 3),
+    Sampler::default(),
+    TexelUpload::reserve(0),
+  )
+  .unwrap();
+
+  assert!(array
+    .upload_part(([0, 0], 2), ([4, 4], 1), TexelUpload::reserve(0))
+    .is_ok());
+  assert_eq!(
+    array.upload_part(([0, 0], 2), ([4, 4], 2), TexelUpload::reserve(0)),
+    Err(TextureError::RegionOutOfBounds)
+  );
+
+  let mut cubemap: Texture<Memory, Cubemap, NormRGBA8UI> =
+    Texture::new(&mut ctx, 4, Sampler::default(), TexelUpload::reserve(0)).unwrap();
+
+  assert!(cubemap
+    .upload_part(([0, 0], CubeFace::NegativeZ), 4, TexelUpload::reserve(0))
+    .is_ok());
+  assert_eq!(
+    cubemap.upload_part(([1, 0], CubeFace::PositiveX), 4, TexelUpload::reserve(0)),
+    Err(TextureError::RegionOutOfBounds)
+  );
+}
+
+#[test]
+fn generate_mipmaps_filterable() {
+  let mut ctx = Context(Memory);
+  let mut color: Texture<Memory, Dim2, NormRGBA8UI> = Texture::new(
+    &mut ctx,
+    [16, 8],
+    Sampler::default(),
+    TexelUpload::reserve(4),
+  )
+  .unwrap();
+
+  assert!(color.generate_mipmaps().is_ok());
+
+  let mut integral: Texture<Memory, Dim2, RGBA8UI> = Texture::new(
+    &mut ctx,
+    [16, 8],
+    Sampler::default(),
+    TexelUpload::reserve(4),
+  )
+  .unwrap();
+
+  assert_eq!(
+    integral.generate_mipmaps(),
+    Err(TextureError::UnfilterablePixelFormat(
+      RGBA8UI::pixel_format()
+    ))
+  );
+
+  let mut depth: Texture<Memory, Dim2, Depth32F> = Texture::new(
+    &mut ctx,
+    [16, 8],
+    Sampler::default(),
+    TexelUpload::reserve(4),
+  )
+  .unwrap();
+
+  assert_eq!(
+    depth.generate_mipmaps(),
+    Err(TextureError::UnfilterablePixelFormat(
+      Depth32F::pixel_format()
+    ))
+  );
+
+  assert!(!Depth32FStencil8::pixel_format().is_filterable());
+}
+
+#[test]
+fn framebuffer_from_textures() {
+  let mut ctx = Context(Memory);
+  let color: Texture<Memory, Dim2, NormRGBA8UI> = Texture::new(
+    &mut ctx,
+    [16, 8],
+    Sampler::default(),
+    TexelUpload::reserve(0),
+  )
+  .unwrap();
+  let mask: Texture<Memory, Dim2, NormR8UI> = Texture::new(
+    &mut ctx,
+    [16, 8],
+    Sampler::default(),
+    TexelUpload::reserve(0),
+  )
+  .unwrap();
+  let depth: Texture<Memory, Dim2, Depth32F> = Texture::new(
+    &mut ctx,
+    [16, 8],
+    Sampler::default(),
+    TexelUpload::reserve(0),
+  )
+  .unwrap();
+
+  let framebuffer = Framebuffer::<_, Dim2, (NormRGBA8UI, NormR8UI), Depth32F>::from_textures(
+    &mut ctx,
+    (color, mask),
+    depth,
+  )
+  .unwrap();
+
+  assert_eq!(framebuffer.size(), [16, 8]);
+
+  // give the depth texture back and attach it to a new framebuffer, along with a new color texture
+  let (_, depth) = framebuffer.into_slots();
+  let color: Texture<Memory, Dim2, NormRGBA8UI> = Texture::new(
+    &mut ctx,
+    [16, 8],
+    Sampler::default(),
+    TexelUpload::reserve(0),
+  )
+  .unwrap();
+  let framebuffer = ctx
+    .new_framebuffer_from_textures::<Dim2, NormRGBA8UI, Depth32F>(color, depth)
+    .unwrap();
+
+  assert_eq!(framebuffer.size(), [16, 8]);
+
+  let (_, depth) = framebuffer.into_slots();
+  let color: Texture<Memory, Dim2, NormRGBA8UI> = Texture::new(
+    &mut ctx,
+    [8, 8],
+    Sampler::default(),
+    TexelUpload::reserve(0),
+  )
+  .unwrap();
+
+  assert_eq!(
+    Framebuffer::<_, Dim2, NormRGBA8UI, Depth32F>::from_textures(&mut ctx, color, depth).err(),
+    Some(FramebufferError::AttachmentSizeMismatch)
+  );
+  assert_eq!(
+    Framebuffer::<_, Dim2, (), ()>::from_textures(&mut ctx, (), ()).err(),
+    Some(FramebufferError::Incomplete(
+      IncompleteReason::MissingAttachment
+    ))
+  );
+}
+
+#[cfg(feature = "image")]
+#[test]
+fn texture_from_path() {
+  use luminance::pixel::{NormRGB8UI, RGBA32F};
+
+  let path = std::env::temp_dir().join("luminance_texture_from_path.png");
+  image::save_buffer(&path, &[255; 3 * 4 * 2], 3, 2, image::ColorType::Rgba8).unwrap();
+
+  let mut ctx = Context(Memory);
+
+  let texture: Texture<Memory, Dim2, NormRGBA8UI> =
+    Texture::from_path(&mut ctx, &path, Sampler::default(), 0).unwrap();
+  assert_eq!(texture.size(), [3, 2]);
+
+  let texture: Texture<Memory, Dim2, RGBA32F> =
+    Texture::from_path(&mut ctx, &path, Sampler::default(), 0).unwrap();
+  assert_eq!(texture.size(), [3, 2]);
+
+  assert_eq!(
+    Texture::<Memory, Dim2, NormRGB8UI>::from_path(&mut ctx, &path, Sampler::default(), 0).err(),
+    Some(TextureError::ImageChannelsMismatch {
+      expected_channels: 3,
+      provided_channels: 4,
+    })
+  );
+  assert_eq!(
+    Texture::<Memory, Dim2, Depth32F>::from_path(&mut ctx, &path, Sampler::default(), 0).err(),
+    Some(TextureError::UnsupportedPixelFormat(
+      Depth32F::pixel_format()
+    ))
+  );
+
+  std::fs::remove_file(&path).unwrap();
+
+  assert!(matches!(
+    Texture::<Memory, Dim2, NormRGBA8UI>::from_path(&mut ctx, &path, Sampler::default(), 0),
+    Err(TextureError::CannotLoadImage(_))
+  ));
+}
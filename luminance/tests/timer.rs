@@ -0,0 +1,128 @@
+use luminance::backend::timer::TimerQuery as TimerQueryBackend;
+use luminance::context::GraphicsContext;
+use luminance::timer::TimerQueryError;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Duration;
+
+/// A backend which GPU spends one millisecond per issued command.
+#[derive(Default)]
+struct Clock {
+  commands: u64,
+  active: bool,
+}
+
+impl Clock {
+  fn issue(&mut self, commands: u64) {
+    self.commands += commands;
+  }
+}
+
+struct ClockQuery {
+  start: u64,
+  elapsed: Option<Duration>,
+}
+
+unsafe impl TimerQueryBackend for Clock {
+  type TimerQueryRepr = ClockQuery;
+
+  unsafe fn new_timer_query(&mut self) -> Result<Self::TimerQueryRepr, TimerQueryError> {
+    Ok(ClockQuery {
+      start: 0,
+      elapsed: None,
+    })
+  }
+
+  unsafe fn begin_timer_query(
+    &mut self,
+    query: &mut Self::TimerQueryRepr,
+  ) -> Result<(), TimerQueryError> {
+    if self.active {
+      return Err(TimerQueryError::already_active());
+    }
+
+    self.active = true;
+    query.start = self.commands;
+    query.elapsed = None;
+
+    Ok(())
+  }
+
+  unsafe fn end_timer_query(&mut self, query: &mut Self::TimerQueryRepr) {
+    self.active = false;
+    query.elapsed = Some(Duration::from_millis(self.commands - query.start));
+  }
+
+  unsafe fn timer_query_elapsed(query: &Self::TimerQueryRepr) -> Option<Duration> {
+    query.elapsed
+  }
+}
+
+#[derive(Default)]
+struct Context(Clock);
+
+unsafe impl GraphicsContext for Context {
+  type Backend = Clock;
+
+  fn backend(&mut self) -> &mut Self::Backend {
+    &mut self.0
+  }
+}
+
+#[test]
+fn time_elapsed() {
+  let mut ctx = Context::default();
+  let mut query = ctx.new_timer_query().unwrap();
+
+  assert_eq!(query.elapsed(), None);
+
+  let (r, elapsed) = ctx
+    .time_elapsed(&mut query, |ctx| {
+      ctx.backend().issue(3);
+      "done"
+    })
+    .unwrap();
+
+  assert_eq!(r, "done");
+  assert_eq!(elapsed, Some(Duration::from_millis(3)));
+  assert_eq!(query.elapsed(), Some(Duration::from_millis(3)));
+
+  // measuring again discards the previous result
+  let (_, elapsed) = ctx.time_elapsed(&mut query, |_| ()).unwrap();
+  assert_eq!(elapsed, Some(Duration::ZERO));
+}
+
+#[test]
+fn nested_time_elapsed() {
+  let mut ctx = Context::default();
+  let mut outer = ctx.new_timer_query().unwrap();
+  let mut inner = ctx.new_timer_query().unwrap();
+
+  let (nested, elapsed) = ctx
+    .time_elapsed(&mut outer, |ctx| {
+      ctx.backend().issue(2);
+      ctx.time_elapsed(&mut inner, |_| unreachable!())
+    })
+    .unwrap();
+
+  assert_eq!(nested.err(), Some(TimerQueryError::AlreadyActive));
+  assert_eq!(elapsed, Some(Duration::from_millis(2)));
+  assert_eq!(inner.elapsed(), None);
+}
+
+#[test]
+fn time_elapsed_panicking() {
+  let mut ctx = Context::default();
+  let mut query = ctx.new_timer_query().unwrap();
+
+  let r = panic::catch_unwind(AssertUnwindSafe(|| {
+    let _ = ctx.time_elapsed(&mut query, |ctx| {
+      ctx.backend().issue(1);
+      panic!("oops");
+    });
+  }));
+
+  assert!(r.is_err());
+  assert!(!ctx.backend().active);
+  assert_eq!(query.elapsed(), Some(Duration::from_millis(1)));
+  assert!(ctx.time_elapsed(&mut query, |_| ()).is_ok());
+}
@@ -39,3 +39,17 @@ fn derive_unbound_renamed_uniform_interface() {
     _t2: Uniform<f32>,
   }
 }
+
+#[test]
+fn derive_array_uniform_interface() {
+  use luminance::shader::types::{Arr, Vec3};
+
+  // only checks that the derive compiles; the interface is never built
+  #[allow(dead_code)]
+  #[derive(UniformInterface)]
+  struct SimpleUniformInterface {
+    _light_positions: Uniform<Arr<Vec3<f32>, 8>>,
+    #[uniform(name = "weights", unbound)]
+    _w: Uniform<Arr<f32, 4>>,
+  }
+}
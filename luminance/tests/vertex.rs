@@ -1,7 +1,9 @@
 #![cfg(feature = "derive")]
 
 use luminance::vertex::{
-  HasSemantics, Semantics, Vertex, VertexAttrib, VertexBufferDesc, VertexInstancing,
+  interleaved_layout, split_buffer_groups, Deinterleave, DeinterleaveAll, FlattenVertex,
+  HasSemantics, Semantics, Vertex, VertexAttrib, VertexAttribDesc, VertexAttribDim,
+  VertexBufferDesc, VertexInstancing,
 };
 use luminance::{Semantics, Vertex};
 
@@ -80,3 +82,449 @@ fn derive_struct_tuple_vertex() {
     #[vertex(normalized = "true")] VertexColor,
   );
 }
+
+#[test]
+fn derive_buffer_groups() {
+  #[derive(Clone, Copy, Debug, Eq, PartialEq, Semantics)]
+  pub enum Semantics {
+    #[sem(name = "position", repr = "[f32; 3]", wrapper = "VertexPosition")]
+    Position,
+    #[sem(name = "normal", repr = "[f32; 3]", wrapper = "VertexNormal")]
+    Normal,
+    #[sem(name = "color", repr = "[f32; 4]", wrapper = "VertexColor")]
+    Color,
+  }
+
+  #[derive(Clone, Copy, Debug, Vertex)]
+  #[repr(C)]
+  #[vertex(sem = "Semantics")]
+  struct Vertex {
+    pos: VertexPosition,
+    #[vertex(buffer = "1")]
+    nor: VertexNormal,
+    #[vertex(buffer = "1")]
+    col: VertexColor,
+  }
+
+  let expected_desc = vec![
+    VertexBufferDesc::new(
+      Semantics::Position,
+      VertexInstancing::Off,
+      <[f32; 3] as VertexAttrib>::VERTEX_ATTRIB_DESC,
+    ),
+    VertexBufferDesc::new(
+      Semantics::Normal,
+      VertexInstancing::Off,
+      <[f32; 3] as VertexAttrib>::VERTEX_ATTRIB_DESC,
+    )
+    .with_buffer(1),
+    VertexBufferDesc::new(
+      Semantics::Color,
+      VertexInstancing::Off,
+      <[f32; 4] as VertexAttrib>::VERTEX_ATTRIB_DESC,
+    )
+    .with_buffer(1),
+  ];
+
+  assert_eq!(Vertex::vertex_desc(), expected_desc);
+}
+
+#[test]
+fn split_padded_buffer_groups() {
+  #[derive(Clone, Copy, Debug, Eq, PartialEq, Semantics)]
+  pub enum Semantics {
+    #[sem(name = "position", repr = "[f32; 2]", wrapper = "VertexPosition")]
+    Position,
+    #[sem(name = "weight", repr = "u8", wrapper = "VertexWeight")]
+    Weight,
+    #[sem(name = "scale", repr = "f32", wrapper = "VertexScale")]
+    Scale,
+  }
+
+  #[derive(Clone, Copy, Debug, Vertex)]
+  #[repr(C)]
+  #[vertex(sem = "Semantics")]
+  struct Vertex {
+    #[vertex(buffer = "1")]
+    weight: VertexWeight,
+    pos: VertexPosition,
+    #[vertex(buffer = "1")]
+    scale: VertexScale,
+  }
+
+  // the weight is followed by 3 bytes of padding
+  assert_eq!(
+    interleaved_layout(&Vertex::vertex_desc()),
+    (vec![0, 4, 12], std::mem::size_of::<Vertex>())
+  );
+
+  let vertices = [
+    Vertex::new(
+      VertexWeight::new(1),
+      VertexPosition::new([2., 3.]),
+      VertexScale::new(4.),
+    ),
+    Vertex::new(
+      VertexWeight::new(5),
+      VertexPosition::new([6., 7.]),
+      VertexScale::new(8.),
+    ),
+  ];
+  let groups = split_buffer_groups(&vertices, &Vertex::vertex_desc());
+
+  let bytes = |floats: &[f32]| -> Vec<u8> { floats.iter().flat_map(|f| f.to_ne_bytes()).collect() };
+  let group1 = |weight: u8, scale: f32| -> Vec<u8> {
+    let mut raw = vec![weight, 0, 0, 0];
+    raw.extend(bytes(&[scale]));
+    raw
+  };
+
+  assert_eq!(groups.len(), 2);
+  assert_eq!(groups[0].0, vec![Vertex::vertex_desc()[1]]);
+  assert_eq!(groups[0].1, bytes(&[2., 3., 6., 7.]));
+  assert_eq!(
+    groups[1].0,
+    vec![Vertex::vertex_desc()[0], Vertex::vertex_desc()[2]]
+  );
+  assert_eq!(groups[1].1, [group1(1, 4.), group1(5, 8.)].concat());
+}
+
+#[test]
+fn derive_flattened_fields() {
+  #[derive(Clone, Copy, Debug, Eq, PartialEq, Semantics)]
+  pub enum Semantics {
+    #[sem(name = "position", repr = "[f32; 3]", wrapper = "VertexPosition")]
+    Position,
+    #[sem(name = "normal", repr = "[f32; 3]", wrapper = "VertexNormal")]
+    Normal,
+    #[sem(name = "color", repr = "[f32; 4]", wrapper = "VertexColor")]
+    Color,
+    #[sem(name = "weight", repr = "f32", wrapper = "VertexWeight")]
+    Weight,
+  }
+
+  #[derive(Clone, Copy, Debug, Vertex)]
+  #[repr(C)]
+  #[vertex(sem = "Semantics", slice_set)]
+  struct Transform {
+    pos: VertexPosition,
+    nor: VertexNormal,
+  }
+
+  #[derive(Clone, Copy, Debug, Vertex)]
+  #[repr(C)]
+  #[vertex(sem = "Semantics", instanced = "true", slice_set)]
+  struct Instance {
+    col: VertexColor,
+    #[vertex(flatten, buffer = "1")]
+    transform: Transform,
+    weight: VertexWeight,
+  }
+
+  let expected_desc = vec![
+    VertexBufferDesc::new(
+      Semantics::Color,
+      VertexInstancing::On,
+      <[f32; 4] as VertexAttrib>::VERTEX_ATTRIB_DESC,
+    ),
+    VertexBufferDesc::new(
+      Semantics::Position,
+      VertexInstancing::On,
+      <[f32; 3] as VertexAttrib>::VERTEX_ATTRIB_DESC,
+    )
+    .with_buffer(1),
+    VertexBufferDesc::new(
+      Semantics::Normal,
+      VertexInstancing::On,
+      <[f32; 3] as VertexAttrib>::VERTEX_ATTRIB_DESC,
+    )
+    .with_buffer(1),
+    VertexBufferDesc::new(
+      Semantics::Weight,
+      VertexInstancing::On,
+      <f32 as VertexAttrib>::VERTEX_ATTRIB_DESC,
+    ),
+  ];
+
+  assert_eq!(Instance::vertex_desc(), expected_desc);
+  assert_eq!(<Transform as FlattenVertex>::ATTRIB_NB, 2);
+  assert_eq!(<Instance as Deinterleave<VertexColor>>::RANK, 0);
+  assert_eq!(<Instance as Deinterleave<VertexWeight>>::RANK, 3);
+
+  let cols = [VertexColor::new([1., 0., 0., 1.])];
+  let positions = [VertexPosition::new([1., 2., 3.])];
+  let normals = [VertexNormal::new([0., 1., 0.])];
+  let weights = [VertexWeight::new(0.5)];
+  let attributes: [&[u8]; 4] = unsafe {
+    [
+      as_bytes(&cols),
+      as_bytes(&positions),
+      as_bytes(&normals),
+      as_bytes(&weights),
+    ]
+  };
+
+  let slice_set = unsafe { Instance::slice_set(|rank| attributes[rank]) };
+  assert_eq!(slice_set.col[0].repr, cols[0].repr);
+  assert_eq!(slice_set.transform.pos[0].repr, positions[0].repr);
+  assert_eq!(slice_set.transform.nor[0].repr, normals[0].repr);
+  assert_eq!(slice_set.weight[0].repr, weights[0].repr);
+}
+
+#[test]
+fn flattened_layout() {
+  #[derive(Clone, Copy, Debug, Eq, PartialEq, Semantics)]
+  pub enum Semantics {
+    #[sem(name = "position", repr = "[f32; 3]", wrapper = "VertexPosition")]
+    Position,
+    #[sem(name = "weight", repr = "u8", wrapper = "VertexWeight")]
+    Weight,
+  }
+
+  // trailing padding after the weight
+  #[derive(Clone, Copy, Debug, Vertex)]
+  #[repr(C)]
+  #[vertex(sem = "Semantics")]
+  struct Padded {
+    pos: VertexPosition,
+    weight: VertexWeight,
+  }
+
+  // more aligned than the weight
+  #[derive(Clone, Copy, Debug, Vertex)]
+  #[repr(C)]
+  #[vertex(sem = "Semantics")]
+  struct Overaligned {
+    weight: VertexWeight,
+    pos: VertexPosition,
+  }
+
+  assert_eq!(std::mem::size_of::<Padded>(), 16);
+  assert_eq!(<Padded as FlattenVertex>::ATTRIBS_SIZE, 13);
+  assert_eq!(<Padded as FlattenVertex>::FIRST_ATTRIB_ALIGN, 4);
+
+  assert_eq!(std::mem::align_of::<Overaligned>(), 4);
+  assert_eq!(<Overaligned as FlattenVertex>::ATTRIBS_SIZE, 16);
+  assert_eq!(<Overaligned as FlattenVertex>::FIRST_ATTRIB_ALIGN, 1);
+}
+
+#[test]
+fn flattened_semantics() {
+  #[derive(Clone, Copy, Debug, Eq, PartialEq, Semantics)]
+  pub enum Semantics {
+    #[sem(name = "position", repr = "[f32; 3]", wrapper = "VertexPosition")]
+    Position,
+    #[sem(name = "normal", repr = "[f32; 3]", wrapper = "VertexNormal")]
+    Normal,
+  }
+
+  #[derive(Clone, Copy, Debug, Vertex)]
+  #[repr(C)]
+  #[vertex(sem = "Semantics")]
+  struct Transform {
+    nor: VertexNormal,
+    pos: VertexPosition,
+  }
+
+  assert_eq!(
+    <Transform as FlattenVertex>::SEMANTICS,
+    &[Semantics::Normal, Semantics::Position]
+  );
+}
+
+#[test]
+#[should_panic(expected = "misaligned bytes")]
+fn slice_set_rejects_misaligned_bytes() {
+  #[derive(Clone, Copy, Debug, Eq, PartialEq, Semantics)]
+  pub enum Semantics {
+    #[sem(name = "weight", repr = "f32", wrapper = "VertexWeight")]
+    Weight,
+  }
+
+  #[derive(Clone, Copy, Debug, Vertex)]
+  #[repr(C)]
+  #[vertex(sem = "Semantics", slice_set)]
+  struct Vertex {
+    weight: VertexWeight,
+  }
+
+  let words = [0u32; 2];
+  let bytes = unsafe { &as_bytes(&words)[1..5] };
+
+  let _ = unsafe { Vertex::slice_set(|_| bytes) };
+}
+
+unsafe fn as_bytes<T>(slice: &[T]) -> &[u8] {
+  std::slice::from_raw_parts(slice.as_ptr() as *const u8, std::mem::size_of_val(slice))
+}
+
+#[test]
+fn derive_explicit_locations() {
+  #[derive(Clone, Copy, Debug, Eq, PartialEq, Semantics)]
+  pub enum Semantics {
+    #[sem(name = "position", repr = "[f32; 3]", wrapper = "VertexPosition")]
+    Position,
+    #[sem(name = "model", repr = "[[f32; 4]; 4]", wrapper = "VertexModel")]
+    Model,
+    #[sem(name = "color", repr = "[f32; 4]", wrapper = "VertexColor")]
+    Color,
+  }
+
+  #[derive(Clone, Copy, Debug, Vertex)]
+  #[repr(C)]
+  #[vertex(sem = "Semantics")]
+  struct Vertex {
+    #[vertex(location = 6)]
+    pos: VertexPosition,
+    #[vertex(location = "7")]
+    model: VertexModel,
+    col: VertexColor,
+  }
+
+  let desc = Vertex::vertex_desc();
+  assert_eq!(desc[0].index, 6);
+  assert_eq!(desc[1].index, 7);
+  // the model semantics shifts the color one by its 4 columns
+  assert_eq!(desc[2].index, Semantics::Color.index());
+  assert_eq!(desc[2].index, 5);
+  assert_eq!(
+    desc[0],
+    VertexBufferDesc::new(
+      Semantics::Position,
+      VertexInstancing::Off,
+      <[f32; 3] as VertexAttrib>::VERTEX_ATTRIB_DESC,
+    )
+    .with_location(6)
+  );
+}
+
+#[test]
+#[should_panic(
+  expected = "the locations of the color attribute overlap the locations of the model attribute"
+)]
+fn explicit_locations_overlapping_semantics_indices() {
+  #[derive(Clone, Copy, Debug, Eq, PartialEq, Semantics)]
+  pub enum Semantics {
+    #[sem(name = "position", repr = "[f32; 3]", wrapper = "VertexPosition")]
+    Position,
+    #[sem(name = "model", repr = "[[f32; 4]; 4]", wrapper = "VertexModel")]
+    Model,
+    #[sem(name = "color", repr = "[f32; 4]", wrapper = "VertexColor")]
+    Color,
+  }
+
+  // the model occupies the locations 3 to 6, overlapping the index of the color semantics
+  #[derive(Clone, Copy, Debug, Vertex)]
+  #[repr(C)]
+  #[vertex(sem = "Semantics")]
+  struct Vertex {
+    pos: VertexPosition,
+    #[vertex(location = 3)]
+    model: VertexModel,
+    col: VertexColor,
+  }
+
+  Vertex::vertex_desc();
+}
+
+#[test]
+fn vertex_attrib_desc_size() {
+  assert_eq!(<f32 as VertexAttrib>::VERTEX_ATTRIB_DESC.size(), 4);
+  assert_eq!(<[f32; 3] as VertexAttrib>::VERTEX_ATTRIB_DESC.size(), 12);
+  assert_eq!(<[u8; 4] as VertexAttrib>::VERTEX_ATTRIB_DESC.size(), 4);
+  assert_eq!(<[i16; 2] as VertexAttrib>::VERTEX_ATTRIB_DESC.size(), 4);
+}
+
+#[test]
+fn derive_matrix_semantics() {
+  #[derive(Clone, Copy, Debug, Eq, PartialEq, Semantics)]
+  pub enum Semantics {
+    #[sem(name = "position", repr = "[f32; 3]", wrapper = "VertexPosition")]
+    Position,
+    #[sem(name = "model", repr = "[[f32; 4]; 4]", wrapper = "InstanceModel")]
+    Model,
+    #[sem(
+      name = "normal_matrix",
+      repr = "[[f32; 3]; 3]",
+      wrapper = "InstanceNormal"
+    )]
+    NormalMatrix,
+    #[sem(name = "color", repr = "[f32; 4]", wrapper = "InstanceColor")]
+    Color,
+  }
+
+  #[derive(Clone, Copy, Debug, Vertex)]
+  #[repr(C)]
+  #[vertex(sem = "Semantics", instanced = "true")]
+  struct Instance {
+    model: InstanceModel,
+    normal: InstanceNormal,
+    color: InstanceColor,
+  }
+
+  // matrices occupy one location per column
+  assert_eq!(Semantics::Position.index(), 0);
+  assert_eq!(Semantics::Model.index(), 1);
+  assert_eq!(Semantics::NormalMatrix.index(), 5);
+  assert_eq!(Semantics::Color.index(), 8);
+
+  let indices: Vec<_> = Semantics::semantics_set()
+    .into_iter()
+    .map(|desc| desc.index)
+    .collect();
+  assert_eq!(indices, vec![0, 1, 5, 8]);
+
+  let model_desc = <[[f32; 4]; 4] as VertexAttrib>::VERTEX_ATTRIB_DESC;
+  assert_eq!(model_desc.dim, VertexAttribDim::Mat4);
+  assert_eq!(model_desc.locations(), 4);
+  assert_eq!(model_desc.size(), 64);
+
+  let normal_desc = <[[f32; 3]; 3] as VertexAttrib>::VERTEX_ATTRIB_DESC;
+  assert_eq!(normal_desc.dim.column_size(), 3);
+  assert_eq!(normal_desc.size(), 36);
+
+  assert_eq!(Instance::vertex_desc()[1].index, 5);
+}
+
+#[test]
+fn semantics_repr_vertex_attrib_desc() {
+  #[derive(Clone, Copy, Debug, Eq, PartialEq, Semantics)]
+  pub enum Semantics {
+    #[sem(name = "position", repr = "[f32; 3]", wrapper = "VertexPosition")]
+    Position,
+    #[sem(name = "color", repr = "[u8; 4]", wrapper = "VertexColor")]
+    Color,
+  }
+
+  // hand-written attribute of the position semantics, without any declared representation
+  #[derive(Clone, Copy, Debug)]
+  #[repr(C)]
+  struct RawPosition([i32; 3]);
+
+  unsafe impl VertexAttrib for RawPosition {
+    const VERTEX_ATTRIB_DESC: VertexAttribDesc = <[i32; 3] as VertexAttrib>::VERTEX_ATTRIB_DESC;
+  }
+
+  impl HasSemantics for RawPosition {
+    type Sem = Semantics;
+
+    const SEMANTICS: Self::Sem = Semantics::Position;
+  }
+
+  const POSITION_DESC: Option<VertexAttribDesc> = VertexPosition::REPR_VERTEX_ATTRIB_DESC;
+  const COLOR_DESC: Option<VertexAttribDesc> = VertexColor::REPR_VERTEX_ATTRIB_DESC;
+
+  let position_desc = POSITION_DESC.unwrap();
+  let color_desc = COLOR_DESC.unwrap();
+
+  assert_eq!(
+    position_desc,
+    <[f32; 3] as VertexAttrib>::VERTEX_ATTRIB_DESC
+  );
+  assert_eq!(color_desc, <[u8; 4] as VertexAttrib>::VERTEX_ATTRIB_DESC);
+  assert_eq!(RawPosition::REPR_VERTEX_ATTRIB_DESC, None);
+
+  assert!(position_desc.same_format(&VertexPosition::VERTEX_ATTRIB_DESC));
+  assert!(!position_desc.same_format(&<[f32; 4] as VertexAttrib>::VERTEX_ATTRIB_DESC));
+  assert!(!color_desc.same_format(&color_desc.normalize()));
+}